@@ -0,0 +1,160 @@
+//! Benchmarks for the per-frame hot paths of a round: looking up segment
+//! targets on the movement trail, scanning long chains for merges and placing
+//! options on a crowded board.
+//!
+//! Run with `cargo bench --features bench`.
+
+use std::{collections::HashSet, hint::black_box, time::Duration};
+
+use bevy::prelude::*;
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use konnektoren_chain_game::bench::*;
+use rand::{SeedableRng, rngs::StdRng};
+
+const MAP_WIDTH: f32 = 2000.0;
+const MAP_HEIGHT: f32 = 1600.0;
+const SEGMENT_SPACING: f32 = 25.0;
+
+/// A trail winding across the map and around its right edge
+fn winding_trail(points: usize) -> MovementTrail {
+    let mut trail = MovementTrail::default();
+    trail.max_trail_length = points;
+    for i in 0..points {
+        let t = i as f32 * 0.05;
+        let x = (i as f32 * 6.0) % MAP_WIDTH - MAP_WIDTH / 2.0;
+        trail.add_position(Vec2::new(x, t.sin() * 300.0));
+    }
+    trail
+}
+
+fn trail_lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("trail_lookup");
+    for points in [100, 1000] {
+        let mut trail = winding_trail(points);
+        group.bench_with_input(BenchmarkId::new("linear", points), &trail, |b, trail| {
+            b.iter(|| {
+                for segment in 0..100 {
+                    black_box(trail.get_position_at_distance_with_wraparound(
+                        (segment + 1) as f32 * SEGMENT_SPACING,
+                        MAP_WIDTH,
+                        MAP_HEIGHT,
+                    ));
+                }
+            })
+        });
+
+        trail.rebuild_distance_index(MAP_WIDTH / 2.0, MAP_HEIGHT / 2.0);
+        group.bench_with_input(BenchmarkId::new("indexed", points), &trail, |b, trail| {
+            b.iter(|| {
+                for segment in 0..100 {
+                    black_box(trail.get_position_at_distance_with_wraparound(
+                        (segment + 1) as f32 * SEGMENT_SPACING,
+                        MAP_WIDTH,
+                        MAP_HEIGHT,
+                    ));
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+/// A world with `players` players holding `length` segments each. Options
+/// alternate so no merge is ever found and every window gets checked.
+fn merge_world(players: usize, length: usize) -> World {
+    let mut world = World::new();
+    world.insert_resource(Time::<()>::default());
+    world.init_resource::<Events<ChainMergeEvent>>();
+    world.init_resource::<ChainMode>();
+    world.init_resource::<GameConfig>();
+
+    let mut merge_state = ChainMergeState {
+        merge_cooldown: Timer::from_seconds(0.0, TimerMode::Once),
+        ..default()
+    };
+    merge_state.merge_cooldown.tick(Duration::ZERO);
+    world.insert_resource(merge_state);
+
+    for _ in 0..players {
+        let player = world.spawn(Player).id();
+        let segments = (0..length)
+            .map(|index| {
+                world
+                    .spawn((
+                        ChainSegment::new(
+                            index,
+                            format!("option {}", index % 2),
+                            index % 2,
+                            Color::WHITE,
+                        ),
+                        PlayerChainSegment(player),
+                    ))
+                    .id()
+            })
+            .collect();
+        world.entity_mut(player).insert(PlayerChain {
+            segments,
+            max_segments: length,
+        });
+    }
+    world
+}
+
+fn merge_detection(c: &mut Criterion) {
+    let mut group = c.benchmark_group("merge_detection");
+    for length in [20, 100] {
+        let mut world = merge_world(4, length);
+        let mut schedule = Schedule::default();
+        schedule.add_systems(detect_chain_merges);
+
+        group.bench_function(BenchmarkId::from_parameter(length), |b| {
+            b.iter(|| schedule.run(&mut world))
+        });
+    }
+    group.finish();
+}
+
+fn spawn_placement(c: &mut Criterion) {
+    let mut group = c.benchmark_group("spawn_placement");
+    let grid_map = GridMap::from_config(&MapConfig::new(120, 96).with_obstacle_rect(
+        40,
+        30,
+        40,
+        36,
+        GridCellType::Wall,
+    ));
+    let avoid_positions: Vec<Vec2> = (0..400)
+        .map(|i| {
+            Vec2::new(
+                (i % 40) as f32 * 30.0 - 600.0,
+                (i / 40) as f32 * 30.0 - 150.0,
+            )
+        })
+        .collect();
+
+    let config = OptionSpawnConfig::default();
+    for occupied_share in [10, 60] {
+        let occupied: HashSet<(usize, usize)> = (0..grid_map.width)
+            .flat_map(|x| (0..grid_map.height).map(move |y| (x, y)))
+            .filter(|(x, y)| (x * 31 + y * 17) % 100 < occupied_share)
+            .collect();
+        let mut rng = StdRng::seed_from_u64(7);
+
+        group.bench_function(BenchmarkId::new("occupied_percent", occupied_share), |b| {
+            b.iter(|| {
+                black_box(find_empty_spawn_position(
+                    &grid_map,
+                    &occupied,
+                    &avoid_positions,
+                    None,
+                    &config,
+                    &mut rng,
+                ))
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, trail_lookup, merge_detection, spawn_placement);
+criterion_main!(benches);
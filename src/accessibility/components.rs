@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use crate::locale::Language;
+
+/// Event to read a line out loud with the platform's text-to-speech
+#[derive(Event, Debug, Clone)]
+pub struct SpeakEvent {
+    pub text: String,
+    /// Language of the text, picks the voice
+    pub language: Language,
+}
+
+/// Event to show an on-screen caption for something the player might not hear
+#[derive(Event, Debug, Clone)]
+pub struct CaptionEvent(pub String);
+
+/// Container the captions are stacked in
+#[derive(Component)]
+pub struct CaptionContainer;
+
+/// A single caption line, removed when its timer runs out
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Caption {
+    pub timer: Timer,
+}
+
+impl Caption {
+    pub fn new() -> Self {
+        Self {
+            timer: Timer::from_seconds(super::CAPTION_DURATION, TimerMode::Once),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+
+mod components;
+mod speech;
+mod systems;
+
+pub use components::*;
+use systems::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Caption>();
+
+    app.add_event::<SpeakEvent>();
+    app.add_event::<CaptionEvent>();
+
+    app.init_resource::<speech::SpeechSynthesizer>();
+
+    app.add_systems(
+        OnEnter(crate::screens::Screen::Gameplay),
+        setup_caption_container,
+    );
+
+    app.add_systems(
+        Update,
+        (
+            announce_question,
+            announce_collected_options,
+            caption_game_events,
+        )
+            .in_set(crate::AppSystems::Update)
+            .run_if(in_state(crate::screens::Screen::Gameplay)),
+    );
+
+    // Speech and captions keep working while paused so queued lines are not lost
+    app.add_systems(
+        Update,
+        (speak_queued_text, (show_captions, expire_captions).chain()),
+    );
+}
+
+// Caption constants
+pub const CAPTION_DURATION: f32 = 3.0; // Seconds a caption stays on screen
+pub const MAX_CAPTIONS: usize = 3;
+pub const CAPTION_FONT_SIZE: f32 = 18.0;
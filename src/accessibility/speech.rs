@@ -0,0 +1,82 @@
+//! Platform text-to-speech: the browser's SpeechSynthesis on the web, the
+//! system speech command on desktop.
+
+use bevy::prelude::*;
+
+use crate::locale::Language;
+
+/// Speaks one line at a time, a new line cuts off the previous one
+#[derive(Resource, Default)]
+pub struct SpeechSynthesizer {
+    #[cfg(not(target_family = "wasm"))]
+    current: Option<std::process::Child>,
+}
+
+impl SpeechSynthesizer {
+    #[cfg(target_family = "wasm")]
+    pub fn speak(&mut self, text: &str, language: Language) {
+        let Some(synthesis) = web_sys::window().and_then(|window| window.speech_synthesis().ok())
+        else {
+            warn!("Speech synthesis is not available in this browser");
+            return;
+        };
+
+        synthesis.cancel();
+        match web_sys::SpeechSynthesisUtterance::new_with_text(text) {
+            Ok(utterance) => {
+                utterance.set_lang(language_tag(language));
+                synthesis.speak(&utterance);
+            }
+            Err(err) => warn!("Failed to create speech utterance: {:?}", err),
+        }
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    pub fn speak(&mut self, text: &str, language: Language) {
+        use std::io::Write;
+        use std::process::{Command, Stdio};
+
+        if let Some(mut previous) = self.current.take() {
+            let _ = previous.kill();
+        }
+
+        // The text goes through stdin where possible so it is never parsed as
+        // command arguments
+        let spawned = if cfg!(target_os = "macos") {
+            Command::new("say").stdin(Stdio::piped()).spawn()
+        } else if cfg!(target_os = "windows") {
+            Command::new("powershell")
+                .args([
+                    "-NoProfile",
+                    "-Command",
+                    "Add-Type -AssemblyName System.Speech; \
+                     (New-Object System.Speech.Synthesis.SpeechSynthesizer).Speak([Console]::In.ReadToEnd())",
+                ])
+                .stdin(Stdio::piped())
+                .spawn()
+        } else {
+            Command::new("spd-say")
+                .args(["--language", &language_tag(language)[..2], "--", text])
+                .stdin(Stdio::null())
+                .spawn()
+        };
+
+        match spawned {
+            Ok(mut child) => {
+                if let Some(mut stdin) = child.stdin.take() {
+                    let _ = stdin.write_all(text.as_bytes());
+                }
+                self.current = Some(child);
+            }
+            Err(err) => warn!("Text-to-speech is not available: {}", err),
+        }
+    }
+}
+
+/// BCP 47 tag for the voice of a language
+fn language_tag(language: Language) -> &'static str {
+    match language {
+        Language::English => "en-US",
+        Language::German => "de-DE",
+    }
+}
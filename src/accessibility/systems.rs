@@ -0,0 +1,185 @@
+use super::{components::*, speech::SpeechSynthesizer};
+use crate::{
+    chain::{ChainMergeCompletedEvent, ChainReactionEvent},
+    gameplay::GameTimerEvent,
+    locale::{Language, Locale},
+    player::OptionCollectedEvent,
+    question::{QuestionChangedEvent, QuestionSystem},
+    screens::Screen,
+    settings::GameSettings,
+};
+use bevy::prelude::*;
+
+/// Challenge content is German, so it is always read with a German voice
+const CONTENT_LANGUAGE: Language = Language::German;
+
+/// System to spawn the caption area at the bottom of the screen
+pub fn setup_caption_container(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Captions"),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(90.0),
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(4.0),
+            ..default()
+        },
+        Pickable::IGNORE,
+        CaptionContainer,
+        StateScoped(Screen::Gameplay),
+    ));
+}
+
+/// System to read each new question out loud
+pub fn announce_question(
+    game_settings: Res<GameSettings>,
+    question_system: Option<Res<QuestionSystem>>,
+    mut question_events: EventReader<QuestionChangedEvent>,
+    mut speak_events: EventWriter<SpeakEvent>,
+) {
+    if question_events.read().count() == 0 || !game_settings.accessibility.read_questions {
+        return;
+    }
+
+    let Some(question) = question_system
+        .as_ref()
+        .and_then(|system| system.get_current_question())
+    else {
+        return;
+    };
+
+    speak_events.write(SpeakEvent {
+        text: question.question.clone(),
+        language: CONTENT_LANGUAGE,
+    });
+}
+
+/// System to announce collected options
+pub fn announce_collected_options(
+    game_settings: Res<GameSettings>,
+    mut collected_events: EventReader<OptionCollectedEvent>,
+    mut speak_events: EventWriter<SpeakEvent>,
+) {
+    for event in collected_events.read() {
+        if game_settings.accessibility.announce_collections {
+            speak_events.write(SpeakEvent {
+                text: event.option_text.clone(),
+                language: CONTENT_LANGUAGE,
+            });
+        }
+    }
+}
+
+/// System to turn game events into captions
+pub fn caption_game_events(
+    locale: Res<Locale>,
+    mut collected_events: EventReader<OptionCollectedEvent>,
+    mut reaction_events: EventReader<ChainReactionEvent>,
+    mut merge_events: EventReader<ChainMergeCompletedEvent>,
+    mut timer_events: EventReader<GameTimerEvent>,
+    mut caption_events: EventWriter<CaptionEvent>,
+) {
+    for event in collected_events.read() {
+        let key = if event.is_correct {
+            "a11y.collected_correct"
+        } else {
+            "a11y.collected_wrong"
+        };
+        caption_events.write(CaptionEvent(
+            locale.format(key, &[("option", &event.option_text)]),
+        ));
+    }
+
+    if reaction_events.read().count() > 0 {
+        caption_events.write(CaptionEvent(locale.text("a11y.chain_reaction").to_string()));
+    }
+
+    for event in merge_events.read() {
+        caption_events.write(CaptionEvent(
+            locale.format("a11y.merge", &[("level", &event.new_level)]),
+        ));
+    }
+
+    for event in timer_events.read() {
+        match event {
+            GameTimerEvent::GameEnded => {
+                caption_events.write(CaptionEvent(locale.text("a11y.round_over").to_string()));
+            }
+            GameTimerEvent::SuddenDeath => {
+                caption_events.write(CaptionEvent(locale.text("a11y.sudden_death").to_string()));
+            }
+        }
+    }
+}
+
+/// System to hand queued lines to the speech synthesizer, newest wins
+pub fn speak_queued_text(
+    mut speak_events: EventReader<SpeakEvent>,
+    mut synthesizer: ResMut<SpeechSynthesizer>,
+) {
+    if let Some(event) = speak_events.read().last() {
+        synthesizer.speak(&event.text, event.language);
+    }
+}
+
+/// System to add caption lines, dropping the oldest beyond the limit
+pub fn show_captions(
+    mut commands: Commands,
+    game_settings: Res<GameSettings>,
+    mut caption_events: EventReader<CaptionEvent>,
+    container_query: Query<(Entity, Option<&Children>), With<CaptionContainer>>,
+) {
+    if !game_settings.accessibility.captions {
+        caption_events.clear();
+        return;
+    }
+
+    let Ok((container, children)) = container_query.single() else {
+        caption_events.clear();
+        return;
+    };
+
+    let mut shown: Vec<Entity> = children.map(|c| c.to_vec()).unwrap_or_default();
+
+    for event in caption_events.read() {
+        if shown.len() >= super::MAX_CAPTIONS {
+            commands.entity(shown.remove(0)).try_despawn();
+        }
+
+        let caption = commands
+            .spawn((
+                Name::new("Caption"),
+                Text(event.0.clone()),
+                TextFont {
+                    font_size: super::CAPTION_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                Node {
+                    padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.75)),
+                BorderRadius::all(Val::Px(4.0)),
+                Caption::new(),
+            ))
+            .id();
+        commands.entity(container).add_child(caption);
+        shown.push(caption);
+    }
+}
+
+/// System to remove captions whose time is up
+pub fn expire_captions(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut caption_query: Query<(Entity, &mut Caption)>,
+) {
+    for (entity, mut caption) in &mut caption_query {
+        if caption.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
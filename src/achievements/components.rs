@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashSet};
+
+/// All achievements that can be unlocked
+#[derive(
+    Reflect, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum Achievement {
+    FirstGame,
+    Streak,
+    LevelThreeMerge,
+    CleanGame,
+    LongChain,
+    HundredCorrect,
+}
+
+impl Achievement {
+    pub const ALL: [Achievement; 6] = [
+        Achievement::FirstGame,
+        Achievement::Streak,
+        Achievement::LevelThreeMerge,
+        Achievement::CleanGame,
+        Achievement::LongChain,
+        Achievement::HundredCorrect,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Achievement::FirstGame => "First Round",
+            Achievement::Streak => "10-Streak",
+            Achievement::LevelThreeMerge => "Level-3 Merge",
+            Achievement::CleanGame => "Untouchable",
+            Achievement::LongChain => "Long Chain",
+            Achievement::HundredCorrect => "Century",
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            Achievement::FirstGame => "Finish your first game".to_string(),
+            Achievement::Streak => format!(
+                "Answer {} questions correctly in a row",
+                super::STREAK_ACHIEVEMENT
+            ),
+            Achievement::LevelThreeMerge => "Merge segments up to level 3".to_string(),
+            Achievement::CleanGame => "Survive a game without hitting your chain".to_string(),
+            Achievement::LongChain => {
+                format!(
+                    "Build a chain of {} segments",
+                    super::LONG_CHAIN_ACHIEVEMENT
+                )
+            }
+            Achievement::HundredCorrect => format!(
+                "Give {} correct answers in total",
+                super::CORRECT_ANSWERS_ACHIEVEMENT
+            ),
+        }
+    }
+}
+
+/// Resource with the achievements unlocked on this device
+#[derive(Resource, Reflect, Clone, Debug, Default, Serialize, Deserialize)]
+#[reflect(Resource)]
+pub struct UnlockedAchievements {
+    pub unlocked: BTreeSet<Achievement>,
+}
+
+impl UnlockedAchievements {
+    pub fn is_unlocked(&self, achievement: Achievement) -> bool {
+        self.unlocked.contains(&achievement)
+    }
+
+    /// Unlock an achievement, returning `true` if it was newly unlocked
+    pub fn unlock(&mut self, achievement: Achievement) -> bool {
+        self.unlocked.insert(achievement)
+    }
+
+    pub fn load() -> Self {
+        crate::storage::load(super::ACHIEVEMENTS_STORAGE_KEY).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        crate::storage::save(super::ACHIEVEMENTS_STORAGE_KEY, self);
+    }
+}
+
+/// Event sent when an achievement is unlocked for the first time
+#[derive(Event, Debug)]
+pub struct AchievementUnlockedEvent {
+    pub achievement: Achievement,
+}
+
+/// Resource tracking which players ran into their own chain this round
+#[derive(Resource, Reflect, Default, Debug)]
+#[reflect(Resource)]
+pub struct ChainHitTracker {
+    pub players: HashSet<Entity>,
+}
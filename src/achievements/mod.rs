@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+
+mod components;
+mod systems;
+
+pub use components::*;
+use systems::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<UnlockedAchievements>();
+    app.register_type::<ChainHitTracker>();
+
+    app.init_resource::<UnlockedAchievements>();
+    app.init_resource::<ChainHitTracker>();
+
+    app.add_event::<AchievementUnlockedEvent>();
+
+    app.add_systems(Startup, load_achievements);
+    app.add_systems(OnEnter(crate::screens::Screen::Gameplay), reset_chain_hits);
+
+    app.add_systems(
+        Update,
+        (
+            (
+                check_streak_achievements,
+                check_merge_achievements,
+                check_statistics_achievements,
+                track_chain_hits,
+                check_game_end_achievements,
+            )
+                .in_set(crate::AppSystems::Update)
+                .run_if(in_state(crate::screens::Screen::Gameplay)),
+            announce_achievements.run_if(on_event::<AchievementUnlockedEvent>),
+        )
+            .chain(),
+    );
+}
+
+pub const ACHIEVEMENTS_STORAGE_KEY: &str = "achievements";
+pub const STREAK_ACHIEVEMENT: u32 = 10;
+pub const CORRECT_ANSWERS_ACHIEVEMENT: u32 = 100;
+pub const LONG_CHAIN_ACHIEVEMENT: usize = 15;
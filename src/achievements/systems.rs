@@ -0,0 +1,161 @@
+use super::components::*;
+use crate::{
+    bot::BotController,
+    chain::{ChainMergeCompletedEvent, ChainReactionEvent},
+    gameplay::{GameTimerEvent, GameplayScore},
+    notifications::NotificationEvent,
+    stats::PlayerStatistics,
+};
+use bevy::prelude::*;
+
+/// System to load the unlocked achievements at startup
+pub fn load_achievements(mut commands: Commands) {
+    let achievements = UnlockedAchievements::load();
+    info!(
+        "Loaded {} unlocked achievements",
+        achievements.unlocked.len()
+    );
+    commands.insert_resource(achievements);
+}
+
+fn unlock(
+    achievements: &mut UnlockedAchievements,
+    achievement: Achievement,
+    unlocked_events: &mut EventWriter<AchievementUnlockedEvent>,
+) {
+    if achievements.unlock(achievement) {
+        unlocked_events.write(AchievementUnlockedEvent { achievement });
+    }
+}
+
+/// System to clear chain hits at the start of a round
+pub fn reset_chain_hits(mut tracker: ResMut<ChainHitTracker>) {
+    tracker.players.clear();
+}
+
+/// System to unlock the streak achievement for human players
+pub fn check_streak_achievements(
+    gameplay_score: Res<GameplayScore>,
+    mut achievements: ResMut<UnlockedAchievements>,
+    mut unlocked_events: EventWriter<AchievementUnlockedEvent>,
+    bot_query: Query<(), With<BotController>>,
+) {
+    if !gameplay_score.is_changed() || achievements.is_unlocked(Achievement::Streak) {
+        return;
+    }
+
+    let reached = gameplay_score.players.iter().any(|(entity, score)| {
+        !bot_query.contains(*entity) && score.best_streak >= super::STREAK_ACHIEVEMENT
+    });
+    if reached {
+        unlock(&mut achievements, Achievement::Streak, &mut unlocked_events);
+    }
+}
+
+/// System to unlock the level-3 merge achievement
+pub fn check_merge_achievements(
+    mut merge_events: EventReader<ChainMergeCompletedEvent>,
+    mut achievements: ResMut<UnlockedAchievements>,
+    mut unlocked_events: EventWriter<AchievementUnlockedEvent>,
+    bot_query: Query<(), With<BotController>>,
+) {
+    for event in merge_events.read() {
+        if event.new_level >= 3 && !bot_query.contains(event.player_entity) {
+            unlock(
+                &mut achievements,
+                Achievement::LevelThreeMerge,
+                &mut unlocked_events,
+            );
+        }
+    }
+}
+
+/// System to unlock achievements based on lifetime statistics
+pub fn check_statistics_achievements(
+    statistics: Res<PlayerStatistics>,
+    mut achievements: ResMut<UnlockedAchievements>,
+    mut unlocked_events: EventWriter<AchievementUnlockedEvent>,
+) {
+    if !statistics.is_changed() {
+        return;
+    }
+
+    if statistics.correct_answers >= super::CORRECT_ANSWERS_ACHIEVEMENT {
+        unlock(
+            &mut achievements,
+            Achievement::HundredCorrect,
+            &mut unlocked_events,
+        );
+    }
+    if statistics.longest_chain >= super::LONG_CHAIN_ACHIEVEMENT {
+        unlock(
+            &mut achievements,
+            Achievement::LongChain,
+            &mut unlocked_events,
+        );
+    }
+}
+
+/// System to remember which players hit their own chain
+pub fn track_chain_hits(
+    mut reaction_events: EventReader<ChainReactionEvent>,
+    mut tracker: ResMut<ChainHitTracker>,
+) {
+    for event in reaction_events.read() {
+        tracker.players.insert(event.player_entity);
+    }
+}
+
+/// System to unlock achievements for finishing a game
+pub fn check_game_end_achievements(
+    mut timer_events: EventReader<GameTimerEvent>,
+    gameplay_score: Res<GameplayScore>,
+    tracker: Res<ChainHitTracker>,
+    mut achievements: ResMut<UnlockedAchievements>,
+    mut unlocked_events: EventWriter<AchievementUnlockedEvent>,
+    bot_query: Query<(), With<BotController>>,
+) {
+    for event in timer_events.read() {
+        match event {
+            GameTimerEvent::GameEnded => {
+                unlock(
+                    &mut achievements,
+                    Achievement::FirstGame,
+                    &mut unlocked_events,
+                );
+
+                // Only counts if the player actually played the round
+                let clean = gameplay_score.players.iter().any(|(entity, score)| {
+                    !bot_query.contains(*entity)
+                        && score.correct_answers > 0
+                        && !tracker.players.contains(entity)
+                });
+                if clean {
+                    unlock(
+                        &mut achievements,
+                        Achievement::CleanGame,
+                        &mut unlocked_events,
+                    );
+                }
+            }
+            GameTimerEvent::SuddenDeath => {}
+        }
+    }
+}
+
+/// System to show a toast and store achievements when they are unlocked
+pub fn announce_achievements(
+    mut unlocked_events: EventReader<AchievementUnlockedEvent>,
+    achievements: Res<UnlockedAchievements>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    for event in unlocked_events.read() {
+        info!("Achievement unlocked: {}", event.achievement.title());
+        notifications.write(NotificationEvent::new(format!(
+            "Achievement unlocked: {}",
+            event.achievement.title()
+        )));
+    }
+
+    achievements.save();
+}
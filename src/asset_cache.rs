@@ -0,0 +1,119 @@
+//! Shared meshes and materials for chain segments, flying objects and
+//! options. Entities that look the same use one asset instead of each adding
+//! their own; entries no entity holds any more are dropped periodically.
+//!
+//! Materials handed out here are shared, so they must never be changed in
+//! place. Entities that animate their material keep a material of their own.
+
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use bevy::{prelude::*, time::common_conditions::on_timer};
+
+use crate::palette::{OptionMarker, OptionStyleRegistry, shape_mesh};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<SharedAssetCache>();
+
+    app.add_systems(
+        Update,
+        prune_shared_assets
+            .in_set(crate::AppSystems::Update)
+            .run_if(on_timer(Duration::from_secs_f32(
+                ASSET_CACHE_PRUNE_INTERVAL,
+            ))),
+    );
+}
+
+/// Shape and size of a cached mesh. The radius is kept in hundredths so it
+/// can be hashed; segment levels map onto it through `ChainSegment::get_radius`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct MeshKey {
+    shape: Option<OptionMarker>,
+    radius: u32,
+}
+
+impl MeshKey {
+    fn new(shape: Option<OptionMarker>, radius: f32) -> Self {
+        Self {
+            shape,
+            radius: (radius * 100.0).round() as u32,
+        }
+    }
+}
+
+/// Resource holding the shared mesh and material handles
+#[derive(Resource, Default, Debug)]
+pub struct SharedAssetCache {
+    meshes: HashMap<MeshKey, Handle<Mesh>>,
+    materials: HashMap<[u8; 4], Handle<ColorMaterial>>,
+}
+
+impl SharedAssetCache {
+    /// Mesh for an option id at `radius`, shaped like the option's marker
+    pub fn option_mesh(
+        &mut self,
+        styles: &OptionStyleRegistry,
+        option_id: usize,
+        radius: f32,
+        meshes: &mut Assets<Mesh>,
+    ) -> Handle<Mesh> {
+        self.shape_mesh(styles.shape(option_id), radius, meshes)
+    }
+
+    /// Plain circle mesh at `radius`
+    pub fn circle_mesh(&mut self, radius: f32, meshes: &mut Assets<Mesh>) -> Handle<Mesh> {
+        self.shape_mesh(None, radius, meshes)
+    }
+
+    /// Mesh of a marker shape at `radius`, or a circle without one
+    pub fn shape_mesh(
+        &mut self,
+        shape: Option<OptionMarker>,
+        radius: f32,
+        meshes: &mut Assets<Mesh>,
+    ) -> Handle<Mesh> {
+        self.meshes
+            .entry(MeshKey::new(shape, radius))
+            .or_insert_with(|| meshes.add(shape_mesh(shape, radius)))
+            .clone()
+    }
+
+    /// Shared material of a solid color. Do not change it in place.
+    pub fn material(
+        &mut self,
+        color: Color,
+        materials: &mut Assets<ColorMaterial>,
+    ) -> Handle<ColorMaterial> {
+        self.materials
+            .entry(color.to_srgba().to_u8_array())
+            .or_insert_with(|| materials.add(ColorMaterial::from(color)))
+            .clone()
+    }
+
+    /// Drop entries that only the cache still holds, returning how many were removed
+    fn prune(&mut self) -> usize {
+        let before = self.meshes.len() + self.materials.len();
+        self.meshes.retain(|_, handle| is_in_use(handle));
+        self.materials.retain(|_, handle| is_in_use(handle));
+        before - self.meshes.len() - self.materials.len()
+    }
+}
+
+/// Whether anything besides the cache holds a strong handle to the asset
+fn is_in_use<A: Asset>(handle: &Handle<A>) -> bool {
+    match handle {
+        Handle::Strong(strong) => Arc::strong_count(strong) > 1,
+        Handle::Weak(_) => false,
+    }
+}
+
+/// System to release cached assets once the entities using them are despawned
+fn prune_shared_assets(mut cache: ResMut<SharedAssetCache>) {
+    let removed = cache.prune();
+    if removed > 0 {
+        debug!("Released {} unused shared assets", removed);
+    }
+}
+
+// Asset cache constants
+pub const ASSET_CACHE_PRUNE_INTERVAL: f32 = 5.0; // Seconds between unused-asset sweeps
@@ -14,6 +14,13 @@ pub trait LoadResource {
     /// have been loaded, it will be inserted as a resource. This ensures that the resource only
     /// exists when the assets are ready.
     fn load_resource<T: Resource + Asset + Clone + FromWorld>(&mut self) -> &mut Self;
+
+    /// Like [`LoadResource::load_resource`], but the [`Asset`] is read from a file. The
+    /// resource is replaced whenever the asset changes, so hot reloading reaches it too.
+    fn load_resource_from_path<T: Resource + Asset + Clone>(
+        &mut self,
+        path: &'static str,
+    ) -> &mut Self;
 }
 
 impl LoadResource for App {
@@ -34,6 +41,25 @@ impl LoadResource for App {
             }));
         self
     }
+
+    fn load_resource_from_path<T: Resource + Asset + Clone>(
+        &mut self,
+        path: &'static str,
+    ) -> &mut Self {
+        let world = self.world_mut();
+        let handle: Handle<T> = world.resource::<AssetServer>().load(path);
+        let mut handles = world.resource_mut::<ResourceHandles>();
+        handles
+            .waiting
+            .push_back((handle.untyped(), |world, handle| {
+                let assets = world.resource::<Assets<T>>();
+                if let Some(value) = assets.get(handle.id().typed::<T>()) {
+                    world.insert_resource(value.clone());
+                }
+            }));
+        self.add_systems(PreUpdate, reload_resource_asset::<T>);
+        self
+    }
 }
 
 /// A function that inserts a loaded resource.
@@ -45,6 +71,41 @@ pub struct ResourceHandles {
     // `finished` one at a time.
     waiting: VecDeque<(UntypedHandle, InsertLoadedResource)>,
     finished: Vec<UntypedHandle>,
+    /// Paths of assets that failed to load and fell back to defaults
+    failed: Vec<String>,
+}
+
+impl ResourceHandles {
+    /// Number of assets done loading, failed ones included
+    pub fn loaded(&self) -> usize {
+        self.finished.len()
+    }
+
+    pub fn total(&self) -> usize {
+        self.finished.len() + self.waiting.len()
+    }
+
+    pub fn is_all_done(&self) -> bool {
+        self.waiting.is_empty()
+    }
+
+    pub fn failed(&self) -> &[String] {
+        &self.failed
+    }
+
+    /// Paths of the assets still loading
+    pub fn waiting_paths(&self) -> Vec<String> {
+        self.waiting
+            .iter()
+            .map(|(handle, _)| asset_name(handle))
+            .collect()
+    }
+}
+
+fn asset_name(handle: &UntypedHandle) -> String {
+    handle
+        .path()
+        .map_or_else(|| format!("{:?}", handle.id()), |path| path.to_string())
 }
 
 fn load_resource_assets(world: &mut World) {
@@ -55,6 +116,11 @@ fn load_resource_assets(world: &mut World) {
                 if assets.is_loaded_with_dependencies(&handle) {
                     insert_fn(world, &handle);
                     resource_handles.finished.push(handle);
+                } else if assets.load_state(&handle).is_failed() {
+                    // Keep the resource's defaults rather than block loading forever
+                    warn!("Failed to load {:?}, using defaults", handle.path());
+                    resource_handles.failed.push(asset_name(&handle));
+                    resource_handles.finished.push(handle);
                 } else {
                     resource_handles.waiting.push_back((handle, insert_fn));
                 }
@@ -62,3 +128,19 @@ fn load_resource_assets(world: &mut World) {
         });
     });
 }
+
+/// Replace a resource loaded from a file when its asset changes
+fn reload_resource_asset<T: Resource + Asset + Clone>(
+    mut commands: Commands,
+    mut asset_events: EventReader<AssetEvent<T>>,
+    assets: Res<Assets<T>>,
+) {
+    for event in asset_events.read() {
+        if let AssetEvent::Modified { id } = event {
+            if let Some(value) = assets.get(*id) {
+                commands.insert_resource(value.clone());
+                info!("Reloaded {}", T::short_type_path());
+            }
+        }
+    }
+}
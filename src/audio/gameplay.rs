@@ -1,6 +1,7 @@
 use crate::{
     asset_tracking::LoadResource,
     audio::{music, sound_effect},
+    chain::ChainStealEvent,
     player::OptionCollectedEvent,
 };
 use bevy::prelude::*;
@@ -17,7 +18,8 @@ pub(super) fn plugin(app: &mut App) {
 
     app.add_systems(
         Update,
-        handle_option_collection_audio.run_if(in_state(crate::screens::Screen::Gameplay)),
+        (handle_option_collection_audio, handle_chain_steal_audio)
+            .run_if(in_state(crate::screens::Screen::Gameplay)),
     );
 }
 
@@ -96,3 +98,21 @@ fn handle_option_collection_audio(
         );
     }
 }
+
+/// System to play audio feedback when a chain is stolen
+fn handle_chain_steal_audio(
+    mut commands: Commands,
+    mut steal_events: EventReader<ChainStealEvent>,
+    gameplay_audio: Option<Res<GameplayAudioAssets>>,
+) {
+    let Some(audio_assets) = gameplay_audio else {
+        return;
+    };
+
+    for _ in steal_events.read() {
+        commands.spawn((
+            Name::new("Chain Steal Sound"),
+            sound_effect(audio_assets.incorrect_sound.clone()),
+        ));
+    }
+}
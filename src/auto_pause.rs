@@ -0,0 +1,126 @@
+//! Pause when the game goes into the background. On the web that's the tab
+//! being hidden, where the round would otherwise keep ticking (and the music
+//! keep playing) unseen; on native it's the window losing focus. A round in
+//! progress drops into the pause menu and all audio is suspended. Coming back
+//! resumes the audio where it stopped, while the round waits in the pause
+//! menu for the players to continue.
+
+use bevy::prelude::*;
+
+use crate::screens::PauseRequested;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<AppBackground>();
+
+    #[cfg(target_family = "wasm")]
+    app.add_systems(Startup, listen_for_visibility_changes);
+
+    app.add_systems(
+        Update,
+        (detect_background_changes, suspend_in_background).chain(),
+    );
+}
+
+/// Resource tracking whether the game is in the background
+#[derive(Resource, Default)]
+pub struct AppBackground {
+    pub hidden: bool,
+    /// Latest visibility reported by the browser, picked up on the next update
+    #[cfg(target_family = "wasm")]
+    reported: std::sync::Arc<std::sync::Mutex<Option<bool>>>,
+    /// Sinks that were playing when the game went into the background
+    suspended_sinks: Vec<Entity>,
+}
+
+#[cfg(target_family = "wasm")]
+fn listen_for_visibility_changes(background: Res<AppBackground>) {
+    use wasm_bindgen::{JsCast, prelude::Closure};
+
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return;
+    };
+
+    let reported = background.reported.clone();
+    let listener_document = document.clone();
+    let listener = Closure::<dyn FnMut()>::new(move || {
+        if let Ok(mut reported) = reported.lock() {
+            *reported = Some(listener_document.hidden());
+        }
+    });
+
+    if let Err(err) = document
+        .add_event_listener_with_callback("visibilitychange", listener.as_ref().unchecked_ref())
+    {
+        warn!("Failed to listen for visibility changes: {:?}", err);
+        return;
+    }
+    // The listener lives as long as the page
+    listener.forget();
+}
+
+/// The hidden tab on the web
+#[cfg(target_family = "wasm")]
+fn detect_background_changes(mut background: ResMut<AppBackground>) {
+    let reported = background
+        .reported
+        .lock()
+        .ok()
+        .and_then(|mut reported| reported.take());
+    if let Some(hidden) = reported.filter(|hidden| *hidden != background.hidden) {
+        background.hidden = hidden;
+    }
+}
+
+/// The unfocused window on native
+#[cfg(not(target_family = "wasm"))]
+fn detect_background_changes(
+    mut focus_events: EventReader<bevy::window::WindowFocused>,
+    primary_window: Query<(), With<bevy::window::PrimaryWindow>>,
+    mut background: ResMut<AppBackground>,
+) {
+    let Some(focused) = focus_events
+        .read()
+        .filter(|event| primary_window.contains(event.window))
+        .last()
+        .map(|event| event.focused)
+    else {
+        return;
+    };
+    let hidden = !focused;
+    if background.hidden != hidden {
+        background.hidden = hidden;
+    }
+}
+
+fn suspend_in_background(
+    mut background: ResMut<AppBackground>,
+    mut pause_requests: EventWriter<PauseRequested>,
+    sink_query: Query<(Entity, &AudioSink)>,
+) {
+    if !background.is_changed() || background.is_added() {
+        return;
+    }
+
+    if background.hidden {
+        info!("Game went into the background, pausing");
+        // Only honored in an interactive round without a menu open
+        pause_requests.write(PauseRequested);
+
+        // Bookkeeping only, it mustn't look like another visibility change
+        background.bypass_change_detection().suspended_sinks = sink_query
+            .iter()
+            .filter(|(_, sink)| !sink.is_paused())
+            .map(|(entity, sink)| {
+                sink.pause();
+                entity
+            })
+            .collect();
+    } else {
+        info!("Game came back from the background");
+        for entity in std::mem::take(&mut background.bypass_change_detection().suspended_sinks) {
+            if let Ok((_, sink)) = sink_query.get(entity) {
+                sink.play();
+            }
+        }
+    }
+}
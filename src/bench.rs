@@ -0,0 +1,14 @@
+//! Hot paths re-exported for the criterion benchmarks in `benches/`. Only
+//! built with the `bench` feature; the game keeps these modules private.
+//! Run them with `cargo bench --features bench`.
+
+pub use crate::{
+    chain::{
+        ChainMergeEvent, ChainMergeState, ChainMode, ChainSegment, MovementTrail, PlayerChain,
+        PlayerChainSegment, detect_chain_merges,
+    },
+    config::{GameConfig, OptionSpawnConfig},
+    map::{GridCellType, GridMap, MapConfig},
+    options::find_empty_spawn_position,
+    player::Player,
+};
@@ -0,0 +1,29 @@
+use crate::settings::Difficulty;
+use bevy::prelude::*;
+
+/// Component for computer-controlled players
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct BotController {
+    pub difficulty: Difficulty,
+    /// Delay between target decisions, simulating reaction time
+    pub reaction_timer: Timer,
+    /// Option the bot is currently heading for
+    pub target: Option<Entity>,
+    /// Direction used while there is nothing to collect
+    pub wander_direction: Vec2,
+}
+
+impl BotController {
+    pub fn new(difficulty: Difficulty) -> Self {
+        Self {
+            difficulty,
+            reaction_timer: Timer::from_seconds(
+                difficulty.bot_reaction_delay(),
+                TimerMode::Repeating,
+            ),
+            target: None,
+            wander_direction: Vec2::X,
+        }
+    }
+}
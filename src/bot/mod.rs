@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+mod components;
+mod systems;
+
+pub use components::*;
+use systems::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<BotController>();
+
+    // Bots take over their slots once the players have been spawned
+    app.add_systems(
+        OnEnter(crate::screens::Screen::Gameplay),
+        setup_bots.after(crate::player::spawn_player),
+    );
+
+    app.add_systems(
+        Update,
+        (choose_bot_targets, steer_bots)
+            .chain()
+            .in_set(crate::AppSystems::RecordInput)
+            .run_if(in_state(crate::screens::Screen::Gameplay))
+            .in_set(crate::PausableSystems),
+    );
+}
+
+// Configuration constants for bots
+pub const BOT_IGNORED_SEGMENTS: usize = 2; // Segments right behind the bot can't be hit
+pub const BOT_CHAIN_AVOID_RADIUS: f32 = 60.0;
+pub const BOT_CHAIN_AVOID_WEIGHT: f32 = 2.0;
+pub const BOT_OBSTACLE_LOOKAHEAD: f32 = 30.0; // How far ahead bots look for walls
@@ -0,0 +1,150 @@
+use super::components::*;
+use crate::{
+    chain::{ChainSegment, PlayerChain},
+    map::GridMap,
+    options::OptionCollectible,
+    player::{PLAYER_SIZE, Player, PlayerIndex},
+    screens::GameplayMode,
+    settings::GameSettings,
+};
+use bevy::prelude::*;
+use konnektoren_bevy::input::{InputController, PlayerInputMapping};
+use rand::Rng;
+
+/// System to hand the bot player slots over to `BotController`s
+pub fn setup_bots(
+    mut commands: Commands,
+    game_settings: Res<GameSettings>,
+    mode: Option<Res<State<GameplayMode>>>,
+    player_query: Query<(Entity, &PlayerIndex), With<Player>>,
+) {
+    let multiplayer = &game_settings.multiplayer;
+    // The attract mode demo is played by bots only
+    let demo = mode.is_some_and(|mode| *mode.get() == GameplayMode::Demo);
+
+    for (entity, player_index) in &player_query {
+        if !demo && !multiplayer.is_bot(player_index.0) {
+            continue;
+        }
+
+        // Bots get their input from the steering systems instead of a device
+        commands
+            .entity(entity)
+            .remove::<PlayerInputMapping>()
+            .insert((
+                BotController::new(multiplayer.bot_difficulty),
+                Name::new(format!("Bot {}", player_index.0 + 1)),
+            ));
+
+        info!(
+            "Player slot {} is controlled by a {} bot",
+            player_index.0 + 1,
+            multiplayer.bot_difficulty.name()
+        );
+    }
+}
+
+/// System to let bots pick the option they want to collect next
+pub fn choose_bot_targets(
+    time: Res<Time>,
+    mut bot_query: Query<(&mut BotController, &Transform), With<Player>>,
+    option_query: Query<(Entity, &Transform, &OptionCollectible), Without<Player>>,
+) {
+    let mut rng = rand::thread_rng();
+
+    for (mut bot, bot_transform) in &mut bot_query {
+        bot.reaction_timer.tick(time.delta());
+
+        let target_gone = bot
+            .target
+            .is_some_and(|target| !option_query.contains(target));
+        if !bot.reaction_timer.just_finished() && !target_gone {
+            continue;
+        }
+
+        // Occasionally go for a wrong answer on purpose
+        let wants_correct = !rng.gen_bool(bot.difficulty.bot_mistake_rate() as f64);
+        let bot_pos = bot_transform.translation.xy();
+
+        let nearest = |want_correct: bool| {
+            option_query
+                .iter()
+                .filter(|(_, _, option)| option.is_correct == want_correct)
+                .min_by(|(_, a, _), (_, b, _)| {
+                    let da = bot_pos.distance_squared(a.translation.xy());
+                    let db = bot_pos.distance_squared(b.translation.xy());
+                    da.total_cmp(&db)
+                })
+                .map(|(entity, _, _)| entity)
+        };
+
+        bot.target = nearest(wants_correct).or_else(|| nearest(!wants_correct));
+
+        if bot.target.is_none() {
+            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+            bot.wander_direction = Vec2::from_angle(angle);
+        }
+    }
+}
+
+/// System to steer bots toward their target while keeping clear of their own chain
+pub fn steer_bots(
+    grid_map: Option<Res<GridMap>>,
+    mut bot_query: Query<
+        (
+            &BotController,
+            &Transform,
+            &PlayerChain,
+            &mut InputController,
+        ),
+        With<Player>,
+    >,
+    target_query: Query<&Transform, (With<OptionCollectible>, Without<Player>)>,
+    segment_query: Query<(&ChainSegment, &Transform), Without<Player>>,
+) {
+    let Some(grid_map) = grid_map else {
+        return;
+    };
+
+    for (bot, bot_transform, chain, mut input) in &mut bot_query {
+        let bot_pos = bot_transform.translation.xy();
+
+        let mut desired = bot
+            .target
+            .and_then(|target| target_query.get(target).ok())
+            .map(|target| {
+                grid_map
+                    .wrapped_offset(bot_pos, target.translation.xy())
+                    .normalize_or_zero()
+            })
+            .unwrap_or(bot.wander_direction);
+
+        // Push away from nearby segments of the bot's own chain
+        for &segment_entity in &chain.segments {
+            let Ok((segment, segment_transform)) = segment_query.get(segment_entity) else {
+                continue;
+            };
+            if segment.segment_index < super::BOT_IGNORED_SEGMENTS {
+                continue;
+            }
+
+            let away = grid_map.wrapped_offset(segment_transform.translation.xy(), bot_pos);
+            let distance = away.length();
+            if distance > 0.0 && distance < super::BOT_CHAIN_AVOID_RADIUS {
+                let strength = 1.0 - distance / super::BOT_CHAIN_AVOID_RADIUS;
+                desired += away / distance * strength * super::BOT_CHAIN_AVOID_WEIGHT;
+            }
+        }
+
+        // Steer around walls instead of pushing into them
+        let movement = grid_map.steer_around_obstacles(
+            bot_pos,
+            desired.normalize_or_zero(),
+            super::BOT_OBSTACLE_LOOKAHEAD,
+            PLAYER_SIZE * 0.7,
+        );
+        if input.movement != movement {
+            input.movement = movement;
+        }
+    }
+}
@@ -0,0 +1,281 @@
+//! Self-serve bug reports: a redacted snapshot of settings, round and recent
+//! events that is saved locally and pre-filled into a new GitHub issue.
+//!
+//! With the `bug-report-upload` feature the snapshot is also posted as JSON to
+//! `BUG_REPORT_UPLOAD_URL`, when a build sets one.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::{
+    chain::ChainReactionEvent,
+    gameplay::GameTimerEvent,
+    notifications::NotificationEvent,
+    player::{OptionCollectedEvent, PlayerRespawnEvent},
+    round::RoundInfo,
+    screens::Screen,
+    settings::GameSettings,
+    url::percent_encode,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<RecentEventLog>();
+    app.add_event::<BugReportRequested>();
+
+    app.add_systems(
+        Update,
+        (
+            record_recent_events,
+            create_bug_report.run_if(on_event::<BugReportRequested>),
+        )
+            .chain(),
+    );
+}
+
+/// Event to capture a bug report snapshot and open the issue page
+#[derive(Event, Debug)]
+pub struct BugReportRequested;
+
+/// Rolling log of the last few gameplay events, attached to bug reports
+#[derive(Resource, Default, Debug)]
+pub struct RecentEventLog {
+    entries: VecDeque<String>,
+}
+
+impl RecentEventLog {
+    pub fn push(&mut self, time: f32, entry: impl Into<String>) {
+        if self.entries.len() == RECENT_EVENT_LIMIT {
+            self.entries.pop_front();
+        }
+        self.entries
+            .push_back(format!("[{:>7.2}s] {}", time, entry.into()));
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &String> {
+        self.entries.iter()
+    }
+}
+
+/// Snapshot attached to a bug report. Player names are left out on purpose.
+#[derive(Serialize, Debug)]
+pub struct BugReport {
+    pub game_version: String,
+    pub platform: String,
+    pub screen: String,
+    pub round: Option<BugReportRound>,
+    pub settings: BugReportSettings,
+    pub recent_events: Vec<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct BugReportRound {
+    pub share_code: String,
+    pub challenge_id: String,
+    pub seed: u64,
+}
+
+#[derive(Serialize, Debug)]
+pub struct BugReportSettings {
+    pub difficulty: String,
+    pub map_preset: String,
+    pub respawn_after_chain_reaction: bool,
+    pub reaction_spread: String,
+    pub hazards: bool,
+    pub learning_feedback: bool,
+    pub multiplayer: bool,
+    pub player_count: usize,
+    pub bot_count: usize,
+    pub bot_difficulty: String,
+    pub chain_steal_enabled: bool,
+    pub chain_steal_segments: usize,
+    pub coop_shared_chain: bool,
+    pub match_rounds: u32,
+    pub master_volume: f32,
+    pub show_fps: bool,
+}
+
+impl BugReportSettings {
+    fn from_settings(settings: &GameSettings) -> Self {
+        Self {
+            difficulty: format!("{:?}", settings.gameplay.difficulty),
+            map_preset: settings.gameplay.map_preset.name().to_string(),
+            respawn_after_chain_reaction: settings.gameplay.respawn_after_chain_reaction,
+            reaction_spread: settings.gameplay.reaction_spread.name().to_string(),
+            hazards: settings.gameplay.hazards,
+            learning_feedback: settings.gameplay.learning_feedback,
+            multiplayer: settings.multiplayer.enabled,
+            player_count: settings.multiplayer.player_count,
+            bot_count: settings.multiplayer.bot_count,
+            bot_difficulty: format!("{:?}", settings.multiplayer.bot_difficulty),
+            chain_steal_enabled: settings.multiplayer.chain_steal_enabled,
+            chain_steal_segments: settings.multiplayer.chain_steal_segments,
+            coop_shared_chain: settings.multiplayer.coop_shared_chain,
+            match_rounds: settings.multiplayer.match_rounds,
+            master_volume: settings.audio.master_volume,
+            show_fps: settings.display.show_fps,
+        }
+    }
+}
+
+fn record_recent_events(
+    time: Res<Time<Real>>,
+    mut log: ResMut<RecentEventLog>,
+    mut collected_events: EventReader<OptionCollectedEvent>,
+    mut reaction_events: EventReader<ChainReactionEvent>,
+    mut respawn_events: EventReader<PlayerRespawnEvent>,
+    mut timer_events: EventReader<GameTimerEvent>,
+    mut notification_events: EventReader<NotificationEvent>,
+) {
+    let now = time.elapsed_secs();
+
+    for event in collected_events.read() {
+        log.push(
+            now,
+            format!(
+                "{:?} collected option {} (correct: {})",
+                event.player_entity, event.option_id, event.is_correct
+            ),
+        );
+    }
+
+    for event in reaction_events.read() {
+        log.push(
+            now,
+            format!(
+                "{:?} chain reaction at segment {}",
+                event.player_entity, event.hit_segment_index
+            ),
+        );
+    }
+
+    for event in respawn_events.read() {
+        log.push(now, format!("{:?} respawned", event.player_entity));
+    }
+
+    for event in timer_events.read() {
+        log.push(now, format!("Game timer: {:?}", event));
+    }
+
+    for event in notification_events.read() {
+        log.push(now, format!("Notification: {}", event.message));
+    }
+}
+
+fn create_bug_report(
+    mut requests: EventReader<BugReportRequested>,
+    screen: Res<State<Screen>>,
+    game_settings: Res<GameSettings>,
+    round_info: Option<Res<RoundInfo>>,
+    log: Res<RecentEventLog>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    if requests.read().count() == 0 {
+        return;
+    }
+
+    let report = BugReport {
+        game_version: env!("CARGO_PKG_VERSION").to_string(),
+        platform: format!(
+            "{} {} ({})",
+            std::env::consts::OS,
+            std::env::consts::ARCH,
+            std::env::consts::FAMILY
+        ),
+        screen: format!("{:?}", screen.get()),
+        round: round_info.map(|round| BugReportRound {
+            share_code: round.share_code(),
+            challenge_id: round.challenge_id.clone(),
+            seed: round.seed,
+        }),
+        settings: BugReportSettings::from_settings(&game_settings),
+        recent_events: log.entries().cloned().collect(),
+    };
+
+    crate::storage::save(BUG_REPORT_STORAGE_KEY, &report);
+    upload_report(&report);
+
+    let snapshot = match serde_yaml::to_string(&report) {
+        Ok(snapshot) => snapshot,
+        Err(err) => {
+            warn!("Failed to serialize bug report: {}", err);
+            notifications.write(NotificationEvent::new("Could not create the bug report"));
+            return;
+        }
+    };
+
+    let url = issue_url(&snapshot);
+    if let Err(err) = webbrowser::open(&url) {
+        warn!("Failed to open bug report page: {}", err);
+        notifications.write(NotificationEvent::new(
+            "Bug report saved, but the issue page could not be opened",
+        ));
+        return;
+    }
+
+    info!("Opened bug report page");
+    notifications.write(NotificationEvent::new(
+        "Bug report ready - describe what happened and submit the issue",
+    ));
+}
+
+/// Build a new-issue link with the snapshot pre-filled. The target can be
+/// swapped for another endpoint at build time with `BUG_REPORT_URL`.
+fn issue_url(snapshot: &str) -> String {
+    let base = option_env!("BUG_REPORT_URL").unwrap_or(DEFAULT_BUG_REPORT_URL);
+
+    // Keep the link short enough for browsers; the full snapshot is saved locally
+    let mut snapshot = snapshot.to_string();
+    if snapshot.len() > MAX_SNAPSHOT_LENGTH {
+        let cut = (0..=MAX_SNAPSHOT_LENGTH)
+            .rev()
+            .find(|&index| snapshot.is_char_boundary(index))
+            .unwrap_or(0);
+        snapshot.truncate(cut);
+        snapshot.push_str("\n# (truncated)");
+    }
+
+    let body = format!(
+        "**What happened?**\n\n\n**What did you expect?**\n\n\n<details><summary>State snapshot</summary>\n\n```yaml\n{}\n```\n</details>\n",
+        snapshot
+    );
+
+    format!(
+        "{}?title={}&body={}",
+        base,
+        percent_encode("Bug report"),
+        percent_encode(&body)
+    )
+}
+
+/// Post the snapshot to the upload endpoint set at build time, if any
+#[cfg(feature = "bug-report-upload")]
+fn upload_report(report: &BugReport) {
+    let Some(url) = option_env!("BUG_REPORT_UPLOAD_URL") else {
+        return;
+    };
+    let request = match ehttp::Request::json(url, report) {
+        Ok(request) => request,
+        Err(err) => {
+            warn!("Failed to serialize bug report for upload: {}", err);
+            return;
+        }
+    };
+
+    ehttp::fetch(request, |result| match result {
+        Ok(response) if response.ok => info!("Uploaded bug report"),
+        Ok(response) => warn!("Bug report upload failed: HTTP {}", response.status),
+        Err(err) => warn!("Bug report upload failed: {}", err),
+    });
+}
+
+#[cfg(not(feature = "bug-report-upload"))]
+fn upload_report(_report: &BugReport) {}
+
+// Bug report constants
+pub const BUG_REPORT_STORAGE_KEY: &str = "bug_report";
+pub const DEFAULT_BUG_REPORT_URL: &str =
+    "https://github.com/konnektoren/konnektoren-chain-game/issues/new";
+pub const RECENT_EVENT_LIMIT: usize = 50;
+pub const MAX_SNAPSHOT_LENGTH: usize = 2500; // Bytes of YAML kept in the issue link
@@ -27,6 +27,33 @@ impl Default for CameraController {
     }
 }
 
+/// Manual zoom chosen with the mouse wheel, gamepad triggers or a pinch.
+/// While set it overrides auto zoom; it is dropped after a few idle seconds.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct ManualZoom {
+    pub zoom: Option<f32>,
+    pub idle_timer: Timer,
+}
+
+impl Default for ManualZoom {
+    fn default() -> Self {
+        Self {
+            zoom: None,
+            idle_timer: Timer::from_seconds(super::MANUAL_ZOOM_RESET_SECONDS, TimerMode::Once),
+        }
+    }
+}
+
+impl ManualZoom {
+    /// Scale the current zoom by `factor` and restart the idle timer
+    pub fn apply(&mut self, current_zoom: f32, factor: f32) {
+        let zoom = self.zoom.unwrap_or(current_zoom) * factor;
+        self.zoom = Some(zoom.clamp(super::MIN_CAMERA_ZOOM, super::MAX_CAMERA_ZOOM));
+        self.idle_timer.reset();
+    }
+}
+
 /// Marker component for entities that the camera should follow
 #[derive(Component, Reflect)]
 #[reflect(Component)]
@@ -10,6 +10,7 @@ use systems::*;
 pub(super) fn plugin(app: &mut App) {
     app.register_type::<CameraController>();
     app.register_type::<CameraTarget>();
+    app.register_type::<ManualZoom>();
     app.register_type::<CameraSettings>();
     app.register_type::<CameraBounds>();
 
@@ -28,14 +29,24 @@ pub(super) fn plugin(app: &mut App) {
         setup_gameplay_camera,
     );
 
+    app.add_systems(
+        Update,
+        handle_manual_zoom_input
+            .in_set(crate::AppSystems::RecordInput)
+            .run_if(in_state(crate::screens::Screen::Gameplay))
+            .in_set(crate::PausableSystems),
+    );
+
     // Only run camera follow systems during gameplay
     app.add_systems(
         Update,
         (
             update_camera_targets,
+            apply_manual_zoom,
             update_camera_follow,
             update_camera_bounds,
         )
+            .chain()
             .in_set(crate::AppSystems::Update)
             .run_if(in_state(crate::screens::Screen::Gameplay))
             .in_set(crate::PausableSystems),
@@ -54,3 +65,10 @@ pub const MULTI_PLAYER_PADDING: f32 = 200.0; // For map bounds padding
 pub const BASE_VIEWPORT_WIDTH: f32 = 800.0;
 pub const BASE_VIEWPORT_HEIGHT: f32 = 600.0;
 pub const DEFAULT_ZOOM_MARGIN: f32 = 150.0;
+
+// Manual zoom constants
+pub const WHEEL_ZOOM_STEP: f32 = 0.1; // Zoom change per wheel line
+pub const WHEEL_PIXELS_PER_LINE: f32 = 100.0; // Touchpads report pixels instead of lines
+pub const TRIGGER_ZOOM_SPEED: f32 = 1.5; // Zoom change per second at full trigger
+pub const TRIGGER_DEADZONE: f32 = 0.1;
+pub const MANUAL_ZOOM_RESET_SECONDS: f32 = 4.0; // Idle time before auto zoom takes over again
@@ -1,6 +1,9 @@
 use super::{components::*, viewport::ViewportCalculator};
 use crate::{map::GridMap, screens::Screen};
-use bevy::prelude::*;
+use bevy::{
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    prelude::*,
+};
 
 /// System to set up the title/UI camera
 pub fn setup_title_camera(mut commands: Commands, existing_cameras: Query<Entity, With<Camera2d>>) {
@@ -55,6 +58,8 @@ pub fn setup_gameplay_camera(
         Transform::from_translation(Vec3::new(0.0, 0.0, 999.0)),
         camera_controller,
         camera_bounds,
+        crate::game_feel::CameraShake::default(),
+        ManualZoom::default(),
         StateScoped(Screen::Gameplay),
     ));
 }
@@ -81,7 +86,7 @@ pub fn setup_loading_camera(
 /// System to update camera targets using ViewportCalculator for multiple targets or simple follow for single target
 pub fn update_camera_targets(
     mut camera_query: Query<&mut CameraController>,
-    target_query: Query<(&Transform, &CameraTarget)>,
+    target_query: Query<(&Transform, &CameraTarget, Has<crate::idle::IdlePlayer>)>,
     camera_settings: Res<CameraSettings>,
 ) {
     for mut camera_controller in &mut camera_query {
@@ -89,7 +94,18 @@ pub fn update_camera_targets(
             continue;
         }
 
-        let targets: Vec<_> = target_query.iter().collect();
+        // Idle players are left out so the view doesn't stay zoomed out for them
+        let mut targets: Vec<_> = target_query
+            .iter()
+            .filter(|(_, _, idle)| !idle)
+            .map(|(transform, target, _)| (transform, target))
+            .collect();
+        if targets.is_empty() {
+            targets = target_query
+                .iter()
+                .map(|(transform, target, _)| (transform, target))
+                .collect();
+        }
 
         if targets.is_empty() {
             continue;
@@ -146,6 +162,68 @@ pub fn update_camera_targets(
     }
 }
 
+/// System to read mouse wheel, gamepad trigger and pinch input into a manual zoom
+pub fn handle_manual_zoom_input(
+    time: Res<Time>,
+    touches: Res<Touches>,
+    gamepads: Query<&Gamepad>,
+    mut wheel_events: EventReader<MouseWheel>,
+    mut camera_query: Query<(&Transform, &mut ManualZoom), With<Camera>>,
+) {
+    // Wheel up zooms in, which means a smaller Transform::scale
+    let wheel_lines: f32 = wheel_events
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / super::WHEEL_PIXELS_PER_LINE,
+        })
+        .sum();
+    let mut factor = (1.0 - super::WHEEL_ZOOM_STEP).powf(wheel_lines);
+
+    // Right trigger zooms in, left trigger zooms out
+    for gamepad in &gamepads {
+        let zoom_in = gamepad.get(GamepadButton::RightTrigger2).unwrap_or(0.0);
+        let zoom_out = gamepad.get(GamepadButton::LeftTrigger2).unwrap_or(0.0);
+        let trigger = zoom_out - zoom_in;
+        if trigger.abs() > super::TRIGGER_DEADZONE {
+            factor *= 1.0 + trigger * super::TRIGGER_ZOOM_SPEED * time.delta_secs();
+        }
+    }
+
+    // Two-finger pinch: spreading the fingers zooms in
+    let pressed: Vec<_> = touches.iter().take(2).collect();
+    if let [first, second] = pressed.as_slice() {
+        let previous = first
+            .previous_position()
+            .distance(second.previous_position());
+        let current = first.position().distance(second.position());
+        if previous > 0.0 && current > 0.0 && (current - previous).abs() > f32::EPSILON {
+            factor *= previous / current;
+        }
+    }
+
+    for (transform, mut manual_zoom) in &mut camera_query {
+        if (factor - 1.0).abs() > f32::EPSILON {
+            manual_zoom.apply(transform.scale.x, factor);
+        } else if manual_zoom.zoom.is_some() {
+            manual_zoom.idle_timer.tick(time.delta());
+            if manual_zoom.idle_timer.finished() {
+                manual_zoom.zoom = None;
+                info!("Manual camera zoom released back to auto zoom");
+            }
+        }
+    }
+}
+
+/// System to let an active manual zoom override the zoom picked by update_camera_targets
+pub fn apply_manual_zoom(mut camera_query: Query<(&mut CameraController, &ManualZoom)>) {
+    for (mut controller, manual_zoom) in &mut camera_query {
+        if let Some(zoom) = manual_zoom.zoom {
+            controller.target_zoom = zoom;
+        }
+    }
+}
+
 /// System to smoothly move camera to target position and zoom using Transform::scale
 pub fn update_camera_follow(
     time: Res<Time>,
@@ -11,16 +11,176 @@ pub struct PlayerChain {
 
 impl Default for PlayerChain {
     fn default() -> Self {
+        Self::with_max_segments(super::DEFAULT_MAX_CHAIN_LENGTH)
+    }
+}
+
+impl PlayerChain {
+    pub fn with_max_segments(max_segments: usize) -> Self {
         Self {
             segments: Vec::new(),
-            max_segments: 20, // Maximum chain length
+            max_segments,
+        }
+    }
+
+    /// The chain is close enough to its maximum length to warn the player
+    pub fn is_near_limit(&self) -> bool {
+        self.segments.len() + super::CHAIN_LIMIT_WARNING_MARGIN >= self.max_segments
+    }
+}
+
+/// What happens to the oldest segments when a chain grows past its maximum length
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ChainOverflowRule {
+    /// The oldest segments are removed
+    DropOldest,
+    /// The oldest segments are removed and turned into points
+    #[default]
+    CashIn,
+    /// The oldest segments blow up in a small chain reaction and cost points
+    Reaction,
+}
+
+impl ChainOverflowRule {
+    pub const ALL: [ChainOverflowRule; 3] = [
+        ChainOverflowRule::DropOldest,
+        ChainOverflowRule::CashIn,
+        ChainOverflowRule::Reaction,
+    ];
+
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index.min(Self::ALL.len() - 1)]
+    }
+
+    pub fn index(&self) -> usize {
+        Self::ALL.iter().position(|r| r == self).unwrap_or(1)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChainOverflowRule::DropOldest => "Drop Oldest",
+            ChainOverflowRule::CashIn => "Cash In",
+            ChainOverflowRule::Reaction => "Reaction",
         }
     }
 }
 
+/// How far a chain reaction spreads from the hit segment
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReactionSpreadRule {
+    /// Picked from the difficulty and chain mode, see `resolve`
+    #[default]
+    Auto,
+    /// The reaction runs through the whole chain
+    FullChain,
+    /// Only a few segments on either side of the hit react
+    Limited,
+    /// The hit segment and everything behind it react, the head is spared
+    TowardTail,
+}
+
+impl ReactionSpreadRule {
+    pub const ALL: [ReactionSpreadRule; 4] = [
+        ReactionSpreadRule::Auto,
+        ReactionSpreadRule::FullChain,
+        ReactionSpreadRule::Limited,
+        ReactionSpreadRule::TowardTail,
+    ];
+
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index.min(Self::ALL.len() - 1)]
+    }
+
+    pub fn index(&self) -> usize {
+        Self::ALL.iter().position(|r| r == self).unwrap_or(0)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ReactionSpreadRule::Auto => "Auto",
+            ReactionSpreadRule::FullChain => "Full Chain",
+            ReactionSpreadRule::Limited => "Limited",
+            ReactionSpreadRule::TowardTail => "Toward Tail",
+        }
+    }
+
+    /// Rule to play with. `Auto` goes easy on beginners and on the shared
+    /// co-op chain, where one crash would otherwise cost the whole team.
+    pub fn resolve(&self, difficulty: crate::settings::Difficulty, chain_mode: &ChainMode) -> Self {
+        match self {
+            ReactionSpreadRule::Auto => match difficulty {
+                crate::settings::Difficulty::Easy => ReactionSpreadRule::Limited,
+                _ if chain_mode.is_team() => ReactionSpreadRule::TowardTail,
+                _ => ReactionSpreadRule::FullChain,
+            },
+            rule => *rule,
+        }
+    }
+
+    /// Furthest a reaction spreads, in segments from the hit
+    pub fn max_spread_distance(&self, limit: usize, full_chain: i32) -> i32 {
+        match self {
+            ReactionSpreadRule::Limited => (limit as i32).min(full_chain),
+            _ => full_chain,
+        }
+    }
+
+    pub fn spreads_toward_head(&self) -> bool {
+        !matches!(self, ReactionSpreadRule::TowardTail)
+    }
+
+    /// Color of the warning rings on the segments about to react
+    pub fn telegraph_color(&self) -> Color {
+        match self {
+            ReactionSpreadRule::Auto | ReactionSpreadRule::FullChain => {
+                Color::srgba(1.0, 0.2, 0.2, 0.6)
+            }
+            ReactionSpreadRule::Limited => Color::srgba(1.0, 0.6, 0.1, 0.6),
+            ReactionSpreadRule::TowardTail => Color::srgba(1.0, 0.9, 0.2, 0.6),
+        }
+    }
+}
+
+/// How collected options are routed to chains
+#[derive(Resource, Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[reflect(Resource)]
+pub enum ChainMode {
+    /// Every player builds their own chain
+    #[default]
+    Individual,
+    /// Co-op: everyone feeds one team chain that follows the `anchor` player
+    TeamChain { anchor: Entity },
+}
+
+impl ChainMode {
+    /// Player whose chain receives what `player_entity` collects
+    pub fn chain_owner(&self, player_entity: Entity) -> Entity {
+        match self {
+            ChainMode::Individual => player_entity,
+            ChainMode::TeamChain { anchor } => *anchor,
+        }
+    }
+
+    pub fn is_team(&self) -> bool {
+        matches!(self, ChainMode::TeamChain { .. })
+    }
+}
+
+/// Event sent when a chain grew past its maximum length and the overflow rule was applied
+#[derive(Event, Debug)]
+pub struct ChainOverflowEvent {
+    pub player_entity: Entity,
+    pub position: Vec2,
+    pub rule: ChainOverflowRule,
+    pub segment_count: usize,
+    /// Points earned by cashing in, 0 for the other rules
+    pub points: i32,
+}
+
 /// Component for individual chain segments
 #[derive(Component, Reflect, Clone)]
 #[reflect(Component)]
+#[require(crate::fixed_step::FixedStepPosition)]
 pub struct ChainSegment {
     pub segment_index: usize,
     pub option_text: String,
@@ -29,6 +189,13 @@ pub struct ChainSegment {
     pub pulse_phase: f32,
     pub level: u32,
     pub merge_value: u32,
+    /// Chain-reaction hits the segment can still absorb without being destroyed
+    pub durability: u32,
+    /// Players whose collections went into this segment, including merged ones
+    pub contributors: Vec<Entity>,
+    /// Topic of the question the segment answered, `None` for cursed segments.
+    /// Topics are named after their answering option, see `question_topic`.
+    pub category: Option<String>,
 }
 
 impl ChainSegment {
@@ -46,12 +213,50 @@ impl ChainSegment {
             pulse_phase: segment_index as f32 * 0.3,
             level: 1,
             merge_value: 1,
+            durability: 0,
+            contributors: Vec::new(),
+            category: None,
         }
     }
 
     pub fn get_radius(&self) -> f32 {
         super::CHAIN_SEGMENT_SIZE * (1.0 + (self.level - 1) as f32 * 0.5)
     }
+
+    /// Raise the segment one level, refreshing its durability for the new level
+    pub fn level_up(&mut self) {
+        self.level += 1;
+        self.durability = if self.level >= 2 {
+            super::MERGED_SEGMENT_DURABILITY
+        } else {
+            0
+        };
+    }
+
+    /// Whether a chain reaction stops spreading once it reaches this segment
+    pub fn blocks_reaction(&self) -> bool {
+        self.level >= super::REACTION_BLOCKING_LEVEL
+    }
+}
+
+/// Copy of a chain segment drawn on the opposite side of the map while the
+/// segment is close to an edge, so chains don't visually teleport when wrapping
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct SegmentGhost {
+    pub segment: Entity,
+    pub offset: Vec2,
+}
+
+/// Stretched sprite connecting chain element `index` to the one before it
+/// (the player for the first segment). `mirrored` is the far-side half of a
+/// link that crosses the map edge.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct ChainLink {
+    pub player: Entity,
+    pub index: usize,
+    pub mirrored: bool,
 }
 
 /// Component to track the player's movement trail
@@ -61,6 +266,9 @@ pub struct MovementTrail {
     pub positions: VecDeque<Vec2>,
     pub sample_timer: Timer,
     pub max_trail_length: usize,
+    /// Distance of each point from the head along the trail, rebuilt once per
+    /// sample. Ignored while it doesn't match `positions`.
+    cumulative_distances: Vec<f32>,
 }
 
 impl Default for MovementTrail {
@@ -69,6 +277,7 @@ impl Default for MovementTrail {
             positions: VecDeque::new(),
             sample_timer: Timer::from_seconds(super::MOVEMENT_SAMPLE_RATE, TimerMode::Repeating),
             max_trail_length: 1000,
+            cumulative_distances: Vec::new(),
         }
     }
 }
@@ -112,9 +321,13 @@ impl MovementTrail {
             return None;
         }
 
-        let mut accumulated_distance = 0.0;
         let half_width = map_width / 2.0;
         let half_height = map_height / 2.0;
+        if self.cumulative_distances.len() == self.positions.len() {
+            return self.indexed_position_at_distance(distance, half_width, half_height);
+        }
+
+        let mut accumulated_distance = 0.0;
 
         for i in 0..self.positions.len().saturating_sub(1) {
             let current_pos = self.positions[i];
@@ -161,6 +374,14 @@ impl MovementTrail {
             }
         }
 
+        // A head that barely bends the trail adds nothing, so move it forward instead
+        if let (Some(&head), Some(&previous)) = (self.positions.front(), self.positions.get(1)) {
+            if is_nearly_collinear(previous, head, position) {
+                self.positions[0] = position;
+                return;
+            }
+        }
+
         self.positions.push_front(position);
 
         // Limit trail length
@@ -168,6 +389,103 @@ impl MovementTrail {
             self.positions.pop_back();
         }
     }
+
+    /// Drop the trail past `length`, keeping the first point beyond it so the
+    /// end of the chain still has a place to go
+    pub fn prune_beyond(&mut self, length: f32, half_width: f32, half_height: f32) {
+        let mut accumulated_distance = 0.0;
+
+        for i in 0..self.positions.len().saturating_sub(1) {
+            accumulated_distance += calculate_wraparound_distance(
+                self.positions[i],
+                self.positions[i + 1],
+                half_width,
+                half_height,
+            );
+            if accumulated_distance >= length {
+                self.positions.truncate(i + 2);
+                break;
+            }
+        }
+
+        // Give back memory left over from a longer chain
+        let slack = self.positions.len() + super::TRAIL_CAPACITY_SLACK;
+        if self.positions.capacity() > slack * 2 {
+            self.positions.shrink_to(slack);
+        }
+    }
+
+    /// Rebuild the distance index used by `get_position_at_distance_with_wraparound`
+    pub fn rebuild_distance_index(&mut self, half_width: f32, half_height: f32) {
+        self.cumulative_distances.clear();
+
+        let mut accumulated_distance = 0.0;
+        let mut previous = None;
+        for &position in &self.positions {
+            if let Some(previous) = previous {
+                accumulated_distance +=
+                    calculate_wraparound_distance(previous, position, half_width, half_height);
+            }
+            self.cumulative_distances.push(accumulated_distance);
+            previous = Some(position);
+        }
+    }
+
+    /// Binary search of the distance index for the point `distance` behind the head
+    fn indexed_position_at_distance(
+        &self,
+        distance: f32,
+        half_width: f32,
+        half_height: f32,
+    ) -> Option<Vec2> {
+        // First point at least `distance` along the trail
+        let next = self
+            .cumulative_distances
+            .partition_point(|&accumulated| accumulated < distance);
+        if next == 0 {
+            return self.positions.front().copied();
+        }
+        let Some(&end) = self.cumulative_distances.get(next) else {
+            // If we've run out of trail, return the oldest position
+            return self.positions.back().copied();
+        };
+
+        let start = self.cumulative_distances[next - 1];
+        let t = (distance - start) / (end - start);
+        Some(interpolate_with_wraparound(
+            self.positions[next - 1],
+            self.positions[next],
+            t,
+            half_width,
+            half_height,
+        ))
+    }
+
+    /// Length of the whole trail, considering wraparound
+    pub fn length(&self, half_width: f32, half_height: f32) -> f32 {
+        self.positions
+            .iter()
+            .zip(self.positions.iter().skip(1))
+            .map(|(from, to)| calculate_wraparound_distance(*from, *to, half_width, half_height))
+            .sum()
+    }
+
+    /// Bytes allocated for the trail points
+    pub fn memory_bytes(&self) -> usize {
+        self.positions.capacity() * std::mem::size_of::<Vec2>()
+    }
+}
+
+/// Whether `head` lies on the way from `previous` to `next`. Wrapping around
+/// the map turns the trail back on itself, so it never counts.
+fn is_nearly_collinear(previous: Vec2, head: Vec2, next: Vec2) -> bool {
+    let direction = next - previous;
+    let length = direction.length();
+    if length <= f32::EPSILON || (head - previous).dot(next - head) <= 0.0 {
+        return false;
+    }
+
+    direction.perp_dot(head - previous).abs() / length <= super::TRAIL_COLLINEAR_TOLERANCE
 }
 
 /// Calculate distance between two points considering map wraparound
@@ -261,6 +579,9 @@ pub struct FlyingToChain {
     pub option_id: usize,
     pub option_color: Color,
     pub curve_height: f32,
+    pub cursed: bool,
+    /// Player who collected the option
+    pub contributor: Option<Entity>,
 }
 
 impl FlyingToChain {
@@ -279,9 +600,21 @@ impl FlyingToChain {
             option_id,
             option_color,
             curve_height: 50.0,
+            cursed: false,
+            contributor: None,
         }
     }
 
+    pub fn with_cursed(mut self, cursed: bool) -> Self {
+        self.cursed = cursed;
+        self
+    }
+
+    pub fn with_contributor(mut self, contributor: Entity) -> Self {
+        self.contributor = Some(contributor);
+        self
+    }
+
     /// Get current position along the flight path (parabolic arc)
     pub fn current_position(&self) -> Vec2 {
         let t = self.flight_timer.fraction();
@@ -296,14 +629,45 @@ impl FlyingToChain {
     }
 }
 
+/// Marker for a segment added by a wrong answer. It never merges and blocks
+/// merges across it until the player burns it off with a correct-answer streak.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct CursedSegment;
+
+/// Correct answers in a row since the player's last wrong answer or burned curse
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct CurseBurnStreak(pub u32);
+
+/// Marker for segments that already counted toward a sentence bonus
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct SentenceSegment;
+
+/// Event for when the newest segments of a chain form a sentence: connectors
+/// from different categories in a row
+#[derive(Event)]
+pub struct SentenceBonusEvent {
+    pub player_entity: Entity,
+    /// Categories of the segments, oldest first
+    pub categories: Vec<String>,
+    pub position: Vec2,
+}
+
 /// Event to extend the chain with a new segment
 #[derive(Event)]
 pub struct ChainExtendEvent {
+    /// Owner of the chain the segment is added to
     pub player_entity: Entity,
+    /// Player who collected the option
+    pub contributor: Entity,
     pub option_text: String,
     pub option_id: usize,
     pub option_color: Color,
     pub collect_position: Vec2,
+    /// Segment comes from a wrong answer and is added as a cursed segment
+    pub cursed: bool,
 }
 
 /// Component for chain segments undergoing reaction
@@ -337,6 +701,13 @@ pub struct PlayerReaction {
     pub player_entity: Entity,
     pub hit_segment_index: usize,
     pub current_spread_distance: i32,
+    /// Spread stops after this many segments from the hit
+    pub max_spread_distance: i32,
+    /// Segments destroyed so far, for the event feed
+    pub segments_destroyed: u32,
+    /// The spread towards the chain head / tail was stopped by a high-level segment
+    pub blocked_before: bool,
+    pub blocked_after: bool,
 }
 
 /// Resource to manage the chain reaction state
@@ -366,7 +737,14 @@ impl ChainReactionState {
         !self.active_reactions.is_empty()
     }
 
-    pub fn start_reaction(&mut self, player_entity: Entity, hit_segment_index: usize) {
+    /// Start a reaction spreading according to `rule`, which must already be resolved
+    pub fn start_reaction(
+        &mut self,
+        player_entity: Entity,
+        hit_segment_index: usize,
+        rule: ReactionSpreadRule,
+        limit: usize,
+    ) {
         // Remove any existing reaction for this player
         self.active_reactions
             .retain(|r| r.player_entity != player_entity);
@@ -376,6 +754,11 @@ impl ChainReactionState {
             player_entity,
             hit_segment_index,
             current_spread_distance: 0,
+            max_spread_distance: rule.max_spread_distance(limit, self.max_spread_distance),
+            segments_destroyed: 0,
+            // Spreading toward the tail only is a spread already blocked toward the head
+            blocked_before: !rule.spreads_toward_head(),
+            blocked_after: false,
         });
 
         self.reaction_spread_timer.reset();
@@ -387,6 +770,13 @@ impl ChainReactionState {
     }
 }
 
+/// Component for the warning ring on a segment an active reaction is about to reach
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct ReactionTelegraph {
+    pub player_entity: Entity,
+}
+
 /// Event for when a chain reaction starts
 #[derive(Event)]
 pub struct ChainReactionEvent {
@@ -402,6 +792,7 @@ pub struct ChainSegmentDestroyedEvent {
     pub segment_index: usize,
     pub option_text: String,
     pub points_lost: i32,
+    pub position: Vec2,
 }
 
 /// Component to track which player owns a chain segment
@@ -416,6 +807,17 @@ pub struct ChainMergeEvent {
     pub merge_segments: Vec<(Entity, usize)>, // (entity, segment_index)
     pub option_color: Color,
     pub new_level: u32,
+    /// Everyone who contributed to the merged segments
+    pub contributors: Vec<Entity>,
+}
+
+/// Event sent when a merge animation finishes and the merged segment is in place
+#[derive(Event, Debug)]
+pub struct ChainMergeCompletedEvent {
+    pub player_entity: Entity,
+    pub position: Vec2,
+    pub new_level: u32,
+    pub merge_value: u32,
 }
 
 /// Component for segments undergoing merge animation
@@ -480,3 +882,26 @@ pub struct SegmentReindexMarker {
 pub struct ChainCleanupMarker {
     pub player_entity: Entity,
 }
+
+/// Event fired when a player steals the tail of another player's chain
+#[derive(Event)]
+pub struct ChainStealEvent {
+    pub thief_entity: Entity,
+    pub victim_entity: Entity,
+    pub segment_count: usize,
+}
+
+/// Component protecting a player from chain steals for a short time
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct StealInvulnerability {
+    pub timer: Timer,
+}
+
+impl StealInvulnerability {
+    pub fn new(duration: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+        }
+    }
+}
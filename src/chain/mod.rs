@@ -4,6 +4,9 @@ mod components;
 mod systems;
 
 pub use components::*;
+#[cfg(feature = "bench")]
+pub use systems::detect_chain_merges;
+pub use systems::spawn_chain_segment;
 use systems::*;
 
 pub(super) fn plugin(app: &mut App) {
@@ -17,61 +20,133 @@ pub(super) fn plugin(app: &mut App) {
     app.register_type::<ChainMerging>();
     app.register_type::<ChainMergeState>();
     app.register_type::<SegmentReindexMarker>();
+    app.register_type::<StealInvulnerability>();
+    app.register_type::<CursedSegment>();
+    app.register_type::<CurseBurnStreak>();
+    app.register_type::<SegmentGhost>();
+    app.register_type::<ChainLink>();
+    app.register_type::<ChainOverflowRule>();
+    app.register_type::<ReactionSpreadRule>();
+    app.register_type::<ReactionTelegraph>();
+    app.register_type::<ChainMode>();
+    app.register_type::<SentenceSegment>();
 
     app.add_event::<ChainExtendEvent>();
     app.add_event::<ChainReactionEvent>();
     app.add_event::<ChainSegmentDestroyedEvent>();
     app.add_event::<ChainMergeEvent>();
+    app.add_event::<ChainMergeCompletedEvent>();
+    app.add_event::<ChainStealEvent>();
+    app.add_event::<ChainOverflowEvent>();
+    app.add_event::<SentenceBonusEvent>();
 
     app.init_resource::<ChainReactionState>();
     app.init_resource::<ChainMergeState>();
+    app.init_resource::<ChainMode>();
 
     // Run setup system after player spawns (which runs after map setup)
     app.add_systems(
         OnEnter(crate::screens::Screen::Gameplay),
-        setup_player_chain.after(crate::player::spawn_player),
+        (setup_player_chain, setup_chain_mode).after(crate::player::spawn_player),
     );
+    app.add_systems(OnExit(crate::screens::Screen::Gameplay), reset_chain_mode);
 
     app.add_systems(
         Update,
         (
-            track_player_movement.in_set(crate::AppSystems::Update),
             handle_chain_extend_events.in_set(crate::AppSystems::Update),
+            burn_cursed_segments.in_set(crate::AppSystems::Update),
             create_flying_to_chain_objects.in_set(crate::AppSystems::Update),
             update_flying_objects.in_set(crate::AppSystems::Update),
-            update_chain_positions.in_set(crate::AppSystems::Update),
-            animate_chain_segments.in_set(crate::AppSystems::Update),
-            detect_player_chain_collision.in_set(crate::AppSystems::Update),
-            handle_chain_reaction_events.in_set(crate::AppSystems::Update),
-            update_chain_reaction.in_set(crate::AppSystems::Update),
-            animate_reacting_segments.in_set(crate::AppSystems::Update),
+            (update_segment_ghosts, update_chain_links).in_set(crate::AppSystems::Update),
+            (animate_reacting_segments, animate_reaction_telegraphs)
+                .in_set(crate::AppSystems::Update),
             detect_chain_merges.in_set(crate::AppSystems::Update),
+            detect_sentence_bonus.in_set(crate::AppSystems::Update),
             handle_chain_merge_events.in_set(crate::AppSystems::Update),
             animate_merging_segments.in_set(crate::AppSystems::Update),
             cleanup_merged_chains
                 .in_set(crate::AppSystems::Update)
                 .after(animate_merging_segments),
+            handle_chain_overflow
+                .in_set(crate::AppSystems::Update)
+                .after(update_flying_objects)
+                .after(handle_chain_steal_events)
+                .before(handle_segment_reindexing),
             handle_segment_reindexing
                 .in_set(crate::AppSystems::Update)
                 .after(cleanup_merged_chains),
             update_merge_cooldown.in_set(crate::AppSystems::Update),
+            reset_trail_on_respawn.in_set(crate::AppSystems::Update),
+            (
+                update_steal_invulnerability.in_set(crate::AppSystems::TickTimers),
+                (detect_chain_steal, handle_chain_steal_events)
+                    .chain()
+                    .in_set(crate::AppSystems::Update),
+            ),
         )
             .run_if(in_state(crate::screens::Screen::Gameplay))
             .in_set(crate::PausableSystems),
     );
+
+    // Following, collisions and reaction spreading run at a fixed rate after
+    // the players moved, see `fixed_step`
+    app.add_systems(
+        FixedUpdate,
+        (
+            track_player_movement,
+            update_chain_positions,
+            animate_chain_segments,
+            detect_player_chain_collision,
+            handle_chain_reaction_events,
+            update_chain_reaction,
+        )
+            .chain()
+            .in_set(crate::AppSystems::Update)
+            .after(crate::player::move_player)
+            .run_if(in_state(crate::screens::Screen::Gameplay))
+            .in_set(crate::PausableSystems),
+    );
 }
 
 // Configuration constants
 pub const CHAIN_SEGMENT_SIZE: f32 = 12.0;
 pub const CHAIN_SEGMENT_SPACING: f32 = 25.0;
-pub const MOVEMENT_SAMPLE_RATE: f32 = 0.1; // Record position every 0.1 seconds
+pub const MOVEMENT_SAMPLE_RATE: f32 = 0.1; // Record position every 0.1 seconds of fixed time
+pub const TRAIL_PRUNE_MARGIN: f32 = CHAIN_SEGMENT_SPACING * 4.0; // Trail kept past the chain's end for segments about to join
+pub const TRAIL_COLLINEAR_TOLERANCE: f32 = 1.0; // Pixels a point may stray from a straight line and still be merged
+pub const TRAIL_CAPACITY_SLACK: usize = 32; // Spare trail points kept allocated
 pub const FLY_TO_CHAIN_DURATION: f32 = 0.8; // Duration of fly animation
+pub const WRAP_GHOST_MARGIN: f32 = CHAIN_SEGMENT_SIZE * 3.0; // Distance from an edge at which ghosts appear
+pub const CHAIN_LINK_ALPHA: f32 = 0.5;
+pub const CHAIN_LINK_PLAYER_TINT: f32 = 0.35; // Share of the owner's color in the links
+pub const CHAIN_LINK_WIDTH: f32 = 3.0; // Widened by half for each segment level
+pub const CHAIN_LINK_Z: f32 = 1.4; // Just below the segments
+
+// Chain length constants
+pub const DEFAULT_MAX_CHAIN_LENGTH: usize = 20;
+pub const CHAIN_LIMIT_WARNING_MARGIN: usize = 3; // Segments left before the HUD warns
+pub const OVERFLOW_CASH_IN_POINTS: i32 = 3; // Per segment level
+pub const OVERFLOW_REACTION_EXTRA_SEGMENTS: usize = 2; // Blown up on top of the overflow itself
 
 // Chain reaction constants
 pub const REACTION_SPREAD_INTERVAL: f32 = 0.1; // Time between each ball starting to react
 pub const REACTION_BALL_DURATION: f32 = 0.5; // How long each ball takes to disappear
+pub const DEFAULT_REACTION_SPREAD_LIMIT: usize = 3; // Segments either side of the hit for the limited rule
+pub const REACTION_TELEGRAPH_RADIUS: f32 = CHAIN_SEGMENT_SIZE * 1.6;
+pub const REACTION_TELEGRAPH_BLINK_SPEED: f32 = 14.0; // Radians per second
 pub const POINTS_LOST_PER_SEGMENT: i32 = 5; // Points deducted per destroyed chain segment
+pub const MERGED_SEGMENT_DURABILITY: u32 = 1; // Reaction hits a level 2+ segment absorbs
+pub const REACTION_BLOCKING_LEVEL: u32 = 3; // Segments of this level stop the reaction spreading past them
 
 pub const MERGE_ANIMATION_DURATION: f32 = 0.8; // Duration of merge animation
 pub const MERGE_COOLDOWN_DURATION: f32 = 1.0; // Cooldown between merges
 pub const MIN_SEGMENTS_TO_MERGE: usize = 3; // Number of same segments needed to merge
+pub const MIN_MERGE_CONTRIBUTORS: usize = 2; // Different players needed for a co-op merge
+
+// Sentence bonus constants
+pub const SENTENCE_LENGTH: usize = 3; // Newest segments that need different categories
+
+// Cursed segment constants
+pub const CURSED_SEGMENT_COLOR: Color = Color::srgb(0.25, 0.08, 0.3);
+pub const CURSE_BURN_STREAK: u32 = 3; // Correct answers in a row needed to burn off a curse
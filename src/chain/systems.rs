@@ -1,25 +1,41 @@
-use super::MIN_SEGMENTS_TO_MERGE;
 use super::components::*;
 use crate::{
+    asset_cache::SharedAssetCache,
+    config::GameConfig,
+    effects::{EntityPool, PoolKind, SpawnExplosionEvent},
+    feed::GameFeedEvent,
+    locale::Locale,
     map::GridMap,
-    player::{OptionCollectedEvent, Player},
+    palette::OptionStyleRegistry,
+    player::{
+        OptionCollectedEvent, Player, PlayerEffects, PlayerIndex, PlayerRespawnEvent,
+        PlayerVisualEvent, PlayerVisualEventType, SpawnProtection,
+    },
     screens::Screen,
+    settings::GameSettings,
+    theme::prelude::*,
 };
-use bevy::prelude::*;
+use bevy::{color::Mix, prelude::*};
+use std::collections::HashMap;
 
 // Track which player a flying object belongs to
 #[derive(Component)]
 pub struct FlyingToPlayer(pub Entity);
 
 /// System to set up the player chain when entering gameplay
-pub fn setup_player_chain(mut commands: Commands, player_query: Query<Entity, With<Player>>) {
+pub fn setup_player_chain(
+    mut commands: Commands,
+    game_settings: Res<GameSettings>,
+    player_query: Query<Entity, With<Player>>,
+) {
     info!("Setting up player chain system...");
 
     let mut player_count = 0;
     for player_entity in &player_query {
         commands.entity(player_entity).insert((
-            PlayerChain::default(),
+            PlayerChain::with_max_segments(game_settings.gameplay.max_chain_length),
             MovementTrail::default(), // Add MovementTrail as component instead of resource
+            CurseBurnStreak::default(),
         ));
         player_count += 1;
         info!(
@@ -38,12 +54,45 @@ pub fn setup_player_chain(mut commands: Commands, player_query: Query<Entity, Wi
     );
 }
 
+/// System to pick the chain mode for the round. In co-op the team chain
+/// follows the first player.
+pub fn setup_chain_mode(
+    game_settings: Res<GameSettings>,
+    player_query: Query<(Entity, &PlayerIndex), With<Player>>,
+    mut chain_mode: ResMut<ChainMode>,
+) {
+    let anchor = player_query
+        .iter()
+        .min_by_key(|(_, player_index)| player_index.0)
+        .map(|(entity, _)| entity);
+
+    *chain_mode = match anchor {
+        Some(anchor)
+            if game_settings.multiplayer.coop_shared_chain && player_query.iter().len() > 1 =>
+        {
+            info!(
+                "Co-op round: all players feed the team chain of {:?}",
+                anchor
+            );
+            ChainMode::TeamChain { anchor }
+        }
+        _ => ChainMode::Individual,
+    };
+}
+
+pub fn reset_chain_mode(mut chain_mode: ResMut<ChainMode>) {
+    *chain_mode = ChainMode::Individual;
+}
+
 /// System to update flying objects and convert them to chain segments when they arrive
 pub fn update_flying_objects(
     mut commands: Commands,
     time: Res<Time>,
     mut flying_query: Query<(Entity, &mut Transform, &mut FlyingToChain, &FlyingToPlayer)>,
     mut player_query: Query<&mut PlayerChain, With<Player>>,
+    mut pool: ResMut<EntityPool>,
+    option_styles: Res<OptionStyleRegistry>,
+    mut asset_cache: ResMut<SharedAssetCache>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
@@ -70,14 +119,18 @@ pub fn update_flying_objects(
                     flying.option_text.clone(),
                     flying.option_id,
                     flying.option_color,
+                    flying.cursed,
+                    flying.contributor,
                     &mut player_chain,
+                    &option_styles,
+                    &mut asset_cache,
                     &mut meshes,
                     &mut materials,
                 );
             }
 
-            // Remove the flying object
-            commands.entity(entity).despawn();
+            // Hand the flying object back for the next collection
+            pool.release::<FlyingToChain>(&mut commands, PoolKind::FlyingObject, entity);
         }
     }
 }
@@ -90,23 +143,57 @@ fn create_chain_segment_for_player(
     option_text: String,
     option_id: usize,
     color: Color,
+    cursed: bool,
+    contributor: Option<Entity>,
     player_chain: &mut PlayerChain,
+    option_styles: &OptionStyleRegistry,
+    asset_cache: &mut SharedAssetCache,
     meshes: &mut Assets<Mesh>,
     materials: &mut Assets<ColorMaterial>,
 ) {
+    // Going past the maximum length is handled by the overflow rule
     let segment_index = player_chain.segments.len();
 
-    // Check if we've reached max segments
-    if segment_index >= player_chain.max_segments {
-        // Remove the oldest segment
-        if let Some(oldest_segment) = player_chain.segments.first() {
-            commands.entity(*oldest_segment).despawn();
-            player_chain.segments.remove(0);
-        }
+    let mut segment = ChainSegment::new(segment_index, option_text.clone(), option_id, color);
+    segment.contributors.extend(contributor);
+    if !cursed {
+        segment.category = Some(option_text.clone());
     }
 
-    let mesh = meshes.add(Circle::new(super::CHAIN_SEGMENT_SIZE));
-    let material = materials.add(ColorMaterial::from(color));
+    spawn_chain_segment(
+        commands,
+        player_entity,
+        position,
+        segment,
+        cursed,
+        player_chain,
+        option_styles,
+        asset_cache,
+        meshes,
+        materials,
+    );
+}
+
+/// Spawn a prepared segment at the end of a player's chain, sized for its level
+pub fn spawn_chain_segment(
+    commands: &mut Commands,
+    player_entity: Entity,
+    position: Vec2,
+    segment: ChainSegment,
+    cursed: bool,
+    player_chain: &mut PlayerChain,
+    option_styles: &OptionStyleRegistry,
+    asset_cache: &mut SharedAssetCache,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+) -> Entity {
+    let segment_index = segment.segment_index;
+    let option_text = segment.option_text.clone();
+    let option_id = segment.option_id;
+    let color = segment.base_color;
+
+    let mesh = asset_cache.option_mesh(option_styles, option_id, segment.get_radius(), meshes);
+    let material = asset_cache.material(color, materials);
 
     let segment_entity = commands
         .spawn((
@@ -114,7 +201,7 @@ fn create_chain_segment_for_player(
                 "Chain Segment: {} (Player {:?})",
                 option_text, player_entity
             )),
-            ChainSegment::new(segment_index, option_text.clone(), option_id, color),
+            segment,
             PlayerChainSegment(player_entity),
             Mesh2d(mesh),
             MeshMaterial2d(material),
@@ -127,22 +214,29 @@ fn create_chain_segment_for_player(
                     font_size: 10.0,
                     ..default()
                 },
-                TextColor(Color::WHITE),
+                TextColor(contrasting_text_color(color)),
                 Transform::from_translation(Vec3::new(0.0, 0.0, 0.1)),
             )],
         ))
         .id();
 
+    if cursed {
+        commands.entity(segment_entity).insert(CursedSegment);
+    }
+
     player_chain.segments.push(segment_entity);
     info!(
         "Created chain segment {} with text: {} (ID: {}) for player {:?}",
         segment_index, option_text, option_id, player_entity
     );
+    segment_entity
 }
 
-/// System to update chain segment positions based on the movement trail
+/// System to ease chain segments toward their place on the movement trail, a
+/// fixed share of the way each fixed step
 pub fn update_chain_positions(
     grid_map: Option<Res<GridMap>>,
+    config: Res<GameConfig>,
     mut player_query: Query<(Entity, &PlayerChain, &MovementTrail), With<Player>>,
     mut segment_query: Query<(&ChainSegment, &mut Transform), Without<ChainReaction>>,
 ) {
@@ -153,7 +247,7 @@ pub fn update_chain_positions(
     for (_player_entity, player_chain, movement_trail) in &mut player_query {
         for &segment_entity in &player_chain.segments {
             if let Ok((segment, mut transform)) = segment_query.get_mut(segment_entity) {
-                let distance = (segment.segment_index + 1) as f32 * super::CHAIN_SEGMENT_SPACING;
+                let distance = (segment.segment_index + 1) as f32 * config.chain.segment_spacing;
 
                 if let Some(target_position) = movement_trail
                     .get_position_at_distance_with_wraparound(
@@ -179,6 +273,220 @@ pub fn update_chain_positions(
     }
 }
 
+/// System to mirror segments near a map edge onto the opposite side
+pub fn update_segment_ghosts(
+    mut commands: Commands,
+    grid_map: Option<Res<GridMap>>,
+    segment_query: Query<
+        (Entity, &Transform, &Mesh2d, &MeshMaterial2d<ColorMaterial>),
+        (With<ChainSegment>, Without<SegmentGhost>),
+    >,
+    mut ghost_query: Query<
+        (
+            Entity,
+            &SegmentGhost,
+            &mut Transform,
+            &mut Mesh2d,
+            &mut MeshMaterial2d<ColorMaterial>,
+        ),
+        Without<ChainSegment>,
+    >,
+) {
+    let Some(grid_map) = grid_map else {
+        return;
+    };
+
+    // Which (segment, offset) pairs should currently have a ghost
+    let mut wanted: Vec<(Entity, Vec2)> = Vec::new();
+    for (segment_entity, transform, ..) in &segment_query {
+        for offset in wrap_ghost_offsets(transform.translation.xy(), &grid_map) {
+            wanted.push((segment_entity, offset));
+        }
+    }
+
+    // Move ghosts that are still needed, drop the rest
+    for (ghost_entity, ghost, mut ghost_transform, mut ghost_mesh, mut ghost_material) in
+        &mut ghost_query
+    {
+        let Some(index) = wanted
+            .iter()
+            .position(|&(segment, offset)| segment == ghost.segment && offset == ghost.offset)
+        else {
+            commands.entity(ghost_entity).despawn();
+            continue;
+        };
+        wanted.swap_remove(index);
+
+        if let Ok((_, transform, mesh, material)) = segment_query.get(ghost.segment) {
+            *ghost_transform = *transform;
+            ghost_transform.translation += ghost.offset.extend(0.0);
+
+            // Merges swap the segment's mesh and material
+            if ghost_mesh.0 != mesh.0 {
+                ghost_mesh.0 = mesh.0.clone();
+            }
+            if ghost_material.0 != material.0 {
+                ghost_material.0 = material.0.clone();
+            }
+        }
+    }
+
+    for (segment_entity, offset) in wanted {
+        let Ok((_, transform, mesh, material)) = segment_query.get(segment_entity) else {
+            continue;
+        };
+        let mut ghost_transform = *transform;
+        ghost_transform.translation += offset.extend(0.0);
+
+        commands.spawn((
+            Name::new("Chain Segment Ghost"),
+            SegmentGhost {
+                segment: segment_entity,
+                offset,
+            },
+            Mesh2d(mesh.0.clone()),
+            MeshMaterial2d(material.0.clone()),
+            ghost_transform,
+            StateScoped(Screen::Gameplay),
+        ));
+    }
+}
+
+/// Offsets at which a position near the map edges should be mirrored
+fn wrap_ghost_offsets(position: Vec2, grid_map: &GridMap) -> Vec<Vec2> {
+    let margin = super::WRAP_GHOST_MARGIN;
+
+    let x_offset = if position.x < -grid_map.half_width() + margin {
+        Some(grid_map.world_width())
+    } else if position.x > grid_map.half_width() - margin {
+        Some(-grid_map.world_width())
+    } else {
+        None
+    };
+    let y_offset = if position.y < -grid_map.half_height() + margin {
+        Some(grid_map.world_height())
+    } else if position.y > grid_map.half_height() - margin {
+        Some(-grid_map.world_height())
+    } else {
+        None
+    };
+
+    let mut offsets = Vec::new();
+    if let Some(x) = x_offset {
+        offsets.push(Vec2::new(x, 0.0));
+    }
+    if let Some(y) = y_offset {
+        offsets.push(Vec2::new(0.0, y));
+    }
+    if let (Some(x), Some(y)) = (x_offset, y_offset) {
+        offsets.push(Vec2::new(x, y));
+    }
+    offsets
+}
+
+/// System to stretch link sprites between the player and its segments, colored
+/// by segment level. Links crossing the map edge are drawn from both sides.
+pub fn update_chain_links(
+    mut commands: Commands,
+    grid_map: Option<Res<GridMap>>,
+    player_query: Query<(Entity, &Transform, &PlayerChain, &PlayerEffects), With<Player>>,
+    segment_query: Query<(&ChainSegment, &Transform), Without<ChainLink>>,
+    mut link_query: Query<(Entity, &ChainLink, &mut Transform, &mut Sprite), Without<Player>>,
+) {
+    let Some(grid_map) = grid_map else {
+        return;
+    };
+
+    // Wanted links: (player, index, mirrored) -> (start, end, level, color)
+    let mut wanted: HashMap<(Entity, usize, bool), (Vec2, Vec2, u32, Color)> = HashMap::new();
+    for (player_entity, player_transform, chain, effects) in &player_query {
+        let mut previous = player_transform.translation.xy();
+        let player_color = LinearRgba::from(effects.base_color);
+
+        for (index, &segment_entity) in chain.segments.iter().enumerate() {
+            let Ok((segment, transform)) = segment_query.get(segment_entity) else {
+                continue;
+            };
+            let current = transform.translation.xy();
+            // Links carry a bit of the owner's color so chains can be told apart
+            let level_color =
+                LinearRgba::from(enhance_color_for_level(segment.base_color, segment.level));
+            let color = Color::from(level_color.mix(&player_color, super::CHAIN_LINK_PLAYER_TINT))
+                .with_alpha(super::CHAIN_LINK_ALPHA);
+
+            let delta = wrapped_delta(previous, current, &grid_map);
+            wanted.insert(
+                (player_entity, index, false),
+                (previous, previous + delta, segment.level, color),
+            );
+            if (previous + delta).distance_squared(current) > 1.0 {
+                wanted.insert(
+                    (player_entity, index, true),
+                    (current - delta, current, segment.level, color),
+                );
+            }
+
+            previous = current;
+        }
+    }
+
+    for (link_entity, link, mut transform, mut sprite) in &mut link_query {
+        match wanted.remove(&(link.player, link.index, link.mirrored)) {
+            Some((start, end, level, color)) => {
+                *transform = link_transform(start, end);
+                sprite.color = color;
+                sprite.custom_size = Some(link_size(start, end, level));
+            }
+            None => commands.entity(link_entity).despawn(),
+        }
+    }
+
+    for ((player, index, mirrored), (start, end, level, color)) in wanted {
+        commands.spawn((
+            Name::new("Chain Link"),
+            ChainLink {
+                player,
+                index,
+                mirrored,
+            },
+            Sprite {
+                color,
+                custom_size: Some(link_size(start, end, level)),
+                ..default()
+            },
+            link_transform(start, end),
+            StateScoped(Screen::Gameplay),
+        ));
+    }
+}
+
+fn link_size(start: Vec2, end: Vec2, level: u32) -> Vec2 {
+    let width = super::CHAIN_LINK_WIDTH * (1.0 + (level.max(1) - 1) as f32 * 0.5);
+    Vec2::new(start.distance(end), width)
+}
+
+fn link_transform(start: Vec2, end: Vec2) -> Transform {
+    let direction = end - start;
+    Transform::from_translation(((start + end) / 2.0).extend(super::CHAIN_LINK_Z))
+        .with_rotation(Quat::from_rotation_z(direction.y.atan2(direction.x)))
+}
+
+/// Shortest offset from `from` to `to` on the wrapping map
+fn wrapped_delta(from: Vec2, to: Vec2, grid_map: &GridMap) -> Vec2 {
+    let mut delta = to - from;
+    if delta.x > grid_map.half_width() {
+        delta.x -= grid_map.world_width();
+    } else if delta.x < -grid_map.half_width() {
+        delta.x += grid_map.world_width();
+    }
+    if delta.y > grid_map.half_height() {
+        delta.y -= grid_map.world_height();
+    } else if delta.y < -grid_map.half_height() {
+        delta.y += grid_map.world_height();
+    }
+    delta
+}
+
 /// Calculate the shortest movement path considering wraparound
 fn calculate_shortest_movement(
     current: Vec2,
@@ -240,14 +548,16 @@ fn calculate_shortest_movement(
 pub fn animate_chain_segments(
     time: Res<Time>,
     mut segment_query: Query<(&mut ChainSegment, &mut Transform), Without<ChainReaction>>, // Exclude reacting segments
+    game_settings: Res<GameSettings>,
 ) {
     let time_factor = time.elapsed_secs();
+    let motion = game_settings.accessibility.effects_intensity.motion_scale();
 
     for (mut segment, mut transform) in &mut segment_query {
         segment.pulse_phase += time.delta_secs() * 2.0;
 
         // Pulsing scale effect
-        let pulse = 1.0 + (segment.pulse_phase.sin() * 0.15);
+        let pulse = 1.0 + (segment.pulse_phase.sin() * 0.15 * motion);
         transform.scale = Vec3::splat(pulse);
 
         // Gentle floating motion
@@ -256,8 +566,8 @@ pub fn animate_chain_segments(
 
         // Apply floating offset
         let base_translation = Vec3::new(
-            transform.translation.x + float_offset_x * 0.3,
-            transform.translation.y + float_offset_y * 0.3,
+            transform.translation.x + float_offset_x * 0.3 * motion,
+            transform.translation.y + float_offset_y * 0.3 * motion,
             transform.translation.z,
         );
 
@@ -270,6 +580,9 @@ pub fn handle_chain_extend_events(
     mut collection_events: EventReader<OptionCollectedEvent>,
     mut chain_events: EventWriter<ChainExtendEvent>,
     player_query: Query<&Transform, With<Player>>,
+    game_settings: Res<GameSettings>,
+    option_styles: Res<OptionStyleRegistry>,
+    chain_mode: Res<ChainMode>,
 ) {
     for event in collection_events.read() {
         info!(
@@ -277,7 +590,8 @@ pub fn handle_chain_extend_events(
             event.option_text, event.is_correct
         );
 
-        if !event.is_correct {
+        let cursed = !event.is_correct;
+        if cursed && (event.shielded || !game_settings.gameplay.cursed_segments) {
             info!("Skipping incorrect answer for chain");
             continue;
         }
@@ -286,24 +600,24 @@ pub fn handle_chain_extend_events(
         if let Ok(player_transform) = player_query.get(event.player_entity) {
             let collect_position = player_transform.translation.xy();
 
-            // Choose color based on option ID (similar to options system)
-            let base_colors = [
-                Color::srgb(0.3, 0.5, 0.8), // Blue
-                Color::srgb(0.8, 0.5, 0.3), // Orange
-                Color::srgb(0.5, 0.8, 0.3), // Green
-                Color::srgb(0.8, 0.3, 0.5), // Pink
-                Color::srgb(0.5, 0.3, 0.8), // Purple
-            ];
-            let color = base_colors[event.option_id % base_colors.len()];
+            // Choose color based on option ID (same style as the options)
+            let color = if cursed {
+                super::CURSED_SEGMENT_COLOR
+            } else {
+                option_styles.color(event.option_id)
+            };
 
             info!("Creating chain extend event for: {}", event.option_text);
 
+            // In co-op the segment flies from the collector to the team chain
             chain_events.write(ChainExtendEvent {
-                player_entity: event.player_entity,
+                player_entity: chain_mode.chain_owner(event.player_entity),
+                contributor: event.player_entity,
                 option_text: event.option_text.clone(),
                 option_id: event.option_id,
                 option_color: color,
                 collect_position,
+                cursed,
             });
         } else {
             warn!("Could not find player entity for chain extend event");
@@ -311,11 +625,81 @@ pub fn handle_chain_extend_events(
     }
 }
 
+/// System to burn off the oldest cursed segment after a streak of correct answers
+pub fn burn_cursed_segments(
+    mut commands: Commands,
+    mut collection_events: EventReader<OptionCollectedEvent>,
+    mut player_query: Query<(&mut PlayerChain, &mut CurseBurnStreak), With<Player>>,
+    cursed_query: Query<
+        &Transform,
+        (
+            With<CursedSegment>,
+            Without<ChainReaction>,
+            Without<ChainMerging>,
+        ),
+    >,
+    mut explosion_events: EventWriter<SpawnExplosionEvent>,
+) {
+    for event in collection_events.read() {
+        let Ok((mut player_chain, mut streak)) = player_query.get_mut(event.player_entity) else {
+            continue;
+        };
+
+        if !event.is_correct {
+            streak.0 = 0;
+            continue;
+        }
+
+        streak.0 += 1;
+        if streak.0 < super::CURSE_BURN_STREAK {
+            continue;
+        }
+
+        let Some(cursed_index) = player_chain
+            .segments
+            .iter()
+            .position(|&segment| cursed_query.contains(segment))
+        else {
+            continue;
+        };
+
+        let cursed_entity = player_chain.segments.remove(cursed_index);
+        if let Ok(transform) = cursed_query.get(cursed_entity) {
+            explosion_events.write(SpawnExplosionEvent {
+                position: transform.translation,
+                color: super::CURSED_SEGMENT_COLOR,
+                intensity: 1.0,
+            });
+        }
+        commands.entity(cursed_entity).despawn();
+
+        for (new_index, &segment_entity) in player_chain.segments.iter().enumerate() {
+            commands
+                .entity(segment_entity)
+                .insert(SegmentReindexMarker { new_index });
+        }
+
+        streak.0 = 0;
+        info!(
+            "Player {:?} burned off a cursed segment, {} segments remaining",
+            event.player_entity,
+            player_chain.segments.len()
+        );
+    }
+}
+
 /// System to handle chain extend events and create flying objects
+#[allow(clippy::too_many_arguments)]
 pub fn create_flying_to_chain_objects(
     mut commands: Commands,
     mut chain_events: EventReader<ChainExtendEvent>,
     player_query: Query<(&PlayerChain, &MovementTrail), With<Player>>,
+    option_styles: Res<OptionStyleRegistry>,
+    config: Res<GameConfig>,
+    mut pool: ResMut<EntityPool>,
+    mut asset_cache: ResMut<SharedAssetCache>,
+    pooled_query: Query<&Children>,
+    mut label_query: Query<(&mut Text2d, &mut TextColor)>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
@@ -325,7 +709,7 @@ pub fn create_flying_to_chain_objects(
         if let Ok((player_chain, movement_trail)) = player_query.get(event.player_entity) {
             // Calculate where the new segment should go for THIS player
             let target_distance =
-                (player_chain.segments.len() + 1) as f32 * super::CHAIN_SEGMENT_SPACING;
+                (player_chain.segments.len() + 1) as f32 * config.chain.segment_spacing;
             let target_position = movement_trail
                 .get_position_at_distance(target_distance)
                 .unwrap_or(event.collect_position);
@@ -335,39 +719,63 @@ pub fn create_flying_to_chain_objects(
                 event.player_entity, target_position, target_distance
             );
 
-            // Create the flying object
-            let mesh = meshes.add(Circle::new(super::CHAIN_SEGMENT_SIZE));
-            let material = materials.add(ColorMaterial::from(event.option_color));
-
-            commands.spawn((
+            let flying = (
                 Name::new(format!("Flying to Chain: {}", event.option_text)),
-                Mesh2d(mesh),
-                MeshMaterial2d(material),
                 Transform::from_translation(Vec3::new(
                     event.collect_position.x,
                     event.collect_position.y,
                     3.0,
                 )),
+                Visibility::Inherited,
                 FlyingToChain::new(
                     event.collect_position,
                     target_position,
                     event.option_text.clone(),
                     event.option_id,
                     event.option_color,
-                ),
+                )
+                .with_cursed(event.cursed)
+                .with_contributor(event.contributor),
                 FlyingToPlayer(event.player_entity),
-                StateScoped(Screen::Gameplay),
-                children![(
-                    Name::new("Flying Object Text"),
-                    Text2d::new(event.option_text.clone()),
-                    TextFont {
-                        font_size: 10.0,
-                        ..default()
-                    },
-                    TextColor(Color::WHITE),
-                    Transform::from_translation(Vec3::new(0.0, 0.0, 0.1)),
-                )],
-            ));
+                Mesh2d(asset_cache.option_mesh(
+                    &option_styles,
+                    event.option_id,
+                    super::CHAIN_SEGMENT_SIZE,
+                    &mut meshes,
+                )),
+                MeshMaterial2d(asset_cache.material(event.option_color, &mut materials)),
+            );
+            let text_color = contrasting_text_color(event.option_color);
+
+            // Reuse a released flying object, refreshing its label in place
+            let recycled = pool
+                .acquire(PoolKind::FlyingObject)
+                .and_then(|entity| pooled_query.get(entity).ok().map(|parts| (entity, parts)));
+
+            if let Some((entity, children)) = recycled {
+                for child in children.iter() {
+                    if let Ok((mut text, mut color)) = label_query.get_mut(child) {
+                        text.0 = event.option_text.clone();
+                        color.0 = text_color;
+                    }
+                }
+                commands.entity(entity).insert(flying);
+            } else {
+                commands.spawn((
+                    flying,
+                    StateScoped(Screen::Gameplay),
+                    children![(
+                        Name::new("Flying Object Text"),
+                        Text2d::new(event.option_text.clone()),
+                        TextFont {
+                            font_size: 10.0,
+                            ..default()
+                        },
+                        TextColor(text_color),
+                        Transform::from_translation(Vec3::new(0.0, 0.0, 0.1)),
+                    )],
+                ));
+            }
 
             info!("Started fly-to-chain animation for: {}", event.option_text);
         } else {
@@ -379,82 +787,147 @@ pub fn create_flying_to_chain_objects(
     }
 }
 
-/// System to track player movement and build the trail
+/// System to track player movement and build the trail, keeping only as much
+/// of it as the player's chain needs. Segments look up their place on the
+/// trail through its distance index, so that is rebuilt with every sample.
 pub fn track_player_movement(
     time: Res<Time>,
-    mut player_query: Query<(&Transform, &mut MovementTrail), With<Player>>,
+    grid_map: Option<Res<GridMap>>,
+    config: Res<GameConfig>,
+    mut player_query: Query<(&Transform, &mut MovementTrail, Option<&PlayerChain>), With<Player>>,
 ) {
-    for (transform, mut movement_trail) in &mut player_query {
+    for (transform, mut movement_trail, chain) in &mut player_query {
         movement_trail.sample_timer.tick(time.delta());
 
         if movement_trail.sample_timer.just_finished() {
             let position = transform.translation.xy();
             movement_trail.add_position(position);
+
+            if let Some(grid_map) = grid_map.as_ref() {
+                let segments = chain.map_or(0, |chain| chain.segments.len());
+                let needed = (segments + 1) as f32 * config.chain.segment_spacing
+                    + super::TRAIL_PRUNE_MARGIN;
+                movement_trail.prune_beyond(needed, grid_map.half_width(), grid_map.half_height());
+                movement_trail
+                    .rebuild_distance_index(grid_map.half_width(), grid_map.half_height());
+            }
         }
     }
 }
 
 pub fn detect_player_chain_collision(
     mut reaction_events: EventWriter<ChainReactionEvent>,
-    player_query: Query<(Entity, &Transform, &PlayerChain), With<Player>>,
+    spatial_index: Res<crate::spatial::SpatialIndex>,
+    mut commands: Commands,
+    mut shield_events: EventWriter<crate::player::ShieldShatteredEvent>,
+    mut player_query: Query<
+        (
+            Entity,
+            &Transform,
+            Option<&crate::powerups::ActivePowerUps>,
+            &crate::player::Dash,
+            &mut crate::player::PlayerStats,
+        ),
+        (With<Player>, With<PlayerChain>, Without<SpawnProtection>),
+    >,
     segment_query: Query<
         (&ChainSegment, &Transform, &PlayerChainSegment),
         (With<ChainSegment>, Without<Player>),
     >,
     reaction_state: Res<ChainReactionState>,
+    chain_mode: Res<ChainMode>,
 ) {
-    for (player_entity, player_transform, player_chain) in &player_query {
+    let collision_distance = crate::player::PLAYER_SIZE + super::CHAIN_SEGMENT_SIZE;
+
+    for (player_entity, player_transform, power_ups, dash, mut stats) in &mut player_query {
+        // In co-op every player can run into the team chain
+        let chain_owner = chain_mode.chain_owner(player_entity);
+
+        // A chain shield protects the player from hitting their own chain, and
+        // so does dashing
+        if power_ups.is_some_and(|p| p.has(crate::powerups::PowerUpKind::ChainShield))
+            || dash.is_active()
+        {
+            continue;
+        }
+
         // Check if this player already has an active reaction
         if reaction_state
             .active_reactions
             .iter()
-            .any(|r| r.player_entity == player_entity)
+            .any(|r| r.player_entity == chain_owner)
         {
             continue;
         }
 
         let player_pos = player_transform.translation.xy();
 
-        for &segment_entity in &player_chain.segments {
-            if let Ok((segment, segment_transform, segment_owner)) =
-                segment_query.get(segment_entity)
-            {
-                // Only check collision with this player's own segments
-                if segment_owner.0 != player_entity {
-                    continue;
-                }
-
-                // Skip collision detection for the first chain element
-                if segment.segment_index == 0 {
-                    continue;
-                }
+        // The hit closest to the head of the chain starts the reaction
+        let hit = spatial_index
+            .segments
+            .near(player_pos, collision_distance)
+            .filter_map(|segment_entity| segment_query.get(segment_entity).ok())
+            .filter(|(segment, _, segment_owner)| {
+                // Only check collision with this player's own (or the team's)
+                // segments, and skip collision detection for the first chain element
+                segment_owner.0 == chain_owner && segment.segment_index != 0
+            })
+            .map(|(segment, segment_transform, _)| {
+                (
+                    segment.segment_index,
+                    player_pos.distance(segment_transform.translation.xy()),
+                )
+            })
+            .filter(|(_, distance)| *distance <= collision_distance)
+            .min_by_key(|(segment_index, _)| *segment_index);
 
-                let segment_pos = segment_transform.translation.xy();
-                let distance = player_pos.distance(segment_pos);
-                let collision_distance = crate::player::PLAYER_SIZE + super::CHAIN_SEGMENT_SIZE;
+        if let Some((segment_index, distance)) = hit {
+            // A streak shield takes the hit, with a moment to get clear of the chain
+            if stats.shields > 0 {
+                stats.shields -= 1;
+                commands
+                    .entity(player_entity)
+                    .insert(SpawnProtection::default());
+                shield_events.write(crate::player::ShieldShatteredEvent {
+                    player_entity,
+                    position: player_pos,
+                });
+                continue;
+            }
 
-                if distance <= collision_distance {
-                    info!(
-                        "Player {:?} hit their own chain segment {} at distance {}",
-                        player_entity, segment.segment_index, distance
-                    );
+            info!(
+                "Player {:?} hit their own chain segment {} at distance {}",
+                player_entity, segment_index, distance
+            );
 
-                    reaction_events.write(ChainReactionEvent {
-                        player_entity,
-                        hit_segment_index: segment.segment_index,
-                    });
-                    break;
-                }
-            }
+            reaction_events.write(ChainReactionEvent {
+                player_entity: chain_owner,
+                hit_segment_index: segment_index,
+            });
         }
     }
 }
 
-/// System to handle chain reaction events
+/// System to handle chain reaction events and mark the segments about to react
 pub fn handle_chain_reaction_events(
+    mut commands: Commands,
     mut reaction_events: EventReader<ChainReactionEvent>,
     mut reaction_state: ResMut<ChainReactionState>,
+    game_settings: Res<GameSettings>,
+    config: Res<GameConfig>,
+    chain_mode: Res<ChainMode>,
+    player_chain_query: Query<&PlayerChain, With<Player>>,
+    segment_query: Query<&ChainSegment>,
+    mut asset_cache: ResMut<SharedAssetCache>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
+    let rule = game_settings
+        .gameplay
+        .reaction_spread
+        .resolve(game_settings.gameplay.difficulty, &chain_mode);
+    let limit = game_settings.gameplay.reaction_spread_limit;
+
     for event in reaction_events.read() {
         if !reaction_state.is_active()
             || reaction_state
@@ -467,7 +940,48 @@ pub fn handle_chain_reaction_events(
                 event.hit_segment_index, event.player_entity
             );
 
-            reaction_state.start_reaction(event.player_entity, event.hit_segment_index);
+            let spread_interval =
+                std::time::Duration::from_secs_f32(config.chain.reaction_spread_interval);
+            if reaction_state.reaction_spread_timer.duration() != spread_interval {
+                reaction_state
+                    .reaction_spread_timer
+                    .set_duration(spread_interval);
+            }
+            reaction_state.start_reaction(
+                event.player_entity,
+                event.hit_segment_index,
+                rule,
+                limit,
+            );
+
+            let Ok(player_chain) = player_chain_query.get(event.player_entity) else {
+                continue;
+            };
+            let max_spread_distance =
+                rule.max_spread_distance(limit, reaction_state.max_spread_distance);
+            let mesh = asset_cache.circle_mesh(super::REACTION_TELEGRAPH_RADIUS, &mut meshes);
+            let material = asset_cache.material(rule.telegraph_color(), &mut materials);
+
+            for &segment_entity in &player_chain.segments {
+                let Ok(segment) = segment_query.get(segment_entity) else {
+                    continue;
+                };
+                let offset = segment.segment_index as i32 - event.hit_segment_index as i32;
+                if offset.abs() > max_spread_distance || (offset < 0 && !rule.spreads_toward_head())
+                {
+                    continue;
+                }
+
+                commands.entity(segment_entity).with_child((
+                    Name::new("Reaction Telegraph"),
+                    Mesh2d(mesh.clone()),
+                    MeshMaterial2d(material.clone()),
+                    Transform::from_translation(Vec3::new(0.0, 0.0, -0.1)),
+                    ReactionTelegraph {
+                        player_entity: event.player_entity,
+                    },
+                ));
+            }
         }
     }
 }
@@ -477,11 +991,19 @@ pub fn update_chain_reaction(
     mut commands: Commands,
     time: Res<Time>,
     mut reaction_state: ResMut<ChainReactionState>,
+    mut respawn_events: EventWriter<PlayerRespawnEvent>,
+    game_settings: Res<GameSettings>,
+    config: Res<GameConfig>,
     player_chain_query: Query<(Entity, &PlayerChain), With<Player>>,
-    segment_query: Query<
-        (Entity, &ChainSegment, &PlayerChainSegment),
+    mut segment_query: Query<
+        (Entity, &mut ChainSegment, &PlayerChainSegment, &Transform),
         (With<ChainSegment>, Without<ChainReaction>),
     >,
+    reacting_query: Query<&PlayerChainSegment, With<ChainReaction>>,
+    mut explosion_events: EventWriter<crate::effects::SpawnExplosionEvent>,
+    player_index_query: Query<&PlayerIndex>,
+    locale: Res<Locale>,
+    mut feed_events: EventWriter<GameFeedEvent>,
 ) {
     if !reaction_state.is_active() {
         return;
@@ -492,9 +1014,6 @@ pub fn update_chain_reaction(
     if reaction_state.reaction_spread_timer.just_finished() {
         let mut reactions_to_remove = Vec::new();
 
-        // Extract max_spread_distance before the mutable borrow
-        let max_spread_distance = reaction_state.max_spread_distance;
-
         // Process each active reaction
         for reaction in &mut reaction_state.active_reactions {
             let hit_index = reaction.hit_segment_index;
@@ -509,24 +1028,50 @@ pub fn update_chain_reaction(
             {
                 // Find segments at the current spread distance for this specific player
                 for &segment_entity in &player_chain.segments {
-                    if let Ok((entity, segment, segment_owner)) = segment_query.get(segment_entity)
+                    if let Ok((entity, mut segment, segment_owner, transform)) =
+                        segment_query.get_mut(segment_entity)
                     {
                         // Only affect this player's segments
                         if segment_owner.0 != player_entity {
                             continue;
                         }
 
-                        let segment_distance_from_hit =
-                            (segment.segment_index as i32 - hit_index as i32).abs();
+                        let offset = segment.segment_index as i32 - hit_index as i32;
+                        if offset.abs() != spread_distance
+                            || (offset < 0 && reaction.blocked_before)
+                            || (offset > 0 && reaction.blocked_after)
+                        {
+                            continue;
+                        }
+
+                        // High-level segments stop the spread past them
+                        if segment.blocks_reaction() {
+                            reaction.blocked_before |= offset <= 0;
+                            reaction.blocked_after |= offset >= 0;
+                        }
 
-                        if segment_distance_from_hit == spread_distance {
-                            segments_to_react.push(entity);
+                        // Merged segments absorb a hit instead of being destroyed
+                        if segment.durability > 0 {
+                            segment.durability -= 1;
+                            info!(
+                                "Segment {} absorbed a chain reaction hit",
+                                segment.segment_index
+                            );
+                            explosion_events.write(crate::effects::SpawnExplosionEvent {
+                                position: transform.translation,
+                                color: Color::WHITE,
+                                intensity: 0.4,
+                            });
+                            continue;
                         }
+
+                        segments_to_react.push(entity);
                     }
                 }
             }
 
             // Add ChainReaction component to segments that should start reacting
+            let started_reacting = !segments_to_react.is_empty();
             for entity in segments_to_react {
                 info!(
                     "Starting reaction on segment at distance {} from hit for player {:?}",
@@ -534,31 +1079,73 @@ pub fn update_chain_reaction(
                 );
                 commands
                     .entity(entity)
-                    .insert(ChainReaction::new(super::REACTION_BALL_DURATION));
+                    .insert(ChainReaction::new(config.chain.reaction_ball_duration));
             }
 
             // Increase spread distance for next iteration
             reaction.current_spread_distance += 1;
 
-            // Check if this reaction is complete - use the extracted value
-            if reaction.current_spread_distance > max_spread_distance {
-                // Check if any segments are still reacting for this player
-                let player_reacting_segments: Vec<_> = segment_query
+            // Check if this reaction is complete
+            let spread_done = reaction.current_spread_distance > reaction.max_spread_distance
+                || (reaction.blocked_before && reaction.blocked_after);
+            if spread_done {
+                // Wait until this player's reacting segments are gone
+                let still_reacting = reacting_query
                     .iter()
-                    .filter(|(_, _, segment_owner)| segment_owner.0 == player_entity)
-                    .collect();
+                    .any(|segment_owner| segment_owner.0 == player_entity);
 
-                if player_reacting_segments.is_empty() {
+                if !still_reacting && !started_reacting {
                     info!("Chain reaction complete for player {:?}", player_entity);
-                    reactions_to_remove.push(player_entity);
+                    reactions_to_remove.push((player_entity, reaction.segments_destroyed));
                 }
             }
         }
 
         // Remove completed reactions
-        for player_entity in reactions_to_remove {
+        for (player_entity, segments_destroyed) in reactions_to_remove {
             reaction_state.remove_completed_reaction(player_entity);
+
+            if segments_destroyed > 0 {
+                let name = player_index_query
+                    .get(player_entity)
+                    .map(|index| game_settings.multiplayer.player_name(index.0))
+                    .unwrap_or_default();
+                let points = segments_destroyed as i32 * config.chain.points_lost_per_segment;
+                feed_events.write(
+                    GameFeedEvent::new(
+                        locale.format("feed.chain_hit", &[("name", &name), ("points", &points)]),
+                    )
+                    .with_color(Color::srgb(1.0, 0.45, 0.45)),
+                );
+            }
+
+            if game_settings.gameplay.respawn_after_chain_reaction {
+                respawn_events.write(PlayerRespawnEvent { player_entity });
+            }
+        }
+    }
+}
+
+/// System to blink the warning rings and remove them once their reaction is over
+pub fn animate_reaction_telegraphs(
+    mut commands: Commands,
+    time: Res<Time>,
+    reaction_state: Res<ChainReactionState>,
+    mut telegraph_query: Query<(Entity, &ReactionTelegraph, &mut Transform)>,
+) {
+    let pulse = 1.0 + (time.elapsed_secs() * super::REACTION_TELEGRAPH_BLINK_SPEED).sin() * 0.25;
+
+    for (entity, telegraph, mut transform) in &mut telegraph_query {
+        let active = reaction_state
+            .active_reactions
+            .iter()
+            .any(|r| r.player_entity == telegraph.player_entity);
+        if !active {
+            commands.entity(entity).despawn();
+            continue;
         }
+
+        transform.scale = Vec3::splat(pulse);
     }
 }
 
@@ -574,8 +1161,10 @@ pub fn animate_reacting_segments(
         &PlayerChainSegment,
     )>,
     mut player_chain_query: Query<(Entity, &mut PlayerChain), With<Player>>,
+    mut reaction_state: ResMut<ChainReactionState>,
     mut destruction_events: EventWriter<ChainSegmentDestroyedEvent>,
     mut explosion_events: EventWriter<crate::effects::SpawnExplosionEvent>,
+    config: Res<GameConfig>,
 ) {
     for (entity, mut reaction, mut transform, segment, segment_owner) in &mut reacting_query {
         reaction.reaction_timer.tick(time.delta());
@@ -624,9 +1213,18 @@ pub fn animate_reacting_segments(
                 player_entity,
                 segment_index: segment.segment_index,
                 option_text: segment.option_text.clone(),
-                points_lost: crate::chain::POINTS_LOST_PER_SEGMENT,
+                points_lost: config.chain.points_lost_per_segment,
+                position: transform.translation.xy(),
             });
 
+            if let Some(player_reaction) = reaction_state
+                .active_reactions
+                .iter_mut()
+                .find(|r| r.player_entity == player_entity)
+            {
+                player_reaction.segments_destroyed += 1;
+            }
+
             // Remove from the correct player's chain
             if let Ok((_, mut player_chain)) = player_chain_query.get_mut(player_entity) {
                 player_chain
@@ -647,15 +1245,23 @@ pub fn detect_chain_merges(
     merge_state: Res<ChainMergeState>,
     player_query: Query<(Entity, &PlayerChain), With<Player>>,
     segment_query: Query<
-        (Entity, &ChainSegment, &PlayerChainSegment),
+        (
+            Entity,
+            &ChainSegment,
+            &PlayerChainSegment,
+            Has<CursedSegment>,
+        ),
         (
             With<ChainSegment>,
             Without<ChainMerging>,
             Without<ChainReaction>,
         ),
     >,
+    chain_mode: Res<ChainMode>,
+    config: Res<GameConfig>,
 ) {
     let current_time = time.elapsed_secs();
+    let merge_length = config.chain.min_segments_to_merge.max(2);
 
     for (player_entity, player_chain) in &player_query {
         // Check if this player can merge (cooldown check)
@@ -671,35 +1277,46 @@ pub fn detect_chain_merges(
                 segment_query
                     .get(segment_entity)
                     .ok()
-                    .map(|(entity, segment, owner)| (entity, segment.clone(), owner.0))
+                    .map(|(entity, segment, owner, cursed)| {
+                        (entity, segment.clone(), owner.0, cursed)
+                    })
             })
-            .filter(|(_, _, owner)| *owner == player_entity)
+            .filter(|(_, _, owner, _)| *owner == player_entity)
             .collect();
 
         // Check for mergeable sequences
-        for window_start in 0..segments_data
-            .len()
-            .saturating_sub(MIN_SEGMENTS_TO_MERGE - 1)
-        {
-            let window = &segments_data[window_start..window_start + MIN_SEGMENTS_TO_MERGE];
+        for window_start in 0..segments_data.len().saturating_sub(merge_length - 1) {
+            let window = &segments_data[window_start..window_start + merge_length];
 
             // Check if all segments in window have same option_id and are level 1
             let first_segment = &window[0].1;
-            let can_merge = window.iter().all(|(_, segment, _)| {
-                segment.option_id == first_segment.option_id
+            // Cursed segments never merge, which also blocks merges across them
+            let can_merge = window.iter().all(|(_, segment, _, cursed)| {
+                !cursed
+                    && segment.option_id == first_segment.option_id
                     && segment.level == first_segment.level
                     && segment.level < 3 // Don't merge beyond level 3
             });
 
-            if can_merge {
+            // In co-op a merge needs collections from more than one player
+            let mut contributors: Vec<Entity> = window
+                .iter()
+                .flat_map(|(_, segment, _, _)| segment.contributors.iter().copied())
+                .collect();
+            contributors.sort();
+            contributors.dedup();
+            let enough_contributors =
+                !chain_mode.is_team() || contributors.len() >= super::MIN_MERGE_CONTRIBUTORS;
+
+            if can_merge && enough_contributors {
                 let merge_segments: Vec<_> = window
                     .iter()
-                    .map(|(entity, segment, _)| (*entity, segment.segment_index))
+                    .map(|(entity, segment, _, _)| (*entity, segment.segment_index))
                     .collect();
 
                 info!(
                     "Detected mergeable sequence for player {:?}: {} segments of type '{}'",
-                    player_entity, MIN_SEGMENTS_TO_MERGE, first_segment.option_text
+                    player_entity, merge_length, first_segment.option_text
                 );
 
                 merge_events.write(ChainMergeEvent {
@@ -707,6 +1324,7 @@ pub fn detect_chain_merges(
                     merge_segments,
                     option_color: first_segment.base_color,
                     new_level: first_segment.level + 1,
+                    contributors,
                 });
 
                 // Only trigger one merge per detection cycle per player
@@ -721,12 +1339,15 @@ pub fn handle_chain_merge_events(
     mut commands: Commands,
     mut merge_events: EventReader<ChainMergeEvent>,
     mut merge_state: ResMut<ChainMergeState>,
-    player_query: Query<&PlayerChain, With<Player>>,
-    segment_query: Query<&Transform, With<ChainSegment>>,
+    player_query: Query<&PlayerIndex, (With<Player>, With<PlayerChain>)>,
+    mut segment_query: Query<(&Transform, &mut ChainSegment)>,
     time: Res<Time>,
+    game_settings: Res<GameSettings>,
+    locale: Res<Locale>,
+    mut feed_events: EventWriter<GameFeedEvent>,
 ) {
     for event in merge_events.read() {
-        let Ok(_player_chain) = player_query.get(event.player_entity) else {
+        let Ok(player_index) = player_query.get(event.player_entity) else {
             continue;
         };
 
@@ -737,13 +1358,37 @@ pub fn handle_chain_merge_events(
         let target_index = event.merge_segments.len() / 2;
         let (target_entity, _target_segment_index) = event.merge_segments[target_index];
 
-        let target_transform = segment_query.get(target_entity).ok();
+        let target_transform = segment_query
+            .get(target_entity)
+            .ok()
+            .map(|(transform, _)| *transform);
+
+        // The merged segment keeps everyone who contributed to it
+        if let Ok((_, mut target_segment)) = segment_query.get_mut(target_entity) {
+            target_segment.contributors = event.contributors.clone();
+
+            feed_events.write(
+                GameFeedEvent::new(locale.format(
+                    "feed.merge",
+                    &[
+                        (
+                            "name",
+                            &game_settings.multiplayer.player_name(player_index.0),
+                        ),
+                        ("count", &event.merge_segments.len()),
+                        ("option", &target_segment.option_text),
+                        ("level", &event.new_level),
+                    ],
+                ))
+                .with_color(event.option_color),
+            );
+        }
 
         // Start merge animation for all segments
         for (i, &(segment_entity, _)) in event.merge_segments.iter().enumerate() {
             let is_target = i == target_index;
 
-            if let Ok(transform) = segment_query.get(segment_entity) {
+            if let Ok((transform, _)) = segment_query.get(segment_entity) {
                 let target_pos = if is_target {
                     transform.translation
                 } else if let Some(target_transform) = target_transform {
@@ -786,8 +1431,11 @@ pub fn animate_merging_segments(
         &PlayerChainSegment,
     )>,
     _player_query: Query<&PlayerChain, With<Player>>,
+    option_styles: Res<OptionStyleRegistry>,
+    mut asset_cache: ResMut<SharedAssetCache>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    mut completed_events: EventWriter<ChainMergeCompletedEvent>,
 ) {
     let mut completed_merges: Vec<(Entity, ChainSegment, Entity, Vec3)> = Vec::new();
     let mut entities_to_despawn: Vec<Entity> = Vec::new();
@@ -821,7 +1469,7 @@ pub fn animate_merging_segments(
             if merging.is_target_segment {
                 // Convert target to merged segment
                 let mut new_segment = segment.clone();
-                new_segment.level += 1;
+                new_segment.level_up();
                 new_segment.merge_value = 3; // For now, assume we're always merging 3
 
                 completed_merges.push((
@@ -849,8 +1497,13 @@ pub fn animate_merging_segments(
         let enhanced_color =
             enhance_color_for_level(new_segment_data.base_color, new_segment_data.level);
 
-        let new_mesh = meshes.add(Circle::new(new_radius));
-        let new_material = materials.add(ColorMaterial::from(enhanced_color));
+        let new_mesh = asset_cache.option_mesh(
+            &option_styles,
+            new_segment_data.option_id,
+            new_radius,
+            &mut meshes,
+        );
+        let new_material = asset_cache.material(enhanced_color, &mut materials);
 
         commands
             .entity(target_entity)
@@ -871,6 +1524,13 @@ pub fn animate_merging_segments(
             player_entity, new_segment_data.level, new_radius
         );
 
+        completed_events.write(ChainMergeCompletedEvent {
+            player_entity,
+            position: merge_position.xy(),
+            new_level: new_segment_data.level,
+            merge_value: new_segment_data.merge_value,
+        });
+
         // Spawn merge effect
         commands.spawn((
             Name::new("Merge Effect"),
@@ -968,3 +1628,352 @@ pub fn handle_segment_reindexing(
         commands.entity(entity).remove::<SegmentReindexMarker>();
     }
 }
+
+/// System to detect players running into the tail of another player's chain
+#[allow(clippy::type_complexity)]
+pub fn detect_chain_steal(
+    game_settings: Res<GameSettings>,
+    chain_mode: Res<ChainMode>,
+    reaction_state: Res<ChainReactionState>,
+    spatial_index: Res<crate::spatial::SpatialIndex>,
+    mut steal_events: EventWriter<ChainStealEvent>,
+    player_query: Query<
+        (
+            Entity,
+            &Transform,
+            &PlayerChain,
+            Has<StealInvulnerability>,
+            Has<SpawnProtection>,
+        ),
+        With<Player>,
+    >,
+    segment_query: Query<
+        (&ChainSegment, &Transform, &PlayerChainSegment),
+        (
+            Without<Player>,
+            Without<ChainReaction>,
+            Without<ChainMerging>,
+        ),
+    >,
+) {
+    let settings = &game_settings.multiplayer;
+    // Nobody steals from their own team chain in co-op
+    if !settings.chain_steal_enabled || chain_mode.is_team() {
+        return;
+    }
+
+    let mut victims = Vec::new();
+    let search_radius = crate::player::PLAYER_SIZE + super::CHAIN_SEGMENT_SIZE * 4.0;
+
+    for (thief_entity, thief_transform, _, thief_cooldown, thief_spawning) in &player_query {
+        if thief_cooldown || thief_spawning {
+            continue;
+        }
+
+        let thief_pos = thief_transform.translation.xy();
+
+        for segment_entity in spatial_index.segments.near(thief_pos, search_radius) {
+            let Ok((tail, tail_transform, segment_owner)) = segment_query.get(segment_entity)
+            else {
+                continue;
+            };
+            let victim_entity = segment_owner.0;
+
+            let Ok((_, _, victim_chain, victim_cooldown, victim_spawning)) =
+                player_query.get(victim_entity)
+            else {
+                continue;
+            };
+
+            // Only the tail of another player's chain can be stolen
+            if victim_entity == thief_entity
+                || victim_cooldown
+                || victim_spawning
+                || victims.contains(&victim_entity)
+                || victim_chain.segments.last() != Some(&segment_entity)
+            {
+                continue;
+            }
+
+            // Chains in the middle of a reaction can't be stolen from
+            if reaction_state
+                .active_reactions
+                .iter()
+                .any(|r| r.player_entity == victim_entity)
+            {
+                continue;
+            }
+
+            let distance = thief_pos.distance(tail_transform.translation.xy());
+            if distance <= crate::player::PLAYER_SIZE + tail.get_radius() {
+                let segment_count = settings
+                    .chain_steal_segments
+                    .min(victim_chain.segments.len());
+
+                info!(
+                    "Player {:?} steals {} segments from player {:?}",
+                    thief_entity, segment_count, victim_entity
+                );
+
+                steal_events.write(ChainStealEvent {
+                    thief_entity,
+                    victim_entity,
+                    segment_count,
+                });
+                victims.push(victim_entity);
+                break;
+            }
+        }
+    }
+}
+
+/// System to move stolen segments from the victim's chain to the thief's chain
+pub fn handle_chain_steal_events(
+    mut commands: Commands,
+    game_settings: Res<GameSettings>,
+    mut steal_events: EventReader<ChainStealEvent>,
+    mut explosion_events: EventWriter<SpawnExplosionEvent>,
+    mut visual_events: EventWriter<PlayerVisualEvent>,
+    mut player_query: Query<&mut PlayerChain, With<Player>>,
+    mut segment_query: Query<(&mut ChainSegment, &mut PlayerChainSegment, &Transform)>,
+) {
+    let invulnerability = game_settings.multiplayer.chain_steal_invulnerability;
+
+    for event in steal_events.read() {
+        let Ok([mut thief_chain, mut victim_chain]) =
+            player_query.get_many_mut([event.thief_entity, event.victim_entity])
+        else {
+            continue;
+        };
+
+        let split_at = victim_chain
+            .segments
+            .len()
+            .saturating_sub(event.segment_count);
+        let stolen = victim_chain.segments.split_off(split_at);
+        if stolen.is_empty() {
+            continue;
+        }
+
+        // Stolen segments join the thief's chain, the tail segment first
+        for &segment_entity in stolen.iter().rev() {
+            thief_chain.segments.push(segment_entity);
+        }
+
+        let thief_segments = thief_chain.segments.clone();
+        for (index, &segment_entity) in thief_segments.iter().enumerate() {
+            if let Ok((mut segment, mut owner, transform)) = segment_query.get_mut(segment_entity) {
+                segment.segment_index = index;
+                if owner.0 != event.thief_entity {
+                    owner.0 = event.thief_entity;
+                    explosion_events.write(SpawnExplosionEvent {
+                        position: transform.translation,
+                        color: segment.base_color,
+                        intensity: 0.5,
+                    });
+                }
+            }
+        }
+
+        commands
+            .entity(event.thief_entity)
+            .insert(StealInvulnerability::new(invulnerability));
+        commands
+            .entity(event.victim_entity)
+            .insert(StealInvulnerability::new(invulnerability));
+
+        visual_events.write(PlayerVisualEvent {
+            player_entity: event.thief_entity,
+            event_type: PlayerVisualEventType::Boost {
+                duration: 1.0,
+                intensity: 1.0,
+            },
+        });
+        visual_events.write(PlayerVisualEvent {
+            player_entity: event.victim_entity,
+            event_type: PlayerVisualEventType::WrongAnswer,
+        });
+    }
+}
+
+/// System to count down steal invulnerability. The blinking is done by
+/// `blink_protected_players` together with spawn protection.
+pub fn update_steal_invulnerability(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut player_query: Query<(Entity, &mut StealInvulnerability), With<Player>>,
+) {
+    for (entity, mut protection) in &mut player_query {
+        if protection.timer.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<StealInvulnerability>();
+        }
+    }
+}
+
+/// System to restart the movement trail of respawned players
+pub fn reset_trail_on_respawn(
+    mut respawn_events: EventReader<PlayerRespawnEvent>,
+    mut trail_query: Query<&mut MovementTrail, With<Player>>,
+) {
+    for event in respawn_events.read() {
+        if let Ok(mut trail) = trail_query.get_mut(event.player_entity) {
+            trail.positions.clear();
+        }
+    }
+}
+
+/// System to apply the overflow rule to chains that grew past their maximum length
+pub fn handle_chain_overflow(
+    mut commands: Commands,
+    game_settings: Res<GameSettings>,
+    mut player_query: Query<(Entity, &mut PlayerChain), With<Player>>,
+    segment_query: Query<
+        (&ChainSegment, &Transform),
+        (Without<ChainReaction>, Without<ChainMerging>),
+    >,
+    mut overflow_events: EventWriter<ChainOverflowEvent>,
+    mut explosion_events: EventWriter<SpawnExplosionEvent>,
+    config: Res<GameConfig>,
+) {
+    let rule = game_settings.gameplay.chain_overflow;
+
+    for (player_entity, mut player_chain) in &mut player_query {
+        if player_chain.segments.len() <= player_chain.max_segments {
+            continue;
+        }
+
+        // Merging segments are left alone, the oldest settled ones go first
+        let settled: Vec<Entity> = player_chain
+            .segments
+            .iter()
+            .copied()
+            .filter(|&segment| segment_query.contains(segment))
+            .collect();
+        let overflow = player_chain.segments.len() - player_chain.max_segments;
+        let removed_count = match rule {
+            ChainOverflowRule::Reaction => overflow + super::OVERFLOW_REACTION_EXTRA_SEGMENTS,
+            _ => overflow,
+        }
+        .min(settled.len());
+        if removed_count == 0 {
+            continue;
+        }
+
+        let removed = &settled[..removed_count];
+        let mut points = 0;
+        let mut position = Vec2::ZERO;
+
+        for &segment_entity in removed {
+            let Ok((segment, transform)) = segment_query.get(segment_entity) else {
+                continue;
+            };
+            position = transform.translation.xy();
+
+            match rule {
+                ChainOverflowRule::DropOldest => {
+                    commands.entity(segment_entity).despawn();
+                }
+                ChainOverflowRule::CashIn => {
+                    points += config.chain.overflow_cash_in_points * segment.level.max(1) as i32;
+                    explosion_events.write(SpawnExplosionEvent {
+                        position: transform.translation,
+                        color: Color::srgb(1.0, 0.9, 0.3),
+                        intensity: 0.5,
+                    });
+                    commands.entity(segment_entity).despawn();
+                }
+                ChainOverflowRule::Reaction => {
+                    // Reacting segments explode on their own and cost points when gone
+                    commands
+                        .entity(segment_entity)
+                        .insert(ChainReaction::new(config.chain.reaction_ball_duration));
+                }
+            }
+        }
+
+        player_chain
+            .segments
+            .retain(|segment| !removed.contains(segment));
+        for (new_index, &segment_entity) in player_chain.segments.iter().enumerate() {
+            commands
+                .entity(segment_entity)
+                .insert(SegmentReindexMarker { new_index });
+        }
+
+        info!(
+            "Chain of player {:?} overflowed, {} segments removed ({})",
+            player_entity,
+            removed_count,
+            rule.name()
+        );
+
+        overflow_events.write(ChainOverflowEvent {
+            player_entity,
+            position,
+            rule,
+            segment_count: removed_count,
+            points,
+        });
+    }
+}
+
+/// System to award a sentence bonus when the newest segments of a chain come
+/// from different categories. Each segment counts toward one sentence at most.
+pub fn detect_sentence_bonus(
+    mut commands: Commands,
+    new_segments: Query<&PlayerChainSegment, Added<ChainSegment>>,
+    chain_query: Query<&PlayerChain>,
+    segment_query: Query<(&ChainSegment, &Transform, Has<SentenceSegment>)>,
+    mut bonus_events: EventWriter<SentenceBonusEvent>,
+) {
+    let mut owners: Vec<Entity> = new_segments.iter().map(|owner| owner.0).collect();
+    owners.sort();
+    owners.dedup();
+
+    for owner in owners {
+        let Ok(chain) = chain_query.get(owner) else {
+            continue;
+        };
+        let Some(newest) = chain
+            .segments
+            .len()
+            .checked_sub(super::SENTENCE_LENGTH)
+            .map(|start| &chain.segments[start..])
+        else {
+            continue;
+        };
+
+        let mut categories: Vec<String> = Vec::with_capacity(super::SENTENCE_LENGTH);
+        let mut position = None;
+        for &entity in newest {
+            let Ok((segment, transform, counted)) = segment_query.get(entity) else {
+                break;
+            };
+            let Some(category) = segment.category.as_ref() else {
+                break;
+            };
+            if counted || categories.contains(category) {
+                break;
+            }
+            categories.push(category.clone());
+            position = Some(transform.translation.xy());
+        }
+        if categories.len() < super::SENTENCE_LENGTH {
+            continue;
+        }
+
+        for &entity in newest {
+            commands.entity(entity).insert(SentenceSegment);
+        }
+        info!(
+            "Player {:?} built a sentence: {}",
+            owner,
+            categories.join(" ")
+        );
+        bonus_events.write(SentenceBonusEvent {
+            player_entity: owner,
+            categories,
+            position: position.unwrap_or_default(),
+        });
+    }
+}
@@ -0,0 +1,331 @@
+//! Challenges brought by teachers or players: a YAML or JSON file picked from
+//! disk or dropped onto the window, downloaded from a URL or pasted as text.
+//! Valid multiple choice challenges are added to the asset registry under a
+//! `custom-` id, listed in the own challenges menu and kept between sessions.
+//!
+//! The native file picker and downloads need the `challenge-import` feature.
+//! Pasting and dropping files always work.
+
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::*;
+use konnektoren_bevy::assets::*;
+use konnektoren_core::challenges::{
+    challenge_type::ChallengeType, multiple_choice::MultipleChoice,
+};
+
+use crate::{locale::Locale, notifications::NotificationEvent};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<ChallengeImport>();
+    app.init_resource::<CustomChallenges>();
+    app.add_event::<ImportChallengeEvent>();
+
+    app.add_systems(Startup, restore_custom_challenges);
+    app.add_systems(
+        Update,
+        (
+            start_challenge_imports.run_if(on_event::<ImportChallengeEvent>),
+            #[cfg(not(target_family = "wasm"))]
+            import_dropped_files.run_if(on_event::<FileDragAndDrop>),
+            register_imported_challenges.run_if(resource_exists::<KonnektorenAssetRegistry>),
+        )
+            .chain(),
+    );
+}
+
+/// Request to import a challenge
+#[derive(Event, Debug)]
+pub enum ImportChallengeEvent {
+    /// Open the native file picker
+    PickFile,
+    Url(String),
+    Text(String),
+}
+
+/// Result of reading a challenge, handed back from file dialogs and downloads
+#[derive(Debug)]
+#[cfg_attr(
+    not(all(feature = "challenge-import", not(target_family = "wasm"))),
+    allow(dead_code)
+)]
+enum ImportOutcome {
+    Loaded(String),
+    /// Saved in an earlier session, registered without a notification
+    Restored(String),
+    Failed(String),
+    Cancelled,
+}
+
+/// Resource with the import inputs of the menu and the reads still in flight
+#[derive(Resource, Default)]
+pub struct ChallengeImport {
+    pending: Arc<Mutex<Vec<ImportOutcome>>>,
+    pub url_input: String,
+    pub paste_input: String,
+    /// Outcome of the last import, shown in the menu
+    pub status: Option<ImportStatus>,
+}
+
+impl ChallengeImport {
+    fn push_outcome(pending: &Arc<Mutex<Vec<ImportOutcome>>>, outcome: ImportOutcome) {
+        if let Ok(mut pending) = pending.lock() {
+            pending.push(outcome);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ImportStatus {
+    Loading,
+    Imported(String),
+    Failed(String),
+}
+
+/// Resource listing the imported challenges, in import order
+#[derive(Resource, Default)]
+pub struct CustomChallenges {
+    pub challenges: Vec<CustomChallenge>,
+    /// Source text of every challenge, saved so they survive a restart
+    sources: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CustomChallenge {
+    /// Id in the asset registry
+    pub id: String,
+    pub name: String,
+    pub questions: usize,
+}
+
+/// Read a challenge from YAML or JSON. Both the tagged form of the challenge
+/// files (`!multiple-choice`) and a bare multiple choice challenge are accepted.
+pub fn parse_challenge(text: &str) -> Result<MultipleChoice, String> {
+    let challenge_type = match serde_yaml::from_str::<ChallengeType>(text) {
+        Ok(challenge_type) => challenge_type,
+        Err(err) => match serde_yaml::from_str::<MultipleChoice>(text) {
+            Ok(multiple_choice) => ChallengeType::MultipleChoice(multiple_choice),
+            Err(_) => return Err(err.to_string()),
+        },
+    };
+
+    let ChallengeType::MultipleChoice(multiple_choice) = challenge_type else {
+        return Err("only multiple choice challenges can be played".to_string());
+    };
+
+    if multiple_choice.options.len() < 2 {
+        return Err("a challenge needs at least two options".to_string());
+    }
+    if multiple_choice.questions.is_empty() {
+        return Err("the challenge has no questions".to_string());
+    }
+    for (index, option) in multiple_choice.options.iter().enumerate() {
+        if multiple_choice.options[..index]
+            .iter()
+            .any(|other| other.id == option.id)
+        {
+            return Err(format!("option id {} is used twice", option.id));
+        }
+    }
+    if let Some(question) = multiple_choice.questions.iter().find(|question| {
+        !multiple_choice
+            .options
+            .iter()
+            .any(|option| option.id == question.option)
+    }) {
+        return Err(format!(
+            "question '{}' answers with the unknown option {}",
+            question.question, question.option
+        ));
+    }
+
+    Ok(multiple_choice)
+}
+
+fn restore_custom_challenges(import: Res<ChallengeImport>) {
+    let sources: Vec<String> =
+        crate::storage::load(CUSTOM_CHALLENGES_STORAGE_KEY).unwrap_or_default();
+    for source in sources {
+        ChallengeImport::push_outcome(&import.pending, ImportOutcome::Restored(source));
+    }
+}
+
+fn start_challenge_imports(
+    mut import_events: EventReader<ImportChallengeEvent>,
+    mut import: ResMut<ChallengeImport>,
+) {
+    for event in import_events.read() {
+        import.status = Some(ImportStatus::Loading);
+        match event {
+            ImportChallengeEvent::PickFile => pick_challenge_file(import.pending.clone()),
+            ImportChallengeEvent::Url(url) => {
+                download_challenge(url.trim(), import.pending.clone())
+            }
+            ImportChallengeEvent::Text(text) => {
+                ChallengeImport::push_outcome(&import.pending, ImportOutcome::Loaded(text.clone()))
+            }
+        }
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn import_dropped_files(
+    mut drop_events: EventReader<FileDragAndDrop>,
+    mut import: ResMut<ChallengeImport>,
+) {
+    for event in drop_events.read() {
+        let FileDragAndDrop::DroppedFile { path_buf, .. } = event else {
+            continue;
+        };
+
+        info!("Importing challenge from {}", path_buf.display());
+        import.status = Some(ImportStatus::Loading);
+        let outcome = match std::fs::read_to_string(path_buf) {
+            Ok(text) => ImportOutcome::Loaded(text),
+            Err(err) => ImportOutcome::Failed(err.to_string()),
+        };
+        ChallengeImport::push_outcome(&import.pending, outcome);
+    }
+}
+
+/// Parse finished reads and add the valid challenges to the asset registry
+fn register_imported_challenges(
+    mut import: ResMut<ChallengeImport>,
+    mut custom_challenges: ResMut<CustomChallenges>,
+    mut registry: ResMut<KonnektorenAssetRegistry>,
+    mut challenge_assets: ResMut<Assets<ChallengeAsset>>,
+    locale: Res<Locale>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    let outcomes: Vec<ImportOutcome> = match import.pending.lock() {
+        Ok(mut pending) => pending.drain(..).collect(),
+        Err(_) => return,
+    };
+    if outcomes.is_empty() {
+        return;
+    }
+
+    let mut changed = false;
+    for outcome in outcomes {
+        let (source, announce) = match outcome {
+            ImportOutcome::Loaded(source) => (source, true),
+            ImportOutcome::Restored(source) => (source, false),
+            ImportOutcome::Failed(error) => {
+                warn!("Challenge import failed: {}", error);
+                import.status = Some(ImportStatus::Failed(error));
+                continue;
+            }
+            ImportOutcome::Cancelled => {
+                import.status = None;
+                continue;
+            }
+        };
+
+        let multiple_choice = match parse_challenge(&source) {
+            Ok(multiple_choice) => multiple_choice,
+            Err(error) => {
+                warn!("Invalid challenge: {}", error);
+                if announce {
+                    import.status = Some(ImportStatus::Failed(error));
+                }
+                continue;
+            }
+        };
+
+        let id = format!("{}{}", CUSTOM_CHALLENGE_PREFIX, multiple_choice.id);
+        let challenge = CustomChallenge {
+            id: id.clone(),
+            name: multiple_choice.name.clone(),
+            questions: multiple_choice.questions.len(),
+        };
+        let handle = challenge_assets.add(ChallengeAsset {
+            challenge_type: ChallengeType::MultipleChoice(multiple_choice),
+        });
+        registry.challenges.insert(id.clone(), handle);
+        info!(
+            "Imported challenge '{}' with {} questions",
+            id, challenge.questions
+        );
+
+        // Importing a challenge again replaces the old version
+        if let Some(index) = custom_challenges
+            .challenges
+            .iter()
+            .position(|existing| existing.id == id)
+        {
+            custom_challenges.challenges.remove(index);
+            custom_challenges.sources.remove(index);
+        }
+        if announce {
+            notifications.write(NotificationEvent::new(
+                locale.format("challenges.imported", &[("name", &challenge.name)]),
+            ));
+            import.status = Some(ImportStatus::Imported(challenge.name.clone()));
+            import.paste_input.clear();
+            changed = true;
+        }
+        custom_challenges.challenges.push(challenge);
+        custom_challenges.sources.push(source);
+    }
+
+    if changed {
+        crate::storage::save(CUSTOM_CHALLENGES_STORAGE_KEY, &custom_challenges.sources);
+    }
+}
+
+#[cfg(all(feature = "challenge-import", not(target_family = "wasm")))]
+fn pick_challenge_file(pending: Arc<Mutex<Vec<ImportOutcome>>>) {
+    bevy::tasks::IoTaskPool::get()
+        .spawn(async move {
+            let file = rfd::AsyncFileDialog::new()
+                .add_filter("Challenge", &["yml", "yaml", "json"])
+                .pick_file()
+                .await;
+            let outcome = match file {
+                Some(file) => match String::from_utf8(file.read().await) {
+                    Ok(text) => ImportOutcome::Loaded(text),
+                    Err(err) => ImportOutcome::Failed(err.to_string()),
+                },
+                None => ImportOutcome::Cancelled,
+            };
+            ChallengeImport::push_outcome(&pending, outcome);
+        })
+        .detach();
+}
+
+#[cfg(not(all(feature = "challenge-import", not(target_family = "wasm"))))]
+fn pick_challenge_file(pending: Arc<Mutex<Vec<ImportOutcome>>>) {
+    ChallengeImport::push_outcome(
+        &pending,
+        ImportOutcome::Failed("no file picker in this build, paste the challenge instead".into()),
+    );
+}
+
+#[cfg(feature = "challenge-import")]
+fn download_challenge(url: &str, pending: Arc<Mutex<Vec<ImportOutcome>>>) {
+    info!("Downloading challenge from {}", url);
+    ehttp::fetch(ehttp::Request::get(url), move |result| {
+        let outcome = match result {
+            Ok(response) if response.ok => match response.text() {
+                Some(text) => ImportOutcome::Loaded(text.to_string()),
+                None => ImportOutcome::Failed("the download is not a text file".to_string()),
+            },
+            Ok(response) => ImportOutcome::Failed(format!("HTTP {}", response.status)),
+            Err(err) => ImportOutcome::Failed(err),
+        };
+        ChallengeImport::push_outcome(&pending, outcome);
+    });
+}
+
+#[cfg(not(feature = "challenge-import"))]
+fn download_challenge(url: &str, pending: Arc<Mutex<Vec<ImportOutcome>>>) {
+    debug!("Challenge import disabled, not fetching {}", url);
+    ChallengeImport::push_outcome(
+        &pending,
+        ImportOutcome::Failed("downloads are not available in this build".into()),
+    );
+}
+
+// Challenge import constants
+pub const CUSTOM_CHALLENGE_PREFIX: &str = "custom-";
+pub const CUSTOM_CHALLENGES_STORAGE_KEY: &str = "custom_challenges";
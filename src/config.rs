@@ -0,0 +1,138 @@
+//! Gameplay tuning loaded from `assets/config/game.config.ron`, so balancing
+//! doesn't need a rebuild. The built-in defaults are the constants of each
+//! module, so the game plays the same without the file, and native dev builds
+//! pick up edits to it while the game runs.
+
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::asset_tracking::LoadResource;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<GameConfig>();
+    app.init_asset::<GameConfig>();
+    app.init_asset_loader::<GameConfigLoader>();
+
+    // Defaults until the file is loaded, or for good if it is missing
+    app.init_resource::<GameConfig>();
+    app.load_resource_from_path::<GameConfig>(GAME_CONFIG_PATH);
+}
+
+/// Tuning values read by the chain, scoring and option spawn systems
+#[derive(Resource, Asset, Reflect, Clone, Debug, Default, Serialize, Deserialize)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct GameConfig {
+    pub chain: ChainConfig,
+    pub scoring: ScoringConfig,
+    pub options: OptionSpawnConfig,
+}
+
+#[derive(Reflect, Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChainConfig {
+    /// Distance between segments along the movement trail
+    pub segment_spacing: f32,
+    /// Seconds between each ring of segments joining a chain reaction
+    pub reaction_spread_interval: f32,
+    /// Seconds a reacting segment takes to disappear
+    pub reaction_ball_duration: f32,
+    /// Points lost for every segment a chain reaction destroys
+    pub points_lost_per_segment: i32,
+    /// Equal segments in a row needed to merge
+    pub min_segments_to_merge: usize,
+    /// Points per segment level when a full chain is cashed in
+    pub overflow_cash_in_points: i32,
+}
+
+impl Default for ChainConfig {
+    fn default() -> Self {
+        Self {
+            segment_spacing: crate::chain::CHAIN_SEGMENT_SPACING,
+            reaction_spread_interval: crate::chain::REACTION_SPREAD_INTERVAL,
+            reaction_ball_duration: crate::chain::REACTION_BALL_DURATION,
+            points_lost_per_segment: crate::chain::POINTS_LOST_PER_SEGMENT,
+            min_segments_to_merge: crate::chain::MIN_SEGMENTS_TO_MERGE,
+            overflow_cash_in_points: crate::chain::OVERFLOW_CASH_IN_POINTS,
+        }
+    }
+}
+
+#[derive(Reflect, Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScoringConfig {
+    pub correct_answer_points: u32,
+    /// Extra points per answer in a row after the first
+    pub streak_bonus: u32,
+    pub wrong_answer_penalty: i32,
+    /// Multiplied by the merge value and the new level
+    pub merge_points_per_value: u32,
+    /// For ending a question on a correct collection
+    pub resolution_bonus_points: i32,
+    /// For the newest segments of a chain coming from different categories
+    pub sentence_bonus_points: i32,
+}
+
+impl Default for ScoringConfig {
+    fn default() -> Self {
+        Self {
+            correct_answer_points: crate::gameplay::CORRECT_ANSWER_POINTS,
+            streak_bonus: crate::gameplay::STREAK_BONUS_MULTIPLIER,
+            wrong_answer_penalty: crate::gameplay::WRONG_ANSWER_PENALTY,
+            merge_points_per_value: crate::gameplay::MERGE_POINTS_PER_VALUE,
+            resolution_bonus_points: crate::gameplay::RESOLUTION_BONUS_POINTS,
+            sentence_bonus_points: crate::gameplay::SENTENCE_BONUS_POINTS,
+        }
+    }
+}
+
+#[derive(Reflect, Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OptionSpawnConfig {
+    /// Map cells per option on the board
+    pub cells_per_option: f32,
+    /// Positions compared when picking a spawn spot
+    pub spawn_candidates: usize,
+    /// Distance from chains beyond which spots score the same
+    pub spawn_clearance: f32,
+}
+
+impl Default for OptionSpawnConfig {
+    fn default() -> Self {
+        Self {
+            cells_per_option: crate::options::CELLS_PER_OPTION,
+            spawn_candidates: crate::options::SPAWN_CANDIDATES,
+            spawn_clearance: crate::options::SPAWN_CLEARANCE,
+        }
+    }
+}
+
+#[derive(Default, TypePath)]
+struct GameConfigLoader;
+
+impl AssetLoader for GameConfigLoader {
+    type Asset = GameConfig;
+    type Settings = ();
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<GameConfig, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["config.ron"]
+    }
+}
+
+// Game config constants
+pub const GAME_CONFIG_PATH: &str = "config/game.config.ron";
@@ -0,0 +1,130 @@
+//! Daily challenge: one fixed round per day. The seed and challenge are
+//! derived from the date alone, so every device plays the same round and
+//! results can be compared. Daily results go to their own leaderboard list.
+//!
+//! The round is played alone with the default rules whatever the player's own
+//! settings are; those are put back on the title screen.
+
+use bevy::prelude::*;
+use konnektoren_bevy::assets::*;
+
+use crate::{
+    game_state::GameState,
+    map::MapPreset,
+    round::SettingsBackup,
+    screens::Screen,
+    settings::{Difficulty, GameSettings, GameplaySettings, Handicap},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<DailyRound>();
+    app.add_event::<PlayDailyChallengeEvent>();
+
+    app.add_systems(
+        Update,
+        start_daily_challenge.run_if(on_event::<PlayDailyChallengeEvent>),
+    );
+    app.add_systems(OnExit(Screen::Gameplay), end_daily_round);
+}
+
+/// Event to start today's daily challenge
+#[derive(Event)]
+pub struct PlayDailyChallengeEvent;
+
+/// Resource present while the daily challenge of `date` is being played
+#[derive(Resource, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Resource)]
+pub struct DailyRound {
+    /// UTC date as `YYYY-MM-DD`
+    pub date: String,
+    pub seed: u64,
+}
+
+impl DailyRound {
+    pub fn today() -> Self {
+        Self::for_date(
+            chrono::Utc::now()
+                .date_naive()
+                .format("%Y-%m-%d")
+                .to_string(),
+        )
+    }
+
+    pub fn for_date(date: String) -> Self {
+        let seed = date_seed(&date);
+        Self { date, seed }
+    }
+
+    /// Challenge of the level played on this day
+    pub fn challenge(&self, level_asset: &LevelAsset) -> Option<String> {
+        let challenges = &level_asset.game_path.challenges;
+        if challenges.is_empty() {
+            return None;
+        }
+        let index = (self.seed % challenges.len() as u64) as usize;
+        Some(challenges[index].challenge.clone())
+    }
+}
+
+/// FNV-1a hash of the date, stable across platforms and Rust versions
+fn date_seed(date: &str) -> u64 {
+    date.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
+
+fn start_daily_challenge(
+    mut commands: Commands,
+    mut events: EventReader<PlayDailyChallengeEvent>,
+    mut game_settings: ResMut<GameSettings>,
+    mut game_state: ResMut<GameState>,
+    backup: Option<Res<SettingsBackup>>,
+    asset_registry: Option<Res<KonnektorenAssetRegistry>>,
+    level_assets: Option<Res<Assets<LevelAsset>>>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    events.clear();
+
+    let daily = DailyRound::today();
+    let level_asset = asset_registry
+        .as_ref()
+        .and_then(|registry| registry.get_level_handle(&game_state.current_level_id))
+        .zip(level_assets.as_ref())
+        .and_then(|(handle, assets)| assets.get(handle));
+    let Some(challenge_id) = level_asset.and_then(|level| daily.challenge(level)) else {
+        warn!("Level not loaded, cannot pick the daily challenge");
+        return;
+    };
+
+    // Everyone plays the same rules, whatever their own settings are
+    SettingsBackup::store(&mut commands, backup.as_deref(), &game_settings);
+    game_settings.multiplayer.set_single_player();
+    if let Some(player) = game_settings.multiplayer.players.first_mut() {
+        player.handicap = Handicap::default();
+    }
+    game_settings.gameplay = GameplaySettings {
+        difficulty: DAILY_DIFFICULTY,
+        map_preset: DAILY_MAP_PRESET,
+        // The theme only changes the look
+        map_theme: game_settings.gameplay.map_theme,
+        ..default()
+    };
+
+    game_state.random_challenge = false;
+    game_state.select_challenge(challenge_id.clone());
+    game_state.round_seed = Some(daily.seed);
+
+    info!("Starting daily challenge {} ({})", daily.date, challenge_id);
+    commands.insert_resource(daily);
+    next_screen.set(Screen::Loading);
+}
+
+fn end_daily_round(mut commands: Commands) {
+    commands.remove_resource::<DailyRound>();
+}
+
+// Daily challenge constants
+pub const DAILY_DIFFICULTY: Difficulty = Difficulty::Normal;
+pub const DAILY_MAP_PRESET: MapPreset = MapPreset::Large;
+pub const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+pub const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
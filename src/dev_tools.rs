@@ -4,10 +4,22 @@ use bevy::{
     dev_tools::states::log_transitions, input::common_conditions::input_just_pressed, prelude::*,
     ui::UiDebugOptions,
 };
+use bevy_egui::{EguiContextPass, egui};
 
-use crate::screens::Screen;
+mod console;
+mod inspector;
+
+use crate::{
+    chain::{ChainSegment, MovementTrail, PlayerChain},
+    config::GameConfig,
+    map::GridMap,
+    player::{Player, PlayerIndex},
+    screens::Screen,
+};
 
 pub(super) fn plugin(app: &mut App) {
+    app.add_plugins((console::plugin, inspector::plugin));
+
     // Log `Screen` state transitions.
     app.add_systems(Update, log_transitions::<Screen>);
 
@@ -16,10 +28,128 @@ pub(super) fn plugin(app: &mut App) {
         Update,
         toggle_debug_ui.run_if(input_just_pressed(TOGGLE_KEY)),
     );
+
+    // Toggle the chain debug overlay.
+    app.init_resource::<ChainDebugOverlay>();
+    app.add_systems(
+        Update,
+        (
+            toggle_chain_debug.run_if(input_just_pressed(CHAIN_DEBUG_KEY)),
+            draw_chain_debug.run_if(
+                in_state(Screen::Gameplay).and(|overlay: Res<ChainDebugOverlay>| overlay.0),
+            ),
+        )
+            .chain(),
+    );
+    app.add_systems(
+        EguiContextPass,
+        chain_debug_stats_ui
+            .run_if(in_state(Screen::Gameplay).and(|overlay: Res<ChainDebugOverlay>| overlay.0)),
+    );
 }
 
 const TOGGLE_KEY: KeyCode = KeyCode::Backquote;
+const CHAIN_DEBUG_KEY: KeyCode = KeyCode::F3;
 
 fn toggle_debug_ui(mut options: ResMut<UiDebugOptions>) {
     options.toggle();
 }
+
+/// Whether the chain debug overlay is drawn
+#[derive(Resource, Default)]
+struct ChainDebugOverlay(bool);
+
+fn toggle_chain_debug(mut overlay: ResMut<ChainDebugOverlay>) {
+    overlay.0 = !overlay.0;
+    info!("Chain debug overlay: {}", overlay.0);
+}
+
+/// Draw each player's movement trail, the target slot of every chain segment
+/// and a line from each segment to its target.
+fn draw_chain_debug(
+    mut gizmos: Gizmos,
+    grid_map: Option<Res<GridMap>>,
+    config: Res<GameConfig>,
+    player_query: Query<(&PlayerChain, &MovementTrail, Option<&PlayerIndex>), With<Player>>,
+    segment_query: Query<(&ChainSegment, &Transform)>,
+) {
+    let Some(grid_map) = grid_map else {
+        return;
+    };
+
+    for (chain, trail, player_index) in &player_query {
+        let hue = player_index.map_or(0, |index| index.0) as f32 * 90.0;
+        let color = Color::hsl(hue, 0.9, 0.6);
+
+        // Trail polyline, broken where the player wrapped around the map edge
+        for (from, to) in trail.positions.iter().zip(trail.positions.iter().skip(1)) {
+            let delta = (*to - *from).abs();
+            if delta.x > grid_map.half_width() || delta.y > grid_map.half_height() {
+                continue;
+            }
+            gizmos.line_2d(*from, *to, color.with_alpha(0.5));
+        }
+
+        for &segment_entity in &chain.segments {
+            let Ok((segment, transform)) = segment_query.get(segment_entity) else {
+                continue;
+            };
+
+            let distance = (segment.segment_index + 1) as f32 * config.chain.segment_spacing;
+            let Some(target) = trail.get_position_at_distance_with_wraparound(
+                distance,
+                grid_map.world_width(),
+                grid_map.world_height(),
+            ) else {
+                continue;
+            };
+
+            let position = transform.translation.xy();
+            gizmos.circle_2d(target, 4.0, color);
+            gizmos.line_2d(position, target, Color::WHITE.with_alpha(0.6));
+        }
+    }
+}
+
+/// List each player's trail size next to the chain debug drawing
+fn chain_debug_stats_ui(
+    mut contexts: bevy_egui::EguiContexts,
+    grid_map: Option<Res<GridMap>>,
+    player_query: Query<(&PlayerChain, &MovementTrail, Option<&PlayerIndex>), With<Player>>,
+) {
+    let Some(grid_map) = grid_map else {
+        return;
+    };
+
+    let mut trails: Vec<_> = player_query
+        .iter()
+        .map(|(chain, trail, player_index)| {
+            (
+                player_index.map_or(0, |index| index.0),
+                chain.segments.len(),
+                trail,
+            )
+        })
+        .collect();
+    trails.sort_unstable_by_key(|(index, ..)| *index);
+
+    let ctx = contexts.ctx_mut();
+    egui::Area::new(egui::Id::new("chain_debug_stats"))
+        .anchor(egui::Align2::LEFT_BOTTOM, [10.0, -10.0])
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.monospace("Trails");
+                for (index, segments, trail) in trails {
+                    ui.monospace(format!(
+                        "P{} {:>3} seg {:>4} pts {:>6.0} px {:>5.1} KiB",
+                        index + 1,
+                        segments,
+                        trail.positions.len(),
+                        trail.length(grid_map.half_width(), grid_map.half_height()),
+                        trail.memory_bytes() as f32 / 1024.0,
+                    ));
+                }
+            });
+        });
+}
@@ -0,0 +1,407 @@
+//! Debug console with cheat commands for balancing and reproducing bugs.
+//! Toggle it with F1 during a round and type `help` for the commands.
+
+use std::time::Duration;
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use bevy_egui::{EguiContextPass, egui};
+use konnektoren_bevy::input::{InputController, PlayerInputMapping};
+use rand::seq::SliceRandom;
+
+use crate::{
+    bot::BotController,
+    chain::{ChainExtendEvent, ChainReactionEvent, PlayerChain},
+    gameplay::GameTimer,
+    options::OptionSpawnTimer,
+    palette::OptionStyleRegistry,
+    player::{Player, PlayerIndex},
+    question::{QuestionSystem, QuestionTimer},
+    screens::Screen,
+    settings::{CustomKeyBindings, GameSettings},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<DevConsole>();
+    app.add_event::<DevCommandEvent>();
+
+    app.add_systems(
+        Update,
+        (
+            toggle_console.run_if(input_just_pressed(CONSOLE_KEY)),
+            run_dev_commands.run_if(on_event::<DevCommandEvent>),
+        )
+            .chain()
+            .run_if(in_state(Screen::Gameplay)),
+    );
+    app.add_systems(
+        Update,
+        fill_stress_chains.run_if(in_state(Screen::Gameplay).and(resource_exists::<StressTest>)),
+    );
+    app.add_systems(
+        EguiContextPass,
+        console_egui_ui
+            .run_if(in_state(Screen::Gameplay).and(|console: Res<DevConsole>| console.open)),
+    );
+}
+
+const CONSOLE_KEY: KeyCode = KeyCode::F1;
+const MAX_LOG_LINES: usize = 100;
+const HELP: &str = "spawn | question | grant <player> <count> | react <player> <segment> | \
+                    timer <seconds> | bot <player> | stress  (players count from 1)";
+const STRESS_PLAYERS: usize = 4;
+const STRESS_CHAIN_LENGTH: usize = 100;
+
+/// Commands the console understands
+#[derive(Debug, Clone, PartialEq)]
+enum DevCommand {
+    /// Run an option spawn wave right away
+    SpawnOptions,
+    /// End the current question now
+    NextQuestion,
+    /// Add segments of random options to a player's chain
+    GrantSegments { player: usize, count: usize },
+    /// Start a chain reaction at a segment
+    React { player: usize, segment: usize },
+    /// Set the remaining round time
+    SetTimer(f32),
+    /// Hand a player slot to a bot, or back to its device
+    ToggleBot { player: usize },
+    /// Restart with four players, each given a long chain
+    Stress,
+}
+
+impl DevCommand {
+    /// Parse a console line; player numbers are typed from 1
+    fn parse(line: &str) -> Result<Self, String> {
+        let mut words = line.split_whitespace();
+        let command = words.next().unwrap_or_default();
+        let mut number = |name: &str| -> Result<usize, String> {
+            words
+                .next()
+                .ok_or_else(|| format!("missing <{}>", name))?
+                .parse()
+                .map_err(|_| format!("<{}> must be a number", name))
+        };
+        let player = |number: usize| number.checked_sub(1).ok_or("players count from 1");
+
+        match command {
+            "spawn" => Ok(DevCommand::SpawnOptions),
+            "question" => Ok(DevCommand::NextQuestion),
+            "grant" => Ok(DevCommand::GrantSegments {
+                player: player(number("player")?)?,
+                count: number("count")?,
+            }),
+            "react" => Ok(DevCommand::React {
+                player: player(number("player")?)?,
+                segment: number("segment")?,
+            }),
+            "timer" => Ok(DevCommand::SetTimer(number("seconds")? as f32)),
+            "bot" => Ok(DevCommand::ToggleBot {
+                player: player(number("player")?)?,
+            }),
+            "stress" => Ok(DevCommand::Stress),
+            "" | "help" => Err(HELP.to_string()),
+            other => Err(format!("unknown command '{}', {}", other, HELP)),
+        }
+    }
+}
+
+#[derive(Event)]
+struct DevCommandEvent(DevCommand);
+
+/// Present from the `stress` command until the restarted round's chains are filled
+#[derive(Resource)]
+struct StressTest;
+
+/// Resource with the console window state
+#[derive(Resource, Default)]
+struct DevConsole {
+    open: bool,
+    input: String,
+    log: Vec<String>,
+}
+
+impl DevConsole {
+    fn log(&mut self, line: impl Into<String>) {
+        self.log.push(line.into());
+        let overflow = self.log.len().saturating_sub(MAX_LOG_LINES);
+        self.log.drain(..overflow);
+    }
+}
+
+fn toggle_console(mut console: ResMut<DevConsole>) {
+    console.open = !console.open;
+}
+
+fn console_egui_ui(
+    mut contexts: bevy_egui::EguiContexts,
+    mut console: ResMut<DevConsole>,
+    mut command_events: EventWriter<DevCommandEvent>,
+) {
+    let ctx = contexts.ctx_mut();
+    let mut open = console.open;
+
+    egui::Window::new("Debug Console")
+        .open(&mut open)
+        .default_width(420.0)
+        .show(ctx, |ui| {
+            egui::ScrollArea::vertical()
+                .max_height(200.0)
+                .stick_to_bottom(true)
+                .show(ui, |ui| {
+                    for line in &console.log {
+                        ui.monospace(line);
+                    }
+                });
+
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut console.input)
+                    .hint_text("help")
+                    .desired_width(f32::INFINITY),
+            );
+            if response.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter)) {
+                let line = std::mem::take(&mut console.input);
+                console.log(format!("> {}", line));
+                match DevCommand::parse(&line) {
+                    Ok(command) => {
+                        command_events.write(DevCommandEvent(command));
+                    }
+                    Err(message) => console.log(message),
+                }
+                response.request_focus();
+            }
+        });
+
+    console.open = open;
+}
+
+/// Everything the cheat commands touch
+#[derive(bevy::ecs::system::SystemParam)]
+struct DevTargets<'w, 's> {
+    commands: Commands<'w, 's>,
+    game_settings: ResMut<'w, GameSettings>,
+    next_screen: ResMut<'w, NextState<Screen>>,
+    key_bindings: Res<'w, CustomKeyBindings>,
+    option_styles: Res<'w, OptionStyleRegistry>,
+    question_system: Option<Res<'w, QuestionSystem>>,
+    option_spawn_timer: ResMut<'w, OptionSpawnTimer>,
+    game_timer: ResMut<'w, GameTimer>,
+    question_timer_query: Query<'w, 's, &'static mut QuestionTimer>,
+    player_query: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static PlayerIndex,
+            &'static Transform,
+            &'static PlayerChain,
+            Has<BotController>,
+            &'static mut InputController,
+        ),
+        With<Player>,
+    >,
+    extend_events: EventWriter<'w, ChainExtendEvent>,
+    reaction_events: EventWriter<'w, ChainReactionEvent>,
+}
+
+fn run_dev_commands(
+    mut command_events: EventReader<DevCommandEvent>,
+    mut console: ResMut<DevConsole>,
+    mut targets: DevTargets,
+) {
+    for DevCommandEvent(command) in command_events.read() {
+        let result = apply_command(command, &mut targets);
+        info!("Dev command {:?}: {}", command, result);
+        console.log(result);
+    }
+}
+
+fn apply_command(command: &DevCommand, targets: &mut DevTargets) -> String {
+    let player_entity = |targets: &DevTargets, player: usize| {
+        targets
+            .player_query
+            .iter()
+            .find(|(_, index, ..)| index.0 == player)
+            .map(|(entity, ..)| entity)
+    };
+
+    match *command {
+        DevCommand::SpawnOptions => {
+            let duration = targets.option_spawn_timer.timer.duration();
+            targets.option_spawn_timer.timer.set_elapsed(duration);
+            "Spawning options".to_string()
+        }
+        DevCommand::NextQuestion => {
+            for mut question_timer in &mut targets.question_timer_query {
+                let duration = question_timer.timer.duration();
+                question_timer.timer.set_elapsed(duration);
+            }
+            "Ending the current question".to_string()
+        }
+        DevCommand::GrantSegments { player, count } => {
+            let Some(entity) = player_entity(targets, player) else {
+                return format!("No player {}", player + 1);
+            };
+            let Some(question_system) = &targets.question_system else {
+                return "No question loaded".to_string();
+            };
+            let Ok((_, _, transform, ..)) = targets.player_query.get(entity) else {
+                return format!("No player {}", player + 1);
+            };
+
+            let position = transform.translation.xy();
+            let options = question_system.get_current_options();
+            let mut rng = rand::thread_rng();
+            for _ in 0..count {
+                let Some(option) = options.choose(&mut rng) else {
+                    break;
+                };
+                targets.extend_events.write(ChainExtendEvent {
+                    player_entity: entity,
+                    contributor: entity,
+                    option_text: option.name.clone(),
+                    option_id: option.id,
+                    option_color: targets.option_styles.color(option.id),
+                    collect_position: position,
+                    cursed: false,
+                });
+            }
+            format!("Granted {} segments to player {}", count, player + 1)
+        }
+        DevCommand::React { player, segment } => {
+            let Some(entity) = player_entity(targets, player) else {
+                return format!("No player {}", player + 1);
+            };
+            let length = targets
+                .player_query
+                .get(entity)
+                .map_or(0, |(_, _, _, chain, ..)| chain.segments.len());
+            if segment >= length {
+                return format!("Player {} has only {} segments", player + 1, length);
+            }
+
+            targets.reaction_events.write(ChainReactionEvent {
+                player_entity: entity,
+                hit_segment_index: segment,
+            });
+            format!(
+                "Chain reaction at segment {} of player {}",
+                segment,
+                player + 1
+            )
+        }
+        DevCommand::SetTimer(seconds) => {
+            let timer = &mut targets.game_timer;
+            let seconds = seconds.min(timer.game_duration);
+            let elapsed = timer.game_duration - seconds;
+            timer.timer.reset();
+            timer.timer.set_elapsed(Duration::from_secs_f32(elapsed));
+            timer.time_remaining = seconds;
+            timer.is_overtime = false;
+            format!("{} seconds left", seconds)
+        }
+        DevCommand::ToggleBot { player } => {
+            let Some(entity) = player_entity(targets, player) else {
+                return format!("No player {}", player + 1);
+            };
+            let Ok((_, _, _, _, is_bot, mut input)) = targets.player_query.get_mut(entity) else {
+                return format!("No player {}", player + 1);
+            };
+            input.movement = Vec2::ZERO;
+
+            if is_bot {
+                // Same device setup as when the player was spawned
+                let uses_mapping = targets
+                    .game_settings
+                    .multiplayer
+                    .players
+                    .get(player)
+                    .is_some_and(|settings| {
+                        let custom_keys = targets.key_bindings.is_custom(player)
+                            && targets
+                                .key_bindings
+                                .bindings_for(player, &settings.input)
+                                .is_some();
+                        !custom_keys && !settings.input.uses_game_touch_controls()
+                    });
+
+                let mut entity_commands = targets.commands.entity(entity);
+                entity_commands.remove::<BotController>();
+                if uses_mapping {
+                    entity_commands.insert(PlayerInputMapping {
+                        player_id: player as u32,
+                        ..Default::default()
+                    });
+                }
+                format!("Player {} is back on their device", player + 1)
+            } else {
+                targets
+                    .commands
+                    .entity(entity)
+                    .remove::<PlayerInputMapping>()
+                    .insert(BotController::new(
+                        targets.game_settings.multiplayer.bot_difficulty,
+                    ));
+                format!("Player {} is now a bot", player + 1)
+            }
+        }
+        DevCommand::Stress => {
+            // Everyone but the first player is a bot, so the round plays itself
+            let settings = &mut targets.game_settings;
+            settings.multiplayer.enabled = true;
+            settings.multiplayer.set_player_count(STRESS_PLAYERS);
+            settings.multiplayer.bot_count = STRESS_PLAYERS - 1;
+            settings.gameplay.max_chain_length = STRESS_CHAIN_LENGTH;
+
+            targets.commands.insert_resource(StressTest);
+            targets.next_screen.set(Screen::Loading);
+            format!(
+                "Restarting with {} players and {} segments each",
+                STRESS_PLAYERS, STRESS_CHAIN_LENGTH
+            )
+        }
+    }
+}
+
+/// Fill every chain of the restarted round once the question is loaded
+fn fill_stress_chains(
+    mut commands: Commands,
+    question_system: Option<Res<QuestionSystem>>,
+    option_styles: Res<OptionStyleRegistry>,
+    player_query: Query<(Entity, &Transform), With<Player>>,
+    mut extend_events: EventWriter<ChainExtendEvent>,
+    mut console: ResMut<DevConsole>,
+) {
+    let Some(question_system) = question_system else {
+        return;
+    };
+    let options = question_system.get_current_options();
+    if options.is_empty() || player_query.is_empty() {
+        return;
+    }
+
+    let mut rng = rand::thread_rng();
+    for (entity, transform) in &player_query {
+        for _ in 0..STRESS_CHAIN_LENGTH {
+            let Some(option) = options.choose(&mut rng) else {
+                break;
+            };
+            extend_events.write(ChainExtendEvent {
+                player_entity: entity,
+                contributor: entity,
+                option_text: option.name.clone(),
+                option_id: option.id,
+                option_color: option_styles.color(option.id),
+                collect_position: transform.translation.xy(),
+                cursed: false,
+            });
+        }
+    }
+
+    commands.remove_resource::<StressTest>();
+    console.log(format!(
+        "Stress test: {} players with {} segments each",
+        player_query.iter().count(),
+        STRESS_CHAIN_LENGTH
+    ));
+}
@@ -0,0 +1,135 @@
+//! Live inspector for tweaking game values without recompiling. Toggle it with
+//! F2. It shows curated panels for the settings, the gameplay config, the map,
+//! chain reactions, the question system and every player's chain instead of the
+//! whole world.
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use bevy_egui::{EguiContext, EguiContextPass, PrimaryEguiContext, egui};
+use bevy_inspector_egui::{
+    DefaultInspectorConfigPlugin, bevy_inspector, reflect_inspector::ui_for_value,
+};
+
+use crate::{
+    chain::{ChainReactionState, PlayerChain},
+    config::GameConfig,
+    map::MapConfig,
+    player::{Player, PlayerIndex},
+    question::QuestionSystem,
+    settings::GameSettings,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
+        app.add_plugins(DefaultInspectorConfigPlugin);
+    }
+
+    app.init_resource::<Inspector>();
+    app.add_systems(
+        Update,
+        toggle_inspector.run_if(input_just_pressed(INSPECTOR_KEY)),
+    );
+    app.add_systems(
+        EguiContextPass,
+        inspector_ui.run_if(|inspector: Res<Inspector>| inspector.open),
+    );
+}
+
+const INSPECTOR_KEY: KeyCode = KeyCode::F2;
+
+/// Whether the inspector window is shown
+#[derive(Resource, Default)]
+struct Inspector {
+    open: bool,
+}
+
+fn toggle_inspector(mut inspector: ResMut<Inspector>) {
+    inspector.open = !inspector.open;
+}
+
+/// Exclusive so the panels can edit any resource or component through reflection
+fn inspector_ui(world: &mut World) {
+    let Ok(egui_context) = world
+        .query_filtered::<&mut EguiContext, With<PrimaryEguiContext>>()
+        .single(world)
+    else {
+        return;
+    };
+    let mut egui_context = egui_context.clone();
+    let mut open = true;
+
+    egui::Window::new("Inspector")
+        .open(&mut open)
+        .default_width(360.0)
+        .default_pos([10.0, 60.0])
+        .show(egui_context.get_mut(), |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.collapsing("Game settings", |ui| {
+                    bevy_inspector::ui_for_resource::<GameSettings>(world, ui);
+                });
+                ui.collapsing("Game config", |ui| {
+                    bevy_inspector::ui_for_resource::<GameConfig>(world, ui);
+                });
+                ui.collapsing("Map", |ui| {
+                    bevy_inspector::ui_for_resource::<MapConfig>(world, ui);
+                });
+                ui.collapsing("Chain reactions", |ui| {
+                    bevy_inspector::ui_for_resource::<ChainReactionState>(world, ui);
+                });
+                ui.collapsing("Questions", |ui| question_panel(world, ui));
+                ui.collapsing("Player chains", |ui| player_chains_panel(world, ui));
+            });
+        });
+
+    if !open {
+        world.resource_mut::<Inspector>().open = false;
+    }
+}
+
+/// The question system holds challenge data without reflection, so it gets a
+/// hand-made panel to jump between questions
+fn question_panel(world: &mut World, ui: &mut egui::Ui) {
+    let Some(mut question_system) = world.get_resource_mut::<QuestionSystem>() else {
+        ui.label("No question loaded");
+        return;
+    };
+
+    ui.label(format!("Category: {}", question_system.category));
+    if !question_system.topics.is_empty() {
+        ui.label(format!("Topics: {}", question_system.topics.join(", ")));
+    }
+
+    let last = question_system.question_order.len().saturating_sub(1);
+    let mut index = question_system.current_question_index;
+    ui.add(egui::Slider::new(&mut index, 0..=last).text("Question"));
+    if index != question_system.current_question_index {
+        question_system.current_question_index = index;
+    }
+
+    if let Some(question) = question_system.get_current_question() {
+        ui.label(&question.question);
+        ui.weak(&question.help);
+    }
+}
+
+fn player_chains_panel(world: &mut World, ui: &mut egui::Ui) {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    let mut players = world.query_filtered::<(&PlayerIndex, &mut PlayerChain), With<Player>>();
+    let mut chains: Vec<_> = players.iter_mut(world).collect();
+    chains.sort_unstable_by_key(|(index, _)| index.0);
+
+    if chains.is_empty() {
+        ui.label("No players");
+    }
+    for (index, mut chain) in chains {
+        ui.push_id(index.0, |ui| {
+            ui.collapsing(
+                format!("Player {} ({} segments)", index.0 + 1, chain.segments.len()),
+                |ui| {
+                    ui_for_value(chain.as_mut(), ui, &type_registry);
+                },
+            );
+        });
+    }
+}
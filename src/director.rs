@@ -0,0 +1,139 @@
+//! Difficulty director: watches how well each player has answered lately and
+//! tunes the option spawner live. A player who barely misses sees options
+//! expire sooner among more wrong ones; a struggling player finds extra
+//! correct options close by. Competitive rounds, the daily challenge and
+//! versus multiplayer, are left alone so everyone plays by the same rules.
+
+use std::collections::{HashMap, VecDeque};
+
+use bevy::prelude::*;
+
+use crate::{
+    daily::DailyRound, options::OptionSpawnTimer, player::OptionCollectedEvent, screens::Screen,
+    settings::GameSettings,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<DifficultyDirector>();
+
+    app.add_systems(OnEnter(Screen::Gameplay), reset_director);
+    app.add_systems(OnExit(Screen::Gameplay), reset_director);
+    app.add_systems(
+        Update,
+        direct_difficulty
+            .in_set(crate::AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(crate::PausableSystems),
+    );
+}
+
+/// Resource with the recent answers of every player and what the director made of them
+#[derive(Resource, Debug, Default)]
+pub struct DifficultyDirector {
+    recent_answers: HashMap<Entity, VecDeque<bool>>,
+    /// Extra correct options kept near each struggling player
+    help: HashMap<Entity, usize>,
+}
+
+impl DifficultyDirector {
+    /// Share of correct answers among the player's last answers, once there are enough of them
+    pub fn accuracy(&self, player_entity: Entity) -> Option<f32> {
+        let answers = self.recent_answers.get(&player_entity)?;
+        if answers.len() < DIRECTOR_MIN_ANSWERS {
+            return None;
+        }
+        let correct = answers.iter().filter(|correct| **correct).count();
+        Some(correct as f32 / answers.len() as f32)
+    }
+
+    /// Correct options to keep near the player on top of the usual one
+    pub fn extra_correct_options(&self, player_entity: Entity) -> usize {
+        self.help.get(&player_entity).copied().unwrap_or(0)
+    }
+}
+
+fn reset_director(
+    mut director: ResMut<DifficultyDirector>,
+    mut spawn_timer: ResMut<OptionSpawnTimer>,
+) {
+    *director = DifficultyDirector::default();
+    spawn_timer.lifetime_scale = 1.0;
+    spawn_timer.extra_wrong_options = 0;
+}
+
+fn direct_difficulty(
+    mut collected_events: EventReader<OptionCollectedEvent>,
+    mut director: ResMut<DifficultyDirector>,
+    mut spawn_timer: ResMut<OptionSpawnTimer>,
+    game_settings: Res<GameSettings>,
+    daily: Option<Res<DailyRound>>,
+) {
+    let mut answered = false;
+    for event in collected_events.read() {
+        let answers = director
+            .recent_answers
+            .entry(event.player_entity)
+            .or_default();
+        answers.push_back(event.is_correct);
+        if answers.len() > DIRECTOR_WINDOW {
+            answers.pop_front();
+        }
+        answered = true;
+    }
+    if !answered && !game_settings.is_changed() {
+        return;
+    }
+
+    let competitive = daily.is_some() || game_settings.multiplayer.is_competitive();
+    let intensity = if competitive {
+        0.0
+    } else {
+        game_settings.gameplay.director_intensity as f32 / 100.0
+    };
+
+    // One player running away with it is enough to turn up the pressure
+    let accuracies: Vec<(Entity, f32)> = director
+        .recent_answers
+        .keys()
+        .filter_map(|&entity| director.accuracy(entity).map(|accuracy| (entity, accuracy)))
+        .collect();
+    let pressure = if accuracies
+        .iter()
+        .any(|(_, accuracy)| *accuracy > DIRECTOR_HIGH_ACCURACY)
+    {
+        intensity
+    } else {
+        0.0
+    };
+
+    let lifetime_scale = 1.0 - DIRECTOR_MAX_LIFETIME_CUT * pressure;
+    let extra_wrong_options = (DIRECTOR_MAX_EXTRA_WRONG_OPTIONS as f32 * pressure).round() as usize;
+    if spawn_timer.extra_wrong_options != extra_wrong_options {
+        info!(
+            "Difficulty director: {} extra wrong options, lifetimes at {:.0}%",
+            extra_wrong_options,
+            lifetime_scale * 100.0
+        );
+    }
+    spawn_timer.lifetime_scale = lifetime_scale;
+    spawn_timer.extra_wrong_options = extra_wrong_options;
+
+    let extra_correct = (DIRECTOR_MAX_EXTRA_CORRECT_OPTIONS as f32 * intensity).ceil() as usize;
+    director.help = accuracies
+        .into_iter()
+        .filter(|(_, accuracy)| *accuracy < DIRECTOR_LOW_ACCURACY)
+        .map(|(entity, _)| (entity, extra_correct))
+        .filter(|(_, extra)| *extra > 0)
+        .collect();
+}
+
+// Difficulty director constants
+pub const DEFAULT_DIRECTOR_INTENSITY: u32 = 50; // Percent, 0 turns the director off
+pub const DIRECTOR_WINDOW: usize = 10; // Answers the rolling accuracy looks at
+pub const DIRECTOR_MIN_ANSWERS: usize = 5; // Answers needed before the director steps in
+pub const DIRECTOR_HIGH_ACCURACY: f32 = 0.9;
+pub const DIRECTOR_LOW_ACCURACY: f32 = 0.5;
+pub const DIRECTOR_MAX_LIFETIME_CUT: f32 = 0.4; // Share of the option lifetime cut at full intensity
+pub const DIRECTOR_MAX_EXTRA_WRONG_OPTIONS: usize = 2; // Per wrong option type
+pub const DIRECTOR_MAX_EXTRA_CORRECT_OPTIONS: usize = 2;
+pub const DIRECTOR_HELP_RADIUS: usize = 6; // Cells around a struggling player
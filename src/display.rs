@@ -0,0 +1,241 @@
+//! Window mode, resolution, vsync and UI scale from the display settings.
+//! Changes to the window mode or resolution take effect right away and are
+//! rolled back after a few seconds unless the player confirms them, so a mode
+//! the screen can't show never leaves them stuck.
+
+use bevy::{
+    prelude::*,
+    window::{MonitorSelection, PresentMode, PrimaryWindow, VideoModeSelection, WindowMode},
+};
+use bevy_egui::{EguiContextPass, egui};
+
+use crate::{locale::Locale, screens::Screen, settings::GameSettings};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<AppliedDisplay>();
+
+    app.add_systems(
+        Update,
+        (
+            apply_display_settings.run_if(resource_changed::<GameSettings>),
+            count_down_display_confirmation.run_if(resource_exists::<DisplayConfirmation>),
+        )
+            .chain(),
+    );
+    app.add_systems(
+        EguiContextPass,
+        display_confirmation_egui_ui.run_if(resource_exists::<DisplayConfirmation>),
+    );
+}
+
+/// How the game window fills the screen
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DisplayMode {
+    #[default]
+    Windowed,
+    Borderless,
+    Fullscreen,
+}
+
+impl DisplayMode {
+    pub const ALL: [DisplayMode; 3] = [
+        DisplayMode::Windowed,
+        DisplayMode::Borderless,
+        DisplayMode::Fullscreen,
+    ];
+
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index.min(Self::ALL.len() - 1)]
+    }
+
+    pub fn index(&self) -> usize {
+        Self::ALL.iter().position(|m| m == self).unwrap_or(0)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DisplayMode::Windowed => "Windowed",
+            DisplayMode::Borderless => "Borderless",
+            DisplayMode::Fullscreen => "Fullscreen",
+        }
+    }
+
+    fn window_mode(&self) -> WindowMode {
+        match self {
+            DisplayMode::Windowed => WindowMode::Windowed,
+            DisplayMode::Borderless => WindowMode::BorderlessFullscreen(MonitorSelection::Current),
+            DisplayMode::Fullscreen => {
+                WindowMode::Fullscreen(MonitorSelection::Current, VideoModeSelection::Current)
+            }
+        }
+    }
+}
+
+/// Window size for a `resolution_index` setting, in logical pixels
+pub fn resolution(index: usize) -> (u32, u32) {
+    RESOLUTIONS[index.min(RESOLUTIONS.len() - 1)]
+}
+
+/// The parts of the display settings that are pushed to the window
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct DisplaySnapshot {
+    mode: DisplayMode,
+    resolution_index: usize,
+    vsync: bool,
+    ui_scale_percent: u32,
+}
+
+impl DisplaySnapshot {
+    fn from_settings(game_settings: &GameSettings) -> Self {
+        let display = &game_settings.display;
+        Self {
+            mode: display.window_mode,
+            resolution_index: display.resolution_index,
+            vsync: display.vsync,
+            ui_scale_percent: display.ui_scale_percent,
+        }
+    }
+
+    /// Changes that could leave the player with a window they can't use
+    fn needs_confirmation(&self, previous: &Self) -> bool {
+        self.mode != previous.mode || self.resolution_index != previous.resolution_index
+    }
+}
+
+/// Resource with what the window was last set to
+#[derive(Resource, Default, Debug)]
+struct AppliedDisplay {
+    last: Option<DisplaySnapshot>,
+    /// The next change is a revert, which needs no confirmation of its own
+    reverting: bool,
+}
+
+/// Resource present while a new window mode or resolution waits to be kept
+#[derive(Resource, Debug)]
+struct DisplayConfirmation {
+    previous_mode: DisplayMode,
+    previous_resolution_index: usize,
+    timer: Timer,
+}
+
+fn apply_display_settings(
+    mut commands: Commands,
+    game_settings: Res<GameSettings>,
+    screen: Res<State<Screen>>,
+    mut applied: ResMut<AppliedDisplay>,
+    confirmation: Option<ResMut<DisplayConfirmation>>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+    mut ui_scale: ResMut<UiScale>,
+) {
+    let current = DisplaySnapshot::from_settings(&game_settings);
+    if applied.last == Some(current) {
+        return;
+    }
+
+    if let Ok(mut window) = window_query.single_mut() {
+        window.mode = current.mode.window_mode();
+        window.present_mode = if current.vsync {
+            PresentMode::AutoVsync
+        } else {
+            PresentMode::AutoNoVsync
+        };
+        // The web canvas follows the page, so only native windows get a size
+        #[cfg(not(target_family = "wasm"))]
+        if current.mode == DisplayMode::Windowed {
+            let (width, height) = resolution(current.resolution_index);
+            window.resolution.set(width as f32, height as f32);
+        }
+    }
+
+    // The gameplay HUD adds its own scale on top of this one
+    if *screen.get() != Screen::Gameplay {
+        ui_scale.0 = current.ui_scale_percent as f32 / 100.0;
+    }
+
+    if let Some(previous) = applied.last {
+        if applied.reverting {
+            commands.remove_resource::<DisplayConfirmation>();
+        } else if current.needs_confirmation(&previous) {
+            match confirmation {
+                // Further changes while one is pending still roll back to the original
+                Some(mut confirmation) => confirmation.timer.reset(),
+                None => commands.insert_resource(DisplayConfirmation {
+                    previous_mode: previous.mode,
+                    previous_resolution_index: previous.resolution_index,
+                    timer: Timer::from_seconds(DISPLAY_CONFIRMATION_SECONDS, TimerMode::Once),
+                }),
+            }
+        }
+    }
+
+    info!("Applied display settings: {:?}", current);
+    applied.last = Some(current);
+    applied.reverting = false;
+}
+
+fn count_down_display_confirmation(
+    time: Res<Time<Real>>,
+    mut confirmation: ResMut<DisplayConfirmation>,
+    mut game_settings: ResMut<GameSettings>,
+    mut applied: ResMut<AppliedDisplay>,
+) {
+    if confirmation.timer.tick(time.delta()).just_finished() {
+        info!("Display change not confirmed, reverting");
+        revert_display_change(&confirmation, &mut game_settings, &mut applied);
+    }
+}
+
+fn revert_display_change(
+    confirmation: &DisplayConfirmation,
+    game_settings: &mut GameSettings,
+    applied: &mut AppliedDisplay,
+) {
+    game_settings.display.window_mode = confirmation.previous_mode;
+    game_settings.display.resolution_index = confirmation.previous_resolution_index;
+    applied.reverting = true;
+}
+
+fn display_confirmation_egui_ui(
+    mut commands: Commands,
+    mut contexts: bevy_egui::EguiContexts,
+    locale: Res<Locale>,
+    confirmation: Res<DisplayConfirmation>,
+    mut game_settings: ResMut<GameSettings>,
+    mut applied: ResMut<AppliedDisplay>,
+) {
+    let ctx = contexts.ctx_mut();
+
+    egui::Window::new(locale.text("display.confirm_title"))
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.label(locale.format(
+                "display.confirm_countdown",
+                &[("seconds", &confirmation.timer.remaining_secs().ceil())],
+            ));
+            ui.horizontal(|ui| {
+                if ui.button(locale.text("display.keep")).clicked() {
+                    info!("Display change confirmed");
+                    commands.remove_resource::<DisplayConfirmation>();
+                }
+                if ui.button(locale.text("display.revert")).clicked() {
+                    revert_display_change(&confirmation, &mut game_settings, &mut applied);
+                }
+            });
+        });
+}
+
+// Display constants
+pub const DISPLAY_CONFIRMATION_SECONDS: f32 = 10.0;
+pub const RESOLUTIONS: [(u32, u32); 6] = [
+    (1280, 720),
+    (1366, 768),
+    (1600, 900),
+    (1920, 1080),
+    (2560, 1440),
+    (3840, 2160),
+];
+pub const DEFAULT_UI_SCALE_PERCENT: u32 = 100;
+pub const MIN_UI_SCALE_PERCENT: u32 = 75;
+pub const MAX_UI_SCALE_PERCENT: u32 = 150;
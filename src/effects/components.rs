@@ -48,6 +48,103 @@ pub struct SpawnCollectionEvent {
     pub color: Color,
 }
 
-/// Resource containing pre-built particle effects
+/// Resource containing pre-built particle effects, one per color so bursts
+/// of the same color share an effect instead of each building their own
 #[derive(Resource, Default)]
-pub struct ParticleEffects {}
+pub struct ParticleEffects {
+    #[cfg(feature = "particles")]
+    explosions: std::collections::HashMap<[u8; 4], Handle<bevy_hanabi::EffectAsset>>,
+    #[cfg(feature = "particles")]
+    collections: std::collections::HashMap<[u8; 4], Handle<bevy_hanabi::EffectAsset>>,
+}
+
+#[cfg(feature = "particles")]
+impl ParticleEffects {
+    /// Explosion effect of a color, built on first use
+    pub fn explosion(
+        &mut self,
+        color: Color,
+        effects: &mut Assets<bevy_hanabi::EffectAsset>,
+    ) -> Handle<bevy_hanabi::EffectAsset> {
+        Self::cached(&mut self.explosions, color, || {
+            super::systems::create_colored_explosion_effect(effects, color)
+        })
+    }
+
+    /// Collection effect of a color, built on first use
+    pub fn collection(
+        &mut self,
+        color: Color,
+        effects: &mut Assets<bevy_hanabi::EffectAsset>,
+    ) -> Handle<bevy_hanabi::EffectAsset> {
+        Self::cached(&mut self.collections, color, || {
+            super::systems::create_colored_collection_effect(effects, color)
+        })
+    }
+
+    /// Animated colors would fill the cache without ever repeating, so past
+    /// the limit effects are built uncached
+    fn cached(
+        cache: &mut std::collections::HashMap<[u8; 4], Handle<bevy_hanabi::EffectAsset>>,
+        color: Color,
+        create: impl FnOnce() -> Handle<bevy_hanabi::EffectAsset>,
+    ) -> Handle<bevy_hanabi::EffectAsset> {
+        let key = color.to_srgba().to_u8_array();
+        if let Some(handle) = cache.get(&key) {
+            return handle.clone();
+        }
+        let handle = create();
+        if cache.len() < super::MAX_CACHED_PARTICLE_EFFECTS {
+            cache.insert(key, handle.clone());
+        }
+        handle
+    }
+}
+
+/// Kind of celebration burst
+#[derive(Reflect, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CelebrationKind {
+    /// Colorful strips that burst upward and flutter down
+    Confetti,
+    /// Sparks flying out in an even ring
+    FireworkRing,
+    /// Stars drifting down from above the position
+    StarShower,
+}
+
+/// Event to spawn a celebration for milestones, big merges and winners
+#[derive(Event)]
+pub struct SpawnCelebrationEvent {
+    pub position: Vec3,
+    pub kind: CelebrationKind,
+    pub color: Color,
+}
+
+impl SpawnCelebrationEvent {
+    pub fn new(position: Vec3, kind: CelebrationKind, color: Color) -> Self {
+        Self {
+            position,
+            kind,
+            color,
+        }
+    }
+}
+
+/// Component for celebration particle effects
+#[cfg(feature = "particles")]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct CelebrationEffect {
+    pub lifetime: Timer,
+}
+
+/// Mesh-based celebration particle, used when the `particles` feature is off
+#[cfg(not(feature = "particles"))]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct CelebrationParticle {
+    pub velocity: Vec2,
+    pub gravity: f32,
+    pub spin: f32,
+    pub lifetime: Timer,
+}
@@ -1,9 +1,11 @@
 use bevy::prelude::*;
 
 mod components;
+mod pool;
 mod systems;
 
 pub use components::*;
+pub use pool::*;
 use systems::*;
 
 pub(super) fn plugin(app: &mut App) {
@@ -12,14 +14,24 @@ pub(super) fn plugin(app: &mut App) {
 
     app.register_type::<ChainExplosionEffect>();
     app.register_type::<CollectionEffect>();
+    #[cfg(feature = "particles")]
+    app.register_type::<CelebrationEffect>();
+    #[cfg(not(feature = "particles"))]
+    app.register_type::<CelebrationParticle>();
 
     app.add_event::<SpawnExplosionEvent>();
     app.add_event::<SpawnCollectionEvent>();
+    app.add_event::<SpawnCelebrationEvent>();
+
+    app.init_resource::<EntityPool>();
+    app.init_resource::<ParticleEffects>();
 
+    // Kept across rounds, the loading screen warms it up for the next one
     app.add_systems(
-        OnEnter(crate::screens::Screen::Gameplay),
-        setup_particle_effects,
+        OnEnter(crate::screens::Screen::Title),
+        clear_particle_effects,
     );
+    app.add_systems(OnExit(crate::screens::Screen::Gameplay), clear_entity_pool);
 
     app.add_systems(
         Update,
@@ -31,4 +43,36 @@ pub(super) fn plugin(app: &mut App) {
             .run_if(in_state(crate::screens::Screen::Gameplay))
             .in_set(crate::PausableSystems),
     );
+
+    // Celebrations keep going over the paused round, e.g. behind the results
+    app.add_systems(
+        Update,
+        (handle_celebration_events, update_celebrations)
+            .chain()
+            .in_set(crate::AppSystems::Update)
+            .run_if(in_state(crate::screens::Screen::Gameplay)),
+    );
 }
+
+// Pooling constants
+pub const MAX_POOLED_PER_KIND: usize = 256; // Extra released entities are despawned
+pub const EXPLOSION_EFFECT_DURATION: f32 = 2.0;
+pub const COLLECTION_EFFECT_DURATION: f32 = 1.0;
+pub const MAX_CACHED_PARTICLE_EFFECTS: usize = 64; // Per effect kind
+
+// Celebration constants
+pub const CELEBRATION_DURATION: f32 = 2.0;
+pub const CELEBRATION_Z: f32 = 6.0; // Above the board and the victory lap
+pub const CELEBRATION_MERGE_LEVEL: u32 = 3; // Merges reaching this level get fireworks
+pub const CONFETTI_COUNT: usize = 40;
+pub const FIREWORK_RING_COUNT: usize = 24;
+pub const STAR_SHOWER_COUNT: usize = 16;
+pub const CELEBRATION_GRAVITY: f32 = 140.0;
+pub const CELEBRATION_DRAG: f32 = 1.2;
+pub const CONFETTI_COLORS: [Color; 5] = [
+    Color::srgb(1.0, 0.85, 0.2),
+    Color::srgb(0.3, 0.8, 1.0),
+    Color::srgb(1.0, 0.35, 0.5),
+    Color::srgb(0.4, 1.0, 0.5),
+    Color::srgb(0.8, 0.5, 1.0),
+];
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Kinds of short-lived entities that are recycled instead of despawned
+#[derive(Reflect, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PoolKind {
+    Explosion,
+    Collection,
+    Popup,
+    FlyingObject,
+    DashTrail,
+}
+
+/// Released entities waiting to be handed out again, per kind.
+///
+/// A released entity keeps its mesh, material, text and children. Only the
+/// component driving its behaviour is removed, and it stays hidden until the
+/// next `acquire` puts fresh values back in place.
+#[derive(Resource, Default, Debug)]
+pub struct EntityPool {
+    free: HashMap<PoolKind, Vec<Entity>>,
+}
+
+impl EntityPool {
+    /// Take a released entity of `kind`, or `None` if the caller has to spawn a new one
+    pub fn acquire(&mut self, kind: PoolKind) -> Option<Entity> {
+        self.free.get_mut(&kind)?.pop()
+    }
+
+    /// Hide `entity` and remove its behaviour component `T` so it can be reused.
+    /// Entities beyond the pool limit are despawned as before.
+    pub fn release<T: Component>(
+        &mut self,
+        commands: &mut Commands,
+        kind: PoolKind,
+        entity: Entity,
+    ) {
+        let free = self.free.entry(kind).or_default();
+        if free.len() >= super::MAX_POOLED_PER_KIND {
+            commands.entity(entity).despawn();
+            return;
+        }
+
+        commands
+            .entity(entity)
+            .remove::<T>()
+            .insert(Visibility::Hidden);
+        free.push(entity);
+    }
+
+    /// Forget all released entities, used when they are despawned with the screen
+    pub fn clear(&mut self) {
+        self.free.clear();
+    }
+}
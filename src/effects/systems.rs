@@ -1,43 +1,54 @@
-use super::components::*;
+use super::{components::*, pool::*};
 use bevy::prelude::*;
 
 #[cfg(feature = "particles")]
 use bevy_hanabi::prelude::*;
 
-/// System to set up particle effects when entering gameplay
-pub fn setup_particle_effects(mut commands: Commands) {
+/// System to drop the cached particle effects when leaving the game
+pub fn clear_particle_effects(mut commands: Commands) {
     commands.insert_resource(ParticleEffects::default());
-    info!("Particle effects initialized");
 }
 
 /// System to handle explosion events
 pub fn handle_explosion_events(
     mut commands: Commands,
     mut explosion_events: EventReader<SpawnExplosionEvent>,
+    mut pool: ResMut<EntityPool>,
+    #[cfg(feature = "particles")] mut particle_effects: ResMut<ParticleEffects>,
     #[cfg(feature = "particles")] mut effects: ResMut<Assets<EffectAsset>>,
+    #[cfg(feature = "particles")] mut spawners: Query<&mut EffectSpawner>,
 ) {
     for event in explosion_events.read() {
-        #[cfg(feature = "particles")]
-        {
-            // Create a custom effect with the ball's color
-            let explosion_effect = create_colored_explosion_effect(&mut effects, event.color);
-            commands.spawn((
-                Name::new("Chain Explosion Effect"),
-                ChainExplosionEffect::new(2.0, event.intensity),
-                ParticleEffect::new(explosion_effect),
-                Transform::from_translation(event.position),
-                StateScoped(crate::screens::Screen::Gameplay),
-            ));
-        }
+        let bundle = (
+            ChainExplosionEffect::new(super::EXPLOSION_EFFECT_DURATION, event.intensity),
+            Transform::from_translation(event.position),
+            Visibility::Inherited,
+        );
+        #[cfg_attr(not(feature = "particles"), allow(unused_variables))]
+        let entity = match pool.acquire(PoolKind::Explosion) {
+            Some(entity) => {
+                commands.entity(entity).insert(bundle);
+                entity
+            }
+            None => commands
+                .spawn((
+                    Name::new("Chain Explosion Effect"),
+                    bundle,
+                    StateScoped(crate::screens::Screen::Gameplay),
+                ))
+                .id(),
+        };
 
-        #[cfg(not(feature = "particles"))]
+        #[cfg(feature = "particles")]
         {
-            commands.spawn((
-                Name::new("Chain Explosion Effect"),
-                ChainExplosionEffect::new(2.0, event.intensity),
-                Transform::from_translation(event.position),
-                StateScoped(crate::screens::Screen::Gameplay),
-            ));
+            // Effect in the ball's color, usually already warmed up while loading
+            let explosion_effect = particle_effects.explosion(event.color, &mut effects);
+            commands
+                .entity(entity)
+                .insert(ParticleEffect::new(explosion_effect));
+            if let Ok(mut spawner) = spawners.get_mut(entity) {
+                spawner.reset();
+            }
         }
 
         info!(
@@ -51,37 +62,48 @@ pub fn handle_explosion_events(
 pub fn handle_collection_events(
     mut commands: Commands,
     mut collection_events: EventReader<SpawnCollectionEvent>,
+    mut pool: ResMut<EntityPool>,
+    #[cfg(feature = "particles")] mut particle_effects: ResMut<ParticleEffects>,
     #[cfg(feature = "particles")] mut effects: ResMut<Assets<EffectAsset>>,
+    #[cfg(feature = "particles")] mut spawners: Query<&mut EffectSpawner>,
 ) {
     for event in collection_events.read() {
-        #[cfg(feature = "particles")]
-        {
-            // Use the existing create_colored_collection_effect function
-            let collection_effect = create_colored_collection_effect(&mut effects, event.color);
-            commands.spawn((
-                Name::new("Collection Effect"),
-                CollectionEffect::new(1.0),
-                ParticleEffect::new(collection_effect),
-                Transform::from_translation(event.position),
-                StateScoped(crate::screens::Screen::Gameplay),
-            ));
-        }
+        let bundle = (
+            CollectionEffect::new(super::COLLECTION_EFFECT_DURATION),
+            Transform::from_translation(event.position),
+            Visibility::Inherited,
+        );
+        #[cfg_attr(not(feature = "particles"), allow(unused_variables))]
+        let entity = match pool.acquire(PoolKind::Collection) {
+            Some(entity) => {
+                commands.entity(entity).insert(bundle);
+                entity
+            }
+            None => commands
+                .spawn((
+                    Name::new("Collection Effect"),
+                    bundle,
+                    StateScoped(crate::screens::Screen::Gameplay),
+                ))
+                .id(),
+        };
 
-        #[cfg(not(feature = "particles"))]
+        #[cfg(feature = "particles")]
         {
-            commands.spawn((
-                Name::new("Collection Effect"),
-                CollectionEffect::new(1.0),
-                Transform::from_translation(event.position),
-                StateScoped(crate::screens::Screen::Gameplay),
-            ));
+            let collection_effect = particle_effects.collection(event.color, &mut effects);
+            commands
+                .entity(entity)
+                .insert(ParticleEffect::new(collection_effect));
+            if let Ok(mut spawner) = spawners.get_mut(entity) {
+                spawner.reset();
+            }
         }
     }
 }
 
 #[cfg(feature = "particles")]
 /// Create a collection effect with a specific color
-fn create_colored_collection_effect(
+pub(super) fn create_colored_collection_effect(
     effects: &mut Assets<EffectAsset>,
     color: Color,
 ) -> Handle<EffectAsset> {
@@ -154,8 +176,8 @@ fn create_colored_collection_effect(
 }
 
 #[cfg(feature = "particles")]
-/// Create a collection effect with a specific color
-fn create_colored_explosion_effect(
+/// Create an explosion effect with a specific color
+pub(super) fn create_colored_explosion_effect(
     effects: &mut Assets<EffectAsset>,
     color: Color,
 ) -> Handle<EffectAsset> {
@@ -231,10 +253,11 @@ fn create_colored_explosion_effect(
     effects.add(effect)
 }
 
-/// System to cleanup finished effects
+/// System to return finished effects to the pool
 pub fn cleanup_finished_effects(
     mut commands: Commands,
     time: Res<Time>,
+    mut pool: ResMut<EntityPool>,
     mut explosion_query: Query<(Entity, &mut ChainExplosionEffect)>,
     mut collection_query: Query<(Entity, &mut CollectionEffect)>,
 ) {
@@ -242,7 +265,7 @@ pub fn cleanup_finished_effects(
     for (entity, mut effect) in &mut explosion_query {
         effect.lifetime.tick(time.delta());
         if effect.lifetime.finished() {
-            commands.entity(entity).despawn();
+            pool.release::<ChainExplosionEffect>(&mut commands, PoolKind::Explosion, entity);
         }
     }
 
@@ -250,7 +273,273 @@ pub fn cleanup_finished_effects(
     for (entity, mut effect) in &mut collection_query {
         effect.lifetime.tick(time.delta());
         if effect.lifetime.finished() {
+            pool.release::<CollectionEffect>(&mut commands, PoolKind::Collection, entity);
+        }
+    }
+}
+
+/// System to forget pooled entities when they are despawned with the gameplay screen
+pub fn clear_entity_pool(mut pool: ResMut<EntityPool>) {
+    pool.clear();
+}
+
+/// System to spawn celebration bursts
+pub fn handle_celebration_events(
+    mut commands: Commands,
+    mut celebration_events: EventReader<SpawnCelebrationEvent>,
+    game_settings: Res<crate::settings::GameSettings>,
+    #[cfg(feature = "particles")] mut effects: ResMut<Assets<EffectAsset>>,
+    #[cfg(not(feature = "particles"))] mut game_rng: ResMut<crate::rng::GameRng>,
+    #[cfg(not(feature = "particles"))] mut asset_cache: ResMut<
+        crate::asset_cache::SharedAssetCache,
+    >,
+    #[cfg(not(feature = "particles"))] mut meshes: ResMut<Assets<Mesh>>,
+    #[cfg(not(feature = "particles"))] mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let intensity = game_settings.accessibility.effects_intensity;
+    if intensity.particle_scale() <= 0.0 {
+        celebration_events.clear();
+        return;
+    }
+
+    for event in celebration_events.read() {
+        let position = event.position.truncate().extend(super::CELEBRATION_Z);
+
+        #[cfg(feature = "particles")]
+        {
+            let effect = create_celebration_effect(&mut effects, event.kind, event.color);
+            commands.spawn((
+                Name::new("Celebration Effect"),
+                CelebrationEffect {
+                    lifetime: Timer::from_seconds(super::CELEBRATION_DURATION, TimerMode::Once),
+                },
+                ParticleEffect::new(effect),
+                Transform::from_translation(position),
+                StateScoped(crate::screens::Screen::Gameplay),
+            ));
+        }
+
+        #[cfg(not(feature = "particles"))]
+        spawn_celebration_particles(
+            &mut commands,
+            event,
+            position,
+            intensity,
+            game_rng.effects(),
+            &mut asset_cache,
+            &mut meshes,
+            &mut materials,
+        );
+    }
+}
+
+/// Spawn a celebration as plain meshes, so builds without hanabi still get it
+#[cfg(not(feature = "particles"))]
+fn spawn_celebration_particles(
+    commands: &mut Commands,
+    event: &SpawnCelebrationEvent,
+    position: Vec3,
+    intensity: crate::settings::EffectsIntensity,
+    rng: &mut rand::rngs::StdRng,
+    asset_cache: &mut crate::asset_cache::SharedAssetCache,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+) {
+    use crate::palette::OptionMarker;
+    use rand::Rng;
+    use std::f32::consts::TAU;
+
+    let (count, shape, radius) = match event.kind {
+        CelebrationKind::Confetti => (super::CONFETTI_COUNT, Some(OptionMarker::Square), 3.0),
+        CelebrationKind::FireworkRing => (super::FIREWORK_RING_COUNT, None, 2.5),
+        CelebrationKind::StarShower => (super::STAR_SHOWER_COUNT, Some(OptionMarker::Diamond), 4.0),
+    };
+    let count = intensity.particle_count(count);
+    let mesh = asset_cache.shape_mesh(shape, radius, meshes);
+
+    for index in 0..count {
+        let (offset, velocity, gravity, color) = match event.kind {
+            CelebrationKind::Confetti => (
+                Vec2::ZERO,
+                Vec2::new(rng.gen_range(-90.0..90.0), rng.gen_range(80.0..200.0)),
+                super::CELEBRATION_GRAVITY,
+                super::CONFETTI_COLORS[rng.gen_range(0..super::CONFETTI_COLORS.len())],
+            ),
+            CelebrationKind::FireworkRing => {
+                let direction = Vec2::from_angle(index as f32 * TAU / count as f32);
+                (Vec2::ZERO, direction * 120.0, 0.0, event.color)
+            }
+            CelebrationKind::StarShower => (
+                Vec2::new(rng.gen_range(-60.0..60.0), rng.gen_range(40.0..90.0)),
+                Vec2::new(rng.gen_range(-10.0..10.0), rng.gen_range(-40.0..-10.0)),
+                super::CELEBRATION_GRAVITY * 0.3,
+                Color::srgb(1.0, 0.9, 0.4),
+            ),
+        };
+
+        let mut transform = Transform::from_translation(position + offset.extend(0.0));
+        if event.kind == CelebrationKind::Confetti {
+            // Squashed squares read as paper strips
+            transform.scale = Vec3::new(1.0, 0.4, 1.0);
+        }
+
+        commands.spawn((
+            Name::new("Celebration Particle"),
+            Mesh2d(mesh.clone()),
+            MeshMaterial2d(asset_cache.material(color, materials)),
+            transform,
+            CelebrationParticle {
+                velocity,
+                gravity,
+                spin: rng.gen_range(-8.0..8.0),
+                lifetime: Timer::from_seconds(
+                    super::CELEBRATION_DURATION * rng.gen_range(0.6..1.0),
+                    TimerMode::Once,
+                ),
+            },
+            StateScoped(crate::screens::Screen::Gameplay),
+        ));
+    }
+}
+
+/// System to animate celebrations and remove finished ones. Runs on real
+/// time, so celebrations over a paused round still play out.
+pub fn update_celebrations(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    #[cfg(feature = "particles")] mut effect_query: Query<(Entity, &mut CelebrationEffect)>,
+    #[cfg(not(feature = "particles"))] mut particle_query: Query<(
+        Entity,
+        &mut CelebrationParticle,
+        &mut Transform,
+    )>,
+) {
+    #[cfg(feature = "particles")]
+    for (entity, mut effect) in &mut effect_query {
+        if effect.lifetime.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    #[cfg(not(feature = "particles"))]
+    for (entity, mut particle, mut transform) in &mut particle_query {
+        if particle.lifetime.tick(time.delta()).finished() {
             commands.entity(entity).despawn();
+            continue;
         }
+
+        let delta = time.delta_secs();
+        particle.velocity.y -= particle.gravity * delta;
+        particle.velocity *= 1.0 - super::CELEBRATION_DRAG * delta;
+        transform.translation += (particle.velocity * delta).extend(0.0);
+        transform.rotate_z(particle.spin * delta);
+
+        let remaining = 1.0 - particle.lifetime.fraction();
+        transform.scale.x = remaining.min(transform.scale.x);
+        transform.scale.y = remaining.min(transform.scale.y);
     }
 }
+
+#[cfg(feature = "particles")]
+/// Create a celebration effect of the given kind
+fn create_celebration_effect(
+    effects: &mut Assets<EffectAsset>,
+    kind: CelebrationKind,
+    color: Color,
+) -> Handle<EffectAsset> {
+    let linear_color = color.to_linear();
+    let base_color = Vec4::new(linear_color.red, linear_color.green, linear_color.blue, 1.0);
+
+    let mut gradient = Gradient::new();
+    match kind {
+        CelebrationKind::Confetti => {
+            // Cycle through the confetti colors over the particle's life
+            let step = 1.0 / super::CONFETTI_COLORS.len() as f32;
+            for (index, confetti) in super::CONFETTI_COLORS.iter().enumerate() {
+                let linear = confetti.to_linear();
+                gradient.add_key(
+                    index as f32 * step,
+                    Vec4::new(linear.red * 2.0, linear.green * 2.0, linear.blue * 2.0, 1.0),
+                );
+            }
+            gradient.add_key(1.0, Vec4::new(1.0, 1.0, 1.0, 0.0));
+        }
+        CelebrationKind::FireworkRing | CelebrationKind::StarShower => {
+            gradient.add_key(0.0, base_color * 3.0);
+            gradient.add_key(0.6, (base_color * 1.5).with_w(0.8));
+            gradient.add_key(1.0, base_color.with_w(0.0));
+        }
+    }
+
+    let writer = ExprWriter::new();
+    let init_age = SetAttributeModifier::new(Attribute::AGE, writer.lit(0.0).expr());
+    let init_lifetime = SetAttributeModifier::new(
+        Attribute::LIFETIME,
+        writer.lit(super::CELEBRATION_DURATION).expr(),
+    );
+
+    let (count, init_pos, init_vel, gravity) = match kind {
+        CelebrationKind::Confetti => (
+            super::CONFETTI_COUNT,
+            SetPositionSphereModifier {
+                center: writer.lit(Vec3::ZERO).expr(),
+                radius: writer.lit(4.0).expr(),
+                dimension: ShapeDimension::Volume,
+            },
+            SetVelocitySphereModifier {
+                center: writer.lit(Vec3::new(0.0, -2.0, 0.0)).expr(),
+                speed: writer.lit(150.0).expr(),
+            },
+            super::CELEBRATION_GRAVITY,
+        ),
+        CelebrationKind::FireworkRing => (
+            super::FIREWORK_RING_COUNT,
+            SetPositionSphereModifier {
+                center: writer.lit(Vec3::ZERO).expr(),
+                radius: writer.lit(2.0).expr(),
+                dimension: ShapeDimension::Surface,
+            },
+            SetVelocitySphereModifier {
+                center: writer.lit(Vec3::ZERO).expr(),
+                speed: writer.lit(120.0).expr(),
+            },
+            0.0,
+        ),
+        CelebrationKind::StarShower => (
+            super::STAR_SHOWER_COUNT,
+            SetPositionSphereModifier {
+                center: writer.lit(Vec3::new(0.0, 60.0, 0.0)).expr(),
+                radius: writer.lit(50.0).expr(),
+                dimension: ShapeDimension::Volume,
+            },
+            SetVelocitySphereModifier {
+                center: writer.lit(Vec3::new(0.0, 80.0, 0.0)).expr(),
+                speed: writer.lit(20.0).expr(),
+            },
+            super::CELEBRATION_GRAVITY * 0.3,
+        ),
+    };
+
+    let accel = AccelModifier::new(writer.lit(Vec3::new(0.0, -gravity, 0.0)).expr());
+    let drag = LinearDragModifier::new(writer.lit(super::CELEBRATION_DRAG).expr());
+
+    let effect = EffectAsset::new(
+        count as u32 * 2,
+        SpawnerSettings::burst((count as f32).into(), 0.05.into()),
+        writer.finish(),
+    )
+    .with_name(format!("celebration_{:?}_{:?}", kind, color))
+    .init(init_pos)
+    .init(init_vel)
+    .init(init_age)
+    .init(init_lifetime)
+    .update(accel)
+    .update(drag)
+    .render(ColorOverLifetimeModifier {
+        gradient,
+        blend: ColorBlendMode::Overwrite,
+        mask: ColorBlendMask::RGBA,
+    });
+
+    effects.add(effect)
+}
@@ -0,0 +1,170 @@
+//! Learning feedback: after a wrong collection the player is held in place for
+//! a moment while a small card next to them shows the question, the correct
+//! connector and the help text. Cards are rate limited per player so a bad
+//! streak doesn't keep someone standing still, and bots never get one.
+
+use std::collections::HashMap;
+
+use bevy::{prelude::*, text::TextBounds};
+
+use crate::{
+    bot::BotController,
+    hazards::Stunned,
+    player::{OptionCollectedEvent, Player, PlayerController},
+    question::QuestionSystem,
+    screens::Screen,
+    settings::GameSettings,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<ExplanationCard>();
+    app.init_resource::<ExplanationCooldowns>();
+
+    app.add_systems(OnEnter(Screen::Gameplay), reset_explanation_cooldowns);
+    app.add_systems(
+        Update,
+        (
+            show_explanation_cards.run_if(|game_settings: Res<GameSettings>| {
+                game_settings.gameplay.learning_feedback
+            }),
+            fade_explanation_cards,
+        )
+            .in_set(crate::AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(crate::PausableSystems),
+    );
+}
+
+/// Card explaining the answer to a wrong collection
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct ExplanationCard {
+    pub timer: Timer,
+}
+
+/// When each player last got a card, in elapsed seconds
+#[derive(Resource, Default)]
+struct ExplanationCooldowns(HashMap<Entity, f32>);
+
+fn reset_explanation_cooldowns(mut cooldowns: ResMut<ExplanationCooldowns>) {
+    cooldowns.0.clear();
+}
+
+fn show_explanation_cards(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut collected_events: EventReader<OptionCollectedEvent>,
+    mut cooldowns: ResMut<ExplanationCooldowns>,
+    question_system: Option<Res<QuestionSystem>>,
+    mut player_query: Query<
+        (&Transform, &mut PlayerController, Has<Stunned>),
+        (With<Player>, Without<BotController>),
+    >,
+) {
+    let Some(question_system) = question_system else {
+        collected_events.clear();
+        return;
+    };
+    let now = time.elapsed_secs();
+
+    for event in collected_events.read() {
+        if event.is_correct {
+            continue;
+        }
+        let Ok((transform, mut controller, stunned)) = player_query.get_mut(event.player_entity)
+        else {
+            continue;
+        };
+        if stunned {
+            continue;
+        }
+        if cooldowns
+            .0
+            .get(&event.player_entity)
+            .is_some_and(|last| now - last < EXPLANATION_COOLDOWN)
+        {
+            continue;
+        }
+        let Some(question) = question_system.get_current_question() else {
+            continue;
+        };
+        let answer = question_system
+            .get_current_options()
+            .iter()
+            .find(|option| option.id == question.option)
+            .map(|option| option.name.as_str())
+            .unwrap_or_default();
+
+        cooldowns.0.insert(event.player_entity, now);
+
+        // Held in place like a stunned player, which also lets them go again
+        controller.can_move = false;
+        controller.movement_input = Vec2::ZERO;
+        commands.entity(event.player_entity).insert(Stunned {
+            timer: Timer::from_seconds(EXPLANATION_DURATION, TimerMode::Once),
+        });
+
+        let mut text = format!("{}\n→ {}", question.question, answer);
+        if !question.help.is_empty() {
+            text.push_str("\n\n");
+            text.push_str(&question.help);
+        }
+
+        let position = transform.translation.xy() + EXPLANATION_CARD_OFFSET;
+        commands.spawn((
+            Name::new("Explanation Card"),
+            Sprite::from_color(EXPLANATION_CARD_COLOR, EXPLANATION_CARD_SIZE),
+            Transform::from_translation(position.extend(EXPLANATION_CARD_Z)),
+            ExplanationCard {
+                timer: Timer::from_seconds(EXPLANATION_DURATION, TimerMode::Once),
+            },
+            StateScoped(Screen::Gameplay),
+            children![(
+                Name::new("Explanation Text"),
+                Text2d::new(text),
+                TextFont {
+                    font_size: EXPLANATION_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                TextLayout::new_with_justify(JustifyText::Center),
+                TextBounds::new_horizontal(EXPLANATION_CARD_SIZE.x - 16.0),
+                Transform::from_xyz(0.0, 0.0, 0.1),
+            )],
+        ));
+    }
+}
+
+/// Fade cards out over their last moments and remove them
+fn fade_explanation_cards(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut card_query: Query<(Entity, &mut ExplanationCard, &mut Sprite, &Children)>,
+    mut text_query: Query<&mut TextColor>,
+) {
+    for (entity, mut card, mut sprite, children) in &mut card_query {
+        card.timer.tick(time.delta());
+        if card.timer.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let alpha = (card.timer.remaining_secs() / EXPLANATION_FADE_DURATION).min(1.0);
+        sprite.color = EXPLANATION_CARD_COLOR.with_alpha(EXPLANATION_CARD_COLOR.alpha() * alpha);
+        for &child in children {
+            if let Ok(mut color) = text_query.get_mut(child) {
+                color.0 = Color::WHITE.with_alpha(alpha);
+            }
+        }
+    }
+}
+
+// Explanation constants
+pub const EXPLANATION_DURATION: f32 = 2.0; // Seconds the player is held and the card shown
+pub const EXPLANATION_COOLDOWN: f32 = 12.0; // Seconds before the same player gets another card
+pub const EXPLANATION_FADE_DURATION: f32 = 0.4;
+pub const EXPLANATION_CARD_SIZE: Vec2 = Vec2::new(260.0, 120.0);
+pub const EXPLANATION_CARD_OFFSET: Vec2 = Vec2::new(0.0, 90.0); // Above the player
+pub const EXPLANATION_CARD_COLOR: Color = Color::srgba(0.08, 0.1, 0.18, 0.9);
+pub const EXPLANATION_CARD_Z: f32 = 60.0;
+pub const EXPLANATION_FONT_SIZE: f32 = 14.0;
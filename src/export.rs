@@ -0,0 +1,269 @@
+//! Result export: the review screen can save the round's answer history and
+//! final player stats as CSV or JSON, for gradebooks or for importing into
+//! konnektoren.help. Native builds write the file to the documents folder,
+//! web builds hand it to the browser as a download.
+
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::{
+    game_state::GameState, gameplay::GameplayScore, locale::Locale,
+    notifications::NotificationEvent, question::QuestionSystem, review::AnswerHistory,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_event::<ExportResultsEvent>();
+
+    app.add_systems(
+        Update,
+        export_results.run_if(on_event::<ExportResultsEvent>),
+    );
+}
+
+/// Event to export the results of the current or last round
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ExportResultsEvent(pub ExportFormat);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv",
+            ExportFormat::Json => "application/json",
+        }
+    }
+}
+
+/// Everything written to an export
+#[derive(Serialize, Debug)]
+pub struct RoundResults {
+    pub date: String,
+    pub challenge: String,
+    pub category: String,
+    pub players: Vec<PlayerResult>,
+    pub questions: Vec<QuestionResult>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PlayerResult {
+    pub name: String,
+    pub score: i32,
+    pub correct_answers: u32,
+    pub wrong_answers: u32,
+    pub best_streak: u32,
+    /// Share of correct collections in percent
+    pub accuracy: f32,
+}
+
+#[derive(Serialize, Debug)]
+pub struct QuestionResult {
+    pub question: String,
+    pub correct_answer: String,
+    pub answers: Vec<AnswerResult>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct AnswerResult {
+    pub player: String,
+    pub option: String,
+    pub correct: bool,
+}
+
+impl RoundResults {
+    fn collect(
+        history: &AnswerHistory,
+        gameplay_score: &GameplayScore,
+        game_state: &GameState,
+        question_system: Option<&QuestionSystem>,
+    ) -> Self {
+        let mut players: Vec<PlayerResult> = gameplay_score
+            .players
+            .values()
+            .map(|score| PlayerResult {
+                name: score.player_name.clone(),
+                score: score.total_score,
+                correct_answers: score.correct_answers,
+                wrong_answers: score.wrong_answers,
+                best_streak: score.best_streak,
+                accuracy: if score.collection_count > 0 {
+                    score.correct_answers as f32 / score.collection_count as f32 * 100.0
+                } else {
+                    0.0
+                },
+            })
+            .collect();
+        players.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.name.cmp(&b.name)));
+
+        let questions = history
+            .questions
+            .iter()
+            .map(|entry| QuestionResult {
+                question: entry.question.clone(),
+                correct_answer: entry.correct_answer.clone(),
+                answers: entry
+                    .answers
+                    .iter()
+                    .map(|answer| AnswerResult {
+                        player: answer.player_name.clone(),
+                        option: answer.option_text.clone(),
+                        correct: answer.is_correct,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Self {
+            date: chrono::Local::now().format("%Y-%m-%d %H:%M").to_string(),
+            challenge: game_state.current_challenge_id.clone().unwrap_or_default(),
+            category: question_system
+                .map(|question_system| question_system.category.clone())
+                .unwrap_or_default(),
+            players,
+            questions,
+        }
+    }
+
+    /// Player stats first, then one row per collected answer
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("player,score,correct,wrong,best_streak,accuracy\n");
+        for player in &self.players {
+            csv.push_str(&csv_row(&[
+                &player.name,
+                &player.score.to_string(),
+                &player.correct_answers.to_string(),
+                &player.wrong_answers.to_string(),
+                &player.best_streak.to_string(),
+                &format!("{:.1}", player.accuracy),
+            ]));
+        }
+
+        csv.push_str("\nquestion,correct_answer,player,option,correct\n");
+        for question in &self.questions {
+            for answer in &question.answers {
+                csv.push_str(&csv_row(&[
+                    &question.question,
+                    &question.correct_answer,
+                    &answer.player,
+                    &answer.option,
+                    &answer.correct.to_string(),
+                ]));
+            }
+        }
+        csv
+    }
+
+    pub fn to_json(&self) -> Option<String> {
+        serde_json::to_string_pretty(self)
+            .inspect_err(|err| warn!("Failed to serialize the results: {}", err))
+            .ok()
+    }
+}
+
+/// A CSV line, quoting fields that contain separators, quotes or line breaks
+fn csv_row(fields: &[&str]) -> String {
+    let mut row = fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    row.push('\n');
+    row
+}
+
+fn export_results(
+    mut events: EventReader<ExportResultsEvent>,
+    history: Res<AnswerHistory>,
+    gameplay_score: Res<GameplayScore>,
+    game_state: Res<GameState>,
+    question_system: Option<Res<QuestionSystem>>,
+    locale: Res<Locale>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    for &ExportResultsEvent(format) in events.read() {
+        let results = RoundResults::collect(
+            &history,
+            &gameplay_score,
+            &game_state,
+            question_system.as_deref(),
+        );
+        let contents = match format {
+            ExportFormat::Csv => results.to_csv(),
+            ExportFormat::Json => match results.to_json() {
+                Some(json) => json,
+                None => continue,
+            },
+        };
+
+        let file_name = format!(
+            "konnektoren-results-{}.{}",
+            chrono::Local::now().format("%Y%m%d-%H%M%S"),
+            format.extension()
+        );
+        match write_export(&file_name, &contents, format.mime_type()) {
+            Some(location) => {
+                info!("Exported the results to {}", location);
+                notifications.write(NotificationEvent::new(
+                    locale.format("export.saved", &[("file", &location)]),
+                ));
+            }
+            None => {
+                notifications.write(NotificationEvent::new(locale.text("export.failed")));
+            }
+        }
+    }
+}
+
+/// Write the export next to the user's documents, returning where it went
+#[cfg(not(target_family = "wasm"))]
+fn write_export(file_name: &str, contents: &str, _mime_type: &str) -> Option<String> {
+    #[cfg(feature = "persistence")]
+    let path = dirs::document_dir()
+        .map(|documents| documents.join(file_name))
+        .unwrap_or_else(|| file_name.into());
+    #[cfg(not(feature = "persistence"))]
+    let path = std::path::PathBuf::from(file_name);
+
+    std::fs::write(&path, contents)
+        .inspect_err(|err| warn!("Failed to write {}: {}", path.display(), err))
+        .ok()?;
+    Some(path.to_string_lossy().into_owned())
+}
+
+/// Offer the export as a download through a temporary link
+#[cfg(target_family = "wasm")]
+fn write_export(file_name: &str, contents: &str, mime_type: &str) -> Option<String> {
+    use wasm_bindgen::JsCast;
+
+    let document = web_sys::window()?.document()?;
+    let link = document
+        .create_element("a")
+        .ok()?
+        .dyn_into::<web_sys::HtmlAnchorElement>()
+        .ok()?;
+    link.set_href(&format!(
+        "data:{};charset=utf-8,{}",
+        mime_type,
+        crate::url::percent_encode(contents)
+    ));
+    link.set_download(file_name);
+    link.click();
+    Some(file_name.to_string())
+}
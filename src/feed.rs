@@ -0,0 +1,183 @@
+//! Event feed in the gameplay HUD: a short scrolling list of notable things
+//! that just happened, like merges, crashes into a chain and question changes.
+
+use bevy::prelude::*;
+
+use crate::{gameplay::HudLayout, screens::Screen};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<FeedEntry>();
+    app.add_event::<GameFeedEvent>();
+
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_feed_area);
+    app.add_systems(
+        Update,
+        (show_feed_entries, update_feed_entries)
+            .chain()
+            .in_set(crate::AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(crate::PausableSystems),
+    );
+    app.add_systems(
+        Update,
+        update_feed_layout
+            .in_set(crate::AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// Event to add a line to the event feed
+#[derive(Event, Clone, Debug)]
+pub struct GameFeedEvent {
+    pub message: String,
+    pub color: Color,
+}
+
+impl GameFeedEvent {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            color: Color::WHITE,
+        }
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+/// Container the feed entries are stacked in, newest at the bottom
+#[derive(Component)]
+struct FeedArea;
+
+/// A single line of the feed
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct FeedEntry {
+    pub timer: Timer,
+    pub color: Color,
+}
+
+fn spawn_feed_area(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Event Feed"),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(70.0),
+            left: Val::Px(20.0),
+            max_width: Val::Px(FEED_MAX_WIDTH),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Start,
+            row_gap: Val::Px(4.0),
+            ..default()
+        },
+        Pickable::IGNORE,
+        FeedArea,
+        StateScoped(Screen::Gameplay),
+    ));
+}
+
+/// Keep the feed clear of the screen edges, and of the question overlay that
+/// moves to the bottom in portrait
+fn update_feed_layout(
+    hud_layout: Res<HudLayout>,
+    added_areas: Query<(), Added<FeedArea>>,
+    mut area_query: Query<&mut Node, With<FeedArea>>,
+) {
+    if !hud_layout.is_changed() && added_areas.is_empty() {
+        return;
+    }
+
+    let safe_area = hud_layout.safe_area;
+    for mut node in &mut area_query {
+        node.left = Val::Px(20.0 + safe_area.left);
+        if hud_layout.portrait {
+            // Beside the score panel, which keeps the right half of the top
+            node.top = Val::Px(20.0 + safe_area.top);
+            node.bottom = Val::Auto;
+            node.max_width = Val::Percent(45.0);
+        } else {
+            node.top = Val::Auto;
+            node.bottom = Val::Px(70.0 + safe_area.bottom);
+            node.max_width = Val::Px(FEED_MAX_WIDTH);
+        }
+    }
+}
+
+fn show_feed_entries(
+    mut commands: Commands,
+    mut feed_events: EventReader<GameFeedEvent>,
+    area_query: Query<(Entity, Option<&Children>), With<FeedArea>>,
+) {
+    let Ok((area, children)) = area_query.single() else {
+        feed_events.clear();
+        return;
+    };
+
+    let events: Vec<&GameFeedEvent> = feed_events.read().collect();
+    if events.is_empty() {
+        return;
+    }
+
+    // Oldest entries scroll out to make room for the new ones
+    let shown: Vec<Entity> = children.map(|c| c.to_vec()).unwrap_or_default();
+    let overflow = (shown.len() + events.len()).saturating_sub(MAX_FEED_ENTRIES);
+    for &entry in shown.iter().take(overflow) {
+        commands.entity(entry).despawn();
+    }
+
+    for event in events
+        .iter()
+        .skip(events.len().saturating_sub(MAX_FEED_ENTRIES))
+    {
+        debug!("Feed: {}", event.message);
+
+        commands.entity(area).with_child((
+            Name::new("Feed Entry"),
+            Node {
+                padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                ..default()
+            },
+            BackgroundColor(FEED_BACKGROUND),
+            BorderRadius::all(Val::Px(4.0)),
+            Text(event.message.clone()),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(event.color),
+            FeedEntry {
+                timer: Timer::from_seconds(FEED_ENTRY_DURATION, TimerMode::Once),
+                color: event.color,
+            },
+        ));
+    }
+}
+
+/// Count down feed entries, fading them out before they disappear
+fn update_feed_entries(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut entry_query: Query<(Entity, &mut FeedEntry, &mut BackgroundColor, &mut TextColor)>,
+) {
+    for (entity, mut entry, mut background, mut text_color) in &mut entry_query {
+        entry.timer.tick(time.delta());
+
+        if entry.timer.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let alpha = (entry.timer.remaining_secs() / FEED_FADE_DURATION).min(1.0);
+        background.0 = FEED_BACKGROUND.with_alpha(FEED_BACKGROUND.alpha() * alpha);
+        text_color.0 = entry.color.with_alpha(alpha);
+    }
+}
+
+// Event feed constants
+pub const MAX_FEED_ENTRIES: usize = 5;
+pub const FEED_ENTRY_DURATION: f32 = 6.0;
+pub const FEED_FADE_DURATION: f32 = 1.0;
+pub const FEED_MAX_WIDTH: f32 = 360.0;
+pub const FEED_BACKGROUND: Color = Color::srgba(0.0, 0.0, 0.0, 0.55);
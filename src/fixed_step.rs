@@ -0,0 +1,116 @@
+//! Fixed-timestep gameplay core: movement, trail sampling, chain following,
+//! collisions and reaction spreading run in `FixedUpdate`, so the round plays
+//! the same at 30 Hz on a phone as at 144 Hz on a desktop. Entities moved there
+//! carry a `FixedStepPosition`, and their `Transform` is blended between the
+//! last two steps for rendering.
+//!
+//! Systems in `Update` may still move these entities directly (respawns,
+//! merges, magnets); the moved position is taken over as the new simulated one.
+
+use bevy::{app::RunFixedMainLoopSystem, prelude::*};
+
+use crate::screens::Screen;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<FixedStepPosition>();
+
+    app.insert_resource(Time::<Fixed>::from_hz(FIXED_STEP_HZ));
+
+    app.add_systems(
+        FixedFirst,
+        restore_simulated_positions.run_if(in_state(Screen::Gameplay)),
+    );
+    app.add_systems(
+        FixedLast,
+        record_simulated_positions.run_if(in_state(Screen::Gameplay)),
+    );
+    app.add_systems(
+        RunFixedMainLoop,
+        interpolate_simulated_positions
+            .in_set(RunFixedMainLoopSystem::AfterFixedMainLoop)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// Position simulated in `FixedUpdate`, blended for rendering
+#[derive(Component, Reflect, Debug, Default, Clone, Copy)]
+#[reflect(Component)]
+pub struct FixedStepPosition {
+    /// Position after the second to last fixed step
+    pub previous: Vec2,
+    /// Position after the last fixed step
+    pub current: Vec2,
+    /// Translation last written for rendering, to notice outside moves
+    rendered: Vec2,
+}
+
+impl FixedStepPosition {
+    /// Jump to `position` without blending
+    pub fn snap(&mut self, position: Vec2) {
+        self.previous = position;
+        self.current = position;
+        self.rendered = position;
+    }
+
+    /// Rendered position `alpha` of the way from the previous to the current step
+    pub fn blend(&self, alpha: f32) -> Vec2 {
+        // Wrapping around the map edge or a teleport shouldn't sweep across the board
+        if self.previous.distance_squared(self.current)
+            > FIXED_STEP_SNAP_DISTANCE * FIXED_STEP_SNAP_DISTANCE
+        {
+            self.current
+        } else {
+            self.previous.lerp(self.current, alpha)
+        }
+    }
+}
+
+/// Put the simulated positions back before a step, taking over outside moves
+fn restore_simulated_positions(mut query: Query<(&mut FixedStepPosition, &mut Transform)>) {
+    for (mut position, mut transform) in &mut query {
+        let translation = transform.translation.xy();
+        if translation != position.rendered {
+            position.snap(translation);
+            continue;
+        }
+
+        let current = position.current;
+        position.previous = current;
+        transform.translation.x = current.x;
+        transform.translation.y = current.y;
+    }
+}
+
+fn record_simulated_positions(mut query: Query<(&mut FixedStepPosition, &Transform)>) {
+    for (mut position, transform) in &mut query {
+        let translation = transform.translation.xy();
+        position.current = translation;
+        position.rendered = translation;
+    }
+}
+
+/// Blend the rendered positions by how far the clock is into the next step
+fn interpolate_simulated_positions(
+    fixed_time: Res<Time<Fixed>>,
+    mut query: Query<(&mut FixedStepPosition, &mut Transform)>,
+) {
+    let alpha = fixed_time.overstep_fraction();
+
+    for (mut position, mut transform) in &mut query {
+        let translation = transform.translation.xy();
+        if translation != position.rendered {
+            // Spawned or moved outside the fixed step since the last frame
+            position.snap(translation);
+            continue;
+        }
+
+        let blended = position.blend(alpha);
+        position.rendered = blended;
+        transform.translation.x = blended.x;
+        transform.translation.y = blended.y;
+    }
+}
+
+// Fixed step constants
+pub const FIXED_STEP_HZ: f64 = 64.0;
+pub const FIXED_STEP_SNAP_DISTANCE: f32 = 64.0; // Farther than any step moves, so a wrap or teleport
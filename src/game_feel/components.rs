@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+
+/// Trauma-based camera shake. Trauma is added by explosions and decays over
+/// time; the shake strength grows with the square of the trauma.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+pub struct CameraShake {
+    pub trauma: f32,
+    /// Offset currently applied on top of the camera position
+    pub applied_offset: Vec2,
+}
+
+impl CameraShake {
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).min(1.0);
+    }
+
+    pub fn strength(&self) -> f32 {
+        self.trauma * self.trauma
+    }
+}
+
+/// Drives brief slow-motion moments by scaling virtual time
+#[derive(Resource, Reflect, Default, Debug)]
+#[reflect(Resource)]
+pub struct TimeScaleController {
+    /// Real-time timer of the running slow-mo, `None` at normal speed
+    pub slow_motion: Option<Timer>,
+}
+
+impl TimeScaleController {
+    pub fn start_slow_motion(&mut self) {
+        self.slow_motion = Some(Timer::from_seconds(
+            super::SLOW_MOTION_DURATION,
+            TimerMode::Once,
+        ));
+    }
+
+    pub fn is_slowed(&self) -> bool {
+        self.slow_motion.is_some()
+    }
+}
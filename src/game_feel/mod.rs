@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+
+mod components;
+mod systems;
+
+pub use components::*;
+use systems::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<CameraShake>();
+    app.register_type::<TimeScaleController>();
+
+    app.init_resource::<TimeScaleController>();
+
+    app.add_systems(OnExit(crate::screens::Screen::Gameplay), reset_time_scale);
+
+    app.add_systems(
+        Update,
+        (trigger_slow_motion, update_time_scale, add_shake_trauma)
+            .in_set(crate::AppSystems::Update)
+            .run_if(in_state(crate::screens::Screen::Gameplay))
+            .in_set(crate::PausableSystems),
+    );
+
+    // The shake offset is taken off before the camera follows its targets and
+    // put back on afterwards, so it never feeds into the follow movement
+    app.add_systems(
+        PreUpdate,
+        remove_camera_shake.run_if(in_state(crate::screens::Screen::Gameplay)),
+    );
+    app.add_systems(
+        PostUpdate,
+        apply_camera_shake
+            .before(TransformSystem::TransformPropagate)
+            .run_if(in_state(crate::screens::Screen::Gameplay))
+            .run_if(in_state(crate::Pause(false))),
+    );
+}
+
+// Slow-motion constants
+pub const SLOW_MOTION_SPEED: f32 = 0.35; // Relative game speed during slow-mo
+pub const SLOW_MOTION_DURATION: f32 = 0.6; // Real-time seconds
+pub const SLOW_MOTION_MERGE_LEVEL: u32 = 3; // Merges reaching this level trigger slow-mo
+
+// Screen shake constants
+pub const SHAKE_TRAUMA_PER_EXPLOSION: f32 = 0.35; // Scaled by the explosion intensity
+pub const SHAKE_TRAUMA_DECAY: f32 = 1.5; // Trauma lost per second
+pub const SHAKE_MAX_OFFSET: f32 = 12.0;
+pub const SHAKE_FREQUENCY: f32 = 25.0;
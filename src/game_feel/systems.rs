@@ -0,0 +1,111 @@
+use super::components::*;
+use crate::{
+    chain::{ChainMergeCompletedEvent, ChainReactionEvent},
+    effects::SpawnExplosionEvent,
+    settings::GameSettings,
+};
+use bevy::prelude::*;
+
+/// System to start a slow-mo when a chain reaction starts or a big merge completes
+pub fn trigger_slow_motion(
+    game_settings: Res<GameSettings>,
+    mut reaction_events: EventReader<ChainReactionEvent>,
+    mut merge_events: EventReader<ChainMergeCompletedEvent>,
+    mut controller: ResMut<TimeScaleController>,
+) {
+    let reaction_started = reaction_events.read().count() > 0;
+    let big_merge = merge_events
+        .read()
+        .any(|event| event.new_level >= super::SLOW_MOTION_MERGE_LEVEL);
+
+    if game_settings.display.slow_motion && (reaction_started || big_merge) {
+        controller.start_slow_motion();
+    }
+}
+
+/// System to apply the slow-mo to virtual time and end it in real time
+pub fn update_time_scale(
+    real_time: Res<Time<Real>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut controller: ResMut<TimeScaleController>,
+) {
+    let Some(timer) = controller.slow_motion.as_mut() else {
+        return;
+    };
+
+    if timer.tick(real_time.delta()).finished() {
+        controller.slow_motion = None;
+        virtual_time.set_relative_speed(1.0);
+    } else {
+        // Ease back to full speed over the slow-mo
+        let speed = super::SLOW_MOTION_SPEED + (1.0 - super::SLOW_MOTION_SPEED) * timer.fraction();
+        virtual_time.set_relative_speed(speed);
+    }
+}
+
+/// System to restore normal speed when leaving gameplay mid slow-mo
+pub fn reset_time_scale(
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut controller: ResMut<TimeScaleController>,
+) {
+    controller.slow_motion = None;
+    virtual_time.set_relative_speed(1.0);
+}
+
+/// System to add camera trauma for explosions
+pub fn add_shake_trauma(
+    game_settings: Res<GameSettings>,
+    mut explosion_events: EventReader<SpawnExplosionEvent>,
+    mut shake_query: Query<&mut CameraShake>,
+) {
+    let trauma: f32 = explosion_events
+        .read()
+        .map(|event| event.intensity * super::SHAKE_TRAUMA_PER_EXPLOSION)
+        .sum();
+
+    if trauma <= 0.0
+        || !game_settings.display.screen_shake
+        || !game_settings
+            .accessibility
+            .effects_intensity
+            .allows_screen_shake()
+    {
+        return;
+    }
+
+    for mut shake in &mut shake_query {
+        shake.add_trauma(trauma);
+    }
+}
+
+/// System to take last frame's shake offset off the camera
+pub fn remove_camera_shake(mut camera_query: Query<(&mut Transform, &mut CameraShake)>) {
+    for (mut transform, mut shake) in &mut camera_query {
+        transform.translation -= shake.applied_offset.extend(0.0);
+        shake.applied_offset = Vec2::ZERO;
+    }
+}
+
+/// System to decay the trauma and offset the camera by the current shake
+pub fn apply_camera_shake(
+    time: Res<Time<Real>>,
+    mut camera_query: Query<(&mut Transform, &mut CameraShake)>,
+) {
+    for (mut transform, mut shake) in &mut camera_query {
+        shake.trauma = (shake.trauma - super::SHAKE_TRAUMA_DECAY * time.delta_secs()).max(0.0);
+        if shake.trauma <= 0.0 {
+            continue;
+        }
+
+        // Smooth pseudo-noise from out-of-phase sines
+        let t = time.elapsed_secs() * super::SHAKE_FREQUENCY;
+        let noise = Vec2::new(
+            (t * 1.0).sin() + (t * 2.3 + 1.7).sin() * 0.5,
+            (t * 1.3 + 0.5).cos() + (t * 2.9 + 0.3).sin() * 0.5,
+        ) / 1.5;
+
+        let offset = noise * super::SHAKE_MAX_OFFSET * shake.strength() * transform.scale.x;
+        transform.translation += offset.extend(0.0);
+        shake.applied_offset = offset;
+    }
+}
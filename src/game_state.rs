@@ -1,5 +1,8 @@
 use bevy::prelude::*;
 use konnektoren_bevy::assets::*;
+use rand::seq::SliceRandom;
+
+use crate::asset_tracking::ResourceHandles;
 
 /// Resource to track the current game state and level
 #[derive(Resource, Reflect, Clone)]
@@ -9,6 +12,13 @@ pub struct GameState {
     pub current_challenge_id: Option<String>,
     pub level_loaded: bool,
     pub challenge_loaded: bool,
+    /// Pick a random challenge from the level instead of the first one
+    pub random_challenge: bool,
+    /// Seed for the next round, set when replaying a shared round
+    pub round_seed: Option<u64>,
+    /// Topics of the next round, set when resuming a round instead of the
+    /// topics picked on the menu
+    pub round_topics: Option<Vec<String>>,
 }
 
 impl Default for GameState {
@@ -18,6 +28,9 @@ impl Default for GameState {
             current_challenge_id: None,
             level_loaded: false,
             challenge_loaded: false,
+            random_challenge: false,
+            round_seed: None,
+            round_topics: None,
         }
     }
 }
@@ -26,6 +39,32 @@ impl GameState {
     pub fn is_ready(&self) -> bool {
         self.level_loaded && self.challenge_loaded
     }
+
+    /// Share of the resource assets, the level and the challenge loaded so
+    /// far, from 0.0 to 1.0
+    pub fn loading_progress(&self, handles: &ResourceHandles) -> f32 {
+        let loaded = handles.loaded() + self.level_loaded as usize + self.challenge_loaded as usize;
+        let total = handles.total() + 2;
+        loaded as f32 / total as f32
+    }
+
+    /// Switch to another challenge, which then has to finish loading again
+    pub fn select_challenge(&mut self, challenge_id: String) {
+        if self.current_challenge_id.as_deref() != Some(challenge_id.as_str()) {
+            info!("Selected challenge {}", challenge_id);
+            self.current_challenge_id = Some(challenge_id);
+            self.challenge_loaded = false;
+        }
+    }
+}
+
+/// Pick a random challenge id from the level's game path
+pub fn random_level_challenge(level_asset: &LevelAsset) -> Option<String> {
+    level_asset
+        .game_path
+        .challenges
+        .choose(&mut rand::thread_rng())
+        .map(|challenge| challenge.challenge.clone())
 }
 
 /// System to update game state when assets are loaded
@@ -46,14 +85,27 @@ pub fn update_game_state(
                 if let Some(level_asset) = level_assets.get(level_handle) {
                     game_state.level_loaded = true;
 
-                    // Get the first challenge from the level
-                    if let Some(first_challenge) = level_asset.game_path.challenges.first() {
-                        game_state.current_challenge_id = Some(first_challenge.challenge.clone());
+                    // Keep a challenge picked before the level arrived, e.g. at launch,
+                    // otherwise get the first (or a random) challenge from the level
+                    let challenge_id = if game_state.current_challenge_id.is_some() {
+                        game_state.current_challenge_id.clone()
+                    } else if game_state.random_challenge {
+                        random_level_challenge(level_asset)
+                    } else {
+                        level_asset
+                            .game_path
+                            .challenges
+                            .first()
+                            .map(|challenge| challenge.challenge.clone())
+                    };
+
+                    if let Some(challenge_id) = challenge_id {
                         info!(
                             "Level {} loaded, setting challenge to {}",
                             level_asset.name(),
-                            first_challenge.challenge
+                            challenge_id
                         );
+                        game_state.current_challenge_id = Some(challenge_id);
                     } else {
                         warn!("Level {} has no challenges defined", level_asset.name());
                         // Fallback to articles challenge
@@ -0,0 +1,252 @@
+//! Gamepads that drop out mid-match: each player's pad is remembered by entity,
+//! so a disconnect pauses the game behind a "reconnect or reassign" overlay.
+//! Plugging the pad back in, pressing A on a free pad or picking a keyboard
+//! scheme hands the player their controls back.
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_egui::{EguiContextPass, egui};
+use konnektoren_bevy::input::{
+    InputDeviceAssignment,
+    device::{AvailableInputDevices, InputDevice, KeyboardScheme},
+};
+
+use crate::{
+    Pause,
+    locale::Locale,
+    menus::Menu,
+    screens::{GameplayMode, Screen},
+    settings::{GameSettings, MultiplayerSettings, systems::detect_input_devices},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<PlayerGamepads>();
+
+    app.add_systems(OnEnter(Screen::Gameplay), remember_player_gamepads);
+    app.add_systems(OnExit(Screen::Gameplay), forget_player_gamepads);
+    app.add_systems(
+        Update,
+        (
+            follow_player_gamepads,
+            detect_disconnected_gamepads,
+            (rebind_with_free_gamepad, keep_paused)
+                .run_if(resource_exists::<DisconnectedControllers>),
+        )
+            .chain()
+            .after(detect_input_devices)
+            .run_if(in_state(GameplayMode::Interactive)),
+    );
+    app.add_systems(
+        EguiContextPass,
+        disconnected_controller_egui_ui.run_if(
+            in_state(GameplayMode::Interactive).and(resource_exists::<DisconnectedControllers>),
+        ),
+    );
+}
+
+/// Resource with the gamepad entity each gamepad player started the match on.
+/// `InputDevice::Gamepad` only holds an index into the connected pads, which
+/// shifts whenever a pad comes or goes.
+#[derive(Resource, Default, Debug)]
+pub struct PlayerGamepads(HashMap<usize, Entity>);
+
+/// Resource present while players wait for their controller, in player order
+#[derive(Resource, Default, Debug)]
+pub struct DisconnectedControllers {
+    pub players: Vec<usize>,
+}
+
+fn remember_player_gamepads(
+    mut player_gamepads: ResMut<PlayerGamepads>,
+    game_settings: Res<GameSettings>,
+    available_devices: Res<AvailableInputDevices>,
+) {
+    let multiplayer = &game_settings.multiplayer;
+    player_gamepads.0 = multiplayer
+        .players
+        .iter()
+        .take(multiplayer.player_count)
+        .enumerate()
+        .filter_map(|(player_index, player)| match player.input.primary_input {
+            InputDevice::Gamepad(index) => available_devices
+                .gamepads
+                .get(index as usize)
+                .map(|&entity| (player_index, entity)),
+            _ => None,
+        })
+        .collect();
+}
+
+fn forget_player_gamepads(mut commands: Commands, mut player_gamepads: ResMut<PlayerGamepads>) {
+    player_gamepads.0.clear();
+    commands.remove_resource::<DisconnectedControllers>();
+}
+
+/// Point each player's gamepad index back at their pad after the list shifted
+fn follow_player_gamepads(
+    player_gamepads: Res<PlayerGamepads>,
+    available_devices: Res<AvailableInputDevices>,
+    mut game_settings: ResMut<GameSettings>,
+    mut assignment: ResMut<InputDeviceAssignment>,
+) {
+    if !available_devices.is_changed() && !player_gamepads.is_changed() {
+        return;
+    }
+
+    let mut moved = false;
+    for (&player_index, &entity) in &player_gamepads.0 {
+        let Some(index) = available_devices.gamepads.iter().position(|&e| e == entity) else {
+            continue;
+        };
+        let device = InputDevice::Gamepad(index as u32);
+        let Some(player) = game_settings.multiplayer.players.get_mut(player_index) else {
+            continue;
+        };
+        if player.input.primary_input != device {
+            player.input.primary_input = device;
+            moved = true;
+        }
+    }
+
+    if moved {
+        reassign_devices(&game_settings.multiplayer, &mut assignment);
+    }
+}
+
+fn detect_disconnected_gamepads(
+    mut commands: Commands,
+    player_gamepads: Res<PlayerGamepads>,
+    available_devices: Res<AvailableInputDevices>,
+    disconnected: Option<ResMut<DisconnectedControllers>>,
+    menu: Res<State<Menu>>,
+    mut next_pause: ResMut<NextState<Pause>>,
+) {
+    if !available_devices.is_changed() && !player_gamepads.is_changed() {
+        return;
+    }
+
+    let mut missing: Vec<usize> = player_gamepads
+        .0
+        .iter()
+        .filter(|(_, entity)| !available_devices.gamepads.contains(entity))
+        .map(|(&player_index, _)| player_index)
+        .collect();
+    missing.sort_unstable();
+
+    match (disconnected, missing.is_empty()) {
+        (Some(mut disconnected), false) => {
+            if disconnected.players != missing {
+                disconnected.players = missing;
+            }
+        }
+        (Some(_), true) => {
+            info!("All controllers are back, resuming");
+            commands.remove_resource::<DisconnectedControllers>();
+            if *menu.get() == Menu::None {
+                next_pause.set(Pause(false));
+            }
+        }
+        (None, false) => {
+            warn!("Controller disconnected for players {:?}, pausing", missing);
+            commands.insert_resource(DisconnectedControllers { players: missing });
+            next_pause.set(Pause(true));
+        }
+        (None, true) => {}
+    }
+}
+
+/// A pad nobody plays on takes over for the first waiting player with A
+fn rebind_with_free_gamepad(
+    mut player_gamepads: ResMut<PlayerGamepads>,
+    disconnected: Res<DisconnectedControllers>,
+    available_devices: Res<AvailableInputDevices>,
+    gamepads: Query<&Gamepad>,
+) {
+    let Some(&player_index) = disconnected.players.first() else {
+        return;
+    };
+
+    let free_pad = available_devices.gamepads.iter().copied().find(|&entity| {
+        !player_gamepads.0.values().any(|&taken| taken == entity)
+            && gamepads
+                .get(entity)
+                .is_ok_and(|gamepad| gamepad.just_pressed(GamepadButton::South))
+    });
+    if let Some(entity) = free_pad {
+        info!("Player {} now plays on another gamepad", player_index + 1);
+        player_gamepads.0.insert(player_index, entity);
+    }
+}
+
+/// Closing the pause menu unpauses, but not while a controller is missing
+fn keep_paused(pause: Res<State<Pause>>, mut next_pause: ResMut<NextState<Pause>>) {
+    if !pause.get().0 {
+        next_pause.set(Pause(true));
+    }
+}
+
+fn disconnected_controller_egui_ui(
+    mut contexts: bevy_egui::EguiContexts,
+    locale: Res<Locale>,
+    disconnected: Res<DisconnectedControllers>,
+    mut player_gamepads: ResMut<PlayerGamepads>,
+    mut game_settings: ResMut<GameSettings>,
+    mut assignment: ResMut<InputDeviceAssignment>,
+) {
+    let Some(&player_index) = disconnected.players.first() else {
+        return;
+    };
+    let ctx = contexts.ctx_mut();
+    let multiplayer = &game_settings.multiplayer;
+    let mut chosen_scheme = None;
+
+    egui::Window::new(locale.text("controller.disconnected_title"))
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.label(locale.format(
+                "controller.disconnected_hint",
+                &[("player", &multiplayer.player_name(player_index))],
+            ));
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                for scheme in [
+                    KeyboardScheme::WASD,
+                    KeyboardScheme::Arrows,
+                    KeyboardScheme::IJKL,
+                ] {
+                    let device = InputDevice::Keyboard(scheme.clone());
+                    let in_use = multiplayer
+                        .players
+                        .iter()
+                        .take(multiplayer.player_count)
+                        .any(|player| player.input.primary_input == device);
+                    if ui
+                        .add_enabled(!in_use, egui::Button::new(device.name()))
+                        .clicked()
+                    {
+                        chosen_scheme = Some(scheme);
+                    }
+                }
+            });
+        });
+
+    if let Some(scheme) = chosen_scheme {
+        info!("Player {} switched to the keyboard", player_index + 1);
+        if let Some(player) = game_settings.multiplayer.players.get_mut(player_index) {
+            player.input.primary_input = InputDevice::Keyboard(scheme);
+        }
+        player_gamepads.0.remove(&player_index);
+        reassign_devices(&game_settings.multiplayer, &mut assignment);
+    }
+}
+
+fn reassign_devices(multiplayer: &MultiplayerSettings, assignment: &mut InputDeviceAssignment) {
+    assignment.assignments.clear();
+    for player in multiplayer.players.iter().take(multiplayer.player_count) {
+        assignment.assign_device(player.player_id, player.input.primary_input.clone());
+        if let Some(secondary) = &player.input.secondary_input {
+            assignment.assign_device(player.player_id, secondary.clone());
+        }
+    }
+}
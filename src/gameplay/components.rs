@@ -1,3 +1,5 @@
+use crate::config::ScoringConfig;
+use crate::settings::Handicap;
 use bevy::prelude::*;
 use std::collections::HashMap;
 
@@ -29,6 +31,18 @@ impl GameplayScore {
     pub fn get_player_score_mut(&mut self, player_entity: Entity) -> Option<&mut PlayerScore> {
         self.players.get_mut(&player_entity)
     }
+
+    /// Players sharing the highest score
+    pub fn leaders(&self) -> Vec<Entity> {
+        let Some(best) = self.players.values().map(|score| score.total_score).max() else {
+            return Vec::new();
+        };
+        self.players
+            .iter()
+            .filter(|(_, score)| score.total_score == best)
+            .map(|(&entity, _)| entity)
+            .collect()
+    }
 }
 
 /// Component and data structure for individual player scores
@@ -42,6 +56,9 @@ pub struct PlayerScore {
     pub current_streak: u32,
     pub best_streak: u32,
     pub collection_count: u32,
+    pub merges: u32,
+    pub merge_combo: u32,
+    pub last_merge_time: Option<f32>,
 }
 
 impl PlayerScore {
@@ -54,29 +71,67 @@ impl PlayerScore {
             current_streak: 0,
             best_streak: 0,
             collection_count: 0,
+            merges: 0,
+            merge_combo: 0,
+            last_merge_time: None,
         }
     }
 
-    pub fn add_correct_answer(&mut self) {
+    /// Award points for a completed merge and return them together with the
+    /// combo count. Merges within the combo window escalate the multiplier.
+    pub fn add_merge(
+        &mut self,
+        new_level: u32,
+        merge_value: u32,
+        now: f32,
+        scoring: &ScoringConfig,
+        handicap: &Handicap,
+    ) -> (i32, u32) {
+        let window = super::MERGE_COMBO_WINDOW * handicap.merge_window_multiplier;
+        let in_combo = self
+            .last_merge_time
+            .is_some_and(|last| now - last <= window);
+        self.merge_combo = if in_combo { self.merge_combo + 1 } else { 1 };
+        self.last_merge_time = Some(now);
+        self.merges += 1;
+
+        let base_points = scoring.merge_points_per_value * merge_value * new_level;
+        let multiplier = self.merge_combo.min(super::MAX_MERGE_COMBO_MULTIPLIER);
+        let points = handicap.scale_points((base_points * multiplier) as i32);
+        self.total_score += points;
+
+        (points, self.merge_combo)
+    }
+
+    pub fn add_correct_answer(
+        &mut self,
+        multiplier: u32,
+        scoring: &ScoringConfig,
+        handicap: &Handicap,
+    ) {
         self.correct_answers += 1;
         self.current_streak += 1;
         self.collection_count += 1;
 
         // Calculate score with streak bonus
-        let base_points = super::CORRECT_ANSWER_POINTS;
-        let streak_bonus = self.current_streak.saturating_sub(1) * super::STREAK_BONUS_MULTIPLIER;
-        self.total_score += (base_points + streak_bonus) as i32;
+        let base_points = scoring.correct_answer_points;
+        let streak_bonus = self.current_streak.saturating_sub(1) * scoring.streak_bonus;
+        self.total_score +=
+            handicap.scale_points(((base_points + streak_bonus) * multiplier) as i32);
 
         if self.current_streak > self.best_streak {
             self.best_streak = self.current_streak;
         }
     }
 
-    pub fn add_wrong_answer(&mut self) {
+    /// Count a wrong answer, a streak shield spares the player the penalty
+    pub fn add_wrong_answer(&mut self, shielded: bool, scoring: &ScoringConfig) {
         self.wrong_answers += 1;
         self.current_streak = 0;
         self.collection_count += 1;
-        self.total_score = (self.total_score + super::WRONG_ANSWER_PENALTY).max(0);
+        if !shielded {
+            self.total_score = (self.total_score + scoring.wrong_answer_penalty).max(0);
+        }
     }
 }
 
@@ -88,21 +143,28 @@ pub struct GameTimer {
     pub game_duration: f32,
     pub time_remaining: f32,
     pub is_overtime: bool,
+    /// Time ran out with the lead tied, the next decisive play ends the round
+    pub sudden_death: bool,
 }
 
 impl Default for GameTimer {
     fn default() -> Self {
-        let duration = super::GAME_DURATION_MINUTES * 60.0; // Convert to seconds
+        Self::with_duration(super::GAME_DURATION_MINUTES * 60.0) // Convert to seconds
+    }
+}
+
+impl GameTimer {
+    /// Timer for a round lasting `duration` seconds
+    pub fn with_duration(duration: f32) -> Self {
         Self {
             timer: Timer::from_seconds(duration, TimerMode::Once),
             game_duration: duration,
             time_remaining: duration,
             is_overtime: false,
+            sudden_death: false,
         }
     }
-}
 
-impl GameTimer {
     pub fn time_remaining_formatted(&self) -> String {
         if self.is_overtime {
             let overtime = self.timer.elapsed_secs() - self.game_duration;
@@ -129,11 +191,14 @@ pub struct ScoreUpdateEvent {
     pub player_entity: Entity,
     pub is_correct: bool,
     pub points_awarded: i32,
+    pub shielded: bool,
 }
 
 /// Events for game timer - simplified to only what's used
-#[derive(Event)]
+#[derive(Event, Debug)]
 pub enum GameTimerEvent {
+    /// Time ran out with the leading scores tied, play goes on until decided
+    SuddenDeath,
     GameEnded,
 }
 
@@ -161,11 +226,43 @@ pub struct PlayerStatsDisplay {
     pub player_index: usize,
 }
 
+/// Component for the chain length readout in a player's score panel
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct ChainLengthDisplay {
+    pub player_index: usize,
+}
+
 /// Component for team stats display UI
 #[derive(Component, Reflect)]
 #[reflect(Component)]
 pub struct TeamStatsDisplay;
 
+/// Component for the screen-edge glow shown while a player is in fever
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct FeverGlow;
+
+/// Component for the HUD text listing players in fever
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct FeverIndicator;
+
+/// Component for one of the streak shield icons in a player's score panel
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct ShieldIcon {
+    pub player_index: usize,
+    pub slot: u32,
+}
+
+/// Component for the fill of a player's chain magnet meter
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct ChainMagnetMeter {
+    pub player_index: usize,
+}
+
 /// Component for the options/legend display panel
 #[derive(Component, Reflect)]
 #[reflect(Component)]
@@ -182,3 +279,42 @@ pub struct OptionsLegendContainer;
 pub struct OptionLegendItem {
     pub option_id: usize,
 }
+
+/// Resource describing how the HUD is laid out for the current screen
+#[derive(Resource, Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(Resource)]
+pub struct HudLayout {
+    /// Multiplier applied to all gameplay UI through `UiScale`, the HUD size
+    /// setting on top of the general UI scale
+    pub scale: f32,
+    /// Collapse player stats into small badges
+    pub compact: bool,
+    /// Screen is taller than wide, the question overlay moves to the bottom
+    pub portrait: bool,
+    /// Screen edges covered by notches and system bars, already divided by `scale`
+    pub safe_area: crate::safe_area::SafeAreaInsets,
+    /// A touch player uses the on-screen controls, so their corner is kept clear
+    pub touch_controls: bool,
+}
+
+impl Default for HudLayout {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            compact: false,
+            portrait: false,
+            safe_area: crate::safe_area::SafeAreaInsets::default(),
+            touch_controls: false,
+        }
+    }
+}
+
+/// Component for the top-right score and timer panel
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct HudRoot;
+
+/// Component for the container that holds the player score panels
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct PlayerScoresContainer;
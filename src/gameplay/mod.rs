@@ -16,8 +16,16 @@ pub(super) fn plugin(app: &mut App) {
     app.register_type::<PlayerStatsDisplay>();
     app.register_type::<TeamStatsDisplay>();
     app.register_type::<OptionsLegendDisplay>();
+    app.register_type::<FeverGlow>();
+    app.register_type::<FeverIndicator>();
+    app.register_type::<ChainMagnetMeter>();
+    app.register_type::<ShieldIcon>();
+    app.register_type::<ChainLengthDisplay>();
     app.register_type::<OptionsLegendContainer>();
     app.register_type::<OptionLegendItem>();
+    app.register_type::<HudLayout>();
+    app.register_type::<HudRoot>();
+    app.register_type::<PlayerScoresContainer>();
 
     // Register events
     app.add_event::<ScoreUpdateEvent>();
@@ -26,23 +34,50 @@ pub(super) fn plugin(app: &mut App) {
     // Initialize resources
     app.init_resource::<GameplayScore>();
     app.init_resource::<GameTimer>();
+    app.init_resource::<HudLayout>();
 
     app.add_systems(
         OnEnter(crate::screens::Screen::Gameplay),
         (setup_gameplay_ui, reset_game_state),
     );
+    app.add_systems(OnExit(crate::screens::Screen::Gameplay), reset_hud_layout);
+
+    // Layout follows the window even while paused
+    app.add_systems(
+        Update,
+        (update_hud_layout, apply_hud_layout)
+            .chain()
+            .in_set(crate::AppSystems::Update)
+            .run_if(in_state(crate::screens::Screen::Gameplay)),
+    );
 
     app.add_systems(
         Update,
         (
-            update_game_timer.in_set(crate::AppSystems::TickTimers),
+            update_game_timer
+                .run_if(not(resource_exists::<crate::practice::PracticeRound>))
+                .in_set(crate::AppSystems::TickTimers),
             handle_option_collection_events.in_set(crate::AppSystems::Update),
             handle_score_events.in_set(crate::AppSystems::Update),
             handle_chain_destruction_events.in_set(crate::AppSystems::Update),
+            handle_merge_completed_events.in_set(crate::AppSystems::Update),
+            handle_bonus_word_events.in_set(crate::AppSystems::Update),
+            handle_fever_events.in_set(crate::AppSystems::Update),
+            update_fever_display.in_set(crate::AppSystems::Update),
+            handle_chain_overflow_events.in_set(crate::AppSystems::Update),
+            handle_sentence_bonus_events.in_set(crate::AppSystems::Update),
+            update_chain_magnet_meters.in_set(crate::AppSystems::Update),
+            update_shield_icons.in_set(crate::AppSystems::Update),
+            update_chain_length_displays.in_set(crate::AppSystems::Update),
             update_individual_player_scores.in_set(crate::AppSystems::Update),
             update_team_stats_display.in_set(crate::AppSystems::Update),
             update_timer_display.in_set(crate::AppSystems::Update),
             update_options_legend_display.in_set(crate::AppSystems::Update),
+            pulse_options_legend_warning.in_set(crate::AppSystems::Update),
+            handle_question_resolved_events.in_set(crate::AppSystems::Update),
+            flash_resolved_legend_item
+                .run_if(in_state(crate::question::QuestionPhase::Resolving))
+                .in_set(crate::AppSystems::Update),
         )
             .run_if(in_state(crate::screens::Screen::Gameplay))
             .in_set(crate::PausableSystems),
@@ -54,3 +89,29 @@ pub const CORRECT_ANSWER_POINTS: u32 = 10;
 pub const STREAK_BONUS_MULTIPLIER: u32 = 5;
 pub const WRONG_ANSWER_PENALTY: i32 = -5;
 pub const GAME_DURATION_MINUTES: f32 = 5.0;
+
+// Merge scoring constants
+pub const MERGE_POINTS_PER_VALUE: u32 = 5; // Multiplied by merge value and new level
+pub const MERGE_COMBO_WINDOW: f32 = 4.0; // Seconds between merges to keep a combo going
+pub const MAX_MERGE_COMBO_MULTIPLIER: u32 = 5;
+pub const RESOLUTION_BONUS_POINTS: i32 = 5; // For ending a question on a correct collection
+pub const SENTENCE_BONUS_POINTS: i32 = 25; // For a chain ending in connectors from different categories
+pub const SENTENCE_BONUS_COLOR: Color = Color::srgb(0.55, 1.0, 0.6);
+pub const STREAK_MILESTONE_INTERVAL: u32 = 5; // Show a popup every N correct answers in a row
+
+// HUD layout constants
+pub const DEFAULT_HUD_SCALE_PERCENT: u32 = 100;
+pub const MIN_HUD_SCALE_PERCENT: u32 = 60;
+pub const MAX_HUD_SCALE_PERCENT: u32 = 160;
+pub const HUD_MAX_WIDTH: f32 = 400.0;
+pub const COMPACT_HUD_MAX_WIDTH: f32 = 220.0;
+pub const TOUCH_CONTROLS_CLEARANCE: f32 = 180.0; // room left for the virtual joystick in portrait
+
+// Question change warning constants
+pub const LEGEND_WARNING_PULSE_SPEED: f32 = 8.0; // Radians per second
+pub const LEGEND_BORDER_COLOR: Color = Color::srgb(0.3, 0.3, 0.4);
+pub const LEGEND_WARNING_COLOR: Color = Color::srgb(1.0, 0.35, 0.2);
+
+// Fever display constants
+pub const FEVER_GLOW_WIDTH: f32 = 12.0;
+pub const FEVER_GLOW_HUE_SPEED: f32 = 120.0; // Degrees per second
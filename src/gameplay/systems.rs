@@ -1,10 +1,21 @@
 use super::components::*;
+use crate::config::GameConfig;
+use crate::effects::{CelebrationKind, SpawnCelebrationEvent};
+use crate::locale::Locale;
+use crate::palette::{MarkerAtlas, OptionStyleRegistry};
+use crate::popups::{PopupStyle, SpawnTextPopupEvent};
 use crate::screens::Screen;
 use crate::settings::GameSettings;
+use crate::theme::prelude::*;
 use bevy::prelude::*;
+use konnektoren_bevy::prelude::ResponsiveInfo;
 
 /// System to set up the gameplay UI
-pub fn setup_gameplay_ui(mut commands: Commands, game_settings: Res<GameSettings>) {
+pub fn setup_gameplay_ui(
+    mut commands: Commands,
+    game_settings: Res<GameSettings>,
+    locale: Res<Locale>,
+) {
     let player_count = game_settings.multiplayer.player_count;
 
     // Score and timer overlay at the top right
@@ -19,12 +30,13 @@ pub fn setup_gameplay_ui(mut commands: Commands, game_settings: Res<GameSettings
                 flex_direction: FlexDirection::Column,
                 row_gap: Val::Px(10.0),
                 align_items: AlignItems::End,
-                max_width: Val::Px(400.0),
+                max_width: Val::Px(super::HUD_MAX_WIDTH),
                 ..default()
             },
             BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
             BorderRadius::all(Val::Px(8.0)),
             StateScoped(Screen::Gameplay),
+            HudRoot,
         ))
         .id();
 
@@ -53,6 +65,7 @@ pub fn setup_gameplay_ui(mut commands: Commands, game_settings: Res<GameSettings
                 width: Val::Percent(100.0),
                 ..default()
             },
+            PlayerScoresContainer,
         ))
         .id();
 
@@ -61,7 +74,7 @@ pub fn setup_gameplay_ui(mut commands: Commands, game_settings: Res<GameSettings
     for i in 0..player_count {
         let player_settings = &game_settings.multiplayer.players[i];
         let player_data = PlayerScoreData {
-            name: player_settings.name.clone(),
+            name: game_settings.multiplayer.player_name(i),
             color: player_settings.color,
         };
 
@@ -73,7 +86,7 @@ pub fn setup_gameplay_ui(mut commands: Commands, game_settings: Res<GameSettings
     let team_stats = commands
         .spawn((
             Name::new("Team Stats Display"),
-            Text("Team Stats: Loading...".to_string()),
+            Text(locale.text("hud.team_stats_loading").to_string()),
             TextFont {
                 font_size: 14.0,
                 ..default()
@@ -83,12 +96,27 @@ pub fn setup_gameplay_ui(mut commands: Commands, game_settings: Res<GameSettings
         ))
         .id();
 
+    // Fever indicator, empty until a player enters fever
+    let fever_indicator = commands
+        .spawn((
+            Name::new("Fever Indicator"),
+            Text::default(),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            FeverIndicator,
+        ))
+        .id();
+
     // Options/Legend display panel
-    let options_legend_panel = spawn_options_legend_panel(&mut commands);
+    let options_legend_panel = spawn_options_legend_panel(&mut commands, &locale);
 
     // Set up parent-child relationships
     commands.entity(ui_root).add_children(&[
         timer_entity,
+        fever_indicator,
         scores_container,
         team_stats,
         options_legend_panel,
@@ -96,6 +124,24 @@ pub fn setup_gameplay_ui(mut commands: Commands, game_settings: Res<GameSettings
     commands
         .entity(scores_container)
         .add_children(&player_panels);
+
+    // Screen-edge glow, hidden until a player enters fever
+    commands.spawn((
+        Name::new("Fever Glow"),
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            border: UiRect::all(Val::Px(super::FEVER_GLOW_WIDTH)),
+            ..default()
+        },
+        BorderColor(Color::NONE),
+        Visibility::Hidden,
+        Pickable::IGNORE,
+        GlobalZIndex(-1),
+        FeverGlow,
+        StateScoped(Screen::Gameplay),
+    ));
 }
 
 fn spawn_player_score_panel(
@@ -135,6 +181,40 @@ fn spawn_player_score_panel(
         ))
         .id();
 
+    // Streak shields, one icon lights up per shield ready to use
+    let shield_row = commands
+        .spawn((
+            Name::new(format!("Player {} Shields", player_index + 1)),
+            Node {
+                column_gap: Val::Px(3.0),
+                ..default()
+            },
+        ))
+        .id();
+    for slot in 0..crate::player::MAX_STREAK_SHIELDS {
+        let shield_icon = commands
+            .spawn((
+                Name::new(format!("Player {} Shield {}", player_index + 1, slot + 1)),
+                Node {
+                    width: Val::Px(SHIELD_ICON_WIDTH),
+                    height: Val::Px(SHIELD_ICON_HEIGHT),
+                    ..default()
+                },
+                BackgroundColor(crate::player::SHIELD_COLOR),
+                // Flat top, pointed bottom
+                BorderRadius::new(
+                    Val::Px(2.0),
+                    Val::Px(2.0),
+                    Val::Px(SHIELD_ICON_WIDTH / 2.0),
+                    Val::Px(SHIELD_ICON_WIDTH / 2.0),
+                ),
+                Visibility::Hidden,
+                ShieldIcon { player_index, slot },
+            ))
+            .id();
+        commands.entity(shield_row).add_child(shield_icon);
+    }
+
     // Create stats text
     let stats_text = commands
         .spawn((
@@ -149,14 +229,226 @@ fn spawn_player_score_panel(
         ))
         .id();
 
+    // Chain length, turns orange near the maximum length
+    let chain_length_text = commands
+        .spawn((
+            Name::new(format!("Player {} Chain Length", player_index + 1)),
+            Text::default(),
+            TextFont {
+                font_size: if player_count > 2 { 10.0 } else { 12.0 },
+                ..default()
+            },
+            TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            ChainLengthDisplay { player_index },
+        ))
+        .id();
+
+    // Chain magnet meter: fills with merges, drains into a cooldown after use
+    let magnet_meter = commands
+        .spawn((
+            Name::new(format!("Player {} Magnet Meter", player_index + 1)),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(4.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.15)),
+            BorderRadius::all(Val::Px(2.0)),
+        ))
+        .id();
+    let magnet_meter_fill = commands
+        .spawn((
+            Name::new(format!("Player {} Magnet Meter Fill", player_index + 1)),
+            Node {
+                width: Val::Percent(0.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            BackgroundColor(MAGNET_CHARGING_COLOR),
+            BorderRadius::all(Val::Px(2.0)),
+            ChainMagnetMeter { player_index },
+        ))
+        .id();
+    commands.entity(magnet_meter).add_child(magnet_meter_fill);
+
     // Set up parent-child relationship
-    commands
-        .entity(panel_entity)
-        .add_children(&[score_text, stats_text]);
+    commands.entity(panel_entity).add_children(&[
+        score_text,
+        shield_row,
+        stats_text,
+        chain_length_text,
+        magnet_meter,
+    ]);
 
     panel_entity
 }
 
+const MAGNET_CHARGING_COLOR: Color = Color::srgb(0.3, 0.5, 0.8);
+const MAGNET_READY_COLOR: Color = Color::srgb(0.4, 0.9, 1.0);
+const MAGNET_COOLDOWN_COLOR: Color = Color::srgb(0.5, 0.5, 0.5);
+const SHIELD_ICON_WIDTH: f32 = 10.0;
+const SHIELD_ICON_HEIGHT: f32 = 12.0;
+
+/// System to show one icon per streak shield a player has ready
+pub fn update_shield_icons(
+    stats_query: Query<
+        (&crate::player::PlayerStats, &crate::player::PlayerIndex),
+        Changed<crate::player::PlayerStats>,
+    >,
+    mut icon_query: Query<(&ShieldIcon, &mut Visibility)>,
+) {
+    for (stats, player_index) in &stats_query {
+        for (icon, mut visibility) in &mut icon_query {
+            if icon.player_index == player_index.0 {
+                *visibility = if icon.slot < stats.shields {
+                    Visibility::Inherited
+                } else {
+                    Visibility::Hidden
+                };
+            }
+        }
+    }
+}
+
+/// System to keep the chain magnet meters in sync with the players' magnets
+pub fn update_chain_magnet_meters(
+    magnet_query: Query<(&crate::player::ChainMagnet, &crate::player::PlayerIndex)>,
+    mut meter_query: Query<(&ChainMagnetMeter, &mut Node, &mut BackgroundColor)>,
+) {
+    for (meter, mut node, mut background) in &mut meter_query {
+        let Some((magnet, _)) = magnet_query
+            .iter()
+            .find(|(_, index)| index.0 == meter.player_index)
+        else {
+            continue;
+        };
+
+        let (fraction, color) = if magnet.is_pulling() {
+            (1.0, MAGNET_READY_COLOR)
+        } else if let Some(cooldown) = magnet.cooldown_fraction() {
+            (cooldown, MAGNET_COOLDOWN_COLOR)
+        } else if magnet.is_ready() {
+            (1.0, MAGNET_READY_COLOR)
+        } else {
+            (magnet.charge_fraction(), MAGNET_CHARGING_COLOR)
+        };
+
+        node.width = Val::Percent(fraction * 100.0);
+        background.0 = color;
+    }
+}
+
+/// System to show each player's chain length against the maximum
+pub fn update_chain_length_displays(
+    locale: Res<Locale>,
+    chain_query: Query<(&crate::chain::PlayerChain, &crate::player::PlayerIndex)>,
+    mut display_query: Query<(&ChainLengthDisplay, &mut Text, &mut TextColor)>,
+) {
+    for (display, mut text, mut color) in &mut display_query {
+        let Some((chain, _)) = chain_query
+            .iter()
+            .find(|(_, index)| index.0 == display.player_index)
+        else {
+            continue;
+        };
+
+        let length = chain.segments.len();
+        let readout = locale.format(
+            "hud.chain_length",
+            &[("length", &length), ("max", &chain.max_segments)],
+        );
+        if text.0 != readout {
+            text.0 = readout;
+        }
+
+        color.0 = if length >= chain.max_segments {
+            Color::srgb(1.0, 0.35, 0.35)
+        } else if chain.is_near_limit() {
+            Color::srgb(1.0, 0.7, 0.3)
+        } else {
+            Color::srgb(0.8, 0.8, 0.8)
+        };
+    }
+}
+
+/// System to score and announce chains going past their maximum length
+pub fn handle_chain_overflow_events(
+    mut overflow_events: EventReader<crate::chain::ChainOverflowEvent>,
+    mut gameplay_score: ResMut<GameplayScore>,
+    locale: Res<Locale>,
+    mut popup_events: EventWriter<SpawnTextPopupEvent>,
+) {
+    use crate::chain::ChainOverflowRule;
+
+    for event in overflow_events.read() {
+        match event.rule {
+            ChainOverflowRule::DropOldest => {
+                popup_events.write(SpawnTextPopupEvent::new(
+                    event.position,
+                    locale.text("hud.chain_full"),
+                    Color::srgb(0.8, 0.8, 0.8),
+                    PopupStyle::Score,
+                ));
+            }
+            ChainOverflowRule::CashIn => {
+                if !gameplay_score.players.contains_key(&event.player_entity) {
+                    gameplay_score.add_player(event.player_entity, "Player".to_string());
+                }
+                if let Some(player_score) = gameplay_score.get_player_score_mut(event.player_entity)
+                {
+                    player_score.total_score += event.points;
+                }
+                popup_events.write(SpawnTextPopupEvent::new(
+                    event.position,
+                    format!("+{}", event.points),
+                    Color::srgb(1.0, 0.9, 0.3),
+                    PopupStyle::Score,
+                ));
+            }
+            ChainOverflowRule::Reaction => {
+                popup_events.write(SpawnTextPopupEvent::new(
+                    event.position,
+                    locale.text("hud.overflow_reaction"),
+                    Color::srgb(1.0, 0.35, 0.35),
+                    PopupStyle::Penalty,
+                ));
+            }
+        }
+    }
+}
+
+/// System to score and announce sentences built in a chain
+pub fn handle_sentence_bonus_events(
+    mut bonus_events: EventReader<crate::chain::SentenceBonusEvent>,
+    mut gameplay_score: ResMut<GameplayScore>,
+    config: Res<GameConfig>,
+    locale: Res<Locale>,
+    mut popup_events: EventWriter<SpawnTextPopupEvent>,
+) {
+    for event in bonus_events.read() {
+        if !gameplay_score.players.contains_key(&event.player_entity) {
+            gameplay_score.add_player(event.player_entity, "Player".to_string());
+        }
+        let points = config.scoring.sentence_bonus_points;
+        if let Some(player_score) = gameplay_score.get_player_score_mut(event.player_entity) {
+            player_score.total_score += points;
+        }
+
+        popup_events.write(SpawnTextPopupEvent::new(
+            event.position,
+            locale.format(
+                "hud.sentence_bonus",
+                &[
+                    ("words", &event.categories.join(" · ")),
+                    ("points", &points),
+                ],
+            ),
+            super::SENTENCE_BONUS_COLOR,
+            PopupStyle::Milestone,
+        ));
+    }
+}
+
 // Helper struct to hold player data
 #[derive(Clone)]
 struct PlayerScoreData {
@@ -164,12 +456,12 @@ struct PlayerScoreData {
     color: Color,
 }
 
-fn spawn_options_legend_panel(commands: &mut Commands) -> Entity {
+fn spawn_options_legend_panel(commands: &mut Commands, locale: &Locale) -> Entity {
     // Create legend header
     let options_header = commands
         .spawn((
             Name::new("Options Legend Header"),
-            Text("Available Options:".to_string()),
+            Text(locale.text("hud.available_options").to_string()),
             TextFont {
                 font_size: 14.0,
                 ..default()
@@ -207,7 +499,7 @@ fn spawn_options_legend_panel(commands: &mut Commands) -> Entity {
                 ..default()
             },
             BackgroundColor(Color::srgba(0.1, 0.1, 0.2, 0.7)),
-            BorderColor(Color::srgb(0.3, 0.3, 0.4)),
+            BorderColor(super::LEGEND_BORDER_COLOR),
             BorderRadius::all(Val::Px(5.0)),
             OptionsLegendDisplay,
         ))
@@ -246,6 +538,8 @@ pub fn reset_game_state(
 pub fn update_game_timer(
     time: Res<Time>,
     mut game_timer: ResMut<GameTimer>,
+    gameplay_score: Res<GameplayScore>,
+    game_settings: Res<GameSettings>,
     mut timer_events: EventWriter<GameTimerEvent>,
 ) {
     game_timer.timer.tick(time.delta());
@@ -257,6 +551,15 @@ pub fn update_game_timer(
     // Check for overtime
     if game_timer.timer.finished() && !game_timer.is_overtime {
         game_timer.is_overtime = true;
+
+        // A tied lead is settled by sudden death instead of ending in a draw
+        if !game_settings.multiplayer.coop_shared_chain && gameplay_score.leaders().len() > 1 {
+            game_timer.sudden_death = true;
+            timer_events.write(GameTimerEvent::SuddenDeath);
+            info!("Game time ended with a tied lead! Sudden death...");
+            return;
+        }
+
         timer_events.write(GameTimerEvent::GameEnded);
         info!("Game time ended! Entering overtime...");
     }
@@ -266,6 +569,16 @@ pub fn update_game_timer(
 pub fn handle_score_events(
     mut score_events: EventReader<ScoreUpdateEvent>,
     mut gameplay_score: ResMut<GameplayScore>,
+    power_ups_query: Query<&crate::powerups::ActivePowerUps>,
+    fever_query: Query<&crate::player::FeverState>,
+    player_query: Query<(&Transform, &crate::player::PlayerIndex), With<crate::player::Player>>,
+    game_settings: Res<GameSettings>,
+    config: Res<GameConfig>,
+    practice: Option<Res<crate::practice::PracticeRound>>,
+    locale: Res<Locale>,
+    mut popup_events: EventWriter<SpawnTextPopupEvent>,
+    mut feed_events: EventWriter<crate::feed::GameFeedEvent>,
+    mut celebration_events: EventWriter<SpawnCelebrationEvent>,
 ) {
     for event in score_events.read() {
         // Ensure player exists in the score tracking
@@ -275,10 +588,68 @@ pub fn handle_score_events(
 
         // Update player score
         if let Some(player_score) = gameplay_score.get_player_score_mut(event.player_entity) {
+            let score_before = player_score.total_score;
+
             if event.is_correct {
-                player_score.add_correct_answer();
+                let multiplier = power_ups_query
+                    .get(event.player_entity)
+                    .map_or(1, |p| p.score_multiplier())
+                    * fever_query
+                        .get(event.player_entity)
+                        .map_or(1, |f| f.score_multiplier());
+                let handicap = player_query
+                    .get(event.player_entity)
+                    .map(|(_, index)| game_settings.multiplayer.handicap(index.0))
+                    .unwrap_or_default();
+                player_score.add_correct_answer(multiplier, &config.scoring, &handicap);
             } else {
-                player_score.add_wrong_answer();
+                // Practice costs nothing
+                player_score
+                    .add_wrong_answer(event.shielded || practice.is_some(), &config.scoring);
+            }
+
+            let Ok((player_transform, _)) = player_query.get(event.player_entity) else {
+                continue;
+            };
+            let position = player_transform.translation.xy() + Vec2::Y * 20.0;
+            let points = player_score.total_score - score_before;
+
+            if points > 0 {
+                popup_events.write(SpawnTextPopupEvent::new(
+                    position,
+                    format!("+{}", points),
+                    Color::srgb(0.4, 1.0, 0.4),
+                    PopupStyle::Score,
+                ));
+            } else if points < 0 {
+                popup_events.write(SpawnTextPopupEvent::new(
+                    position,
+                    format!("{}", points),
+                    Color::srgb(1.0, 0.35, 0.35),
+                    PopupStyle::Penalty,
+                ));
+            }
+
+            let streak = player_score.current_streak;
+            if event.is_correct && streak > 0 && streak % super::STREAK_MILESTONE_INTERVAL == 0 {
+                popup_events.write(SpawnTextPopupEvent::new(
+                    position + Vec2::Y * 24.0,
+                    locale.format("hud.streak", &[("streak", &streak)]),
+                    Color::srgb(1.0, 0.85, 0.2),
+                    PopupStyle::Milestone,
+                ));
+                feed_events.write(
+                    crate::feed::GameFeedEvent::new(locale.format(
+                        "feed.streak",
+                        &[("name", &player_score.player_name), ("streak", &streak)],
+                    ))
+                    .with_color(Color::srgb(1.0, 0.85, 0.2)),
+                );
+                celebration_events.write(SpawnCelebrationEvent::new(
+                    position.extend(0.0),
+                    CelebrationKind::StarShower,
+                    Color::srgb(1.0, 0.85, 0.2),
+                ));
             }
         }
     }
@@ -291,8 +662,13 @@ pub fn update_individual_player_scores(
     mut player_score_query: Query<(&mut Text, &PlayerScoreDisplay)>,
     mut player_stats_query: Query<(&mut Text, &PlayerStatsDisplay), Without<PlayerScoreDisplay>>,
     player_query: Query<(Entity, &crate::player::PlayerIndex), With<crate::player::Player>>,
+    idle_query: Query<&crate::player::PlayerIndex, With<crate::idle::IdlePlayer>>,
+    added_idle: Query<(), Added<crate::idle::IdlePlayer>>,
+    mut removed_idle: RemovedComponents<crate::idle::IdlePlayer>,
+    locale: Res<Locale>,
 ) {
-    if !gameplay_score.is_changed() {
+    let idle_changed = !added_idle.is_empty() || removed_idle.read().count() > 0;
+    if !gameplay_score.is_changed() && !idle_changed {
         return;
     }
 
@@ -306,28 +682,16 @@ pub fn update_individual_player_scores(
             .find(|(_, idx)| idx.0 == player_index)
             .map(|(entity, _)| entity);
 
-        if let Some(entity) = player_entity {
-            if let Some(player_score) = gameplay_score.players.get(&entity) {
-                if let Some(player_settings) = game_settings.multiplayer.players.get(player_index) {
-                    text.0 = format!("{}: {}", player_settings.name, player_score.total_score);
-                } else {
-                    text.0 = format!("Player {}: {}", player_index + 1, player_score.total_score);
-                }
-            } else if let Some(player_settings) =
-                game_settings.multiplayer.players.get(player_index)
-            {
-                text.0 = format!("{}: 0", player_settings.name);
-            } else {
-                text.0 = format!("Player {}: 0", player_index + 1);
-            }
+        // Players without an entity or a score yet show 0
+        let total_score = player_entity
+            .and_then(|entity| gameplay_score.players.get(&entity))
+            .map_or(0, |player_score| player_score.total_score);
+        let name = game_settings.multiplayer.player_name(player_index);
+        text.0 = if idle_query.iter().any(|idle| idle.0 == player_index) {
+            locale.format("hud.idle", &[("name", &name), ("score", &total_score)])
         } else {
-            // Fallback if player entity not found
-            if let Some(player_settings) = game_settings.multiplayer.players.get(player_index) {
-                text.0 = format!("{}: 0", player_settings.name);
-            } else {
-                text.0 = format!("Player {}: 0", player_index + 1);
-            }
-        }
+            format!("{}: {}", name, total_score)
+        };
     }
 
     // Update individual player stats
@@ -349,15 +713,17 @@ pub fn update_individual_player_scores(
                     0.0
                 };
 
-                text.0 = format!(
-                    "Current: {} | Best: {} | Accuracy: {:.0}%",
-                    player_score.current_streak, player_score.best_streak, accuracy
+                text.0 = player_stats_text(
+                    &locale,
+                    player_score.current_streak,
+                    player_score.best_streak,
+                    accuracy,
                 );
             } else {
-                text.0 = "Current: 0 | Best: 0 | Accuracy: 0%".to_string();
+                text.0 = player_stats_text(&locale, 0, 0, 0.0);
             }
         } else {
-            text.0 = "Current: 0 | Best: 0 | Accuracy: 0%".to_string();
+            text.0 = player_stats_text(&locale, 0, 0, 0.0);
         }
     }
 }
@@ -366,6 +732,7 @@ pub fn update_individual_player_scores(
 pub fn update_team_stats_display(
     gameplay_score: Res<GameplayScore>,
     game_settings: Res<GameSettings>,
+    locale: Res<Locale>,
     mut team_stats_query: Query<&mut Text, With<TeamStatsDisplay>>,
 ) {
     if !gameplay_score.is_changed() {
@@ -413,9 +780,14 @@ pub fn update_team_stats_display(
                 0.0
             };
 
-            text.0 = format!(
-                "Team: {} pts | Current Best: {} | All-Time Best: {} | Accuracy: {:.0}%",
-                total_score, best_current_streak, best_streak_overall, team_accuracy
+            text.0 = locale.format(
+                "hud.team_stats",
+                &[
+                    ("score", &total_score),
+                    ("current", &best_current_streak),
+                    ("best", &best_streak_overall),
+                    ("accuracy", &format!("{:.0}", team_accuracy)),
+                ],
             );
         } else {
             // Single player stats
@@ -426,24 +798,49 @@ pub fn update_team_stats_display(
                 } else {
                     0.0
                 };
-                text.0 = format!(
-                    "Current: {} | Best: {} | Accuracy: {:.0}%",
-                    score.current_streak, score.best_streak, accuracy
-                );
+                text.0 =
+                    player_stats_text(&locale, score.current_streak, score.best_streak, accuracy);
             } else {
-                text.0 = "Current: 0 | Best: 0 | Accuracy: 0%".to_string();
+                text.0 = player_stats_text(&locale, 0, 0, 0.0);
             }
         }
     }
 }
 
+/// Streak and accuracy line shown under a score
+fn player_stats_text(locale: &Locale, current: u32, best: u32, accuracy: f32) -> String {
+    locale.format(
+        "hud.player_stats",
+        &[
+            ("current", &current),
+            ("best", &best),
+            ("accuracy", &format!("{:.0}", accuracy)),
+        ],
+    )
+}
+
 /// System to update timer display
 pub fn update_timer_display(
     game_timer: Res<GameTimer>,
+    practice: Option<Res<crate::practice::PracticeRound>>,
+    locale: Res<Locale>,
     mut timer_query: Query<(&mut Text, &mut TextColor), With<TimerDisplay>>,
 ) {
     for (mut text, mut color) in &mut timer_query {
-        text.0 = game_timer.time_remaining_formatted();
+        if practice.is_some() {
+            text.0 = locale.text("hud.practice").to_string();
+            color.0 = Color::WHITE;
+            continue;
+        }
+
+        text.0 = if game_timer.is_overtime {
+            locale.format(
+                "hud.overtime",
+                &[("time", &game_timer.time_remaining_formatted())],
+            )
+        } else {
+            game_timer.time_remaining_formatted()
+        };
 
         // Change color based on time remaining
         if game_timer.is_overtime {
@@ -464,6 +861,8 @@ pub fn handle_option_collection_events(
     mut score_events: EventWriter<ScoreUpdateEvent>,
     mut gameplay_score: ResMut<GameplayScore>,
     game_settings: Res<GameSettings>,
+    config: Res<GameConfig>,
+    practice: Option<Res<crate::practice::PracticeRound>>,
     player_query: Query<&crate::player::PlayerIndex, With<crate::player::Player>>,
 ) {
     for event in collection_events.read() {
@@ -485,15 +884,18 @@ pub fn handle_option_collection_events(
         }
 
         let points = if event.is_correct {
-            super::CORRECT_ANSWER_POINTS as i32
+            config.scoring.correct_answer_points as i32
+        } else if event.shielded || practice.is_some() {
+            0
         } else {
-            super::WRONG_ANSWER_PENALTY
+            config.scoring.wrong_answer_penalty
         };
 
         score_events.write(ScoreUpdateEvent {
             player_entity: event.player_entity,
             is_correct: event.is_correct,
             points_awarded: points,
+            shielded: event.shielded,
         });
     }
 }
@@ -502,17 +904,205 @@ pub fn handle_option_collection_events(
 pub fn handle_chain_destruction_events(
     mut destruction_events: EventReader<crate::chain::ChainSegmentDestroyedEvent>,
     mut gameplay_score: ResMut<GameplayScore>,
+    chain_mode: Res<crate::chain::ChainMode>,
+    practice: Option<Res<crate::practice::PracticeRound>>,
+    mut popup_events: EventWriter<SpawnTextPopupEvent>,
 ) {
+    // Crashing still costs the chain, but not points, while practicing
+    if practice.is_some() {
+        destruction_events.clear();
+        return;
+    }
+
     for event in destruction_events.read() {
         // Ensure player exists in the score tracking
         if !gameplay_score.players.contains_key(&event.player_entity) {
             gameplay_score.add_player(event.player_entity, "Player".to_string());
         }
 
+        // In co-op the whole team pays for the team chain, split between players
+        if chain_mode.is_team() {
+            let team_size = gameplay_score.players.len() as i32;
+            let share = (event.points_lost + team_size - 1) / team_size;
+            let mut points_lost = 0;
+            for player_score in gameplay_score.players.values_mut() {
+                let score_before = player_score.total_score;
+                player_score.total_score = (player_score.total_score - share).max(0);
+                points_lost += score_before - player_score.total_score;
+            }
+
+            if points_lost > 0 {
+                popup_events.write(SpawnTextPopupEvent::new(
+                    event.position,
+                    format!("-{} team", points_lost),
+                    Color::srgb(1.0, 0.35, 0.35),
+                    PopupStyle::Penalty,
+                ));
+            }
+            continue;
+        }
+
         // Deduct points from player score
         if let Some(player_score) = gameplay_score.get_player_score_mut(event.player_entity) {
+            let score_before = player_score.total_score;
             player_score.total_score = (player_score.total_score - event.points_lost).max(0);
+
+            let points = player_score.total_score - score_before;
+            if points < 0 {
+                popup_events.write(SpawnTextPopupEvent::new(
+                    event.position,
+                    format!("{}", points),
+                    Color::srgb(1.0, 0.35, 0.35),
+                    PopupStyle::Penalty,
+                ));
+            }
+        }
+    }
+}
+
+/// System to announce fever starting and ending above the player
+pub fn handle_fever_events(
+    mut fever_events: EventReader<crate::player::FeverEvent>,
+    player_query: Query<&Transform, With<crate::player::Player>>,
+    mut popup_events: EventWriter<SpawnTextPopupEvent>,
+) {
+    for event in fever_events.read() {
+        let Ok(transform) = player_query.get(event.player_entity) else {
+            continue;
+        };
+        let position = transform.translation.xy() + Vec2::Y * 40.0;
+
+        if event.started {
+            popup_events.write(SpawnTextPopupEvent::new(
+                position,
+                "FEVER!",
+                Color::srgb(1.0, 0.4, 0.9),
+                PopupStyle::Milestone,
+            ));
+        } else {
+            popup_events.write(SpawnTextPopupEvent::new(
+                position,
+                "Fever over",
+                Color::srgb(0.7, 0.7, 0.7),
+                PopupStyle::Penalty,
+            ));
+        }
+    }
+}
+
+/// System to update the fever glow and HUD indicator
+pub fn update_fever_display(
+    time: Res<Time>,
+    game_settings: Res<GameSettings>,
+    fever_query: Query<
+        (
+            &crate::player::FeverState,
+            &crate::player::PlayerIndex,
+            &Name,
+            Has<crate::bot::BotController>,
+        ),
+        With<crate::player::Player>,
+    >,
+    mut glow_query: Query<(&mut BorderColor, &mut Visibility), With<FeverGlow>>,
+    mut indicator_query: Query<&mut Text, With<FeverIndicator>>,
+) {
+    let in_fever: Vec<String> = fever_query
+        .iter()
+        .filter(|(fever, ..)| fever.is_active())
+        .map(|(fever, player_index, name, is_bot)| {
+            let player_name = game_settings
+                .multiplayer
+                .players
+                .get(player_index.0)
+                .filter(|_| !is_bot)
+                .map_or_else(|| name.to_string(), |player| player.name.clone());
+            format!(
+                "{} FEVER x{} {:.0}s",
+                player_name,
+                fever.score_multiplier(),
+                fever.remaining().ceil()
+            )
+        })
+        .collect();
+
+    let hue = (time.elapsed_secs() * super::FEVER_GLOW_HUE_SPEED) % 360.0;
+    for (mut border, mut visibility) in &mut glow_query {
+        if in_fever.is_empty() {
+            *visibility = Visibility::Hidden;
+        } else {
+            *visibility = Visibility::Inherited;
+            border.0 = Color::hsla(hue, 0.9, 0.6, 0.6);
+        }
+    }
+
+    for mut text in &mut indicator_query {
+        let content = in_fever.join("\n");
+        if text.0 != content {
+            text.0 = content;
+        }
+    }
+}
+
+/// System to award points for completed merges and show them at the merge position
+pub fn handle_merge_completed_events(
+    time: Res<Time>,
+    mut merge_events: EventReader<crate::chain::ChainMergeCompletedEvent>,
+    mut gameplay_score: ResMut<GameplayScore>,
+    config: Res<GameConfig>,
+    game_settings: Res<GameSettings>,
+    player_query: Query<&crate::player::PlayerIndex, With<crate::player::Player>>,
+    mut popup_events: EventWriter<SpawnTextPopupEvent>,
+    mut celebration_events: EventWriter<SpawnCelebrationEvent>,
+) {
+    for event in merge_events.read() {
+        if !gameplay_score.players.contains_key(&event.player_entity) {
+            gameplay_score.add_player(event.player_entity, "Player".to_string());
+        }
+
+        let Some(player_score) = gameplay_score.get_player_score_mut(event.player_entity) else {
+            continue;
+        };
+
+        let handicap = player_query
+            .get(event.player_entity)
+            .map(|index| game_settings.multiplayer.handicap(index.0))
+            .unwrap_or_default();
+        let (points, combo) = player_score.add_merge(
+            event.new_level,
+            event.merge_value,
+            time.elapsed_secs(),
+            &config.scoring,
+            &handicap,
+        );
+
+        popup_events.write(if combo > 1 {
+            SpawnTextPopupEvent::new(
+                event.position,
+                format!("+{} (combo x{})", points, combo),
+                Color::srgb(1.0, 0.6, 0.2),
+                PopupStyle::Combo,
+            )
+        } else {
+            SpawnTextPopupEvent::new(
+                event.position,
+                format!("+{}", points),
+                Color::srgb(1.0, 0.9, 0.3),
+                PopupStyle::Combo,
+            )
+        });
+
+        if event.new_level >= crate::effects::CELEBRATION_MERGE_LEVEL {
+            celebration_events.write(SpawnCelebrationEvent::new(
+                event.position.extend(0.0),
+                CelebrationKind::FireworkRing,
+                Color::srgb(1.0, 0.6, 0.2),
+            ));
         }
+
+        info!(
+            "Player {:?} merged to level {} for {} points (combo {})",
+            event.player_entity, event.new_level, points, combo
+        );
     }
 }
 
@@ -522,6 +1112,8 @@ pub fn update_options_legend_display(
     container_query: Query<Entity, With<OptionsLegendContainer>>,
     mut commands: Commands,
     existing_items: Query<Entity, With<OptionLegendItem>>,
+    option_styles: Res<OptionStyleRegistry>,
+    marker_atlas: Option<Res<MarkerAtlas>>,
 ) {
     let Some(question_system) = question_system else {
         return;
@@ -535,8 +1127,8 @@ pub fn update_options_legend_display(
         return;
     };
 
-    // Only update when question system changes
-    if !question_system.is_changed() {
+    // Only update when the question or the option styles change
+    if !question_system.is_changed() && !option_styles.is_changed() {
         return;
     }
 
@@ -547,19 +1139,10 @@ pub fn update_options_legend_display(
 
     let options = question_system.get_current_options();
 
-    // Color palette (same as collectibles)
-    let base_colors = [
-        Color::srgb(0.3, 0.5, 0.8), // Blue
-        Color::srgb(0.8, 0.5, 0.3), // Orange
-        Color::srgb(0.5, 0.8, 0.3), // Green
-        Color::srgb(0.8, 0.3, 0.5), // Pink
-        Color::srgb(0.5, 0.3, 0.8), // Purple
-    ];
-
     // Create new option legend items
     for option in options.iter() {
         let is_correct = option.id == current_question.option;
-        let color = base_colors[option.id % base_colors.len()];
+        let color = option_styles.color(option.id);
 
         // Make correct answers brighter
         let display_color = if is_correct {
@@ -572,6 +1155,13 @@ pub fn update_options_legend_display(
             color
         };
 
+        let item_background = Color::srgba(
+            display_color.to_srgba().red * 0.3,
+            display_color.to_srgba().green * 0.3,
+            display_color.to_srgba().blue * 0.3,
+            0.6,
+        );
+
         // Create the main option legend item
         let option_entity = commands
             .spawn((
@@ -586,12 +1176,7 @@ pub fn update_options_legend_display(
                     width: Val::Percent(100.0),
                     ..default()
                 },
-                BackgroundColor(Color::srgba(
-                    display_color.to_srgba().red * 0.3,
-                    display_color.to_srgba().green * 0.3,
-                    display_color.to_srgba().blue * 0.3,
-                    0.6,
-                )),
+                BackgroundColor(item_background),
                 BorderColor(if is_correct {
                     Color::srgb(1.0, 0.9, 0.3) // Golden border for correct answer
                 } else {
@@ -604,20 +1189,34 @@ pub fn update_options_legend_display(
             ))
             .id();
 
-        // Create color indicator circle
-        let color_indicator = commands
-            .spawn((
-                Name::new("Option Color"),
-                Node {
-                    width: Val::Px(16.0),
-                    height: Val::Px(16.0),
-                    flex_shrink: 0.0,
-                    ..default()
-                },
-                BackgroundColor(display_color),
-                BorderRadius::all(Val::Px(8.0)),
-            ))
-            .id();
+        // Create color indicator, shaped like the option when markers are on
+        let indicator_node = Node {
+            width: Val::Px(16.0),
+            height: Val::Px(16.0),
+            flex_shrink: 0.0,
+            ..default()
+        };
+        let marker_image = marker_atlas
+            .as_ref()
+            .filter(|_| option_styles.shape_markers())
+            .map(|atlas| atlas.image_node(option_styles.marker(option.id)));
+        let color_indicator = match marker_image {
+            Some(image) => commands
+                .spawn((
+                    Name::new("Option Marker"),
+                    indicator_node,
+                    image.with_color(display_color),
+                ))
+                .id(),
+            None => commands
+                .spawn((
+                    Name::new("Option Color"),
+                    indicator_node,
+                    BackgroundColor(display_color),
+                    BorderRadius::all(Val::Px(8.0)),
+                ))
+                .id(),
+        };
 
         // Create option text
         let option_text = commands
@@ -631,7 +1230,7 @@ pub fn update_options_legend_display(
                 TextColor(if is_correct {
                     Color::srgb(1.0, 0.9, 0.3) // Golden text for correct answer
                 } else {
-                    Color::WHITE
+                    contrasting_text_color(item_background)
                 }),
                 Node {
                     flex_grow: 1.0,
@@ -663,3 +1262,257 @@ pub fn update_options_legend_display(
         commands.entity(container_entity).add_child(option_entity);
     }
 }
+
+/// System to pulse the legend border while the question is about to change
+pub fn pulse_options_legend_warning(
+    time: Res<Time>,
+    timer_query: Query<&crate::question::QuestionTimer>,
+    mut legend_query: Query<&mut BorderColor, With<OptionsLegendDisplay>>,
+) {
+    let expiring = timer_query.iter().any(|timer| timer.is_expiring());
+
+    let color = if expiring {
+        let pulse = (time.elapsed_secs() * super::LEGEND_WARNING_PULSE_SPEED).sin() * 0.5 + 0.5;
+        super::LEGEND_BORDER_COLOR.mix(&super::LEGEND_WARNING_COLOR, pulse)
+    } else {
+        super::LEGEND_BORDER_COLOR
+    };
+
+    for mut border in &mut legend_query {
+        if border.0 != color {
+            border.0 = color;
+        }
+    }
+}
+
+/// System to award the bonus word: every letter pays its collector, the last one pays extra
+pub fn handle_bonus_word_events(
+    mut completed_events: EventReader<crate::letters::BonusWordCompletedEvent>,
+    mut gameplay_score: ResMut<GameplayScore>,
+    player_query: Query<&Transform, With<crate::player::Player>>,
+    locale: Res<Locale>,
+    mut popup_events: EventWriter<SpawnTextPopupEvent>,
+    mut feed_events: EventWriter<crate::feed::GameFeedEvent>,
+) {
+    for event in completed_events.read() {
+        let mut points: Vec<(Entity, i32)> = Vec::new();
+        for &player_entity in &event.collectors {
+            match points
+                .iter_mut()
+                .find(|(entity, _)| *entity == player_entity)
+            {
+                Some((_, player_points)) => {
+                    *player_points += crate::letters::BONUS_POINTS_PER_LETTER
+                }
+                None => points.push((player_entity, crate::letters::BONUS_POINTS_PER_LETTER)),
+            }
+        }
+        if let Some(&finisher) = event.collectors.last() {
+            if let Some((_, player_points)) =
+                points.iter_mut().find(|(entity, _)| *entity == finisher)
+            {
+                *player_points += crate::letters::BONUS_WORD_COMPLETION_POINTS;
+            }
+        }
+
+        for (player_entity, player_points) in points {
+            if !gameplay_score.players.contains_key(&player_entity) {
+                gameplay_score.add_player(player_entity, "Player".to_string());
+            }
+            if let Some(player_score) = gameplay_score.get_player_score_mut(player_entity) {
+                player_score.total_score += player_points;
+
+                feed_events.write(
+                    crate::feed::GameFeedEvent::new(locale.format(
+                        "feed.bonus_word",
+                        &[
+                            ("name", &player_score.player_name),
+                            ("word", &event.word),
+                            ("points", &player_points),
+                        ],
+                    ))
+                    .with_color(crate::letters::BONUS_LETTER_COLOR),
+                );
+            }
+
+            if let Ok(player_transform) = player_query.get(player_entity) {
+                popup_events.write(SpawnTextPopupEvent::new(
+                    player_transform.translation.xy() + Vec2::Y * 20.0,
+                    locale.format(
+                        "hud.bonus_word_complete",
+                        &[("word", &event.word), ("points", &player_points)],
+                    ),
+                    crate::letters::BONUS_LETTER_COLOR,
+                    PopupStyle::Milestone,
+                ));
+            }
+        }
+    }
+}
+
+/// System to award the resolution bonus to players who ended the question on a correct answer
+pub fn handle_question_resolved_events(
+    mut resolved_events: EventReader<crate::question::QuestionResolvedEvent>,
+    mut gameplay_score: ResMut<GameplayScore>,
+    config: Res<GameConfig>,
+    player_query: Query<&Transform, With<crate::player::Player>>,
+    locale: Res<Locale>,
+    mut popup_events: EventWriter<SpawnTextPopupEvent>,
+) {
+    let bonus = config.scoring.resolution_bonus_points;
+    for event in resolved_events.read() {
+        for &player_entity in &event.rewarded_players {
+            if !gameplay_score.players.contains_key(&player_entity) {
+                gameplay_score.add_player(player_entity, "Player".to_string());
+            }
+            if let Some(player_score) = gameplay_score.get_player_score_mut(player_entity) {
+                player_score.total_score += bonus;
+            }
+
+            if let Ok(player_transform) = player_query.get(player_entity) {
+                popup_events.write(SpawnTextPopupEvent::new(
+                    player_transform.translation.xy() + Vec2::Y * 20.0,
+                    locale.format("hud.resolution_bonus", &[("points", &bonus)]),
+                    Color::srgb(1.0, 0.9, 0.3),
+                    PopupStyle::Milestone,
+                ));
+            }
+        }
+    }
+}
+
+/// System to flash the correct option's legend entry while the answer is revealed
+pub fn flash_resolved_legend_item(
+    time: Res<Time>,
+    question_system: Option<Res<crate::question::QuestionSystem>>,
+    mut legend_query: Query<(&OptionLegendItem, &mut BackgroundColor)>,
+) {
+    let Some(correct_option_id) = question_system
+        .as_ref()
+        .and_then(|question_system| question_system.get_current_question())
+        .map(|question| question.option)
+    else {
+        return;
+    };
+
+    let flash = (time.elapsed_secs() * crate::options::RESOLUTION_FLASH_SPEED).sin() * 0.5 + 0.5;
+    for (item, mut background) in &mut legend_query {
+        if item.option_id == correct_option_id {
+            background.0 = Color::srgba(1.0, 0.9, 0.3, 0.3 + flash * 0.5);
+        }
+    }
+}
+
+/// System to derive the HUD layout from the screen size and the HUD scale setting
+pub fn update_hud_layout(
+    responsive: Res<ResponsiveInfo>,
+    safe_area: Res<crate::safe_area::SafeAreaInsets>,
+    game_settings: Res<GameSettings>,
+    mut hud_layout: ResMut<HudLayout>,
+    mut ui_scale: ResMut<UiScale>,
+) {
+    let scale_percent = game_settings
+        .display
+        .hud_scale_percent
+        .clamp(super::MIN_HUD_SCALE_PERCENT, super::MAX_HUD_SCALE_PERCENT);
+
+    let ui_scale_percent = game_settings.display.ui_scale_percent.clamp(
+        crate::display::MIN_UI_SCALE_PERCENT,
+        crate::display::MAX_UI_SCALE_PERCENT,
+    );
+    let scale = (scale_percent * ui_scale_percent) as f32 / 10000.0;
+    let multiplayer = &game_settings.multiplayer;
+    let layout = HudLayout {
+        scale,
+        compact: responsive.is_mobile(),
+        portrait: responsive.is_portrait(),
+        safe_area: safe_area.scaled(scale),
+        touch_controls: multiplayer
+            .players
+            .iter()
+            .take(multiplayer.player_count)
+            .any(|player| player.input.uses_joystick()),
+    };
+
+    if *hud_layout != layout {
+        info!("HUD layout changed: {:?}", layout);
+        *hud_layout = layout;
+    }
+    if ui_scale.0 != layout.scale {
+        ui_scale.0 = layout.scale;
+    }
+}
+
+/// System to apply compact mode to the score panel when the layout changes
+#[allow(clippy::type_complexity)]
+pub fn apply_hud_layout(
+    hud_layout: Res<HudLayout>,
+    added_roots: Query<(), Added<HudRoot>>,
+    mut root_query: Query<&mut Node, With<HudRoot>>,
+    mut container_query: Query<&mut Node, (With<PlayerScoresContainer>, Without<HudRoot>)>,
+    mut detail_query: Query<
+        &mut Node,
+        (
+            Or<(
+                With<PlayerStatsDisplay>,
+                With<ChainLengthDisplay>,
+                With<TeamStatsDisplay>,
+            )>,
+            Without<HudRoot>,
+            Without<PlayerScoresContainer>,
+        ),
+    >,
+) {
+    if !hud_layout.is_changed() && added_roots.is_empty() {
+        return;
+    }
+
+    let compact = hud_layout.compact;
+    let safe_area = hud_layout.safe_area;
+
+    for mut node in &mut root_query {
+        let (offset, padding, max_width) = if compact {
+            (8.0, 8.0, super::COMPACT_HUD_MAX_WIDTH)
+        } else {
+            (20.0, 15.0, super::HUD_MAX_WIDTH)
+        };
+        node.top = Val::Px(offset + safe_area.top);
+        node.right = Val::Px(offset + safe_area.right);
+        node.padding = UiRect::all(Val::Px(padding));
+        node.row_gap = Val::Px(if compact { 4.0 } else { 10.0 });
+        node.max_width = Val::Px(max_width);
+    }
+
+    // Compact mode lines the score panels up as small badges
+    for mut node in &mut container_query {
+        if compact {
+            node.flex_direction = FlexDirection::Row;
+            node.flex_wrap = FlexWrap::Wrap;
+            node.column_gap = Val::Px(4.0);
+            node.justify_content = JustifyContent::End;
+        } else {
+            node.flex_direction = FlexDirection::Column;
+            node.flex_wrap = FlexWrap::NoWrap;
+            node.column_gap = Val::Px(0.0);
+            node.justify_content = JustifyContent::Default;
+        }
+    }
+
+    for mut node in &mut detail_query {
+        node.display = if compact {
+            Display::None
+        } else {
+            Display::Flex
+        };
+    }
+}
+
+/// System to restore the default UI scale when leaving gameplay
+pub fn reset_hud_layout(
+    game_settings: Res<GameSettings>,
+    mut hud_layout: ResMut<HudLayout>,
+    mut ui_scale: ResMut<UiScale>,
+) {
+    *hud_layout = HudLayout::default();
+    ui_scale.0 = game_settings.display.ui_scale_percent as f32 / 100.0;
+}
@@ -0,0 +1,127 @@
+//! Touch gesture layer for players who picked the swipe style instead of the
+//! virtual joystick: swipe anywhere to steer snake-style, double-tap to dash.
+
+use bevy::prelude::*;
+use konnektoren_bevy::input::{InputController, PlayerInputMapping};
+
+use crate::{
+    bot::BotController,
+    player::{Player, PlayerDashEvent, PlayerIndex},
+    screens::Screen,
+    settings::GameSettings,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<TouchGestureState>();
+
+    app.add_systems(OnEnter(Screen::Gameplay), reset_touch_gestures);
+    app.add_systems(
+        Update,
+        handle_touch_gestures
+            .in_set(crate::AppSystems::RecordInput)
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(crate::PausableSystems),
+    );
+}
+
+/// Progress of the current gesture and the direction the last swipe picked
+#[derive(Resource, Default, Debug)]
+pub struct TouchGestureState {
+    /// Touch id, start position and start time of the touch being tracked
+    current: Option<(u64, Vec2, f32)>,
+    last_tap: Option<f32>,
+    direction: Vec2,
+}
+
+fn reset_touch_gestures(mut state: ResMut<TouchGestureState>) {
+    *state = TouchGestureState::default();
+}
+
+fn handle_touch_gestures(
+    time: Res<Time>,
+    touches: Res<Touches>,
+    game_settings: Res<GameSettings>,
+    mut state: ResMut<TouchGestureState>,
+    mut player_query: Query<
+        (Entity, &PlayerIndex, &mut InputController),
+        (
+            With<Player>,
+            Without<PlayerInputMapping>,
+            Without<BotController>,
+        ),
+    >,
+    mut dash_events: EventWriter<PlayerDashEvent>,
+) {
+    let now = time.elapsed_secs();
+    let mut dash = false;
+
+    for touch in touches.iter_just_pressed() {
+        if state.current.is_none() {
+            state.current = Some((touch.id(), touch.position(), now));
+        }
+    }
+
+    for touch in touches.iter_just_released() {
+        let Some((id, start, started_at)) = state.current else {
+            continue;
+        };
+        if id != touch.id() {
+            continue;
+        }
+        state.current = None;
+
+        let delta = touch.position() - start;
+        if delta.length() >= SWIPE_MIN_DISTANCE {
+            state.direction = swipe_direction(delta);
+            state.last_tap = None;
+        } else if now - started_at <= TAP_MAX_DURATION {
+            match state.last_tap {
+                Some(last_tap) if now - last_tap <= DOUBLE_TAP_WINDOW => {
+                    dash = true;
+                    state.last_tap = None;
+                }
+                _ => state.last_tap = Some(now),
+            }
+        }
+    }
+
+    for touch in touches.iter_just_canceled() {
+        if state.current.is_some_and(|(id, ..)| id == touch.id()) {
+            state.current = None;
+        }
+    }
+
+    for (entity, player_index, mut input_controller) in &mut player_query {
+        let uses_swipe = game_settings
+            .multiplayer
+            .players
+            .get(player_index.0)
+            .is_some_and(|player| player.input.uses_swipe());
+        if !uses_swipe {
+            continue;
+        }
+
+        if input_controller.movement != state.direction {
+            input_controller.movement = state.direction;
+        }
+        if dash {
+            dash_events.write(PlayerDashEvent {
+                player_entity: entity,
+            });
+        }
+    }
+}
+
+/// Snap a swipe to the closest of the four directions. Screen y points down.
+fn swipe_direction(delta: Vec2) -> Vec2 {
+    if delta.x.abs() > delta.y.abs() {
+        Vec2::new(delta.x.signum(), 0.0)
+    } else {
+        Vec2::new(0.0, -delta.y.signum())
+    }
+}
+
+// Gesture constants
+const SWIPE_MIN_DISTANCE: f32 = 40.0; // Logical pixels a touch has to travel to count as a swipe
+const TAP_MAX_DURATION: f32 = 0.25;
+const DOUBLE_TAP_WINDOW: f32 = 0.3; // Seconds between two taps of a double-tap
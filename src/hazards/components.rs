@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+
+/// Component for the "error blobs" that drift towards the nearest player
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct Hazard {
+    pub speed: f32,
+    pub spawn_time: f32,
+    pub lifetime: f32,
+}
+
+impl Hazard {
+    pub fn new(spawn_time: f32) -> Self {
+        Self {
+            speed: super::HAZARD_SPEED,
+            spawn_time,
+            lifetime: super::HAZARD_LIFETIME,
+        }
+    }
+
+    pub fn is_expired(&self, current_time: f32) -> bool {
+        current_time - self.spawn_time > self.lifetime
+    }
+}
+
+/// Resource deciding when the next hazard spawns
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
+pub struct HazardSpawner {
+    pub timer: Timer,
+    /// Seconds of the round played so far, spawns speed up as it grows
+    pub round_time: f32,
+    pub max_on_map: usize,
+}
+
+impl Default for HazardSpawner {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(super::HAZARD_SPAWN_INTERVAL, TimerMode::Repeating),
+            round_time: 0.0,
+            max_on_map: super::MAX_HAZARDS_ON_MAP,
+        }
+    }
+}
+
+impl HazardSpawner {
+    /// Spawn interval for the difficulty's `base_interval` at the current round time
+    pub fn interval(&self, base_interval: f32) -> f32 {
+        (base_interval / (1.0 + self.round_time / super::HAZARD_RAMP_TIME))
+            .max(super::MIN_HAZARD_SPAWN_INTERVAL)
+    }
+}
+
+/// Component for a player who ran into a hazard and can't move for a moment
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Stunned {
+    pub timer: Timer,
+}
+
+impl Default for Stunned {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(super::HAZARD_STUN_DURATION, TimerMode::Once),
+        }
+    }
+}
+
+/// Event fired when a hazard touches a player
+#[derive(Event)]
+pub struct HazardHitEvent {
+    pub player_entity: Entity,
+    pub position: Vec2,
+}
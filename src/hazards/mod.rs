@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+
+mod components;
+mod systems;
+
+pub use components::*;
+use systems::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Hazard>();
+    app.register_type::<HazardSpawner>();
+    app.register_type::<Stunned>();
+
+    app.add_event::<HazardHitEvent>();
+
+    app.init_resource::<HazardSpawner>();
+
+    app.add_systems(
+        OnEnter(crate::screens::Screen::Gameplay),
+        reset_hazard_spawner,
+    );
+
+    app.add_systems(
+        Update,
+        (
+            update_stunned_players.in_set(crate::AppSystems::TickTimers),
+            (
+                spawn_hazards,
+                move_hazards,
+                detect_hazard_hits,
+                apply_hazard_hits,
+            )
+                .chain()
+                .in_set(crate::AppSystems::Update),
+        )
+            .run_if(in_state(crate::screens::Screen::Gameplay))
+            .in_set(crate::PausableSystems),
+    );
+}
+
+// Configuration constants for hazards
+pub const HAZARD_SPAWN_INTERVAL: f32 = 20.0; // Seconds between spawns at the start of a round on Normal
+pub const MIN_HAZARD_SPAWN_INTERVAL: f32 = 5.0;
+pub const HAZARD_RAMP_TIME: f32 = 60.0; // Every minute of play adds another spawn per interval
+pub const MAX_HAZARDS_ON_MAP: usize = 4;
+pub const HAZARD_MIN_SPAWN_DISTANCE: f32 = 250.0; // Keep new hazards away from every player
+pub const HAZARD_SPEED: f32 = 55.0; // pixels per second, well below the player's speed
+pub const HAZARD_LIFETIME: f32 = 25.0;
+pub const HAZARD_SIZE: f32 = 14.0;
+pub const HAZARD_COLOR: Color = Color::srgb(0.55, 0.85, 0.25);
+pub const HAZARD_STUN_DURATION: f32 = 1.2;
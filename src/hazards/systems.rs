@@ -0,0 +1,300 @@
+use super::components::*;
+use crate::{
+    asset_cache::SharedAssetCache,
+    chain::{ChainMode, ChainReaction, PlayerChain},
+    config::GameConfig,
+    effects::SpawnExplosionEvent,
+    map::GridMap,
+    player::{PLAYER_SIZE, Player, PlayerController, SpawnProtection},
+    popups::{PopupStyle, SpawnTextPopupEvent},
+    powerups::{ActivePowerUps, PowerUpKind},
+    rng::GameRng,
+    screens::Screen,
+    settings::GameSettings,
+};
+use bevy::prelude::*;
+use konnektoren_bevy::input::InputController;
+use rand::Rng;
+use std::collections::HashSet;
+
+/// System to reset hazard spawning when entering gameplay
+pub fn reset_hazard_spawner(mut spawner: ResMut<HazardSpawner>, game_settings: Res<GameSettings>) {
+    *spawner = HazardSpawner::default();
+    let interval = game_settings.gameplay.tuning().hazard_spawn_interval;
+    spawner
+        .timer
+        .set_duration(std::time::Duration::from_secs_f32(interval));
+}
+
+/// System to spawn hazards, more often the longer the round runs
+pub fn spawn_hazards(
+    mut commands: Commands,
+    time: Res<Time>,
+    game_settings: Res<GameSettings>,
+    mut spawner: ResMut<HazardSpawner>,
+    grid_map: Option<Res<GridMap>>,
+    existing_hazards: Query<(), With<Hazard>>,
+    player_query: Query<&Transform, With<Player>>,
+    mut game_rng: ResMut<GameRng>,
+    mut asset_cache: ResMut<SharedAssetCache>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    if !game_settings.gameplay.hazards {
+        return;
+    }
+
+    spawner.round_time += time.delta_secs();
+    spawner.timer.tick(time.delta());
+
+    if !spawner.timer.just_finished() {
+        return;
+    }
+
+    // The next spawn comes sooner as the round goes on
+    let interval = spawner.interval(game_settings.gameplay.tuning().hazard_spawn_interval);
+    spawner
+        .timer
+        .set_duration(std::time::Duration::from_secs_f32(interval));
+
+    let Some(grid_map) = grid_map else {
+        return;
+    };
+
+    if existing_hazards.iter().count() >= spawner.max_on_map {
+        return;
+    }
+
+    let player_positions: Vec<Vec2> = player_query
+        .iter()
+        .map(|transform| transform.translation.xy())
+        .collect();
+
+    let Some(world_pos) = find_spawn_position(&grid_map, &player_positions, game_rng.gameplay())
+    else {
+        return;
+    };
+
+    commands.spawn((
+        Name::new("Hazard"),
+        Mesh2d(asset_cache.circle_mesh(super::HAZARD_SIZE, &mut meshes)),
+        MeshMaterial2d(asset_cache.material(super::HAZARD_COLOR, &mut materials)),
+        Transform::from_translation(world_pos.extend(1.2)),
+        Hazard::new(time.elapsed_secs()),
+        StateScoped(Screen::Gameplay),
+        children![(
+            Name::new("Hazard Label"),
+            Text2d::new("!"),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(Color::BLACK),
+            Transform::from_translation(Vec3::new(0.0, 0.0, 0.1)),
+        )],
+    ));
+
+    info!("Spawned hazard at {:?}", world_pos);
+}
+
+/// Find a free grid cell far enough from every player
+fn find_spawn_position(
+    grid_map: &GridMap,
+    player_positions: &[Vec2],
+    rng: &mut impl Rng,
+) -> Option<Vec2> {
+    let mut tried = HashSet::new();
+
+    for _ in 0..50 {
+        let x = rng.gen_range(1..grid_map.width.saturating_sub(1).max(2));
+        let y = rng.gen_range(1..grid_map.height.saturating_sub(1).max(2));
+        if !tried.insert((x, y)) || grid_map.is_blocked(x, y) {
+            continue;
+        }
+
+        let world_pos = grid_map.grid_to_world(x, y);
+        let far_enough = player_positions.iter().all(|player_pos| {
+            grid_map.wrapped_offset(world_pos, *player_pos).length()
+                >= super::HAZARD_MIN_SPAWN_DISTANCE
+        });
+        if far_enough {
+            return Some(world_pos);
+        }
+    }
+    None
+}
+
+/// System to move hazards towards the nearest player, around walls, and
+/// remove them once they expire
+pub fn move_hazards(
+    mut commands: Commands,
+    time: Res<Time>,
+    grid_map: Option<Res<GridMap>>,
+    mut hazard_query: Query<(Entity, &Hazard, &mut Transform), Without<Player>>,
+    player_query: Query<&Transform, With<Player>>,
+) {
+    let Some(grid_map) = grid_map else {
+        return;
+    };
+    let current_time = time.elapsed_secs();
+
+    for (entity, hazard, mut transform) in &mut hazard_query {
+        if hazard.is_expired(current_time) {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let position = transform.translation.xy();
+
+        // Blobs stay on their side of the map edge, so no wraparound here
+        let Some(target) = player_query
+            .iter()
+            .map(|player| player.translation.xy())
+            .min_by(|a, b| a.distance(position).total_cmp(&b.distance(position)))
+        else {
+            continue;
+        };
+
+        let direction = grid_map.steer_around_obstacles(
+            position,
+            (target - position).normalize_or_zero(),
+            super::HAZARD_SIZE * 2.0,
+            super::HAZARD_SIZE,
+        );
+        let next_position = position + direction * hazard.speed * time.delta_secs();
+        if !grid_map.is_area_blocked(next_position, super::HAZARD_SIZE) {
+            transform.translation.x = next_position.x;
+            transform.translation.y = next_position.y;
+        }
+
+        // Wobble so the blobs read as alive
+        let age = current_time - hazard.spawn_time;
+        transform.scale = Vec3::new(
+            1.0 + (age * 5.0).sin() * 0.1,
+            1.0 - (age * 5.0).sin() * 0.1,
+            1.0,
+        );
+    }
+}
+
+/// System to detect hazards touching players
+pub fn detect_hazard_hits(
+    mut commands: Commands,
+    mut hit_events: EventWriter<HazardHitEvent>,
+    mut explosion_events: EventWriter<SpawnExplosionEvent>,
+    player_query: Query<
+        (Entity, &Transform),
+        (With<Player>, Without<SpawnProtection>, Without<Stunned>),
+    >,
+    hazard_query: Query<(Entity, &Transform), (With<Hazard>, Without<Player>)>,
+) {
+    let mut popped = HashSet::new();
+
+    for (player_entity, player_transform) in &player_query {
+        let player_pos = player_transform.translation.xy();
+
+        let Some((hazard_entity, hazard_transform)) =
+            hazard_query
+                .iter()
+                .find(|(hazard_entity, hazard_transform)| {
+                    !popped.contains(hazard_entity)
+                        && hazard_transform.translation.xy().distance(player_pos)
+                            <= PLAYER_SIZE + super::HAZARD_SIZE
+                })
+        else {
+            continue;
+        };
+
+        popped.insert(hazard_entity);
+
+        explosion_events.write(SpawnExplosionEvent {
+            position: hazard_transform.translation,
+            color: super::HAZARD_COLOR,
+            intensity: 0.8,
+        });
+
+        hit_events.write(HazardHitEvent {
+            player_entity,
+            position: player_pos,
+        });
+
+        commands.entity(hazard_entity).despawn();
+    }
+}
+
+/// System to blow up the tail of the hit player's chain and stun them
+pub fn apply_hazard_hits(
+    mut commands: Commands,
+    mut hit_events: EventReader<HazardHitEvent>,
+    mut popup_events: EventWriter<SpawnTextPopupEvent>,
+    chain_mode: Res<ChainMode>,
+    config: Res<GameConfig>,
+    mut player_query: Query<(&mut PlayerController, Option<&ActivePowerUps>), With<Player>>,
+    chain_query: Query<&PlayerChain>,
+    reacting_query: Query<(), With<ChainReaction>>,
+) {
+    for event in hit_events.read() {
+        let Ok((mut controller, power_ups)) = player_query.get_mut(event.player_entity) else {
+            continue;
+        };
+
+        // A chain shield keeps the chain intact, the stun still lands
+        let shielded = power_ups.is_some_and(|p| p.has(PowerUpKind::ChainShield));
+        if !shielded {
+            // The tail goes through the usual reaction animation, which scores the loss
+            let tail = chain_query
+                .get(chain_mode.chain_owner(event.player_entity))
+                .ok()
+                .and_then(|chain| {
+                    chain
+                        .segments
+                        .iter()
+                        .rev()
+                        .find(|&&segment| !reacting_query.contains(segment))
+                        .copied()
+                });
+            if let Some(tail) = tail {
+                commands
+                    .entity(tail)
+                    .insert(ChainReaction::new(config.chain.reaction_ball_duration));
+            }
+        }
+
+        controller.can_move = false;
+        controller.movement_input = Vec2::ZERO;
+        commands
+            .entity(event.player_entity)
+            .insert(Stunned::default());
+
+        popup_events.write(SpawnTextPopupEvent::new(
+            event.position,
+            "Stunned!",
+            super::HAZARD_COLOR,
+            PopupStyle::Penalty,
+        ));
+    }
+}
+
+/// System to let stunned players move again once the stun wears off
+pub fn update_stunned_players(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut player_query: Query<(
+        Entity,
+        &mut Stunned,
+        &mut PlayerController,
+        &InputController,
+    )>,
+) {
+    for (entity, mut stunned, mut controller, input_controller) in &mut player_query {
+        stunned.timer.tick(time.delta());
+        if !stunned.timer.finished() {
+            continue;
+        }
+
+        // Input is only read when it changes, so pick up whatever is held right now
+        controller.can_move = true;
+        controller.movement_input = input_controller.movement;
+        commands.entity(entity).remove::<Stunned>();
+    }
+}
@@ -0,0 +1,193 @@
+//! Idle players in multiplayer: someone who hasn't touched their device for a
+//! while is flagged in the HUD, left out of the camera framing and, if the
+//! settings allow it, played by a bot until they come back.
+
+use bevy::prelude::*;
+use konnektoren_bevy::input::{
+    InputController, PlayerInputMapping,
+    device::{AvailableInputDevices, InputDevice},
+};
+
+use crate::{
+    bot::BotController,
+    feed::GameFeedEvent,
+    locale::Locale,
+    player::{Player, PlayerIndex},
+    screens::{GameplayMode, Screen},
+    settings::{CustomKeyBindings, GameSettings, KeyAction, KeyBindings},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<PlayerActivity>();
+
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        track_player_activity.after(crate::player::spawn_player),
+    );
+    app.add_systems(
+        Update,
+        detect_idle_players
+            .in_set(crate::AppSystems::Update)
+            .run_if(in_state(GameplayMode::Interactive))
+            .in_set(crate::PausableSystems),
+    );
+}
+
+/// How long a human player has gone without input
+#[derive(Component, Reflect, Debug, Default)]
+#[reflect(Component)]
+pub struct PlayerActivity {
+    pub idle_seconds: f32,
+    /// A bot was put in charge because the player went idle
+    pub bot_took_over: bool,
+}
+
+/// Marker for players flagged as idle
+#[derive(Component, Debug)]
+pub struct IdlePlayer;
+
+fn track_player_activity(mut commands: Commands, player_query: Query<Entity, With<Player>>) {
+    for entity in &player_query {
+        commands.entity(entity).insert(PlayerActivity::default());
+    }
+}
+
+/// Any input at all from one of the player's devices
+fn device_active(
+    device: &InputDevice,
+    bindings: Option<&KeyBindings>,
+    keyboard: &ButtonInput<KeyCode>,
+    mouse: &ButtonInput<MouseButton>,
+    touches: &Touches,
+    available_devices: &AvailableInputDevices,
+    gamepads: &Query<&Gamepad>,
+) -> bool {
+    match device {
+        InputDevice::Keyboard(scheme) => {
+            let bindings = bindings
+                .cloned()
+                .unwrap_or_else(|| KeyBindings::for_scheme(scheme));
+            KeyAction::ALL
+                .iter()
+                .any(|&action| keyboard.pressed(bindings.get(action)))
+        }
+        InputDevice::Mouse => mouse.get_pressed().next().is_some(),
+        InputDevice::Touch => touches.iter().next().is_some(),
+        InputDevice::Gamepad(index) => available_devices
+            .gamepads
+            .get(*index as usize)
+            .and_then(|&entity| gamepads.get(entity).ok())
+            .is_some_and(|gamepad| {
+                gamepad.get_pressed().next().is_some()
+                    || gamepad.left_stick().length() > IDLE_STICK_THRESHOLD
+            }),
+        // Devices we can't read are never treated as idle
+        _ => true,
+    }
+}
+
+fn detect_idle_players(
+    mut commands: Commands,
+    time: Res<Time>,
+    game_settings: Res<GameSettings>,
+    key_bindings: Res<CustomKeyBindings>,
+    locale: Res<Locale>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    available_devices: Res<AvailableInputDevices>,
+    gamepads: Query<&Gamepad>,
+    mut player_query: Query<(
+        Entity,
+        &PlayerIndex,
+        &InputController,
+        &mut PlayerActivity,
+        Has<IdlePlayer>,
+        Has<BotController>,
+    )>,
+    mut feed_events: EventWriter<GameFeedEvent>,
+) {
+    let multiplayer = &game_settings.multiplayer;
+    let timeout = multiplayer.idle_timeout_seconds;
+    if multiplayer.player_count < 2 || timeout == 0 {
+        return;
+    }
+
+    for (entity, player_index, input_controller, mut activity, idle, bot) in &mut player_query {
+        // Bot slots never have anyone to go idle
+        if bot && !activity.bot_took_over {
+            continue;
+        }
+        let Some(player_settings) = multiplayer.players.get(player_index.0) else {
+            continue;
+        };
+        let input = &player_settings.input;
+        let bindings = key_bindings.bindings_for(player_index.0, input);
+        let active = std::iter::once(&input.primary_input)
+            .chain(input.secondary_input.as_ref())
+            .any(|device| {
+                device_active(
+                    device,
+                    bindings.as_ref(),
+                    &keyboard,
+                    &mouse,
+                    &touches,
+                    &available_devices,
+                    &gamepads,
+                )
+            })
+            // The game's own touch controls only show up as movement
+            || (!activity.bot_took_over && input_controller.movement != Vec2::ZERO);
+
+        let name = multiplayer.player_name(player_index.0);
+        if active {
+            activity.idle_seconds = 0.0;
+            if !idle {
+                continue;
+            }
+
+            let mut entity_commands = commands.entity(entity);
+            entity_commands.remove::<IdlePlayer>();
+            if activity.bot_took_over {
+                activity.bot_took_over = false;
+                entity_commands.remove::<BotController>();
+                let custom_keys = key_bindings.is_custom(player_index.0) && bindings.is_some();
+                if !custom_keys && !input.uses_game_touch_controls() {
+                    entity_commands.insert(PlayerInputMapping {
+                        player_id: player_index.0 as u32,
+                        ..Default::default()
+                    });
+                }
+            }
+            info!("{} is back", name);
+            feed_events.write(GameFeedEvent::new(
+                locale.format("feed.idle_back", &[("name", &name)]),
+            ));
+            continue;
+        }
+
+        activity.idle_seconds += time.delta_secs();
+        if idle || activity.idle_seconds < timeout as f32 {
+            continue;
+        }
+
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.insert(IdlePlayer);
+        if multiplayer.idle_bot_takeover {
+            activity.bot_took_over = true;
+            entity_commands
+                .remove::<PlayerInputMapping>()
+                .insert(BotController::new(multiplayer.bot_difficulty));
+        }
+        info!("{} went idle after {} seconds", name, timeout);
+        feed_events.write(
+            GameFeedEvent::new(locale.format("feed.idle", &[("name", &name)]))
+                .with_color(Color::srgb(0.7, 0.7, 0.7)),
+        );
+    }
+}
+
+// Idle detection constants
+pub const DEFAULT_IDLE_TIMEOUT_SECONDS: u32 = 30;
+pub const MAX_IDLE_TIMEOUT_SECONDS: u32 = 120;
+pub const IDLE_STICK_THRESHOLD: f32 = 0.2;
@@ -0,0 +1,250 @@
+//! Options passed in at launch, via command-line arguments on native builds
+//! or URL query parameters on the web. Besides quick play they can preset a
+//! round, e.g. `--players 2 --mode coop --map maze --challenge articles --seed 42`
+//! or `?players=2&mode=coop&map=maze&challenge=articles&seed=42`, so an embedded
+//! build can open straight into a prepared lesson.
+
+use bevy::prelude::*;
+
+use crate::{game_state::GameState, map::MapPreset, settings::GameSettings};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<LaunchOptions>();
+    app.insert_resource(LaunchOptions::from_environment());
+
+    app.add_systems(Startup, apply_launch_options);
+}
+
+/// Resource describing how the game was launched
+#[derive(Resource, Reflect, Clone, Default, PartialEq)]
+#[reflect(Resource)]
+pub struct LaunchOptions {
+    /// Skip the menus and start a single-player round right away (`--quick` / `?quick`)
+    pub quick_play: bool,
+    /// Konnektoren profile token handed back by the web login (`?token=...`).
+    /// Hidden from the inspector and from `Debug` so it never ends up in a log.
+    #[reflect(ignore)]
+    pub profile_token: Option<String>,
+    /// Number of players (`--players 2`)
+    pub player_count: Option<usize>,
+    /// Solo, versus or co-op (`--mode coop`)
+    pub mode: Option<LaunchMode>,
+    /// Map preset by name (`--map maze`)
+    pub map_preset: Option<MapPreset>,
+    /// Challenge to play instead of the level's first one (`--challenge articles`)
+    pub challenge_id: Option<String>,
+    /// Seed for the first round, for the same map and question order every time (`--seed 42`)
+    pub seed: Option<u64>,
+}
+
+/// How the players of a preset round play together
+#[derive(Reflect, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LaunchMode {
+    Solo,
+    /// Everyone builds their own chain
+    Versus,
+    /// Everyone feeds one shared chain
+    Coop,
+}
+
+impl LaunchMode {
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "solo" | "single" => Some(LaunchMode::Solo),
+            "versus" | "vs" => Some(LaunchMode::Versus),
+            "coop" | "co-op" | "team" => Some(LaunchMode::Coop),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Debug for LaunchOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LaunchOptions")
+            .field("quick_play", &self.quick_play)
+            .field(
+                "profile_token",
+                &self.profile_token.as_ref().map(|_| "<redacted>"),
+            )
+            .field("player_count", &self.player_count)
+            .field("mode", &self.mode)
+            .field("map_preset", &self.map_preset)
+            .field("challenge_id", &self.challenge_id)
+            .field("seed", &self.seed)
+            .finish()
+    }
+}
+
+impl LaunchOptions {
+    pub fn from_environment() -> Self {
+        let options = Self::from_args(launch_args());
+        if options != Self::default() {
+            info!("Launch options: {:?}", options);
+        }
+        options
+    }
+
+    /// Parse options from `--flag`, `--key=value` and `--key value` style arguments
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Self {
+        let mut options = Self::default();
+        let mut args = args.into_iter().peekable();
+
+        while let Some(arg) = args.next() {
+            let arg = arg.trim_start_matches('-');
+            let (key, value) = match arg.split_once('=') {
+                Some((key, value)) => (key.to_string(), value.to_string()),
+                // A following word that isn't a flag itself is the value
+                None => match args.next_if(|next| !next.starts_with('-')) {
+                    Some(value) => (arg.to_string(), value),
+                    None => (arg.to_string(), "true".to_string()),
+                },
+            };
+            let value = value.as_str();
+
+            match key.as_str() {
+                "quick" => options.quick_play = parse_flag(value),
+                "token" if !value.is_empty() => options.profile_token = Some(value.to_string()),
+                "players" => options.player_count = parse_value(&key, value),
+                "mode" => {
+                    options.mode = LaunchMode::from_name(value);
+                    if options.mode.is_none() {
+                        warn!("Unknown launch mode '{}', use solo, versus or coop", value);
+                    }
+                }
+                "map" => {
+                    options.map_preset = MapPreset::ALL
+                        .into_iter()
+                        .find(|preset| preset.name().eq_ignore_ascii_case(value));
+                    if options.map_preset.is_none() {
+                        warn!("Unknown launch map '{}'", value);
+                    }
+                }
+                "challenge" if !value.is_empty() => {
+                    options.challenge_id = Some(value.to_string());
+                }
+                "seed" => options.seed = parse_value(&key, value),
+                _ => {}
+            }
+        }
+
+        options
+    }
+}
+
+/// Put the preset round into the settings before the first screen shows them
+fn apply_launch_options(
+    launch_options: Res<LaunchOptions>,
+    mut game_settings: ResMut<GameSettings>,
+    mut game_state: ResMut<GameState>,
+) {
+    let multiplayer = &mut game_settings.multiplayer;
+    if let Some(count) = launch_options.player_count {
+        multiplayer.auto_detect_players = false;
+        if count <= 1 {
+            multiplayer.set_single_player();
+        } else {
+            multiplayer.enabled = true;
+            multiplayer.set_player_count(count);
+        }
+    }
+
+    match launch_options.mode {
+        Some(LaunchMode::Solo) => multiplayer.set_single_player(),
+        Some(LaunchMode::Versus) => {
+            multiplayer.coop_shared_chain = false;
+            if multiplayer.player_count == 1 {
+                multiplayer.enable_multiplayer(true);
+            }
+        }
+        Some(LaunchMode::Coop) => {
+            multiplayer.coop_shared_chain = true;
+            if multiplayer.player_count == 1 {
+                multiplayer.enable_multiplayer(true);
+            }
+        }
+        None => {}
+    }
+
+    if let Some(preset) = launch_options.map_preset {
+        game_settings.gameplay.map_preset = preset;
+    }
+
+    if let Some(challenge_id) = &launch_options.challenge_id {
+        game_state.random_challenge = false;
+        game_state.select_challenge(challenge_id.clone());
+    }
+    if let Some(seed) = launch_options.seed {
+        game_state.round_seed = Some(seed);
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(key: &str, value: &str) -> Option<T> {
+    let parsed = value.parse().ok();
+    if parsed.is_none() {
+        warn!("Ignoring launch option {}={}", key, value);
+    }
+    parsed
+}
+
+fn parse_flag(value: &str) -> bool {
+    !matches!(value, "0" | "false" | "no" | "off")
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn launch_args() -> Vec<String> {
+    std::env::args().skip(1).collect()
+}
+
+/// Turn the page's `?a=1&b` query string into `a=1`, `b` style arguments
+#[cfg(target_family = "wasm")]
+fn launch_args() -> Vec<String> {
+    let search = web_sys::window()
+        .and_then(|window| window.location().search().ok())
+        .unwrap_or_default();
+
+    let args: Vec<String> = search
+        .trim_start_matches('?')
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| pair.to_string())
+        .collect();
+
+    if args.iter().any(|pair| is_token_arg(pair)) {
+        remove_token_from_url(&args);
+    }
+    args
+}
+
+#[cfg(target_family = "wasm")]
+fn is_token_arg(pair: &str) -> bool {
+    pair == "token" || pair.starts_with("token=")
+}
+
+/// Drop `token=` from the address bar so it stays out of the browser history
+/// and out of the `redirect=` of a later login
+#[cfg(target_family = "wasm")]
+fn remove_token_from_url(args: &[String]) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let location = window.location();
+
+    let query: Vec<&str> = args
+        .iter()
+        .filter(|pair| !is_token_arg(pair))
+        .map(|pair| pair.as_str())
+        .collect();
+    let mut url = location.pathname().unwrap_or_default();
+    if !query.is_empty() {
+        url.push('?');
+        url.push_str(&query.join("&"));
+    }
+    url.push_str(&location.hash().unwrap_or_default());
+
+    let replaced = window.history().and_then(|history| {
+        history.replace_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&url))
+    });
+    if replaced.is_err() {
+        warn!("Could not remove the profile token from the page URL");
+    }
+}
@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A single finished game result for one player
+#[derive(Reflect, Clone, Debug, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    pub player_name: String,
+    pub score: i32,
+    pub best_streak: u32,
+    pub accuracy: f32,
+    pub correct_answers: u32,
+    pub wrong_answers: u32,
+    /// Share code of the round, to replay it under the same conditions
+    #[serde(default)]
+    pub share_code: Option<String>,
+    /// Day of the daily challenge the result was played in
+    #[serde(default)]
+    pub daily_date: Option<String>,
+}
+
+/// Resource holding the best results across sessions
+#[derive(Resource, Reflect, Clone, Debug, Default, Serialize, Deserialize)]
+#[reflect(Resource)]
+pub struct Leaderboard {
+    pub entries: Vec<LeaderboardEntry>,
+    /// Best daily challenge result per day, newest day first
+    #[serde(default)]
+    pub daily_entries: Vec<LeaderboardEntry>,
+}
+
+impl Leaderboard {
+    /// Insert a result, keeping entries sorted by score and capped in size
+    pub fn add_entry(&mut self, entry: LeaderboardEntry) {
+        self.entries.push(entry);
+        self.entries.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then(b.best_streak.cmp(&a.best_streak))
+        });
+        self.entries.truncate(super::MAX_LEADERBOARD_ENTRIES);
+    }
+
+    /// Insert a daily challenge result, keeping only the best one of each day
+    pub fn add_daily_entry(&mut self, entry: LeaderboardEntry) {
+        let existing = self
+            .daily_entries
+            .iter_mut()
+            .find(|existing| existing.daily_date == entry.daily_date);
+        match existing {
+            Some(existing) if existing.score >= entry.score => {}
+            Some(existing) => *existing = entry,
+            None => self.daily_entries.push(entry),
+        }
+
+        // Dates are `YYYY-MM-DD`, so they sort chronologically as text
+        self.daily_entries
+            .sort_by(|a, b| b.daily_date.cmp(&a.daily_date));
+        self.daily_entries.truncate(super::MAX_DAILY_ENTRIES);
+    }
+
+    /// Best result of the daily challenge on `date`
+    pub fn daily_best(&self, date: &str) -> Option<&LeaderboardEntry> {
+        self.daily_entries
+            .iter()
+            .find(|entry| entry.daily_date.as_deref() == Some(date))
+    }
+
+    pub fn load() -> Self {
+        crate::storage::load(super::LEADERBOARD_STORAGE_KEY).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        crate::storage::save(super::LEADERBOARD_STORAGE_KEY, self);
+    }
+}
@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+mod components;
+mod systems;
+
+pub use components::*;
+use systems::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Leaderboard>();
+
+    app.init_resource::<Leaderboard>();
+
+    app.add_systems(Startup, load_leaderboard);
+
+    app.add_systems(
+        Update,
+        record_game_results
+            .in_set(crate::AppSystems::Update)
+            .run_if(in_state(crate::screens::Screen::Gameplay)),
+    );
+}
+
+pub const MAX_LEADERBOARD_ENTRIES: usize = 10;
+pub const MAX_DAILY_ENTRIES: usize = 30; // Days of daily challenge results kept
+pub const LEADERBOARD_STORAGE_KEY: &str = "leaderboard";
@@ -0,0 +1,75 @@
+use super::components::*;
+use crate::{
+    daily::DailyRound,
+    gameplay::{GameTimerEvent, GameplayScore},
+    round::RoundInfo,
+};
+use bevy::prelude::*;
+
+/// System to load the stored leaderboard at startup
+pub fn load_leaderboard(mut commands: Commands) {
+    let leaderboard = Leaderboard::load();
+    info!(
+        "Loaded leaderboard with {} entries",
+        leaderboard.entries.len()
+    );
+    commands.insert_resource(leaderboard);
+}
+
+/// System to record every player's result when the game timer runs out
+pub fn record_game_results(
+    mut timer_events: EventReader<GameTimerEvent>,
+    gameplay_score: Res<GameplayScore>,
+    mut leaderboard: ResMut<Leaderboard>,
+    round: Option<Res<RoundInfo>>,
+    daily: Option<Res<DailyRound>>,
+) {
+    // Daily results are compared separately from regular rounds
+    let daily_date = daily
+        .filter(|daily| round.as_ref().is_some_and(|round| round.seed == daily.seed))
+        .map(|daily| daily.date.clone());
+    let share_code = round.map(|round| round.share_code());
+
+    for event in timer_events.read() {
+        match event {
+            GameTimerEvent::GameEnded => {
+                if gameplay_score.players.is_empty() {
+                    continue;
+                }
+
+                for player_score in gameplay_score.players.values() {
+                    let accuracy = if player_score.collection_count > 0 {
+                        (player_score.correct_answers as f32 / player_score.collection_count as f32)
+                            * 100.0
+                    } else {
+                        0.0
+                    };
+
+                    let entry = LeaderboardEntry {
+                        player_name: player_score.player_name.clone(),
+                        score: player_score.total_score,
+                        best_streak: player_score.best_streak,
+                        accuracy,
+                        correct_answers: player_score.correct_answers,
+                        wrong_answers: player_score.wrong_answers,
+                        share_code: share_code.clone(),
+                        daily_date: daily_date.clone(),
+                    };
+
+                    if daily_date.is_some() {
+                        leaderboard.add_daily_entry(entry);
+                    } else {
+                        leaderboard.add_entry(entry);
+                    }
+                }
+
+                leaderboard.save();
+                info!(
+                    "Recorded {} results to the leaderboard",
+                    gameplay_score.players.len()
+                );
+            }
+            GameTimerEvent::SuddenDeath => {}
+        }
+    }
+}
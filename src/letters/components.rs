@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+
+/// Component for a letter of the bonus word lying on the map
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct BonusLetter {
+    /// Position of the letter within the word
+    pub index: usize,
+    pub letter: char,
+}
+
+/// A bonus word currently being spelled
+#[derive(Reflect, Clone, Debug)]
+pub struct ActiveBonusWord {
+    pub letters: Vec<char>,
+    /// Index of the next letter to collect
+    pub next_index: usize,
+    /// Player that collected each letter so far, in order
+    pub collectors: Vec<Entity>,
+    pub timer: Timer,
+}
+
+impl ActiveBonusWord {
+    pub fn new(word: &str) -> Self {
+        Self {
+            letters: word.chars().flat_map(char::to_uppercase).collect(),
+            next_index: 0,
+            collectors: Vec::new(),
+            timer: Timer::from_seconds(super::BONUS_WORD_TIME_LIMIT, TimerMode::Once),
+        }
+    }
+
+    pub fn word(&self) -> String {
+        self.letters.iter().collect()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.next_index >= self.letters.len()
+    }
+
+    /// Letters collected so far, with blanks for the missing ones
+    pub fn progress_label(&self) -> String {
+        self.letters
+            .iter()
+            .enumerate()
+            .map(|(index, letter)| {
+                if index < self.next_index {
+                    letter.to_string()
+                } else {
+                    "_".to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Resource tracking the bonus word and when the next one may appear
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
+pub struct BonusWord {
+    pub active: Option<ActiveBonusWord>,
+    pub spawn_timer: Timer,
+}
+
+impl Default for BonusWord {
+    fn default() -> Self {
+        Self {
+            active: None,
+            spawn_timer: Timer::from_seconds(super::BONUS_WORD_INTERVAL, TimerMode::Repeating),
+        }
+    }
+}
+
+/// Event fired when the last letter of a bonus word was collected
+#[derive(Event)]
+pub struct BonusWordCompletedEvent {
+    pub word: String,
+    /// Player that collected each letter, the last one completed the word
+    pub collectors: Vec<Entity>,
+}
+
+/// Component for the HUD line spelling out the bonus word
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct BonusWordHud;
@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+
+mod components;
+mod systems;
+
+pub use components::*;
+use systems::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<BonusLetter>();
+    app.register_type::<BonusWord>();
+    app.register_type::<BonusWordHud>();
+
+    app.add_event::<BonusWordCompletedEvent>();
+
+    app.init_resource::<BonusWord>();
+
+    app.add_systems(
+        OnEnter(crate::screens::Screen::Gameplay),
+        (reset_bonus_word, setup_bonus_word_hud),
+    );
+
+    app.add_systems(
+        Update,
+        (
+            update_bonus_word_timer.in_set(crate::AppSystems::TickTimers),
+            (
+                spawn_bonus_word.run_if(in_state(crate::question::QuestionPhase::Asking)),
+                collect_bonus_letters,
+                animate_bonus_letters,
+                update_bonus_word_hud,
+            )
+                .chain()
+                .in_set(crate::AppSystems::Update),
+        )
+            .run_if(in_state(crate::screens::Screen::Gameplay))
+            .in_set(crate::PausableSystems),
+    );
+}
+
+// Configuration constants for bonus letters
+pub const BONUS_WORD_INTERVAL: f32 = 40.0; // Seconds between bonus word attempts
+pub const BONUS_WORD_CHANCE: f32 = 0.7; // Chance an attempt spawns a word
+pub const BONUS_WORD_TIME_LIMIT: f32 = 20.0; // Seconds to spell the word once it appears
+pub const BONUS_WORD_MIN_LENGTH: usize = 4;
+pub const BONUS_WORD_MAX_LENGTH: usize = 8;
+pub const BONUS_POINTS_PER_LETTER: i32 = 15; // Credited to whoever collected the letter
+pub const BONUS_WORD_COMPLETION_POINTS: i32 = 50; // Extra for collecting the last letter
+pub const BONUS_LETTER_SIZE: f32 = 11.0;
+pub const BONUS_LETTER_COLOR: Color = Color::srgb(1.0, 0.82, 0.25);
+pub const FALLBACK_BONUS_WORDS: [&str; 8] = [
+    "aber", "denn", "oder", "weil", "dass", "deshalb", "trotzdem", "obwohl",
+];
@@ -0,0 +1,288 @@
+use super::components::*;
+use crate::{
+    asset_cache::SharedAssetCache,
+    config::GameConfig,
+    effects::SpawnCollectionEvent,
+    locale::Locale,
+    map::{GridMap, GridPosition},
+    options::{OptionCollectible, find_empty_spawn_position},
+    player::{PLAYER_SIZE, Player},
+    question::QuestionSystem,
+    rng::GameRng,
+    screens::Screen,
+};
+use bevy::prelude::*;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use std::collections::HashSet;
+
+/// System to reset the bonus word when entering gameplay
+pub fn reset_bonus_word(mut bonus_word: ResMut<BonusWord>) {
+    *bonus_word = BonusWord::default();
+}
+
+/// System to set up the HUD line spelling out the bonus word
+pub fn setup_bonus_word_hud(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Bonus Word HUD"),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(70.0),
+            left: Val::Px(0.0),
+            right: Val::Px(0.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        Pickable::IGNORE,
+        Visibility::Hidden,
+        StateScoped(Screen::Gameplay),
+        children![(
+            Name::new("Bonus Word Text"),
+            Node {
+                padding: UiRect::axes(Val::Px(12.0), Val::Px(5.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.15, 0.8)),
+            BorderRadius::all(Val::Px(6.0)),
+            Text::default(),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(super::BONUS_LETTER_COLOR),
+            BonusWordHud,
+        )],
+    ));
+}
+
+/// System to occasionally scatter the letters of a connector word over the map
+pub fn spawn_bonus_word(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut bonus_word: ResMut<BonusWord>,
+    question_system: Option<Res<QuestionSystem>>,
+    grid_map: Option<Res<GridMap>>,
+    existing_options: Query<&GridPosition, With<OptionCollectible>>,
+    player_query: Query<&Transform, With<Player>>,
+    config: Res<GameConfig>,
+    mut game_rng: ResMut<GameRng>,
+    mut asset_cache: ResMut<SharedAssetCache>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    if bonus_word.active.is_some() {
+        return;
+    }
+
+    bonus_word.spawn_timer.tick(time.delta());
+    if !bonus_word.spawn_timer.just_finished() {
+        return;
+    }
+
+    let Some(grid_map) = grid_map else {
+        return;
+    };
+
+    let rng = game_rng.gameplay();
+    if !rng.gen_bool(super::BONUS_WORD_CHANCE as f64) {
+        return;
+    }
+
+    // Prefer connectors from the current challenge, they are what is being practised
+    let candidates: Vec<String> = question_system
+        .iter()
+        .flat_map(|system| system.get_current_options())
+        .map(|option| option.name.clone())
+        .filter(|name| is_bonus_word(name))
+        .collect();
+    let word = candidates.choose(rng).cloned().unwrap_or_else(|| {
+        super::FALLBACK_BONUS_WORDS
+            .choose(rng)
+            .map(|word| word.to_string())
+            .unwrap_or_default()
+    });
+    if word.is_empty() {
+        return;
+    }
+
+    let active = ActiveBonusWord::new(&word);
+    let avoid_positions: Vec<Vec2> = player_query
+        .iter()
+        .map(|transform| transform.translation.xy())
+        .collect();
+    let mut occupied: HashSet<(usize, usize)> =
+        existing_options.iter().map(|pos| (pos.x, pos.y)).collect();
+
+    let mesh = asset_cache.circle_mesh(super::BONUS_LETTER_SIZE, &mut meshes);
+    let material = asset_cache.material(super::BONUS_LETTER_COLOR, &mut materials);
+
+    for (index, &letter) in active.letters.iter().enumerate() {
+        let Some(grid_pos) = find_empty_spawn_position(
+            &grid_map,
+            &occupied,
+            &avoid_positions,
+            None,
+            &config.options,
+            rng,
+        ) else {
+            warn!("No room for bonus word {}", word);
+            continue;
+        };
+        occupied.insert((grid_pos.x, grid_pos.y));
+        let world_pos = grid_map.grid_to_world(grid_pos.x, grid_pos.y);
+
+        commands.spawn((
+            Name::new(format!("Bonus Letter: {}", letter)),
+            Mesh2d(mesh.clone()),
+            MeshMaterial2d(material.clone()),
+            Transform::from_translation(world_pos.extend(1.1)),
+            grid_pos,
+            BonusLetter { index, letter },
+            StateScoped(Screen::Gameplay),
+            children![(
+                Name::new("Bonus Letter Label"),
+                Text2d::new(letter.to_string()),
+                TextFont {
+                    font_size: 13.0,
+                    ..default()
+                },
+                TextColor(Color::BLACK),
+                Transform::from_translation(Vec3::new(0.0, 0.0, 0.1)),
+            )],
+        ));
+    }
+
+    info!("Spawned bonus word {}", active.word());
+    bonus_word.active = Some(active);
+}
+
+/// Single words of a handful of letters, so they fit on the map and in the HUD
+fn is_bonus_word(name: &str) -> bool {
+    let length = name.chars().count();
+    (super::BONUS_WORD_MIN_LENGTH..=super::BONUS_WORD_MAX_LENGTH).contains(&length)
+        && name.chars().all(char::is_alphabetic)
+}
+
+/// System to run the time limit and clear the letters when it runs out
+pub fn update_bonus_word_timer(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut bonus_word: ResMut<BonusWord>,
+    letter_query: Query<Entity, With<BonusLetter>>,
+) {
+    let Some(active) = bonus_word.active.as_mut() else {
+        return;
+    };
+
+    active.timer.tick(time.delta());
+    if !active.timer.finished() {
+        return;
+    }
+
+    info!("Bonus word {} ran out of time", active.word());
+    bonus_word.active = None;
+    for entity in &letter_query {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// System to let players pick up the next letter of the word
+pub fn collect_bonus_letters(
+    mut commands: Commands,
+    mut bonus_word: ResMut<BonusWord>,
+    mut completed_events: EventWriter<BonusWordCompletedEvent>,
+    mut collection_effects: EventWriter<SpawnCollectionEvent>,
+    player_query: Query<(Entity, &Transform), With<Player>>,
+    letter_query: Query<(Entity, &Transform, &BonusLetter), Without<Player>>,
+) {
+    let Some(active) = bonus_word.active.as_mut() else {
+        return;
+    };
+
+    for (player_entity, player_transform) in &player_query {
+        let player_pos = player_transform.translation.xy();
+
+        // Only the next letter in the word can be picked up
+        let Some((letter_entity, letter_transform, _)) =
+            letter_query.iter().find(|(_, letter_transform, letter)| {
+                letter.index == active.next_index
+                    && letter_transform.translation.xy().distance(player_pos)
+                        <= PLAYER_SIZE + super::BONUS_LETTER_SIZE
+            })
+        else {
+            continue;
+        };
+
+        collection_effects.write(SpawnCollectionEvent {
+            position: letter_transform.translation,
+            color: super::BONUS_LETTER_COLOR,
+        });
+        commands.entity(letter_entity).despawn();
+
+        active.collectors.push(player_entity);
+        active.next_index += 1;
+
+        if active.is_complete() {
+            completed_events.write(BonusWordCompletedEvent {
+                word: active.word(),
+                collectors: active.collectors.clone(),
+            });
+            info!("Bonus word {} completed", active.word());
+            bonus_word.active = None;
+            return;
+        }
+    }
+}
+
+/// System to bob the letters and make the next one stand out
+pub fn animate_bonus_letters(
+    time: Res<Time>,
+    bonus_word: Res<BonusWord>,
+    mut letter_query: Query<(&mut Transform, &BonusLetter)>,
+) {
+    let next_index = bonus_word
+        .active
+        .as_ref()
+        .map_or(usize::MAX, |active| active.next_index);
+    let elapsed = time.elapsed_secs();
+
+    for (mut transform, letter) in &mut letter_query {
+        let scale = if letter.index == next_index {
+            1.3 + (elapsed * 6.0).sin() * 0.15
+        } else {
+            0.85 + (elapsed * 2.0 + letter.index as f32).sin() * 0.05
+        };
+        transform.scale = Vec3::splat(scale);
+    }
+}
+
+/// System to spell out the bonus word and its remaining time in the HUD
+pub fn update_bonus_word_hud(
+    bonus_word: Res<BonusWord>,
+    locale: Res<Locale>,
+    mut hud_query: Query<(&mut Text, &ChildOf), With<BonusWordHud>>,
+    mut visibility_query: Query<&mut Visibility>,
+) {
+    for (mut text, child_of) in &mut hud_query {
+        let Ok(mut visibility) = visibility_query.get_mut(child_of.parent()) else {
+            continue;
+        };
+
+        let Some(active) = &bonus_word.active else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        visibility.set_if_neq(Visibility::Inherited);
+
+        let label = locale.format(
+            "hud.bonus_word",
+            &[
+                ("letters", &active.progress_label()),
+                ("seconds", &active.timer.remaining_secs().ceil()),
+            ],
+        );
+        if text.0 != label {
+            text.0 = label;
+        }
+    }
+}
@@ -3,25 +3,89 @@
 // Disable console on Windows for non-dev builds.
 #![cfg_attr(not(feature = "dev"), windows_subsystem = "windows")]
 
+#[cfg(not(feature = "minimal-web"))]
+mod accessibility;
+mod achievements;
+mod asset_cache;
 mod asset_tracking;
 mod audio;
+mod auto_pause;
+#[cfg(feature = "bench")]
+pub mod bench;
+mod bot;
+#[cfg(not(feature = "minimal-web"))]
+mod bug_report;
 mod camera;
 mod chain;
+#[cfg(not(feature = "minimal-web"))]
+mod challenge_import;
+mod config;
+mod daily;
 #[cfg(feature = "dev")]
 mod dev_tools;
+mod director;
+mod display;
 mod effects;
+mod explanation;
+#[cfg(not(feature = "minimal-web"))]
+mod export;
+mod feed;
+mod fixed_step;
+mod game_feel;
 mod game_state;
+mod gamepad_hotplug;
 mod gameplay;
+mod gestures;
+mod hazards;
+mod idle;
+mod launch;
+#[cfg(not(feature = "minimal-web"))]
+mod leaderboard;
+mod letters;
+mod locale;
 mod map;
+mod match_play;
 mod menus;
+mod notifications;
 mod options;
+mod palette;
+mod perf_hud;
 mod player;
 mod plugin;
+mod popups;
+mod powerups;
+mod practice;
+mod preload;
+#[cfg(not(feature = "minimal-web"))]
+mod profile;
+#[cfg(not(feature = "minimal-web"))]
+mod pronunciation;
 mod question;
+mod quick_play;
 mod resources;
+mod review;
+mod rng;
+mod round;
+mod safe_area;
 mod screens;
+#[cfg(not(feature = "minimal-web"))]
+mod screenshot;
+mod session;
 mod settings;
+mod spatial;
+mod stats;
+mod storage;
+mod sudden_death;
+mod suspend;
 mod theme;
+mod touch_controls;
+mod transitions;
+#[cfg(not(feature = "minimal-web"))]
+mod url;
+#[cfg(not(feature = "minimal-web"))]
+mod victory_lap;
+#[cfg(target_family = "wasm")]
+mod web_bridge;
 
 pub use plugin::AppPlugin;
 
@@ -31,7 +95,7 @@ pub fn main() -> AppExit {
     App::new().add_plugins(AppPlugin).run()
 }
 
-/// High-level groupings of systems for the app in the `Update` schedule.
+/// High-level groupings of systems for the app in the `Update` and `FixedUpdate` schedules.
 /// When adding a new variant, make sure to order it in the `configure_sets`
 /// call above.
 #[derive(SystemSet, Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
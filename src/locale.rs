@@ -0,0 +1,121 @@
+//! UI string localization. Each language has a flat YAML bundle of
+//! `key: text` pairs under `assets/locales`, embedded at build time so they
+//! are available before any asset finishes loading.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::settings::GameSettings;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Language>();
+
+    app.insert_resource(Locale::load());
+
+    app.add_systems(
+        PreUpdate,
+        sync_locale_language.run_if(resource_changed::<GameSettings>),
+    );
+}
+
+/// Languages the UI is available in
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Language {
+    #[default]
+    English,
+    German,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::German];
+
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index.min(Self::ALL.len() - 1)]
+    }
+
+    pub fn index(&self) -> usize {
+        Self::ALL.iter().position(|l| l == self).unwrap_or(0)
+    }
+
+    /// Language of a code like `de` or `en-US`
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.split(['-', '_']).next()?.to_ascii_lowercase();
+        match code.as_str() {
+            "en" => Some(Language::English),
+            "de" => Some(Language::German),
+            _ => None,
+        }
+    }
+
+    /// Name of the language in that language
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::German => "Deutsch",
+        }
+    }
+
+    fn bundle_source(&self) -> &'static str {
+        match self {
+            Language::English => include_str!("../assets/locales/en.yml"),
+            Language::German => include_str!("../assets/locales/de.yml"),
+        }
+    }
+}
+
+/// Loaded string bundles and the language currently shown
+#[derive(Resource, Debug)]
+pub struct Locale {
+    language: Language,
+    bundles: HashMap<Language, HashMap<String, String>>,
+}
+
+impl Locale {
+    pub fn load() -> Self {
+        let bundles = Language::ALL
+            .iter()
+            .map(|language| {
+                let bundle = serde_yaml::from_str(language.bundle_source()).unwrap_or_else(|err| {
+                    error!("Failed to parse {} locale bundle: {}", language.name(), err);
+                    HashMap::new()
+                });
+                (*language, bundle)
+            })
+            .collect();
+
+        Self {
+            language: Language::default(),
+            bundles,
+        }
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Look up a string, falling back to English and then to the key itself
+    pub fn text<'a>(&'a self, key: &'a str) -> &'a str {
+        [self.language, Language::English]
+            .iter()
+            .find_map(|language| self.bundles.get(language)?.get(key))
+            .map(String::as_str)
+            .unwrap_or(key)
+    }
+
+    /// Look up a string and fill in its `{name}` placeholders
+    pub fn format(&self, key: &str, args: &[(&str, &dyn std::fmt::Display)]) -> String {
+        args.iter()
+            .fold(self.text(key).to_string(), |text, (name, value)| {
+                text.replace(&format!("{{{}}}", name), &value.to_string())
+            })
+    }
+}
+
+fn sync_locale_language(game_settings: Res<GameSettings>, mut locale: ResMut<Locale>) {
+    let language = game_settings.display.language;
+    if locale.language != language {
+        locale.language = language;
+        info!("UI language set to {}", language.name());
+    }
+}
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use rand::{Rng, SeedableRng, rngs::StdRng};
 
 /// Resource for configuring map properties
 #[derive(Resource, Reflect, Clone)]
@@ -10,6 +11,12 @@ pub struct MapConfig {
     pub background_color: Color,
     pub grid_color: Color,
     pub show_grid_lines: bool,
+    /// Cells that block movement and spawning, for designed layouts
+    pub obstacles: Vec<MapObstacle>,
+    /// Look of the board, never `MapTheme::Random` once applied
+    pub theme: MapTheme,
+    pub wall_color: Color,
+    pub grid_style: GridLineStyle,
 }
 
 impl Default for MapConfig {
@@ -21,6 +28,10 @@ impl Default for MapConfig {
             background_color: super::BACKGROUND_COLOR,
             grid_color: super::GRID_COLOR,
             show_grid_lines: true,
+            obstacles: Vec::new(),
+            theme: MapTheme::Classic,
+            wall_color: super::WALL_COLOR,
+            grid_style: GridLineStyle::Lines,
         }
     }
 }
@@ -44,6 +55,296 @@ impl MapConfig {
         self.grid_color = grid;
         self
     }
+
+    /// Take the colors and grid style of a theme
+    pub fn with_theme(mut self, theme: MapTheme) -> Self {
+        let palette = theme.palette();
+        self.theme = theme;
+        self.background_color = palette.background;
+        self.grid_color = palette.grid;
+        self.wall_color = palette.wall;
+        self.grid_style = palette.grid_style;
+        self
+    }
+
+    pub fn with_obstacle(mut self, x: usize, y: usize, cell_type: GridCellType) -> Self {
+        self.obstacles.push(MapObstacle { x, y, cell_type });
+        self
+    }
+
+    /// Fill a rectangle of cells with obstacles
+    pub fn with_obstacle_rect(
+        mut self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+        cell_type: GridCellType,
+    ) -> Self {
+        for cell_y in y..y + height {
+            for cell_x in x..x + width {
+                self.obstacles.push(MapObstacle {
+                    x: cell_x,
+                    y: cell_y,
+                    cell_type: cell_type.clone(),
+                });
+            }
+        }
+        self
+    }
+}
+
+/// Named map layouts players can pick from
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MapPreset {
+    Small,
+    Medium,
+    #[default]
+    Large,
+    Donut,
+    Cross,
+    Maze,
+    Random,
+}
+
+impl MapPreset {
+    pub const ALL: [MapPreset; 7] = [
+        MapPreset::Small,
+        MapPreset::Medium,
+        MapPreset::Large,
+        MapPreset::Donut,
+        MapPreset::Cross,
+        MapPreset::Maze,
+        MapPreset::Random,
+    ];
+
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index.min(Self::ALL.len() - 1)]
+    }
+
+    pub fn index(&self) -> usize {
+        Self::ALL.iter().position(|p| p == self).unwrap_or(2)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            MapPreset::Small => "Small",
+            MapPreset::Medium => "Medium",
+            MapPreset::Large => "Large",
+            MapPreset::Donut => "Donut",
+            MapPreset::Cross => "Cross",
+            MapPreset::Maze => "Maze",
+            MapPreset::Random => "Random",
+        }
+    }
+
+    /// Build the map configuration for this preset. `seed` is only used by `Random`.
+    pub fn config(&self, seed: u64) -> MapConfig {
+        let base = |width, height| {
+            MapConfig::new(width, height)
+                .with_cell_size(super::PRESET_CELL_SIZE)
+                .with_colors(super::PRESET_BACKGROUND_COLOR, super::PRESET_GRID_COLOR)
+        };
+
+        match self {
+            MapPreset::Small => base(48, 40),
+            MapPreset::Medium => base(80, 64),
+            MapPreset::Large => base(120, 100),
+            MapPreset::Donut => base(80, 64).with_obstacle_rect(28, 22, 24, 20, GridCellType::Hole),
+            MapPreset::Cross => base(80, 64)
+                .with_obstacle_rect(39, 10, 2, 18, GridCellType::Wall)
+                .with_obstacle_rect(39, 36, 2, 18, GridCellType::Wall)
+                .with_obstacle_rect(12, 31, 22, 2, GridCellType::Wall)
+                .with_obstacle_rect(46, 31, 22, 2, GridCellType::Wall),
+            MapPreset::Maze => {
+                let mut config = base(96, 80);
+                // Vertical walls with a gap that alternates between top and bottom
+                for (i, x) in (12..96).step_by(12).enumerate() {
+                    let gap_start = if i % 2 == 0 { 8 } else { 64 };
+                    config = config
+                        .with_obstacle_rect(x, 0, 1, gap_start, GridCellType::Wall)
+                        .with_obstacle_rect(
+                            x,
+                            gap_start + 8,
+                            1,
+                            72 - gap_start,
+                            GridCellType::Wall,
+                        );
+                }
+                config
+            }
+            MapPreset::Random => random_map(base(96, 80), seed),
+        }
+    }
+}
+
+/// How the grid lines are drawn
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GridLineStyle {
+    /// Full lines in both directions
+    #[default]
+    Lines,
+    /// Horizontal lines only, like a ruled notebook
+    Ruled,
+    /// Small crosses where the lines would meet
+    Dots,
+}
+
+/// Visual themes for the board
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MapTheme {
+    #[default]
+    Classic,
+    NightSky,
+    Blackboard,
+    Notebook,
+    Neon,
+    /// A different theme every match
+    Random,
+}
+
+/// Colors and grid style of a map theme
+#[derive(Clone, Copy, Debug)]
+pub struct MapThemePalette {
+    pub background: Color,
+    pub grid: Color,
+    pub wall: Color,
+    /// Color outside the board
+    pub clear: Color,
+    /// Background of the HUD panels
+    pub hud_panel: Color,
+    /// Borders and highlights in the HUD
+    pub accent: Color,
+    pub grid_style: GridLineStyle,
+}
+
+impl MapTheme {
+    pub const ALL: [MapTheme; 6] = [
+        MapTheme::Classic,
+        MapTheme::NightSky,
+        MapTheme::Blackboard,
+        MapTheme::Notebook,
+        MapTheme::Neon,
+        MapTheme::Random,
+    ];
+
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index.min(Self::ALL.len() - 1)]
+    }
+
+    pub fn index(&self) -> usize {
+        Self::ALL.iter().position(|t| t == self).unwrap_or(0)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            MapTheme::Classic => "Classic",
+            MapTheme::NightSky => "Night Sky",
+            MapTheme::Blackboard => "Blackboard",
+            MapTheme::Notebook => "Notebook",
+            MapTheme::Neon => "Neon",
+            MapTheme::Random => "Random",
+        }
+    }
+
+    /// Pick a concrete theme for `Random` from the round seed
+    pub fn resolve(&self, seed: u64) -> MapTheme {
+        if *self != MapTheme::Random {
+            return *self;
+        }
+        let themes = &Self::ALL[..Self::ALL.len() - 1];
+        let mut rng = StdRng::seed_from_u64(seed ^ super::MAP_THEME_SALT);
+        themes[rng.gen_range(0..themes.len())]
+    }
+
+    pub fn palette(&self) -> MapThemePalette {
+        match self {
+            MapTheme::Classic | MapTheme::Random => MapThemePalette {
+                background: super::PRESET_BACKGROUND_COLOR,
+                grid: super::PRESET_GRID_COLOR,
+                wall: super::WALL_COLOR,
+                clear: Color::srgb(0.03, 0.03, 0.06),
+                hud_panel: Color::srgba(0.0, 0.0, 0.0, 0.8),
+                accent: Color::srgb(0.4, 0.6, 0.9),
+                grid_style: GridLineStyle::Lines,
+            },
+            MapTheme::NightSky => MapThemePalette {
+                background: Color::srgb(0.02, 0.03, 0.12),
+                grid: Color::srgba(0.5, 0.6, 1.0, 0.35),
+                wall: Color::srgb(0.25, 0.28, 0.5),
+                clear: Color::srgb(0.01, 0.01, 0.05),
+                hud_panel: Color::srgba(0.02, 0.03, 0.15, 0.85),
+                accent: Color::srgb(0.95, 0.9, 0.5),
+                grid_style: GridLineStyle::Dots,
+            },
+            MapTheme::Blackboard => MapThemePalette {
+                background: Color::srgb(0.1, 0.2, 0.15),
+                grid: Color::srgba(0.9, 0.95, 0.9, 0.18),
+                wall: Color::srgb(0.45, 0.3, 0.18),
+                clear: Color::srgb(0.3, 0.2, 0.12),
+                hud_panel: Color::srgba(0.08, 0.16, 0.12, 0.85),
+                accent: Color::srgb(0.95, 0.95, 0.85),
+                grid_style: GridLineStyle::Lines,
+            },
+            MapTheme::Notebook => MapThemePalette {
+                background: Color::srgb(0.95, 0.94, 0.88),
+                grid: Color::srgba(0.4, 0.6, 0.9, 0.6),
+                wall: Color::srgb(0.35, 0.35, 0.45),
+                clear: Color::srgb(0.75, 0.72, 0.65),
+                hud_panel: Color::srgba(0.15, 0.2, 0.35, 0.85),
+                accent: Color::srgb(0.9, 0.3, 0.3),
+                grid_style: GridLineStyle::Ruled,
+            },
+            MapTheme::Neon => MapThemePalette {
+                background: Color::srgb(0.02, 0.0, 0.05),
+                grid: Color::srgba(1.0, 0.2, 0.9, 0.55),
+                wall: Color::srgb(0.1, 0.9, 1.0),
+                clear: Color::BLACK,
+                hud_panel: Color::srgba(0.05, 0.0, 0.1, 0.85),
+                accent: Color::srgb(0.1, 0.9, 1.0),
+                grid_style: GridLineStyle::Lines,
+            },
+        }
+    }
+}
+
+/// Scatter walls and holes across the map, keeping the center free for spawning
+fn random_map(mut config: MapConfig, seed: u64) -> MapConfig {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let center = (config.width / 2, config.height / 2);
+
+    for _ in 0..super::RANDOM_MAP_OBSTACLES {
+        let width = rng.gen_range(1..=4);
+        let height = rng.gen_range(1..=4);
+        let x = rng.gen_range(0..config.width - width);
+        let y = rng.gen_range(0..config.height - height);
+
+        // Keep a clear area around the center
+        let near_center = x + width + 8 > center.0
+            && x < center.0 + 8
+            && y + height + 8 > center.1
+            && y < center.1 + 8;
+        if near_center {
+            continue;
+        }
+
+        let cell_type = if rng.gen_bool(0.25) {
+            GridCellType::Hole
+        } else {
+            GridCellType::Wall
+        };
+        config = config.with_obstacle_rect(x, y, width, height, cell_type);
+    }
+
+    config
+}
+
+/// A single obstacle cell in a map layout
+#[derive(Reflect, Clone, Debug)]
+pub struct MapObstacle {
+    pub x: usize,
+    pub y: usize,
+    pub cell_type: GridCellType,
 }
 
 /// Resource representing the game grid map
@@ -67,6 +368,15 @@ impl GridMap {
             cells.push(row);
         }
 
+        for obstacle in &config.obstacles {
+            if let Some(cell) = cells
+                .get_mut(obstacle.y)
+                .and_then(|row| row.get_mut(obstacle.x))
+            {
+                cell.cell_type = obstacle.cell_type.clone();
+            }
+        }
+
         Self {
             width: config.width,
             height: config.height,
@@ -75,6 +385,41 @@ impl GridMap {
         }
     }
 
+    pub fn cell(&self, x: usize, y: usize) -> Option<&GridCell> {
+        self.cells.get(y).and_then(|row| row.get(x))
+    }
+
+    /// Whether the cell blocks movement and spawning. Cells outside the map never block,
+    /// since players wrap around the edges.
+    pub fn is_blocked(&self, x: usize, y: usize) -> bool {
+        self.cell(x, y)
+            .is_some_and(|cell| cell.cell_type.blocks_movement())
+    }
+
+    /// Whether a circle of `radius` around `world_pos` overlaps a blocking cell
+    pub fn is_area_blocked(&self, world_pos: Vec2, radius: f32) -> bool {
+        let probes = [
+            Vec2::ZERO,
+            Vec2::new(radius, 0.0),
+            Vec2::new(-radius, 0.0),
+            Vec2::new(0.0, radius),
+            Vec2::new(0.0, -radius),
+        ];
+
+        probes.iter().any(|offset| {
+            self.world_to_grid(world_pos + *offset)
+                .is_some_and(|(x, y)| self.is_blocked(x, y))
+        })
+    }
+
+    /// All cells that block movement
+    pub fn obstacle_cells(&self) -> impl Iterator<Item = &GridCell> {
+        self.cells
+            .iter()
+            .flatten()
+            .filter(|cell| cell.cell_type.blocks_movement())
+    }
+
     pub fn world_to_grid(&self, world_pos: Vec2) -> Option<(usize, usize)> {
         let half_width = (self.width as f32 * self.cell_size) / 2.0;
         let half_height = (self.height as f32 * self.cell_size) / 2.0;
@@ -121,6 +466,42 @@ impl GridMap {
     pub fn half_height(&self) -> f32 {
         self.world_height() / 2.0
     }
+
+    /// Shortest offset from `from` to `to`, taking map wraparound into account
+    pub fn wrapped_offset(&self, from: Vec2, to: Vec2) -> Vec2 {
+        let mut offset = to - from;
+        if offset.x > self.half_width() {
+            offset.x -= self.world_width();
+        } else if offset.x < -self.half_width() {
+            offset.x += self.world_width();
+        }
+        if offset.y > self.half_height() {
+            offset.y -= self.world_height();
+        } else if offset.y < -self.half_height() {
+            offset.y += self.world_height();
+        }
+        offset
+    }
+
+    /// Turn `direction` away from obstacles found `lookahead` ahead of `world_pos`.
+    /// Tries increasingly sharp turns to either side and falls back to turning around.
+    pub fn steer_around_obstacles(
+        &self,
+        world_pos: Vec2,
+        direction: Vec2,
+        lookahead: f32,
+        radius: f32,
+    ) -> Vec2 {
+        if direction == Vec2::ZERO {
+            return direction;
+        }
+
+        [0.0, 45.0, -45.0, 90.0, -90.0, 135.0, -135.0]
+            .into_iter()
+            .map(|angle: f32| Vec2::from_angle(angle.to_radians()).rotate(direction))
+            .find(|candidate| !self.is_area_blocked(world_pos + *candidate * lookahead, radius))
+            .unwrap_or(-direction)
+    }
 }
 
 impl Default for GridMap {
@@ -157,11 +538,18 @@ impl GridCell {
 pub enum GridCellType {
     Empty,
     Wall,
+    Hole,
     QuestionZone,
     AnswerZone,
     ParticleSource, // For chain reaction effects
 }
 
+impl GridCellType {
+    pub fn blocks_movement(&self) -> bool {
+        matches!(self, GridCellType::Wall | GridCellType::Hole)
+    }
+}
+
 /// Component for entities that have a position on the grid
 #[derive(Component, Reflect, Clone, Debug)]
 #[reflect(Component)]
@@ -5,27 +5,42 @@ mod systems;
 
 pub use components::*;
 pub use systems::setup_grid_map; // Make sure this is exported
-use systems::{handle_map_config_changes, update_grid_visualization};
+use systems::{
+    apply_map_preset, apply_map_theme_colors, handle_map_config_changes, reset_clear_color,
+    update_grid_visualization,
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.register_type::<MapConfig>();
     app.register_type::<GridMap>();
     app.register_type::<GridCell>();
     app.register_type::<GridPosition>();
+    app.register_type::<MapObstacle>();
+    app.register_type::<MapPreset>();
+    app.register_type::<MapTheme>();
+    app.register_type::<GridLineStyle>();
 
     // Initialize map configuration resource
-    app.insert_resource(MapConfig::new(120, 100).with_cell_size(28.0).with_colors(
-        Color::srgb(0.05, 0.05, 0.1),
-        Color::srgba(0.2, 0.4, 0.6, 0.6),
-    ));
+    app.insert_resource(MapPreset::default().config(0));
 
-    app.add_systems(OnEnter(crate::screens::Screen::Gameplay), setup_grid_map);
+    app.add_systems(
+        OnEnter(crate::screens::Screen::Gameplay),
+        (apply_map_preset, setup_grid_map)
+            .chain()
+            // The question setup consumes the round seed, read it before that
+            .before(crate::question::systems::setup_question_system),
+    );
 
     app.add_systems(
         Update,
-        (update_grid_visualization, handle_map_config_changes)
+        (
+            update_grid_visualization,
+            handle_map_config_changes,
+            apply_map_theme_colors,
+        )
             .run_if(in_state(crate::screens::Screen::Gameplay)),
     );
+    app.add_systems(OnExit(crate::screens::Screen::Gameplay), reset_clear_color);
 }
 
 // Default configuration constants
@@ -34,3 +49,23 @@ pub const DEFAULT_GRID_HEIGHT: usize = 20;
 pub const DEFAULT_CELL_SIZE: f32 = 32.0;
 pub const GRID_COLOR: Color = Color::srgba(0.3, 0.3, 0.4, 0.8);
 pub const BACKGROUND_COLOR: Color = Color::srgb(0.1, 0.1, 0.15);
+pub const WALL_COLOR: Color = Color::srgb(0.35, 0.4, 0.55);
+pub const HOLE_COLOR: Color = Color::srgb(0.0, 0.0, 0.02);
+pub const HOLE_RIM_COLOR: Color = Color::srgb(0.2, 0.15, 0.3);
+
+// Map preset constants
+pub const PRESET_CELL_SIZE: f32 = 28.0;
+pub const PRESET_BACKGROUND_COLOR: Color = Color::srgb(0.05, 0.05, 0.1);
+pub const PRESET_GRID_COLOR: Color = Color::srgba(0.2, 0.4, 0.6, 0.6);
+pub const RANDOM_MAP_OBSTACLES: usize = 40;
+
+// Map theme constants
+pub const MAP_THEME_SALT: u64 = 0x7E3A_11C5_0B5E_D00D;
+pub const NIGHT_SKY_STARS_PER_CELL: f32 = 0.04;
+pub const NIGHT_SKY_STAR_RADIUS: f32 = 1.5;
+pub const NOTEBOOK_MARGIN_COLOR: Color = Color::srgba(0.9, 0.3, 0.3, 0.7);
+pub const NOTEBOOK_MARGIN_CELLS: f32 = 3.0; // Margin line distance from the left edge
+pub const BOARD_FRAME_WIDTH: f32 = 12.0;
+pub const BLACKBOARD_FRAME_COLOR: Color = Color::srgb(0.45, 0.3, 0.18);
+pub const NEON_FRAME_COLOR: Color = Color::srgba(1.0, 0.2, 0.9, 0.8);
+pub const GRID_DOT_SIZE: f32 = 3.0; // Length of each arm of a dot-style cross
@@ -1,6 +1,32 @@
 use super::components::*;
-use crate::screens::Screen;
+use crate::{game_state::GameState, screens::Screen, settings::GameSettings};
 use bevy::prelude::*;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/// System to build the map configuration from the selected preset
+pub fn apply_map_preset(
+    time: Res<Time>,
+    game_settings: Res<GameSettings>,
+    game_state: Res<GameState>,
+    mut map_config: ResMut<MapConfig>,
+) {
+    let preset = game_settings.gameplay.map_preset;
+    // Same seed the question order uses, so a shared round gets the same random map
+    let seed = game_state
+        .round_seed
+        .unwrap_or((time.elapsed_secs() * 1000000.0) as u64);
+
+    let theme = game_settings.gameplay.map_theme.resolve(seed);
+    *map_config = preset.config(seed).with_theme(theme);
+    info!(
+        "Using map preset {} with the {} theme ({}x{}, {} obstacle cells)",
+        preset.name(),
+        theme.name(),
+        map_config.width,
+        map_config.height,
+        map_config.obstacles.len()
+    );
+}
 
 /// System to set up the grid map from configuration
 pub fn setup_grid_map(
@@ -57,9 +83,11 @@ fn spawn_grid_background(
         StateScoped(Screen::Gameplay),
     ));
 
+    spawn_theme_decorations(commands, grid_map, map_config, meshes, materials);
+
     // Only create grid lines if enabled
     if map_config.show_grid_lines {
-        let grid_mesh = create_grid_mesh(grid_map, meshes);
+        let grid_mesh = create_grid_mesh(grid_map, map_config.grid_style, meshes);
         let grid_material = materials.add(ColorMaterial::from(map_config.grid_color));
 
         commands.spawn((
@@ -71,10 +99,137 @@ fn spawn_grid_background(
             StateScoped(Screen::Gameplay),
         ));
     }
+
+    spawn_obstacles(commands, grid_map, map_config, meshes, materials);
+}
+
+/// Spawn the extras a theme draws on top of the plain background
+fn spawn_theme_decorations(
+    commands: &mut Commands,
+    grid_map: &GridMap,
+    map_config: &MapConfig,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+) {
+    let total_width = grid_map.world_width();
+    let total_height = grid_map.world_height();
+
+    match map_config.theme {
+        MapTheme::NightSky => {
+            // Stars at fixed spots, so the sky doesn't change between rebuilds
+            let star_mesh = meshes.add(Circle::new(super::NIGHT_SKY_STAR_RADIUS));
+            let star_material =
+                materials.add(ColorMaterial::from(Color::srgba(1.0, 1.0, 0.9, 0.7)));
+            let cells = (grid_map.width * grid_map.height) as f32;
+            let star_count = (cells * super::NIGHT_SKY_STARS_PER_CELL) as usize;
+            let mut rng = StdRng::seed_from_u64(super::MAP_THEME_SALT);
+            for _ in 0..star_count {
+                let position = Vec2::new(
+                    rng.gen_range(-0.5..0.5) * total_width,
+                    rng.gen_range(-0.5..0.5) * total_height,
+                );
+                commands.spawn((
+                    Name::new("Night Sky Star"),
+                    Mesh2d(star_mesh.clone()),
+                    MeshMaterial2d(star_material.clone()),
+                    Transform::from_translation(position.extend(-0.9))
+                        .with_scale(Vec3::splat(rng.gen_range(0.5..1.5))),
+                    GridVisualization,
+                    StateScoped(Screen::Gameplay),
+                ));
+            }
+        }
+        MapTheme::Notebook => {
+            let x = -grid_map.half_width() + grid_map.cell_size * super::NOTEBOOK_MARGIN_CELLS;
+            commands.spawn((
+                Name::new("Notebook Margin"),
+                Mesh2d(meshes.add(Rectangle::new(2.0, total_height))),
+                MeshMaterial2d(materials.add(ColorMaterial::from(super::NOTEBOOK_MARGIN_COLOR))),
+                Transform::from_translation(Vec3::new(x, 0.0, 0.05)),
+                GridVisualization,
+                StateScoped(Screen::Gameplay),
+            ));
+        }
+        MapTheme::Blackboard | MapTheme::Neon => {
+            let frame_color = if map_config.theme == MapTheme::Blackboard {
+                super::BLACKBOARD_FRAME_COLOR
+            } else {
+                super::NEON_FRAME_COLOR
+            };
+            let frame = super::BOARD_FRAME_WIDTH;
+            commands.spawn((
+                Name::new("Board Frame"),
+                Mesh2d(meshes.add(Rectangle::new(
+                    total_width + frame * 2.0,
+                    total_height + frame * 2.0,
+                ))),
+                MeshMaterial2d(materials.add(ColorMaterial::from(frame_color))),
+                Transform::from_translation(Vec3::new(0.0, 0.0, -1.1)),
+                GridVisualization,
+                StateScoped(Screen::Gameplay),
+            ));
+        }
+        MapTheme::Classic | MapTheme::Random => {}
+    }
+}
+
+/// Spawn a tile for every wall and hole on the map
+fn spawn_obstacles(
+    commands: &mut Commands,
+    grid_map: &GridMap,
+    map_config: &MapConfig,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+) {
+    let tile_mesh = meshes.add(Rectangle::new(grid_map.cell_size, grid_map.cell_size));
+    let hole_rim_mesh = meshes.add(Rectangle::new(
+        grid_map.cell_size * 0.8,
+        grid_map.cell_size * 0.8,
+    ));
+    let wall_material = materials.add(ColorMaterial::from(map_config.wall_color));
+    let hole_material = materials.add(ColorMaterial::from(super::HOLE_RIM_COLOR));
+    let hole_inner_material = materials.add(ColorMaterial::from(super::HOLE_COLOR));
+
+    for cell in grid_map.obstacle_cells() {
+        let position = grid_map.grid_to_world(cell.x, cell.y);
+
+        match cell.cell_type {
+            GridCellType::Hole => {
+                commands.spawn((
+                    Name::new("Hole"),
+                    Mesh2d(tile_mesh.clone()),
+                    MeshMaterial2d(hole_material.clone()),
+                    Transform::from_translation(position.extend(0.1)),
+                    GridVisualization,
+                    StateScoped(Screen::Gameplay),
+                    children![(
+                        Name::new("Hole Inner"),
+                        Mesh2d(hole_rim_mesh.clone()),
+                        MeshMaterial2d(hole_inner_material.clone()),
+                        Transform::from_translation(Vec3::new(0.0, 0.0, 0.01)),
+                    )],
+                ));
+            }
+            _ => {
+                commands.spawn((
+                    Name::new("Wall"),
+                    Mesh2d(tile_mesh.clone()),
+                    MeshMaterial2d(wall_material.clone()),
+                    Transform::from_translation(position.extend(0.1)),
+                    GridVisualization,
+                    StateScoped(Screen::Gameplay),
+                ));
+            }
+        }
+    }
 }
 
 /// Create a mesh for the grid lines
-fn create_grid_mesh(grid_map: &GridMap, meshes: &mut Assets<Mesh>) -> Handle<Mesh> {
+fn create_grid_mesh(
+    grid_map: &GridMap,
+    style: GridLineStyle,
+    meshes: &mut Assets<Mesh>,
+) -> Handle<Mesh> {
     let mut mesh = Mesh::new(
         bevy::render::render_resource::PrimitiveTopology::LineList,
         bevy::render::render_asset::RenderAssetUsages::RENDER_WORLD,
@@ -87,24 +242,40 @@ fn create_grid_mesh(grid_map: &GridMap, meshes: &mut Assets<Mesh>) -> Handle<Mes
     let mut indices = Vec::new();
     let mut index = 0u32;
 
-    // Vertical lines
-    for i in 0..=grid_map.width {
-        let x = (i as f32 * grid_map.cell_size) - half_width;
-        vertices.push([x, -half_height, 0.0]);
-        vertices.push([x, half_height, 0.0]);
+    let mut push_line = |from: [f32; 3], to: [f32; 3]| {
+        vertices.push(from);
+        vertices.push(to);
         indices.push(index);
         indices.push(index + 1);
         index += 2;
+    };
+
+    if style == GridLineStyle::Dots {
+        let arm = super::GRID_DOT_SIZE;
+        for i in 0..=grid_map.width {
+            let x = (i as f32 * grid_map.cell_size) - half_width;
+            for j in 0..=grid_map.height {
+                let y = (j as f32 * grid_map.cell_size) - half_height;
+                push_line([x - arm, y, 0.0], [x + arm, y, 0.0]);
+                push_line([x, y - arm, 0.0], [x, y + arm, 0.0]);
+            }
+        }
+    }
+
+    // Vertical lines, left out on ruled paper
+    if style == GridLineStyle::Lines {
+        for i in 0..=grid_map.width {
+            let x = (i as f32 * grid_map.cell_size) - half_width;
+            push_line([x, -half_height, 0.0], [x, half_height, 0.0]);
+        }
     }
 
     // Horizontal lines
-    for i in 0..=grid_map.height {
-        let y = (i as f32 * grid_map.cell_size) - half_height;
-        vertices.push([-half_width, y, 0.0]);
-        vertices.push([half_width, y, 0.0]);
-        indices.push(index);
-        indices.push(index + 1);
-        index += 2;
+    if style != GridLineStyle::Dots {
+        for i in 0..=grid_map.height {
+            let y = (i as f32 * grid_map.cell_size) - half_height;
+            push_line([-half_width, y, 0.0], [half_width, y, 0.0]);
+        }
     }
 
     mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
@@ -165,3 +336,36 @@ pub fn update_grid_visualization(
         }
     }
 }
+
+/// System to carry the map theme over to the clear color and the HUD
+pub fn apply_map_theme_colors(
+    map_config: Res<MapConfig>,
+    mut clear_color: ResMut<ClearColor>,
+    added_hud: Query<
+        (),
+        Or<(
+            Added<crate::gameplay::HudRoot>,
+            Added<crate::gameplay::OptionsLegendDisplay>,
+        )>,
+    >,
+    mut hud_query: Query<&mut BackgroundColor, With<crate::gameplay::HudRoot>>,
+    mut legend_query: Query<&mut BorderColor, With<crate::gameplay::OptionsLegendDisplay>>,
+) {
+    if !map_config.is_changed() && added_hud.is_empty() {
+        return;
+    }
+
+    let palette = map_config.theme.palette();
+    clear_color.0 = palette.clear;
+    for mut background in &mut hud_query {
+        background.0 = palette.hud_panel;
+    }
+    for mut border in &mut legend_query {
+        border.0 = palette.accent;
+    }
+}
+
+/// System to give the menus their usual clear color back
+pub fn reset_clear_color(mut clear_color: ResMut<ClearColor>) {
+    *clear_color = ClearColor::default();
+}
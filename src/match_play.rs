@@ -0,0 +1,189 @@
+//! Multiplayer matches: a best-of-N series of short rounds. The match sits on
+//! top of the regular round timer, cuts each round to a fixed length, records
+//! who won it and keeps the score across rounds until someone has won enough
+//! rounds to take the match.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    gameplay::{GameTimer, GameTimerEvent, GameplayScore},
+    player::PlayerIndex,
+    screens::Screen,
+    settings::GameSettings,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<MatchState>();
+
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        start_match_round.after(crate::gameplay::systems::reset_game_state),
+    );
+    app.add_systems(
+        Update,
+        record_round_result
+            .in_set(crate::AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay).and(resource_exists::<MatchState>)),
+    );
+    app.add_systems(OnEnter(Screen::Title), end_match);
+}
+
+/// Resource present while a multiplayer match is being played
+#[derive(Resource, Reflect, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[reflect(Resource)]
+pub struct MatchState {
+    pub best_of: u32,
+    /// Number of the current round, counted from 1
+    pub round: u32,
+    /// Rounds won, by player slot
+    pub wins: HashMap<usize, u32>,
+    /// Score summed over all rounds, by player slot
+    pub scores: HashMap<usize, i32>,
+    /// Slot that won the last finished round, `None` for a draw
+    pub round_winner: Option<usize>,
+    /// Set once the last round of the match has been played
+    pub finished: bool,
+}
+
+impl MatchState {
+    pub fn new(best_of: u32) -> Self {
+        Self {
+            best_of,
+            round: 0,
+            wins: HashMap::new(),
+            scores: HashMap::new(),
+            round_winner: None,
+            finished: false,
+        }
+    }
+
+    /// Round wins needed to take the match
+    pub fn rounds_to_win(&self) -> u32 {
+        self.best_of / 2 + 1
+    }
+
+    pub fn wins(&self, slot: usize) -> u32 {
+        self.wins.get(&slot).copied().unwrap_or(0)
+    }
+
+    pub fn score(&self, slot: usize) -> i32 {
+        self.scores.get(&slot).copied().unwrap_or(0)
+    }
+
+    /// Player slots ordered by round wins, then by total score
+    pub fn standings(&self) -> Vec<usize> {
+        let mut slots: Vec<usize> = self.scores.keys().copied().collect();
+        slots.sort_by_key(|&slot| {
+            (
+                std::cmp::Reverse(self.wins(slot)),
+                std::cmp::Reverse(self.score(slot)),
+                slot,
+            )
+        });
+        slots
+    }
+
+    /// Winner of the match once it is finished
+    pub fn match_winner(&self) -> Option<usize> {
+        if !self.finished {
+            return None;
+        }
+        self.standings().first().copied()
+    }
+}
+
+fn start_match_round(
+    mut commands: Commands,
+    game_settings: Res<GameSettings>,
+    match_state: Option<ResMut<MatchState>>,
+    mut game_timer: ResMut<GameTimer>,
+) {
+    let multiplayer = &game_settings.multiplayer;
+    let mut match_state = match match_state {
+        // A new match starts over once the last one is decided
+        Some(match_state) if !match_state.finished => match_state.clone(),
+        _ if multiplayer.enabled
+            && multiplayer.player_count > 1
+            && multiplayer.match_rounds > 1 =>
+        {
+            MatchState::new(multiplayer.match_rounds)
+        }
+        _ => {
+            commands.remove_resource::<MatchState>();
+            return;
+        }
+    };
+
+    match_state.round += 1;
+    match_state.round_winner = None;
+    *game_timer = GameTimer::with_duration(MATCH_ROUND_DURATION);
+
+    info!(
+        "Match round {} of best of {}",
+        match_state.round, match_state.best_of
+    );
+    commands.insert_resource(match_state);
+}
+
+fn record_round_result(
+    mut timer_events: EventReader<GameTimerEvent>,
+    gameplay_score: Res<GameplayScore>,
+    player_query: Query<&PlayerIndex>,
+    mut match_state: ResMut<MatchState>,
+) {
+    if !timer_events
+        .read()
+        .any(|event| matches!(event, GameTimerEvent::GameEnded))
+    {
+        return;
+    }
+
+    let mut round_scores: Vec<(usize, i32)> = gameplay_score
+        .players
+        .iter()
+        .filter_map(|(&entity, score)| {
+            player_query
+                .get(entity)
+                .ok()
+                .map(|index| (index.0, score.total_score))
+        })
+        .collect();
+    round_scores.sort_by_key(|&(slot, score)| (std::cmp::Reverse(score), slot));
+
+    for &(slot, score) in &round_scores {
+        *match_state.scores.entry(slot).or_insert(0) += score;
+    }
+
+    // A shared top score is a draw and nobody gets the round
+    let winner = match round_scores.as_slice() {
+        [(slot, best), rest @ ..] if rest.first().is_none_or(|(_, next)| next < best) => {
+            Some(*slot)
+        }
+        _ => None,
+    };
+    if let Some(slot) = winner {
+        *match_state.wins.entry(slot).or_insert(0) += 1;
+    }
+    match_state.round_winner = winner;
+
+    let rounds_to_win = match_state.rounds_to_win();
+    match_state.finished = match_state.round >= match_state.best_of
+        || match_state.wins.values().any(|&wins| wins >= rounds_to_win);
+
+    info!(
+        "Match round {} ended, winner: {:?}, match finished: {}",
+        match_state.round, winner, match_state.finished
+    );
+}
+
+fn end_match(mut commands: Commands) {
+    commands.remove_resource::<MatchState>();
+}
+
+// Match constants
+pub const MATCH_ROUND_DURATION: f32 = 90.0; // Seconds per round
+pub const DEFAULT_MATCH_ROUNDS: u32 = 1; // A single regular round, no match
+pub const MAX_MATCH_ROUNDS: u32 = 7;
@@ -0,0 +1,95 @@
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use bevy_egui::{
+    EguiContextPass,
+    egui::{self, Widget},
+};
+use konnektoren_bevy::prelude::*;
+
+use crate::{
+    achievements::{Achievement, UnlockedAchievements},
+    menus::Menu,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        EguiContextPass,
+        achievements_menu_egui_ui.run_if(in_state(Menu::Achievements)),
+    );
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::Achievements).and(input_just_pressed(KeyCode::Escape))),
+    );
+}
+
+fn achievements_menu_egui_ui(
+    mut contexts: bevy_egui::EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    achievements: Res<UnlockedAchievements>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    let ctx = contexts.ctx_mut();
+
+    egui::CentralPanel::default()
+        .frame(egui::Frame::NONE.fill(theme.base_100))
+        .show(ctx, |ui| {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+            ui.vertical_centered(|ui| {
+                ResponsiveText::new("Achievements", ResponsiveFontSize::Title, theme.primary)
+                    .responsive(&responsive)
+                    .strong()
+                    .ui(ui);
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                ui.label(format!(
+                    "{} of {} unlocked",
+                    achievements.unlocked.len(),
+                    Achievement::ALL.len()
+                ));
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+                egui::Grid::new("achievements_grid")
+                    .striped(true)
+                    .spacing([24.0, 8.0])
+                    .show(ui, |ui| {
+                        for achievement in Achievement::ALL {
+                            let unlocked = achievements.is_unlocked(achievement);
+                            let color = if unlocked {
+                                theme.primary
+                            } else {
+                                egui::Color32::GRAY
+                            };
+
+                            ui.label(
+                                egui::RichText::new(if unlocked { "★" } else { "☆" }).color(color),
+                            );
+                            ui.label(
+                                egui::RichText::new(achievement.title())
+                                    .strong()
+                                    .color(color),
+                            );
+                            ui.label(egui::RichText::new(achievement.description()).color(color));
+                            ui.end_row();
+                        }
+                    });
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+                if ThemedButton::new("Back", &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .show(ui)
+                    .clicked()
+                {
+                    next_menu.set(Menu::Main);
+                }
+            });
+        });
+}
+
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
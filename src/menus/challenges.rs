@@ -0,0 +1,165 @@
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use bevy_egui::{
+    EguiContextPass,
+    egui::{self, Widget},
+};
+use konnektoren_bevy::prelude::*;
+
+use crate::{
+    challenge_import::{ChallengeImport, CustomChallenges, ImportChallengeEvent, ImportStatus},
+    game_state::GameState,
+    locale::Locale,
+    menus::Menu,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        EguiContextPass,
+        challenges_menu_egui_ui.run_if(in_state(Menu::Challenges)),
+    );
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::Challenges).and(input_just_pressed(KeyCode::Escape))),
+    );
+}
+
+fn challenges_menu_egui_ui(
+    mut contexts: bevy_egui::EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    locale: Res<Locale>,
+    custom_challenges: Res<CustomChallenges>,
+    mut import: ResMut<ChallengeImport>,
+    mut game_state: ResMut<GameState>,
+    mut import_events: EventWriter<ImportChallengeEvent>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    let ctx = contexts.ctx_mut();
+
+    egui::CentralPanel::default()
+        .frame(egui::Frame::NONE.fill(theme.base_100))
+        .show(ctx, |ui| {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+            ui.vertical_centered(|ui| {
+                ResponsiveText::new(
+                    locale.text("challenges.title"),
+                    ResponsiveFontSize::Title,
+                    theme.primary,
+                )
+                .responsive(&responsive)
+                .strong()
+                .ui(ui);
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                ui.label(locale.text("challenges.hint"));
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                egui::ScrollArea::vertical()
+                    .max_height(ui.available_height() - 360.0)
+                    .show(ui, |ui| {
+                        if custom_challenges.challenges.is_empty() {
+                            ui.label(locale.text("challenges.empty"));
+                        }
+                        for challenge in &custom_challenges.challenges {
+                            let label = locale.format(
+                                "challenges.challenge",
+                                &[("name", &challenge.name), ("count", &challenge.questions)],
+                            );
+                            if ThemedButton::new(label, &theme)
+                                .responsive(&responsive)
+                                .width(300.0)
+                                .show(ui)
+                                .clicked()
+                            {
+                                // Pick the topics next, like any other challenge
+                                game_state.select_challenge(challenge.id.clone());
+                                next_menu.set(Menu::Topics);
+                            }
+                        }
+                    });
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+                // Native builds can open a file or take one dropped onto the window
+                #[cfg(not(target_family = "wasm"))]
+                {
+                    if ThemedButton::new(locale.text("challenges.pick_file"), &theme)
+                        .responsive(&responsive)
+                        .width(250.0)
+                        .show(ui)
+                        .clicked()
+                    {
+                        import_events.write(ImportChallengeEvent::PickFile);
+                    }
+                    ui.weak(locale.text("challenges.drop_hint"));
+
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                }
+
+                ui.add(
+                    egui::TextEdit::singleline(&mut import.url_input)
+                        .hint_text(locale.text("challenges.url_hint"))
+                        .desired_width(250.0),
+                );
+                if ThemedButton::new(locale.text("challenges.download"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .enabled(!import.url_input.trim().is_empty())
+                    .show(ui)
+                    .clicked()
+                {
+                    import_events.write(ImportChallengeEvent::Url(import.url_input.clone()));
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                ui.add(
+                    egui::TextEdit::multiline(&mut import.paste_input)
+                        .hint_text(locale.text("challenges.paste_hint"))
+                        .desired_rows(4)
+                        .desired_width(250.0),
+                );
+                if ThemedButton::new(locale.text("challenges.import"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .enabled(!import.paste_input.trim().is_empty())
+                    .show(ui)
+                    .clicked()
+                {
+                    import_events.write(ImportChallengeEvent::Text(import.paste_input.clone()));
+                }
+
+                match &import.status {
+                    Some(ImportStatus::Loading) => {
+                        ui.label(locale.text("challenges.loading"));
+                    }
+                    Some(ImportStatus::Imported(name)) => {
+                        ui.label(locale.format("challenges.imported", &[("name", name)]));
+                    }
+                    Some(ImportStatus::Failed(error)) => {
+                        ui.colored_label(
+                            egui::Color32::from_rgb(230, 90, 90),
+                            locale.format("challenges.failed", &[("error", error)]),
+                        );
+                    }
+                    None => {}
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+                if ThemedButton::new(locale.text("challenges.back"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .show(ui)
+                    .clicked()
+                {
+                    next_menu.set(Menu::Main);
+                }
+            });
+        });
+}
+
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
@@ -0,0 +1,272 @@
+use bevy::prelude::*;
+use bevy_egui::{
+    EguiContextPass,
+    egui::{self, Widget},
+};
+use konnektoren_bevy::{input::device::InputDevice, prelude::*};
+
+use crate::{
+    menus::Menu,
+    settings::{
+        BINDABLE_KEYS, CustomKeyBindings, GameSettings, KeyAction, TouchControlStyle, key_label,
+        reserved_hotkey,
+    },
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<KeyBindingCapture>();
+
+    app.add_systems(OnEnter(Menu::KeyBindings), reset_capture);
+    app.add_systems(
+        EguiContextPass,
+        key_bindings_menu_egui_ui.run_if(in_state(Menu::KeyBindings)),
+    );
+    app.add_systems(Update, capture_key.run_if(in_state(Menu::KeyBindings)));
+}
+
+/// The binding waiting for a key press, and feedback from the last attempt
+#[derive(Resource, Default, Debug)]
+struct KeyBindingCapture {
+    target: Option<(usize, KeyAction)>,
+    message: Option<String>,
+}
+
+fn reset_capture(mut capture: ResMut<KeyBindingCapture>) {
+    *capture = KeyBindingCapture::default();
+}
+
+fn key_bindings_menu_egui_ui(
+    mut contexts: bevy_egui::EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    mut game_settings: ResMut<GameSettings>,
+    mut key_bindings: ResMut<CustomKeyBindings>,
+    mut capture: ResMut<KeyBindingCapture>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    let ctx = contexts.ctx_mut();
+    let multiplayer = &game_settings.multiplayer;
+    let mut touch_style_change = None;
+
+    egui::CentralPanel::default()
+        .frame(egui::Frame::NONE.fill(theme.base_100))
+        .show(ctx, |ui| {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+            ui.vertical_centered(|ui| {
+                ResponsiveText::new("Controls", ResponsiveFontSize::Title, theme.primary)
+                    .responsive(&responsive)
+                    .strong()
+                    .ui(ui);
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                let hint = match (&capture.target, &capture.message) {
+                    (Some((player_index, action)), _) => format!(
+                        "Press a key for {} - {} (Escape to cancel)",
+                        game_settings.multiplayer.player_name(*player_index),
+                        action.name()
+                    ),
+                    (None, Some(message)) => message.clone(),
+                    (None, None) => "Click a key to change it".to_string(),
+                };
+                ui.label(hint);
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                egui::ScrollArea::vertical()
+                    .max_height(ui.available_height() - 80.0)
+                    .show(ui, |ui| {
+                        for player_index in 0..multiplayer.player_count {
+                            if multiplayer.is_bot(player_index) {
+                                continue;
+                            }
+                            let Some(player) = multiplayer.players.get(player_index) else {
+                                continue;
+                            };
+
+                            ui.label(
+                                egui::RichText::new(&player.name)
+                                    .strong()
+                                    .color(theme.primary),
+                            );
+
+                            if matches!(player.input.primary_input, InputDevice::Touch) {
+                                ui.horizontal(|ui| {
+                                    ui.label("Touch controls:");
+                                    for style in TouchControlStyle::ALL {
+                                        if ui
+                                            .selectable_label(
+                                                player.input.touch_style == style,
+                                                style.name(),
+                                            )
+                                            .clicked()
+                                        {
+                                            touch_style_change = Some((player_index, style));
+                                        }
+                                    }
+                                });
+                                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                                continue;
+                            }
+
+                            let Some(bindings) =
+                                key_bindings.bindings_for(player_index, &player.input)
+                            else {
+                                ui.label(format!(
+                                    "Plays with {}. Assign a keyboard to change keys.",
+                                    player.input.primary_input.name()
+                                ));
+                                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                                continue;
+                            };
+
+                            egui::Grid::new(format!("key_bindings_grid_{}", player_index))
+                                .spacing([24.0, 6.0])
+                                .show(ui, |ui| {
+                                    for action in KeyAction::ALL {
+                                        ui.label(action.name());
+
+                                        let waiting =
+                                            capture.target == Some((player_index, action));
+                                        let label = if waiting {
+                                            "...".to_string()
+                                        } else {
+                                            key_label(bindings.get(action))
+                                        };
+                                        if ui.button(label).clicked() {
+                                            capture.target = Some((player_index, action));
+                                            capture.message = None;
+                                        }
+                                        ui.end_row();
+                                    }
+                                });
+
+                            if key_bindings.is_custom(player_index)
+                                && ui.button("Reset to default").clicked()
+                            {
+                                key_bindings.players.remove(&player_index);
+                                key_bindings.save();
+                                capture.message =
+                                    Some(format!("Reset the keys of {}", player.name));
+                            }
+
+                            ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                        }
+                    });
+
+                if ThemedButton::new("Back", &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .show(ui)
+                    .clicked()
+                {
+                    next_menu.set(Menu::Settings);
+                }
+            });
+        });
+
+    if let Some((player_index, style)) = touch_style_change {
+        if let Some(player) = game_settings.multiplayer.players.get_mut(player_index) {
+            player.input.touch_style = style;
+            info!(
+                "Player {} now uses {} touch controls",
+                player_index + 1,
+                style.name()
+            );
+        }
+    }
+}
+
+/// Assign the next key press to the binding being captured, or go back on Escape
+fn capture_key(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    game_settings: Res<GameSettings>,
+    mut key_bindings: ResMut<CustomKeyBindings>,
+    mut capture: ResMut<KeyBindingCapture>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    let Some((player_index, action)) = capture.target else {
+        if keyboard.just_pressed(KeyCode::Escape) {
+            next_menu.set(Menu::Settings);
+        }
+        return;
+    };
+
+    let Some(key) = keyboard.get_just_pressed().next().copied() else {
+        return;
+    };
+
+    if key == KeyCode::Escape {
+        capture.target = None;
+        capture.message = None;
+        return;
+    }
+
+    if !BINDABLE_KEYS.contains(&key) {
+        capture.message = Some(format!("{} can't be bound", key_label(key)));
+        return;
+    }
+
+    let Some(player) = game_settings.multiplayer.players.get(player_index) else {
+        capture.target = None;
+        return;
+    };
+    let Some(mut bindings) = key_bindings.bindings_for(player_index, &player.input) else {
+        capture.target = None;
+        return;
+    };
+
+    if let Some(conflict) = find_conflict(&game_settings, &key_bindings, player_index, action, key)
+    {
+        capture.target = None;
+        capture.message = Some(format!(
+            "{} is already used by {}",
+            key_label(key),
+            conflict
+        ));
+        return;
+    }
+
+    bindings.set(action, key);
+    key_bindings.players.insert(player_index, bindings);
+    key_bindings.save();
+
+    info!(
+        "Bound {} of player {} to {:?}",
+        action.name(),
+        player_index + 1,
+        key
+    );
+    capture.target = None;
+    capture.message = None;
+}
+
+/// Who else already uses `key`, as "<player> (<action>)", or the game's own
+/// hotkey it would clash with. The pause keys may still be bound to Pause.
+fn find_conflict(
+    game_settings: &GameSettings,
+    key_bindings: &CustomKeyBindings,
+    player_index: usize,
+    action: KeyAction,
+    key: KeyCode,
+) -> Option<String> {
+    let reserved =
+        reserved_hotkey(key).filter(|usage| !(action == KeyAction::Pause && *usage == "Pause"));
+    if let Some(usage) = reserved {
+        return Some(usage.to_string());
+    }
+
+    let multiplayer = &game_settings.multiplayer;
+
+    (0..multiplayer.player_count)
+        .filter(|&other| !multiplayer.is_bot(other))
+        .find_map(|other| {
+            let player = multiplayer.players.get(other)?;
+            let bound = key_bindings
+                .bindings_for(other, &player.input)?
+                .action_for(key)?;
+            (other != player_index || bound != action)
+                .then(|| format!("{} ({})", player.name, bound.name()))
+        })
+}
@@ -0,0 +1,136 @@
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use bevy_egui::{
+    EguiContextPass,
+    egui::{self, Widget},
+};
+use konnektoren_bevy::prelude::*;
+
+use crate::{
+    leaderboard::Leaderboard,
+    menus::Menu,
+    round::{PlaySharedRoundEvent, RoundInfo},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        EguiContextPass,
+        leaderboard_menu_egui_ui.run_if(in_state(Menu::Leaderboard)),
+    );
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::Leaderboard).and(input_just_pressed(KeyCode::Escape))),
+    );
+}
+
+fn leaderboard_menu_egui_ui(
+    mut contexts: bevy_egui::EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    leaderboard: Res<Leaderboard>,
+    mut next_menu: ResMut<NextState<Menu>>,
+    mut shared_round_events: EventWriter<PlaySharedRoundEvent>,
+) {
+    let ctx = contexts.ctx_mut();
+
+    egui::CentralPanel::default()
+        .frame(egui::Frame::NONE.fill(theme.base_100))
+        .show(ctx, |ui| {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+            ui.vertical_centered(|ui| {
+                ResponsiveText::new("Leaderboard", ResponsiveFontSize::Title, theme.primary)
+                    .responsive(&responsive)
+                    .strong()
+                    .ui(ui);
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+                if leaderboard.entries.is_empty() {
+                    ui.label("No games played yet. Finish a round to get on the board!");
+                } else {
+                    egui::Grid::new("leaderboard_grid")
+                        .striped(true)
+                        .spacing([24.0, 8.0])
+                        .show(ui, |ui| {
+                            for header in ["#", "Player", "Score", "Best Streak", "Accuracy", ""] {
+                                ui.label(egui::RichText::new(header).strong().color(theme.primary));
+                            }
+                            ui.end_row();
+
+                            for (rank, entry) in leaderboard.entries.iter().enumerate() {
+                                ui.label(format!("{}", rank + 1));
+                                ui.label(entry.player_name.as_str());
+                                ui.label(format!("{}", entry.score));
+                                ui.label(format!("{}", entry.best_streak));
+                                ui.label(format!("{:.0}%", entry.accuracy));
+
+                                let round = entry
+                                    .share_code
+                                    .as_deref()
+                                    .and_then(RoundInfo::from_share_code);
+                                if let Some(round) = round {
+                                    if ui
+                                        .button("Replay")
+                                        .on_hover_text(entry.share_code.as_deref().unwrap_or(""))
+                                        .clicked()
+                                    {
+                                        shared_round_events.write(PlaySharedRoundEvent(round));
+                                    }
+                                } else {
+                                    ui.label("");
+                                }
+                                ui.end_row();
+                            }
+                        });
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+                // Daily challenge results, one per day. Everyone plays the same
+                // round on a given day, so scores compare across devices.
+                if !leaderboard.daily_entries.is_empty() {
+                    ui.heading(
+                        egui::RichText::new("Daily Challenge")
+                            .strong()
+                            .color(theme.primary),
+                    );
+
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                    egui::Grid::new("daily_leaderboard_grid")
+                        .striped(true)
+                        .spacing([24.0, 8.0])
+                        .show(ui, |ui| {
+                            for header in ["Day", "Player", "Score", "Best Streak", "Accuracy"] {
+                                ui.label(egui::RichText::new(header).strong().color(theme.primary));
+                            }
+                            ui.end_row();
+
+                            for entry in &leaderboard.daily_entries {
+                                ui.label(entry.daily_date.as_deref().unwrap_or(""));
+                                ui.label(entry.player_name.as_str());
+                                ui.label(format!("{}", entry.score));
+                                ui.label(format!("{}", entry.best_streak));
+                                ui.label(format!("{:.0}%", entry.accuracy));
+                                ui.end_row();
+                            }
+                        });
+
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+                }
+
+                if ThemedButton::new("Back", &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .show(ui)
+                    .clicked()
+                {
+                    next_menu.set(Menu::Main);
+                }
+            });
+        });
+}
+
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
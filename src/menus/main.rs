@@ -1,4 +1,11 @@
+use crate::daily::PlayDailyChallengeEvent;
 use crate::game_state::GameState;
+#[cfg(not(feature = "minimal-web"))]
+use crate::leaderboard::Leaderboard;
+use crate::locale::Locale;
+use crate::quick_play::QuickPlayEvent;
+use crate::round::{PlaySharedRoundEvent, RoundInfo, ShareCodeInput};
+use crate::suspend::{ResumeSavedGameEvent, SavedGameSlot};
 use bevy::prelude::*;
 use bevy_egui::{
     EguiContextPass,
@@ -35,9 +42,17 @@ fn main_menu_egui_ui(
     mut contexts: bevy_egui::EguiContexts,
     theme: Res<KonnektorenTheme>,
     responsive: Res<ResponsiveInfo>,
+    locale: Res<Locale>,
     mut next_menu: ResMut<NextState<Menu>>,
     mut next_screen: ResMut<NextState<Screen>>,
     game_state: Res<GameState>,
+    mut quick_play_events: EventWriter<QuickPlayEvent>,
+    mut share_code_input: ResMut<ShareCodeInput>,
+    mut shared_round_events: EventWriter<PlaySharedRoundEvent>,
+    saved_game: Res<SavedGameSlot>,
+    mut resume_events: EventWriter<ResumeSavedGameEvent>,
+    #[cfg(not(feature = "minimal-web"))] leaderboard: Res<Leaderboard>,
+    mut daily_events: EventWriter<PlayDailyChallengeEvent>,
     #[cfg(not(target_family = "wasm"))] mut app_exit: EventWriter<AppExit>,
 ) {
     let ctx = contexts.ctx_mut();
@@ -48,7 +63,7 @@ fn main_menu_egui_ui(
         .show(ctx, |ui| {
             // Calculate vertical centering
             let available_height = ui.available_height();
-            let menu_height = 420.0; // Estimate your menu's height (adjust as needed)
+            let menu_height = 780.0; // Estimate your menu's height (adjust as needed)
             let top_space = ((available_height - menu_height) / 2.0).max(0.0);
 
             ui.add_space(top_space);
@@ -56,7 +71,7 @@ fn main_menu_egui_ui(
             ui.vertical_centered(|ui| {
                 // Title
                 ResponsiveText::new(
-                    "Konnektoren Chain Game",
+                    locale.text("menu.title"),
                     ResponsiveFontSize::Title,
                     theme.primary,
                 )
@@ -68,9 +83,9 @@ fn main_menu_egui_ui(
 
                 // Play button
                 let button_text = if assets_ready {
-                    "Play"
+                    locale.text("menu.play")
                 } else {
-                    "Loading Assets..."
+                    locale.text("menu.loading_assets")
                 };
 
                 if ThemedButton::new(button_text, &theme)
@@ -81,7 +96,8 @@ fn main_menu_egui_ui(
                     .clicked()
                 {
                     if assets_ready {
-                        next_screen.set(Screen::Gameplay);
+                        // Pick the topics to play before the round starts
+                        next_menu.set(Menu::Topics);
                     } else {
                         // Go to loading screen to wait for assets
                         next_screen.set(Screen::Loading);
@@ -90,8 +106,103 @@ fn main_menu_egui_ui(
 
                 ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
 
+                // Continue the round that was suspended last time
+                if saved_game.0.is_some() {
+                    if ThemedButton::new(locale.text("menu.continue"), &theme)
+                        .responsive(&responsive)
+                        .width(250.0)
+                        .enabled(assets_ready)
+                        .show(ui)
+                        .clicked()
+                    {
+                        resume_events.write(ResumeSavedGameEvent);
+                    }
+
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                }
+
+                // Quick play button: single player with a random challenge
+                if ThemedButton::new(locale.text("menu.quick_play"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .show(ui)
+                    .clicked()
+                {
+                    quick_play_events.write(QuickPlayEvent);
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                if ThemedButton::new(locale.text("menu.lobby"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .show(ui)
+                    .clicked()
+                {
+                    next_screen.set(Screen::Lobby);
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                // Daily challenge: the same round for everyone today
+                if ThemedButton::new(locale.text("menu.daily_challenge"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .enabled(assets_ready)
+                    .show(ui)
+                    .clicked()
+                {
+                    daily_events.write(PlayDailyChallengeEvent);
+                }
+                // Today's best
+                #[cfg(not(feature = "minimal-web"))]
+                {
+                    let today = crate::daily::DailyRound::today().date;
+                    if let Some(best) = leaderboard.daily_best(&today) {
+                        ui.label(locale.format("menu.daily_best", &[("score", &best.score)]));
+                    }
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                // Challenges imported by teachers or players
+                #[cfg(not(feature = "minimal-web"))]
+                {
+                    if ThemedButton::new(locale.text("menu.own_challenges"), &theme)
+                        .responsive(&responsive)
+                        .width(250.0)
+                        .show(ui)
+                        .clicked()
+                    {
+                        next_menu.set(Menu::Challenges);
+                    }
+
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                }
+
+                // Share code entry to replay someone else's round
+                ui.add(
+                    egui::TextEdit::singleline(&mut share_code_input.0)
+                        .hint_text(locale.text("menu.share_code_hint"))
+                        .desired_width(250.0),
+                );
+                let shared_round = RoundInfo::from_share_code(&share_code_input.0);
+                if ThemedButton::new(locale.text("menu.play_shared_round"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .enabled(assets_ready && shared_round.is_some())
+                    .show(ui)
+                    .clicked()
+                {
+                    if let Some(round) = shared_round {
+                        shared_round_events.write(PlaySharedRoundEvent(round));
+                    }
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
                 // Settings button
-                if ThemedButton::new("Settings", &theme)
+                if ThemedButton::new(locale.text("menu.settings"), &theme)
                     .responsive(&responsive)
                     .width(250.0)
                     .show(ui)
@@ -102,8 +213,47 @@ fn main_menu_egui_ui(
 
                 ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
 
+                // Leaderboard button
+                #[cfg(not(feature = "minimal-web"))]
+                {
+                    if ThemedButton::new(locale.text("menu.leaderboard"), &theme)
+                        .responsive(&responsive)
+                        .width(250.0)
+                        .show(ui)
+                        .clicked()
+                    {
+                        next_menu.set(Menu::Leaderboard);
+                    }
+
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                }
+
+                // Statistics button
+                if ThemedButton::new(locale.text("menu.statistics"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .show(ui)
+                    .clicked()
+                {
+                    next_menu.set(Menu::Statistics);
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                // Achievements button
+                if ThemedButton::new(locale.text("menu.achievements"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .show(ui)
+                    .clicked()
+                {
+                    next_menu.set(Menu::Achievements);
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
                 // Credits button
-                if ThemedButton::new("Credits", &theme)
+                if ThemedButton::new(locale.text("menu.credits"), &theme)
                     .responsive(&responsive)
                     .width(250.0)
                     .show(ui)
@@ -114,6 +264,21 @@ fn main_menu_egui_ui(
 
                 ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
 
+                // Konnektoren profile button
+                #[cfg(not(feature = "minimal-web"))]
+                {
+                    if ThemedButton::new(locale.text("menu.profile"), &theme)
+                        .responsive(&responsive)
+                        .width(250.0)
+                        .show(ui)
+                        .clicked()
+                    {
+                        next_menu.set(Menu::Profile);
+                    }
+
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                }
+
                 // Website button
                 if ThemedButton::new("konnektoren.help", &theme)
                     .responsive(&responsive)
@@ -127,7 +292,7 @@ fn main_menu_egui_ui(
                 #[cfg(not(target_family = "wasm"))]
                 {
                     ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
-                    if ThemedButton::new("Exit", &theme)
+                    if ThemedButton::new(locale.text("menu.exit"), &theme)
                         .responsive(&responsive)
                         .width(250.0)
                         .show(ui)
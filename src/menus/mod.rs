@@ -1,7 +1,20 @@
+mod achievements;
+#[cfg(not(feature = "minimal-web"))]
+mod challenges;
 mod credits;
+mod key_bindings;
+#[cfg(not(feature = "minimal-web"))]
+mod leaderboard;
 mod main;
 mod pause;
+mod players;
+#[cfg(not(feature = "minimal-web"))]
+mod profile;
+mod review;
+mod round_summary;
 mod settings;
+mod statistics;
+mod topics;
 
 use bevy::prelude::*;
 
@@ -9,10 +22,23 @@ pub(super) fn plugin(app: &mut App) {
     app.init_state::<Menu>();
 
     app.add_plugins((
+        achievements::plugin,
+        #[cfg(not(feature = "minimal-web"))]
+        challenges::plugin,
         credits::plugin,
+        key_bindings::plugin,
+        #[cfg(not(feature = "minimal-web"))]
+        leaderboard::plugin,
         main::plugin,
         settings::plugin,
         pause::plugin,
+        players::plugin,
+        #[cfg(not(feature = "minimal-web"))]
+        profile::plugin,
+        statistics::plugin,
+        topics::plugin,
+        review::plugin,
+        round_summary::plugin,
     ));
 }
 
@@ -23,7 +49,17 @@ pub enum Menu {
     None,
     Main,
     Credits,
+    Leaderboard,
     Settings,
+    Statistics,
+    Achievements,
     Pause,
+    Review,
+    /// Between the rounds of a multiplayer match
+    RoundSummary,
     DeviceSelection,
+    KeyBindings,
+    Profile,
+    Topics,
+    Challenges,
 }
@@ -5,7 +5,9 @@ use bevy_egui::{
 };
 use konnektoren_bevy::prelude::*;
 
-use crate::{menus::Menu, screens::Screen};
+#[cfg(not(feature = "minimal-web"))]
+use crate::bug_report::BugReportRequested;
+use crate::{locale::Locale, menus::Menu, screens::Screen};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(
@@ -22,8 +24,10 @@ fn pause_menu_egui_ui(
     mut contexts: bevy_egui::EguiContexts,
     theme: Res<KonnektorenTheme>,
     responsive: Res<ResponsiveInfo>,
+    locale: Res<Locale>,
     mut next_menu: ResMut<NextState<Menu>>,
     mut next_screen: ResMut<NextState<Screen>>,
+    #[cfg(not(feature = "minimal-web"))] mut bug_reports: EventWriter<BugReportRequested>,
 ) {
     let ctx = contexts.ctx_mut();
 
@@ -32,20 +36,24 @@ fn pause_menu_egui_ui(
         .show(ctx, |ui| {
             // Vertically center the menu
             let available_height = ui.available_height();
-            let menu_height = 320.0;
+            let menu_height = 540.0;
             let top_space = ((available_height - menu_height) / 2.0).max(0.0);
             ui.add_space(top_space);
 
             ui.vertical_centered(|ui| {
-                ResponsiveText::new("Game paused", ResponsiveFontSize::Title, theme.primary)
-                    .responsive(&responsive)
-                    .strong()
-                    .ui(ui);
+                ResponsiveText::new(
+                    locale.text("pause.title"),
+                    ResponsiveFontSize::Title,
+                    theme.primary,
+                )
+                .responsive(&responsive)
+                .strong()
+                .ui(ui);
 
                 ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
 
-                // Continue
-                if ThemedButton::new("Continue", &theme)
+                // Resume
+                if ThemedButton::new(locale.text("pause.resume"), &theme)
                     .responsive(&responsive)
                     .width(250.0)
                     .show(ui)
@@ -56,8 +64,32 @@ fn pause_menu_egui_ui(
 
                 ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
 
+                // Restart the round by passing through the loading screen
+                if ThemedButton::new(locale.text("pause.restart"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .show(ui)
+                    .clicked()
+                {
+                    next_screen.set(Screen::Loading);
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                // Questions and answers of the round so far
+                if ThemedButton::new(locale.text("pause.review_answers"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .show(ui)
+                    .clicked()
+                {
+                    next_menu.set(Menu::Review);
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
                 // Settings
-                if ThemedButton::new("Settings", &theme)
+                if ThemedButton::new(locale.text("pause.settings"), &theme)
                     .responsive(&responsive)
                     .width(250.0)
                     .show(ui)
@@ -68,8 +100,23 @@ fn pause_menu_egui_ui(
 
                 ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
 
-                // Quit to title
-                if ThemedButton::new("Quit to title", &theme)
+                // Capture a state snapshot and open the issue page
+                #[cfg(not(feature = "minimal-web"))]
+                {
+                    if ThemedButton::new(locale.text("pause.report_bug"), &theme)
+                        .responsive(&responsive)
+                        .width(250.0)
+                        .show(ui)
+                        .clicked()
+                    {
+                        bug_reports.write(BugReportRequested);
+                    }
+
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                }
+
+                // Quit to the main menu
+                if ThemedButton::new(locale.text("pause.quit_to_menu"), &theme)
                     .responsive(&responsive)
                     .width(250.0)
                     .show(ui)
@@ -0,0 +1,228 @@
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use bevy_egui::{
+    EguiContextPass,
+    egui::{self, Widget},
+};
+use konnektoren_bevy::prelude::*;
+
+use crate::{
+    locale::Locale,
+    menus::Menu,
+    settings::{GameSettings, Handicap, MAX_HANDICAP_MULTIPLIER, PLAYER_COLORS, PlayerShape},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        EguiContextPass,
+        players_menu_egui_ui.run_if(in_state(Menu::DeviceSelection)),
+    );
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::DeviceSelection).and(input_just_pressed(KeyCode::Escape))),
+    );
+}
+
+/// A change picked in the panel, applied once the UI is drawn
+enum PlayerChange {
+    Name(usize, String),
+    Color(usize, Color),
+    Shape(usize, PlayerShape),
+    Handicap(usize, Handicap),
+}
+
+fn players_menu_egui_ui(
+    mut contexts: bevy_egui::EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    locale: Res<Locale>,
+    mut game_settings: ResMut<GameSettings>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    let ctx = contexts.ctx_mut();
+    let multiplayer = &game_settings.multiplayer;
+    let mut changes = Vec::new();
+
+    egui::CentralPanel::default()
+        .frame(egui::Frame::NONE.fill(theme.base_100))
+        .show(ctx, |ui| {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+            ui.vertical_centered(|ui| {
+                ResponsiveText::new(
+                    locale.text("players.title"),
+                    ResponsiveFontSize::Title,
+                    theme.primary,
+                )
+                .responsive(&responsive)
+                .strong()
+                .ui(ui);
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                ui.label(locale.text("players.hint"));
+                ui.label(locale.text("players.handicap_hint"));
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                egui::ScrollArea::vertical()
+                    .max_height(ui.available_height() - 80.0)
+                    .show(ui, |ui| {
+                        for player_index in 0..multiplayer.player_count {
+                            let Some(player) = multiplayer.players.get(player_index) else {
+                                continue;
+                            };
+
+                            egui::Grid::new(format!("player_grid_{}", player_index))
+                                .spacing([16.0, 8.0])
+                                .show(ui, |ui| {
+                                    ui.label(
+                                        egui::RichText::new(locale.format(
+                                            "players.player",
+                                            &[("number", &(player_index + 1))],
+                                        ))
+                                        .strong()
+                                        .color(egui_color(player.color)),
+                                    );
+                                    let mut name = player.name.clone();
+                                    let response = egui::TextEdit::singleline(&mut name)
+                                        .char_limit(MAX_PLAYER_NAME_LENGTH)
+                                        .desired_width(200.0)
+                                        .ui(ui);
+                                    if response.changed() {
+                                        changes.push(PlayerChange::Name(player_index, name));
+                                    }
+                                    ui.end_row();
+
+                                    ui.label(locale.text("players.color"));
+                                    ui.horizontal(|ui| {
+                                        for color in PLAYER_COLORS {
+                                            let selected = player.color == color;
+                                            let taken =
+                                                multiplayer.color_taken(color, player_index);
+                                            let stroke = if selected {
+                                                egui::Stroke::new(3.0, theme.primary)
+                                            } else {
+                                                egui::Stroke::NONE
+                                            };
+                                            let swatch = egui::Button::new("")
+                                                .fill(egui_color(color))
+                                                .stroke(stroke)
+                                                .min_size(egui::vec2(28.0, 28.0));
+                                            // Two players in one color can't be told apart
+                                            let response = ui
+                                                .add_enabled(!taken, swatch)
+                                                .on_disabled_hover_text(
+                                                    locale.text("players.color_taken"),
+                                                );
+                                            if response.clicked() && !selected {
+                                                changes
+                                                    .push(PlayerChange::Color(player_index, color));
+                                            }
+                                        }
+                                    });
+                                    ui.end_row();
+
+                                    ui.label(locale.text("players.shape"));
+                                    ui.horizontal(|ui| {
+                                        for shape in PlayerShape::ALL {
+                                            if ui
+                                                .selectable_label(
+                                                    player.shape == shape,
+                                                    locale.text(shape_key(shape)),
+                                                )
+                                                .clicked()
+                                            {
+                                                changes
+                                                    .push(PlayerChange::Shape(player_index, shape));
+                                            }
+                                        }
+                                    });
+                                    ui.end_row();
+
+                                    let mut handicap = player.handicap;
+                                    for (label, value) in [
+                                        ("players.score_bonus", &mut handicap.score_multiplier),
+                                        (
+                                            "players.option_time",
+                                            &mut handicap.option_expiry_multiplier,
+                                        ),
+                                        (
+                                            "players.merge_window",
+                                            &mut handicap.merge_window_multiplier,
+                                        ),
+                                    ] {
+                                        ui.label(locale.text(label));
+                                        egui::Slider::new(value, 1.0..=MAX_HANDICAP_MULTIPLIER)
+                                            .step_by(HANDICAP_STEP)
+                                            .suffix("×")
+                                            .ui(ui);
+                                        ui.end_row();
+                                    }
+                                    if handicap != player.handicap {
+                                        changes
+                                            .push(PlayerChange::Handicap(player_index, handicap));
+                                    }
+                                });
+
+                            ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                        }
+                    });
+
+                if ThemedButton::new(locale.text("players.back"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .show(ui)
+                    .clicked()
+                {
+                    next_menu.set(Menu::Settings);
+                }
+            });
+        });
+
+    for change in changes {
+        match change {
+            PlayerChange::Name(index, name) => {
+                if let Some(player) = game_settings.multiplayer.players.get_mut(index) {
+                    player.name = name;
+                }
+            }
+            PlayerChange::Color(index, color) => {
+                if let Some(player) = game_settings.multiplayer.players.get_mut(index) {
+                    player.color = color;
+                    info!("Player {} now plays in {:?}", index + 1, color);
+                }
+            }
+            PlayerChange::Shape(index, shape) => {
+                if let Some(player) = game_settings.multiplayer.players.get_mut(index) {
+                    player.shape = shape;
+                    info!("Player {} now plays as a {}", index + 1, shape.name());
+                }
+            }
+            PlayerChange::Handicap(index, handicap) => {
+                if let Some(player) = game_settings.multiplayer.players.get_mut(index) {
+                    player.handicap = handicap;
+                    info!("Player {} now plays with {:?}", index + 1, handicap);
+                }
+            }
+        }
+    }
+}
+
+fn shape_key(shape: PlayerShape) -> &'static str {
+    match shape {
+        PlayerShape::Circle => "players.shape_circle",
+        PlayerShape::Square => "players.shape_square",
+        PlayerShape::Triangle => "players.shape_triangle",
+        PlayerShape::Diamond => "players.shape_diamond",
+    }
+}
+
+fn egui_color(color: Color) -> egui::Color32 {
+    let [red, green, blue, _] = color.to_srgba().to_u8_array();
+    egui::Color32::from_rgb(red, green, blue)
+}
+
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Settings);
+}
+
+const MAX_PLAYER_NAME_LENGTH: usize = 16;
+const HANDICAP_STEP: f64 = 0.25;
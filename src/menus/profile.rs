@@ -0,0 +1,134 @@
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use bevy_egui::{
+    EguiContextPass,
+    egui::{self, Widget},
+};
+use konnektoren_bevy::prelude::*;
+
+use crate::{
+    locale::Locale,
+    menus::Menu,
+    profile::{ProfileAccount, ProfileLoginEvent, ProfileLogoutEvent, ProfileTokenInput},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        EguiContextPass,
+        profile_menu_egui_ui.run_if(in_state(Menu::Profile)),
+    );
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::Profile).and(input_just_pressed(KeyCode::Escape))),
+    );
+}
+
+fn profile_menu_egui_ui(
+    mut contexts: bevy_egui::EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    locale: Res<Locale>,
+    account: Res<ProfileAccount>,
+    mut token_input: ResMut<ProfileTokenInput>,
+    mut login_events: EventWriter<ProfileLoginEvent>,
+    mut logout_events: EventWriter<ProfileLogoutEvent>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    let ctx = contexts.ctx_mut();
+
+    egui::CentralPanel::default()
+        .frame(egui::Frame::NONE.fill(theme.base_100))
+        .show(ctx, |ui| {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+            ui.vertical_centered(|ui| {
+                ResponsiveText::new(
+                    locale.text("profile.title"),
+                    ResponsiveFontSize::Title,
+                    theme.primary,
+                )
+                .responsive(&responsive)
+                .strong()
+                .ui(ui);
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+                if account.is_connected() {
+                    let name = account.display_name.as_deref().unwrap_or("...");
+                    ui.label(locale.format("profile.connected", &[("name", &name)]));
+                } else {
+                    ui.label(locale.text("profile.not_connected"));
+                }
+
+                if !account.pending_results.is_empty() {
+                    ui.label(locale.format(
+                        "profile.pending_results",
+                        &[("count", &account.pending_results.len())],
+                    ));
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+                if account.is_connected() {
+                    if ThemedButton::new(locale.text("profile.disconnect"), &theme)
+                        .responsive(&responsive)
+                        .width(250.0)
+                        .show(ui)
+                        .clicked()
+                    {
+                        logout_events.write(ProfileLogoutEvent);
+                    }
+                } else {
+                    // On the web, log in on konnektoren.help and come back with a token
+                    #[cfg(target_family = "wasm")]
+                    {
+                        if ThemedButton::new(locale.text("profile.login_web"), &theme)
+                            .responsive(&responsive)
+                            .width(250.0)
+                            .show(ui)
+                            .clicked()
+                        {
+                            if let Some(url) = crate::profile::web_login_url() {
+                                if let Some(window) = web_sys::window() {
+                                    let _ = window.location().set_href(&url);
+                                }
+                            }
+                        }
+
+                        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                    }
+
+                    ui.add(
+                        egui::TextEdit::singleline(&mut token_input.0)
+                            .hint_text(locale.text("profile.token_hint"))
+                            .password(true)
+                            .desired_width(250.0),
+                    );
+
+                    if ThemedButton::new(locale.text("profile.connect"), &theme)
+                        .responsive(&responsive)
+                        .width(250.0)
+                        .enabled(!token_input.0.trim().is_empty())
+                        .show(ui)
+                        .clicked()
+                    {
+                        login_events.write(ProfileLoginEvent(std::mem::take(&mut token_input.0)));
+                    }
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+                if ThemedButton::new(locale.text("profile.back"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .show(ui)
+                    .clicked()
+                {
+                    next_menu.set(Menu::Main);
+                }
+            });
+        });
+}
+
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
@@ -0,0 +1,212 @@
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use bevy_egui::{
+    EguiContextPass,
+    egui::{self, Widget},
+};
+use konnektoren_bevy::prelude::*;
+
+#[cfg(not(feature = "minimal-web"))]
+use crate::{
+    export::{ExportFormat, ExportResultsEvent},
+    victory_lap::VictoryLap,
+};
+use crate::{
+    gameplay::GameTimer, locale::Locale, match_play::MatchState, menus::Menu,
+    question::QuestionSystem, review::AnswerHistory, screens::Screen,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        EguiContextPass,
+        review_menu_egui_ui.run_if(in_state(Menu::Review)),
+    );
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::Review).and(input_just_pressed(KeyCode::Escape))),
+    );
+}
+
+fn review_menu_egui_ui(
+    mut contexts: bevy_egui::EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    history: Res<AnswerHistory>,
+    locale: Res<Locale>,
+    #[cfg(not(feature = "minimal-web"))] victory_lap: Option<Res<VictoryLap>>,
+    question_system: Option<Res<QuestionSystem>>,
+    match_state: Option<Res<MatchState>>,
+    game_timer: Res<GameTimer>,
+    #[cfg(not(feature = "minimal-web"))] mut export_events: EventWriter<ExportResultsEvent>,
+    mut next_menu: ResMut<NextState<Menu>>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    let ctx = contexts.ctx_mut();
+    // Let the winner's victory lap show through behind the results
+    #[cfg(not(feature = "minimal-web"))]
+    let lap_running = victory_lap.is_some();
+    #[cfg(feature = "minimal-web")]
+    let lap_running = false;
+    let fill = if lap_running {
+        theme.base_100.gamma_multiply(VICTORY_LAP_PANEL_OPACITY)
+    } else {
+        theme.base_100
+    };
+    let correct_color = egui::Color32::from_rgb(90, 200, 110);
+    let wrong_color = egui::Color32::from_rgb(230, 90, 90);
+
+    egui::CentralPanel::default()
+        .frame(egui::Frame::NONE.fill(fill))
+        .show(ctx, |ui| {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+            ui.vertical_centered(|ui| {
+                ResponsiveText::new(
+                    locale.text("review.title"),
+                    ResponsiveFontSize::Title,
+                    theme.primary,
+                )
+                .responsive(&responsive)
+                .strong()
+                .ui(ui);
+
+                #[cfg(not(feature = "minimal-web"))]
+                {
+                    if let Some(victory_lap) = &victory_lap {
+                        ui.label(
+                            egui::RichText::new(
+                                locale
+                                    .format("review.winner", &[("name", &victory_lap.winner_name)]),
+                            )
+                            .strong()
+                            .color(theme.primary),
+                        );
+                    }
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                let mistakes: usize = history
+                    .questions
+                    .iter()
+                    .map(|q| q.answers.iter().filter(|a| !a.is_correct).count())
+                    .sum();
+                ui.label(locale.format(
+                    "review.summary",
+                    &[
+                        ("questions", &history.questions.len()),
+                        ("mistakes", &mistakes),
+                    ],
+                ));
+                if let Some(question_system) = question_system
+                    .as_ref()
+                    .filter(|question_system| !question_system.topics.is_empty())
+                {
+                    ui.label(locale.format(
+                        "review.topics",
+                        &[("topics", &question_system.topics.join(", "))],
+                    ));
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                egui::ScrollArea::vertical()
+                    .max_height(ui.available_height() - 280.0)
+                    .show(ui, |ui| {
+                        for (number, entry) in history.questions.iter().enumerate() {
+                            ui.label(
+                                egui::RichText::new(format!("{}. {}", number + 1, entry.question))
+                                    .strong()
+                                    .color(theme.primary),
+                            );
+                            ui.label(
+                                locale
+                                    .format("review.answer", &[("answer", &entry.correct_answer)]),
+                            );
+
+                            if entry.answers.is_empty() {
+                                ui.label(
+                                    egui::RichText::new(locale.text("review.no_options"))
+                                        .color(egui::Color32::GRAY),
+                                );
+                            }
+                            for answer in &entry.answers {
+                                let (mark, color) = if answer.is_correct {
+                                    ("✔", correct_color)
+                                } else {
+                                    ("✖", wrong_color)
+                                };
+                                ui.label(
+                                    egui::RichText::new(format!(
+                                        "{} {}: {}",
+                                        mark, answer.player_name, answer.option_text
+                                    ))
+                                    .color(color),
+                                );
+                            }
+
+                            ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                        }
+                    });
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+                // Answer history and stats for gradebooks
+                #[cfg(not(feature = "minimal-web"))]
+                {
+                    for (label, format) in [
+                        ("review.export_csv", ExportFormat::Csv),
+                        ("review.export_json", ExportFormat::Json),
+                    ] {
+                        if ThemedButton::new(locale.text(label), &theme)
+                            .responsive(&responsive)
+                            .width(250.0)
+                            .show(ui)
+                            .clicked()
+                        {
+                            export_events.write(ExportResultsEvent(format));
+                        }
+                        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                    }
+                }
+
+                if ThemedButton::new(locale.text("review.continue"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .show(ui)
+                    .clicked()
+                {
+                    next_menu.set(closed_review_menu(match_state.is_some(), &game_timer));
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                if ThemedButton::new(locale.text("review.quit_to_menu"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .show(ui)
+                    .clicked()
+                {
+                    next_screen.set(Screen::Title);
+                }
+            });
+        });
+}
+
+fn go_back(
+    match_state: Option<Res<MatchState>>,
+    game_timer: Res<GameTimer>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    next_menu.set(closed_review_menu(match_state.is_some(), &game_timer));
+}
+
+/// After a match round the review is opened from the round summary and returns there
+fn closed_review_menu(in_match: bool, game_timer: &GameTimer) -> Menu {
+    if in_match && game_timer.is_overtime {
+        Menu::RoundSummary
+    } else {
+        Menu::None
+    }
+}
+
+const VICTORY_LAP_PANEL_OPACITY: f32 = 0.75;
@@ -0,0 +1,165 @@
+use bevy::prelude::*;
+use bevy_egui::{
+    EguiContextPass,
+    egui::{self, Widget},
+};
+use konnektoren_bevy::prelude::*;
+
+#[cfg(not(feature = "minimal-web"))]
+use crate::victory_lap::VictoryLap;
+use crate::{
+    locale::Locale, match_play::MatchState, menus::Menu, screens::Screen, settings::GameSettings,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        EguiContextPass,
+        round_summary_egui_ui.run_if(in_state(Menu::RoundSummary)),
+    );
+}
+
+fn round_summary_egui_ui(
+    mut contexts: bevy_egui::EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    locale: Res<Locale>,
+    game_settings: Res<GameSettings>,
+    match_state: Option<Res<MatchState>>,
+    #[cfg(not(feature = "minimal-web"))] victory_lap: Option<Res<VictoryLap>>,
+    mut next_menu: ResMut<NextState<Menu>>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    let Some(match_state) = match_state else {
+        next_menu.set(Menu::Review);
+        return;
+    };
+
+    let ctx = contexts.ctx_mut();
+    // Let the round winner's victory lap show through behind the standings
+    #[cfg(not(feature = "minimal-web"))]
+    let lap_running = victory_lap.is_some();
+    #[cfg(feature = "minimal-web")]
+    let lap_running = false;
+    let fill = if lap_running {
+        theme.base_100.gamma_multiply(VICTORY_LAP_PANEL_OPACITY)
+    } else {
+        theme.base_100
+    };
+    let player_name = |slot: usize| {
+        game_settings
+            .multiplayer
+            .players
+            .get(slot)
+            .map(|player| player.name.clone())
+            .unwrap_or_else(|| format!("Player {}", slot + 1))
+    };
+
+    egui::CentralPanel::default()
+        .frame(egui::Frame::NONE.fill(fill))
+        .show(ctx, |ui| {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+            ui.vertical_centered(|ui| {
+                ResponsiveText::new(
+                    locale.format(
+                        "round_summary.title",
+                        &[
+                            ("round", &match_state.round),
+                            ("best_of", &match_state.best_of),
+                        ],
+                    ),
+                    ResponsiveFontSize::Title,
+                    theme.primary,
+                )
+                .responsive(&responsive)
+                .strong()
+                .ui(ui);
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                let round_result = match match_state.round_winner {
+                    Some(slot) => locale.format(
+                        "round_summary.round_winner",
+                        &[("name", &player_name(slot))],
+                    ),
+                    None => locale.text("round_summary.draw"),
+                };
+                ui.label(egui::RichText::new(round_result).strong());
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                for slot in match_state.standings() {
+                    let color = game_settings
+                        .multiplayer
+                        .players
+                        .get(slot)
+                        .map_or(theme.primary, |player| egui_color(player.color));
+                    ui.label(
+                        egui::RichText::new(locale.format(
+                            "round_summary.standings",
+                            &[
+                                ("name", &player_name(slot)),
+                                ("wins", &match_state.wins(slot)),
+                                ("score", &match_state.score(slot)),
+                            ],
+                        ))
+                        .color(color),
+                    );
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+                if match_state.finished {
+                    let result = match match_state.match_winner() {
+                        Some(slot) => locale.format(
+                            "round_summary.match_winner",
+                            &[("name", &player_name(slot))],
+                        ),
+                        None => locale.text("round_summary.match_over"),
+                    };
+                    ResponsiveText::new(result, ResponsiveFontSize::Header, theme.primary)
+                        .responsive(&responsive)
+                        .strong()
+                        .ui(ui);
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+                } else if ThemedButton::new(locale.text("round_summary.next_round"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .show(ui)
+                    .clicked()
+                {
+                    // The next round starts fresh through the loading screen
+                    next_screen.set(Screen::Loading);
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                if ThemedButton::new(locale.text("round_summary.review_answers"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .show(ui)
+                    .clicked()
+                {
+                    next_menu.set(Menu::Review);
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                if ThemedButton::new(locale.text("round_summary.quit_to_menu"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .show(ui)
+                    .clicked()
+                {
+                    next_screen.set(Screen::Title);
+                }
+            });
+        });
+}
+
+fn egui_color(color: Color) -> egui::Color32 {
+    let [red, green, blue, _] = color.to_srgba().to_u8_array();
+    egui::Color32::from_rgb(red, green, blue)
+}
+
+const VICTORY_LAP_PANEL_OPACITY: f32 = 0.75;
@@ -1,4 +1,10 @@
-use crate::settings::GameSettings;
+use crate::chain::{ChainOverflowRule, ReactionSpreadRule};
+use crate::display::DisplayMode;
+use crate::locale::{Language, Locale};
+use crate::map::{MapPreset, MapTheme};
+use crate::palette::ColorPalette;
+use crate::player::MovementModel;
+use crate::settings::{Difficulty, EffectsIntensity, GameSettings};
 use crate::{menus::Menu, screens::Screen};
 use bevy::{input::common_conditions::input_just_pressed, prelude::*};
 use konnektoren_bevy::input::InputEvent;
@@ -18,15 +24,14 @@ pub(super) fn plugin(app: &mut App) {
         );
 }
 
-fn spawn_settings_screen(mut commands: Commands, game_settings: Res<GameSettings>) {
+fn spawn_settings_screen(
+    mut commands: Commands,
+    game_settings: Res<GameSettings>,
+    locale: Res<Locale>,
+) {
     info!("Spawning settings screen");
 
-    let config = SettingsScreenConfig::new("Settings")
-        .mobile_layout(false)
-        .with_back_button_text("Back")
-        .add_section(SettingsSection::audio_section())
-        .add_section(create_multiplayer_section(&game_settings))
-        .add_section(SettingsSection::input_section());
+    let config = create_settings_config(&game_settings, &locale);
 
     commands.spawn((
         Name::new("Game Settings Screen"),
@@ -49,31 +54,419 @@ fn cleanup_settings_screen(
     info!("Cleaned up settings screen");
 }
 
-fn create_multiplayer_section(game_settings: &GameSettings) -> SettingsSection {
-    SettingsSection::new("Multiplayer")
+fn create_settings_config(game_settings: &GameSettings, locale: &Locale) -> SettingsScreenConfig {
+    #[cfg(not(feature = "minimal-web"))]
+    let audio_section = create_audio_section(game_settings, locale);
+    // Spoken words aren't part of the slim web bundle
+    #[cfg(feature = "minimal-web")]
+    let audio_section = SettingsSection::audio_section();
+
+    SettingsScreenConfig::new(locale.text("settings.title"))
+        .mobile_layout(false)
+        .with_back_button_text(locale.text("settings.back"))
+        .add_section(audio_section)
+        .add_section(create_gameplay_section(game_settings, locale))
+        .add_section(create_custom_difficulty_section(game_settings, locale))
+        .add_section(create_display_section(game_settings, locale))
+        .add_section(create_accessibility_section(game_settings, locale))
+        .add_section(create_multiplayer_section(game_settings, locale))
+        .add_section(SettingsSection::input_section())
+        .add_section(create_controls_section(locale))
+}
+
+#[cfg(not(feature = "minimal-web"))]
+fn create_audio_section(game_settings: &GameSettings, locale: &Locale) -> SettingsSection {
+    SettingsSection::audio_section()
+        .add_setting(ScreenSettingsItem::toggle(
+            "pronunciation",
+            locale.text("settings.pronunciation"),
+            game_settings.audio.pronunciation,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "pronunciation_volume",
+            locale.text("settings.pronunciation_volume"),
+            (game_settings.audio.pronunciation_volume * 100.0).round() as i32,
+            0,
+            100,
+            10,
+        ))
+}
+
+fn create_controls_section(locale: &Locale) -> SettingsSection {
+    // The settings screen has no plain buttons, so a toggle opens the key bindings page
+    SettingsSection::new(locale.text("settings.section.controls")).add_setting(
+        ScreenSettingsItem::toggle(
+            "edit_key_bindings",
+            locale.text("settings.edit_key_bindings"),
+            false,
+        ),
+    )
+}
+
+fn create_gameplay_section(game_settings: &GameSettings, locale: &Locale) -> SettingsSection {
+    SettingsSection::new(locale.text("settings.section.gameplay"))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "difficulty",
+            locale.text("settings.difficulty"),
+            game_settings.gameplay.difficulty.index() as i32,
+            0,
+            (Difficulty::ALL.len() - 1) as i32,
+            1,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "map_preset",
+            locale.text("settings.map_preset"),
+            game_settings.gameplay.map_preset.index() as i32,
+            0,
+            (MapPreset::ALL.len() - 1) as i32,
+            1,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "map_theme",
+            locale.text("settings.map_theme"),
+            game_settings.gameplay.map_theme.index() as i32,
+            0,
+            (MapTheme::ALL.len() - 1) as i32,
+            1,
+        ))
+        .add_setting(ScreenSettingsItem::toggle(
+            "cursed_segments",
+            locale.text("settings.cursed_segments"),
+            game_settings.gameplay.cursed_segments,
+        ))
+        .add_setting(ScreenSettingsItem::toggle(
+            "respawn_after_chain_reaction",
+            locale.text("settings.respawn_after_chain_reaction"),
+            game_settings.gameplay.respawn_after_chain_reaction,
+        ))
+        .add_setting(ScreenSettingsItem::toggle(
+            "hazards",
+            locale.text("settings.hazards"),
+            game_settings.gameplay.hazards,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "movement_model",
+            locale.text("settings.movement_model"),
+            game_settings.gameplay.movement_model.index() as i32,
+            0,
+            (MovementModel::ALL.len() - 1) as i32,
+            1,
+        ))
+        .add_setting(ScreenSettingsItem::toggle(
+            "learning_feedback",
+            locale.text("settings.learning_feedback"),
+            game_settings.gameplay.learning_feedback,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "max_chain_length",
+            locale.text("settings.max_chain_length"),
+            game_settings.gameplay.max_chain_length as i32,
+            5,
+            50,
+            5,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "chain_overflow",
+            locale.text("settings.chain_overflow"),
+            game_settings.gameplay.chain_overflow.index() as i32,
+            0,
+            (ChainOverflowRule::ALL.len() - 1) as i32,
+            1,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "reaction_spread",
+            locale.text("settings.reaction_spread"),
+            game_settings.gameplay.reaction_spread.index() as i32,
+            0,
+            (ReactionSpreadRule::ALL.len() - 1) as i32,
+            1,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "reaction_spread_limit",
+            locale.text("settings.reaction_spread_limit"),
+            game_settings.gameplay.reaction_spread_limit as i32,
+            1,
+            10,
+            1,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "correct_option_radius",
+            locale.text("settings.correct_option_radius"),
+            game_settings.gameplay.correct_option_radius as i32,
+            0,
+            20,
+            1,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "director_intensity",
+            locale.text("settings.director_intensity"),
+            game_settings.gameplay.director_intensity as i32,
+            0,
+            100,
+            10,
+        ))
+}
+
+fn create_custom_difficulty_section(
+    game_settings: &GameSettings,
+    locale: &Locale,
+) -> SettingsSection {
+    let tuning = &game_settings.gameplay.custom_tuning;
+    SettingsSection::new(locale.text("settings.section.custom_difficulty"))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "custom_question_duration",
+            locale.text("settings.custom_question_duration"),
+            tuning.question_duration.round() as i32,
+            5,
+            30,
+            1,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "custom_option_lifetime",
+            locale.text("settings.custom_option_lifetime"),
+            tuning.option_lifetime.round() as i32,
+            3,
+            20,
+            1,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "custom_option_spawn_interval",
+            locale.text("settings.custom_option_spawn_interval"),
+            (tuning.option_spawn_interval * 10.0).round() as i32,
+            3,
+            30,
+            1,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "custom_player_speed",
+            locale.text("settings.custom_player_speed"),
+            tuning.player_move_speed.round() as i32,
+            100,
+            400,
+            20,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "custom_moving_option_share",
+            locale.text("settings.custom_moving_option_share"),
+            (tuning.moving_option_share * 100.0).round() as i32,
+            0,
+            100,
+            5,
+        ))
+}
+
+fn create_display_section(game_settings: &GameSettings, locale: &Locale) -> SettingsSection {
+    let section = SettingsSection::new(locale.text("settings.section.display")).add_setting(
+        ScreenSettingsItem::int_slider(
+            "window_mode",
+            locale.text("settings.window_mode"),
+            game_settings.display.window_mode.index() as i32,
+            0,
+            (DisplayMode::ALL.len() - 1) as i32,
+            1,
+        ),
+    );
+
+    // The web canvas is sized by the page
+    #[cfg(not(target_family = "wasm"))]
+    let section = section.add_setting(ScreenSettingsItem::int_slider(
+        "resolution",
+        locale.text("settings.resolution"),
+        game_settings.display.resolution_index as i32,
+        0,
+        (crate::display::RESOLUTIONS.len() - 1) as i32,
+        1,
+    ));
+
+    section
+        .add_setting(ScreenSettingsItem::toggle(
+            "vsync",
+            locale.text("settings.vsync"),
+            game_settings.display.vsync,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "ui_scale_percent",
+            locale.text("settings.ui_scale_percent"),
+            game_settings.display.ui_scale_percent as i32,
+            crate::display::MIN_UI_SCALE_PERCENT as i32,
+            crate::display::MAX_UI_SCALE_PERCENT as i32,
+            5,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "language",
+            locale.text("settings.language"),
+            game_settings.display.language.index() as i32,
+            0,
+            (Language::ALL.len() - 1) as i32,
+            1,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "hud_scale_percent",
+            locale.text("settings.hud_scale_percent"),
+            game_settings.display.hud_scale_percent as i32,
+            crate::gameplay::MIN_HUD_SCALE_PERCENT as i32,
+            crate::gameplay::MAX_HUD_SCALE_PERCENT as i32,
+            10,
+        ))
+        .add_setting(ScreenSettingsItem::toggle(
+            "show_fps",
+            locale.text("settings.show_fps"),
+            game_settings.display.show_fps,
+        ))
+        .add_setting(ScreenSettingsItem::toggle(
+            "show_clock",
+            locale.text("settings.show_clock"),
+            game_settings.display.show_clock,
+        ))
+        .add_setting(ScreenSettingsItem::toggle(
+            "slow_motion",
+            locale.text("settings.slow_motion"),
+            game_settings.display.slow_motion,
+        ))
+        .add_setting(ScreenSettingsItem::toggle(
+            "screen_shake",
+            locale.text("settings.screen_shake"),
+            game_settings.display.screen_shake,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "color_palette",
+            locale.text("settings.color_palette"),
+            game_settings.display.color_palette.index() as i32,
+            0,
+            (ColorPalette::ALL.len() - 1) as i32,
+            1,
+        ))
+        .add_setting(ScreenSettingsItem::toggle(
+            "shape_markers",
+            locale.text("settings.shape_markers"),
+            game_settings.display.shape_markers,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "session_reminder_minutes",
+            locale.text("settings.session_reminder_minutes"),
+            game_settings.display.session_reminder_minutes as i32,
+            0,
+            120,
+            15,
+        ))
+}
+
+fn create_accessibility_section(game_settings: &GameSettings, locale: &Locale) -> SettingsSection {
+    let section = SettingsSection::new(locale.text("settings.section.accessibility"));
+    // Speech and captions aren't part of the slim web bundle
+    #[cfg(not(feature = "minimal-web"))]
+    let section = section
+        .add_setting(ScreenSettingsItem::toggle(
+            "read_questions",
+            locale.text("settings.read_questions"),
+            game_settings.accessibility.read_questions,
+        ))
+        .add_setting(ScreenSettingsItem::toggle(
+            "announce_collections",
+            locale.text("settings.announce_collections"),
+            game_settings.accessibility.announce_collections,
+        ))
+        .add_setting(ScreenSettingsItem::toggle(
+            "captions",
+            locale.text("settings.captions"),
+            game_settings.accessibility.captions,
+        ));
+
+    section.add_setting(ScreenSettingsItem::int_slider(
+        "effects_intensity",
+        locale.text("settings.effects_intensity"),
+        game_settings.accessibility.effects_intensity.index() as i32,
+        0,
+        (EffectsIntensity::ALL.len() - 1) as i32,
+        1,
+    ))
+}
+
+fn create_multiplayer_section(game_settings: &GameSettings, locale: &Locale) -> SettingsSection {
+    SettingsSection::new(locale.text("settings.section.multiplayer"))
         .add_setting(ScreenSettingsItem::toggle(
             "multiplayer_enabled",
-            "Enable Multiplayer",
+            locale.text("settings.multiplayer_enabled"),
             game_settings.multiplayer.enabled,
         ))
         .add_setting(ScreenSettingsItem::int_slider(
             "player_count",
-            "Number of Players",
+            locale.text("settings.player_count"),
             game_settings.multiplayer.player_count as i32,
             1,
             crate::settings::MAX_PLAYERS as i32,
             1,
         ))
+        .add_setting(ScreenSettingsItem::toggle(
+            "configure_players",
+            locale.text("settings.configure_players"),
+            false,
+        ))
         .add_setting(ScreenSettingsItem::toggle(
             "auto_assign_inputs",
-            "Auto Assign Inputs",
+            locale.text("settings.auto_assign_inputs"),
             game_settings.multiplayer.auto_assign_inputs,
         ))
         .add_setting(ScreenSettingsItem::toggle(
             "auto_detect_players",
-            "Auto Detect Players",
+            locale.text("settings.auto_detect_players"),
             game_settings.multiplayer.auto_detect_players,
         ))
+        .add_setting(ScreenSettingsItem::toggle(
+            "coop_shared_chain",
+            locale.text("settings.coop_shared_chain"),
+            game_settings.multiplayer.coop_shared_chain,
+        ))
+        .add_setting(ScreenSettingsItem::toggle(
+            "chain_steal_enabled",
+            locale.text("settings.chain_steal_enabled"),
+            game_settings.multiplayer.chain_steal_enabled,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "chain_steal_segments",
+            locale.text("settings.chain_steal_segments"),
+            game_settings.multiplayer.chain_steal_segments as i32,
+            1,
+            10,
+            1,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "bot_count",
+            locale.text("settings.bot_count"),
+            game_settings.multiplayer.bot_count as i32,
+            0,
+            (crate::settings::MAX_PLAYERS - 1) as i32,
+            1,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "bot_difficulty",
+            locale.text("settings.bot_difficulty"),
+            game_settings.multiplayer.bot_difficulty.index() as i32,
+            0,
+            (Difficulty::PRESETS.len() - 1) as i32,
+            1,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "idle_timeout_seconds",
+            locale.text("settings.idle_timeout_seconds"),
+            game_settings.multiplayer.idle_timeout_seconds as i32,
+            0,
+            crate::idle::MAX_IDLE_TIMEOUT_SECONDS as i32,
+            5,
+        ))
+        .add_setting(ScreenSettingsItem::toggle(
+            "idle_bot_takeover",
+            locale.text("settings.idle_bot_takeover"),
+            game_settings.multiplayer.idle_bot_takeover,
+        ))
+        .add_setting(ScreenSettingsItem::int_slider(
+            "match_rounds",
+            locale.text("settings.match_rounds"),
+            game_settings.multiplayer.match_rounds as i32,
+            1,
+            crate::match_play::MAX_MATCH_ROUNDS as i32,
+            2,
+        ))
 }
 
 fn handle_settings_events(
@@ -99,6 +492,262 @@ fn handle_settings_events(
                             );
                         }
                     }
+                    "pronunciation" => {
+                        if let Some(enabled) = value.as_bool() {
+                            game_settings.audio.pronunciation = enabled;
+                            info!("Updated pronunciation to: {}", enabled);
+                        }
+                    }
+                    "pronunciation_volume" => {
+                        if let Some(percent) = value.as_int() {
+                            game_settings.audio.pronunciation_volume =
+                                percent.clamp(0, 100) as f32 / 100.0;
+                            info!("Updated pronunciation volume to: {}%", percent);
+                        }
+                    }
+                    "difficulty" => {
+                        if let Some(index) = value.as_int() {
+                            let difficulty = Difficulty::from_index(index.max(0) as usize);
+                            game_settings.gameplay.difficulty = difficulty;
+                            info!("Updated difficulty to: {}", difficulty.name());
+                        }
+                    }
+                    "custom_question_duration" => {
+                        if let Some(seconds) = value.as_int() {
+                            game_settings.gameplay.custom_tuning.question_duration =
+                                seconds.max(1) as f32;
+                            info!("Updated custom question duration to: {}s", seconds);
+                        }
+                    }
+                    "custom_option_lifetime" => {
+                        if let Some(seconds) = value.as_int() {
+                            game_settings.gameplay.custom_tuning.option_lifetime =
+                                seconds.max(1) as f32;
+                            info!("Updated custom option lifetime to: {}s", seconds);
+                        }
+                    }
+                    "custom_moving_option_share" => {
+                        if let Some(percent) = value.as_int() {
+                            game_settings.gameplay.custom_tuning.moving_option_share =
+                                percent.clamp(0, 100) as f32 / 100.0;
+                            info!("Updated custom moving option share to: {}%", percent);
+                        }
+                    }
+                    "custom_option_spawn_interval" => {
+                        if let Some(tenths) = value.as_int() {
+                            game_settings.gameplay.custom_tuning.option_spawn_interval =
+                                tenths.max(1) as f32 / 10.0;
+                            info!(
+                                "Updated custom option spawn interval to: {:.1}s",
+                                tenths as f32 / 10.0
+                            );
+                        }
+                    }
+                    "custom_player_speed" => {
+                        if let Some(speed) = value.as_int() {
+                            game_settings.gameplay.custom_tuning.player_move_speed =
+                                speed.max(1) as f32;
+                            info!("Updated custom player speed to: {}", speed);
+                        }
+                    }
+                    "map_preset" => {
+                        if let Some(index) = value.as_int() {
+                            let preset = MapPreset::from_index(index.max(0) as usize);
+                            game_settings.gameplay.map_preset = preset;
+                            info!("Updated map preset to: {}", preset.name());
+                        }
+                    }
+                    "map_theme" => {
+                        if let Some(index) = value.as_int() {
+                            let theme = MapTheme::from_index(index.max(0) as usize);
+                            game_settings.gameplay.map_theme = theme;
+                            info!("Updated map theme to: {}", theme.name());
+                        }
+                    }
+                    "cursed_segments" => {
+                        if let Some(enabled) = value.as_bool() {
+                            game_settings.gameplay.cursed_segments = enabled;
+                            info!("Updated cursed segments to: {}", enabled);
+                        }
+                    }
+                    "respawn_after_chain_reaction" => {
+                        if let Some(enabled) = value.as_bool() {
+                            game_settings.gameplay.respawn_after_chain_reaction = enabled;
+                            info!("Updated respawn after chain reaction to: {}", enabled);
+                        }
+                    }
+                    "hazards" => {
+                        if let Some(enabled) = value.as_bool() {
+                            game_settings.gameplay.hazards = enabled;
+                            info!("Updated hazards to: {}", enabled);
+                        }
+                    }
+                    "movement_model" => {
+                        if let Some(index) = value.as_int() {
+                            let model = MovementModel::from_index(index.max(0) as usize);
+                            game_settings.gameplay.movement_model = model;
+                            info!("Updated movement model to: {}", model.name());
+                        }
+                    }
+                    "learning_feedback" => {
+                        if let Some(enabled) = value.as_bool() {
+                            game_settings.gameplay.learning_feedback = enabled;
+                            info!("Updated learning feedback to: {}", enabled);
+                        }
+                    }
+                    "max_chain_length" => {
+                        if let Some(length) = value.as_int() {
+                            game_settings.gameplay.max_chain_length = length.max(1) as usize;
+                            info!("Updated max chain length to: {}", length);
+                        }
+                    }
+                    "chain_overflow" => {
+                        if let Some(index) = value.as_int() {
+                            let rule = ChainOverflowRule::from_index(index.max(0) as usize);
+                            game_settings.gameplay.chain_overflow = rule;
+                            info!("Updated chain overflow rule to: {}", rule.name());
+                        }
+                    }
+                    "reaction_spread" => {
+                        if let Some(index) = value.as_int() {
+                            let rule = ReactionSpreadRule::from_index(index.max(0) as usize);
+                            game_settings.gameplay.reaction_spread = rule;
+                            info!("Updated reaction spread rule to: {}", rule.name());
+                        }
+                    }
+                    "reaction_spread_limit" => {
+                        if let Some(limit) = value.as_int() {
+                            game_settings.gameplay.reaction_spread_limit = limit.max(1) as usize;
+                            info!("Updated reaction spread limit to: {} segments", limit);
+                        }
+                    }
+                    "correct_option_radius" => {
+                        if let Some(radius) = value.as_int() {
+                            game_settings.gameplay.correct_option_radius = radius.max(0) as usize;
+                            info!("Updated correct option radius to: {} cells", radius);
+                        }
+                    }
+                    "director_intensity" => {
+                        if let Some(intensity) = value.as_int() {
+                            game_settings.gameplay.director_intensity =
+                                intensity.clamp(0, 100) as u32;
+                            info!("Updated difficulty director intensity to: {}%", intensity);
+                        }
+                    }
+                    "language" => {
+                        if let Some(index) = value.as_int() {
+                            let language = Language::from_index(index.max(0) as usize);
+                            game_settings.display.language = language;
+                            info!("Updated language to: {}", language.name());
+                        }
+                    }
+                    "read_questions" => {
+                        if let Some(enabled) = value.as_bool() {
+                            game_settings.accessibility.read_questions = enabled;
+                            info!("Updated read questions to: {}", enabled);
+                        }
+                    }
+                    "announce_collections" => {
+                        if let Some(enabled) = value.as_bool() {
+                            game_settings.accessibility.announce_collections = enabled;
+                            info!("Updated announce collections to: {}", enabled);
+                        }
+                    }
+                    "captions" => {
+                        if let Some(enabled) = value.as_bool() {
+                            game_settings.accessibility.captions = enabled;
+                            info!("Updated captions to: {}", enabled);
+                        }
+                    }
+                    "effects_intensity" => {
+                        if let Some(index) = value.as_int() {
+                            let intensity = EffectsIntensity::from_index(index.max(0) as usize);
+                            game_settings.accessibility.effects_intensity = intensity;
+                            info!("Updated effects intensity to: {}", intensity.name());
+                        }
+                    }
+                    "window_mode" => {
+                        if let Some(index) = value.as_int() {
+                            let mode = DisplayMode::from_index(index.max(0) as usize);
+                            game_settings.display.window_mode = mode;
+                            info!("Updated window mode to: {}", mode.name());
+                        }
+                    }
+                    "resolution" => {
+                        if let Some(index) = value.as_int() {
+                            game_settings.display.resolution_index = index.max(0) as usize;
+                            let (width, height) =
+                                crate::display::resolution(game_settings.display.resolution_index);
+                            info!("Updated resolution to: {}x{}", width, height);
+                        }
+                    }
+                    "vsync" => {
+                        if let Some(enabled) = value.as_bool() {
+                            game_settings.display.vsync = enabled;
+                            info!("Updated vsync to: {}", enabled);
+                        }
+                    }
+                    "ui_scale_percent" => {
+                        if let Some(percent) = value.as_int() {
+                            game_settings.display.ui_scale_percent = (percent.max(0) as u32).clamp(
+                                crate::display::MIN_UI_SCALE_PERCENT,
+                                crate::display::MAX_UI_SCALE_PERCENT,
+                            );
+                            info!(
+                                "Updated UI scale to: {}%",
+                                game_settings.display.ui_scale_percent
+                            );
+                        }
+                    }
+                    "show_fps" => {
+                        if let Some(enabled) = value.as_bool() {
+                            game_settings.display.show_fps = enabled;
+                            info!("Updated show FPS to: {}", enabled);
+                        }
+                    }
+                    "show_clock" => {
+                        if let Some(enabled) = value.as_bool() {
+                            game_settings.display.show_clock = enabled;
+                            info!("Updated show clock to: {}", enabled);
+                        }
+                    }
+                    "slow_motion" => {
+                        if let Some(enabled) = value.as_bool() {
+                            game_settings.display.slow_motion = enabled;
+                            info!("Updated slow motion to: {}", enabled);
+                        }
+                    }
+                    "screen_shake" => {
+                        if let Some(enabled) = value.as_bool() {
+                            game_settings.display.screen_shake = enabled;
+                            info!("Updated screen shake to: {}", enabled);
+                        }
+                    }
+                    "color_palette" => {
+                        if let Some(index) = value.as_int() {
+                            let palette = ColorPalette::from_index(index.max(0) as usize);
+                            game_settings.display.color_palette = palette;
+                            info!("Updated color palette to: {}", palette.name());
+                        }
+                    }
+                    "hud_scale_percent" => {
+                        if let Some(percent) = value.as_int() {
+                            game_settings.display.hud_scale_percent = percent.max(0) as u32;
+                            info!("Updated HUD scale to: {}%", percent);
+                        }
+                    }
+                    "shape_markers" => {
+                        if let Some(enabled) = value.as_bool() {
+                            game_settings.display.shape_markers = enabled;
+                            info!("Updated shape markers to: {}", enabled);
+                        }
+                    }
+                    "session_reminder_minutes" => {
+                        if let Some(minutes) = value.as_int() {
+                            game_settings.display.session_reminder_minutes = minutes.max(0) as u32;
+                            info!("Updated session reminder to: {} minutes", minutes);
+                        }
+                    }
                     "multiplayer_enabled" => {
                         if let Some(enabled) = value.as_bool() {
                             game_settings.multiplayer.enable_multiplayer(enabled);
@@ -131,11 +780,64 @@ fn handle_settings_events(
                         }
                     }
                     "configure_players" => {
-                        // Handle the "Configure Players" button click
-                        info!("Opening device selection");
+                        info!("Opening player customization");
                         next_menu.set(Menu::DeviceSelection);
                         return; // Don't handle dismissed event after this
                     }
+                    "edit_key_bindings" => {
+                        info!("Opening key bindings");
+                        next_menu.set(Menu::KeyBindings);
+                        return;
+                    }
+                    "coop_shared_chain" => {
+                        if let Some(enabled) = value.as_bool() {
+                            game_settings.multiplayer.coop_shared_chain = enabled;
+                            info!("Updated co-op shared chain to: {}", enabled);
+                        }
+                    }
+                    "chain_steal_enabled" => {
+                        if let Some(enabled) = value.as_bool() {
+                            game_settings.multiplayer.chain_steal_enabled = enabled;
+                            info!("Updated chain steal to: {}", enabled);
+                        }
+                    }
+                    "chain_steal_segments" => {
+                        if let Some(count) = value.as_int() {
+                            game_settings.multiplayer.chain_steal_segments = count.max(1) as usize;
+                            info!("Updated chain steal segments to: {}", count);
+                        }
+                    }
+                    "bot_count" => {
+                        if let Some(count) = value.as_int() {
+                            game_settings.multiplayer.bot_count = count.max(0) as usize;
+                            info!("Updated bot count to: {}", count);
+                        }
+                    }
+                    "bot_difficulty" => {
+                        if let Some(index) = value.as_int() {
+                            let difficulty = Difficulty::from_index(index.max(0) as usize);
+                            game_settings.multiplayer.bot_difficulty = difficulty;
+                            info!("Updated bot difficulty to: {}", difficulty.name());
+                        }
+                    }
+                    "idle_timeout_seconds" => {
+                        if let Some(seconds) = value.as_int() {
+                            game_settings.multiplayer.idle_timeout_seconds = seconds.max(0) as u32;
+                            info!("Updated idle timeout to: {} seconds", seconds);
+                        }
+                    }
+                    "idle_bot_takeover" => {
+                        if let Some(enabled) = value.as_bool() {
+                            game_settings.multiplayer.idle_bot_takeover = enabled;
+                            info!("Updated idle bot takeover to: {}", enabled);
+                        }
+                    }
+                    "match_rounds" => {
+                        if let Some(rounds) = value.as_int() {
+                            game_settings.multiplayer.match_rounds = rounds.max(1) as u32;
+                            info!("Updated match rounds to: best of {}", rounds);
+                        }
+                    }
                     _ => warn!("Unhandled setting: {}", setting_id),
                 }
             }
@@ -171,12 +873,7 @@ fn handle_input_configuration_events(
                 }
 
                 // Respawn the settings screen
-                let config = SettingsScreenConfig::new("Settings")
-                    .mobile_layout(false)
-                    .with_back_button_text("Back")
-                    .add_section(SettingsSection::audio_section())
-                    .add_section(create_multiplayer_section(&game_settings))
-                    .add_section(SettingsSection::input_section());
+                let config = create_settings_config(&game_settings);
 
                 commands.spawn((
                     Name::new("Game Settings Screen"),
@@ -0,0 +1,101 @@
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use bevy_egui::{
+    EguiContextPass,
+    egui::{self, Widget},
+};
+use konnektoren_bevy::prelude::*;
+
+use crate::{menus::Menu, stats::PlayerStatistics};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        EguiContextPass,
+        statistics_menu_egui_ui.run_if(in_state(Menu::Statistics)),
+    );
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::Statistics).and(input_just_pressed(KeyCode::Escape))),
+    );
+}
+
+fn statistics_menu_egui_ui(
+    mut contexts: bevy_egui::EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    statistics: Res<PlayerStatistics>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    let ctx = contexts.ctx_mut();
+
+    egui::CentralPanel::default()
+        .frame(egui::Frame::NONE.fill(theme.base_100))
+        .show(ctx, |ui| {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+            ui.vertical_centered(|ui| {
+                ResponsiveText::new("Statistics", ResponsiveFontSize::Title, theme.primary)
+                    .responsive(&responsive)
+                    .strong()
+                    .ui(ui);
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+                egui::Grid::new("statistics_summary_grid")
+                    .spacing([24.0, 8.0])
+                    .show(ui, |ui| {
+                        let rows = [
+                            ("Games played", statistics.games_played.to_string()),
+                            ("Correct answers", statistics.correct_answers.to_string()),
+                            ("Wrong answers", statistics.wrong_answers.to_string()),
+                            ("Average accuracy", format!("{:.0}%", statistics.accuracy())),
+                            ("Longest chain", statistics.longest_chain.to_string()),
+                            ("Total merges", statistics.total_merges.to_string()),
+                        ];
+                        for (label, value) in rows {
+                            ui.label(egui::RichText::new(label).strong().color(theme.primary));
+                            ui.label(value);
+                            ui.end_row();
+                        }
+                    });
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+                if statistics.topics.is_empty() {
+                    ui.label("No answers yet. Play a round to see your progress per topic!");
+                } else {
+                    egui::Grid::new("statistics_topic_grid")
+                        .striped(true)
+                        .spacing([24.0, 8.0])
+                        .show(ui, |ui| {
+                            for header in ["Topic", "Correct", "Wrong", "Accuracy"] {
+                                ui.label(egui::RichText::new(header).strong().color(theme.primary));
+                            }
+                            ui.end_row();
+
+                            for (topic, topic_stats) in &statistics.topics {
+                                ui.label(topic.as_str());
+                                ui.label(format!("{}", topic_stats.correct_answers));
+                                ui.label(format!("{}", topic_stats.wrong_answers));
+                                ui.label(format!("{:.0}%", topic_stats.accuracy()));
+                                ui.end_row();
+                            }
+                        });
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+                if ThemedButton::new("Back", &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .show(ui)
+                    .clicked()
+                {
+                    next_menu.set(Menu::Main);
+                }
+            });
+        });
+}
+
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
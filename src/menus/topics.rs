@@ -0,0 +1,155 @@
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use bevy_egui::{
+    EguiContextPass,
+    egui::{self, Widget},
+};
+use konnektoren_bevy::{assets::*, prelude::*};
+
+use crate::{
+    game_state::GameState,
+    locale::Locale,
+    menus::Menu,
+    practice::PlayPracticeEvent,
+    question::{MIN_TOPICS, TopicFilter, challenge_topics},
+    resources::MultipleChoiceChallenge,
+    screens::Screen,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        EguiContextPass,
+        topics_menu_egui_ui.run_if(in_state(Menu::Topics)),
+    );
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::Topics).and(input_just_pressed(KeyCode::Escape))),
+    );
+}
+
+fn topics_menu_egui_ui(
+    mut contexts: bevy_egui::EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    locale: Res<Locale>,
+    game_state: Res<GameState>,
+    mut topic_filter: ResMut<TopicFilter>,
+    asset_registry: Option<Res<KonnektorenAssetRegistry>>,
+    challenge_assets: Option<Res<Assets<ChallengeAsset>>>,
+    mut practice_events: EventWriter<PlayPracticeEvent>,
+    mut next_menu: ResMut<NextState<Menu>>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    let ctx = contexts.ctx_mut();
+
+    // Topics of the challenge that is about to be played
+    let challenge = game_state
+        .current_challenge_id
+        .as_deref()
+        .and_then(|challenge_id| {
+            let registry = asset_registry.as_ref()?;
+            let assets = challenge_assets.as_ref()?;
+            let challenge = registry
+                .get_challenge_handle(challenge_id)
+                .and_then(|handle| assets.get(handle))
+                .and_then(MultipleChoiceChallenge::from_asset)?;
+            Some((challenge_id, challenge_topics(challenge.get())))
+        });
+
+    egui::CentralPanel::default()
+        .frame(egui::Frame::NONE.fill(theme.base_100))
+        .show(ctx, |ui| {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+            ui.vertical_centered(|ui| {
+                ResponsiveText::new(
+                    locale.text("topics.title"),
+                    ResponsiveFontSize::Title,
+                    theme.primary,
+                )
+                .responsive(&responsive)
+                .strong()
+                .ui(ui);
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                ui.label(locale.text("topics.hint"));
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                if let Some((challenge_id, topics)) = &challenge {
+                    let selected = topic_filter.topics_for(challenge_id).to_vec();
+
+                    if ui
+                        .selectable_label(selected.is_empty(), locale.text("topics.all"))
+                        .clicked()
+                    {
+                        topic_filter.clear();
+                    }
+
+                    egui::ScrollArea::vertical()
+                        .max_height(ui.available_height() - 160.0)
+                        .show(ui, |ui| {
+                            for (topic, count) in topics {
+                                let mut checked = selected.contains(topic);
+                                let label = locale
+                                    .format("topics.topic", &[("topic", topic), ("count", count)]);
+                                if ui.checkbox(&mut checked, label).changed() {
+                                    topic_filter.toggle(challenge_id, topic);
+                                }
+                            }
+                        });
+                } else {
+                    ui.label(locale.text("menu.loading_assets"));
+                }
+
+                // One topic is one answer, the round would ask nothing
+                let playable = challenge
+                    .as_ref()
+                    .is_none_or(|(challenge_id, _)| topic_filter.is_playable(challenge_id));
+                if !playable {
+                    ui.colored_label(
+                        egui::Color32::from_rgb(230, 90, 90),
+                        locale.format("topics.too_few", &[("count", &MIN_TOPICS)]),
+                    );
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                if ThemedButton::new(locale.text("topics.play"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .enabled(game_state.is_ready() && playable)
+                    .show(ui)
+                    .clicked()
+                {
+                    next_screen.set(Screen::Gameplay);
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                // Same round without timer and point losses
+                if ThemedButton::new(locale.text("topics.practice"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .enabled(game_state.is_ready() && playable)
+                    .show(ui)
+                    .clicked()
+                {
+                    practice_events.write(PlayPracticeEvent);
+                }
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                if ThemedButton::new(locale.text("topics.back"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .show(ui)
+                    .clicked()
+                {
+                    next_menu.set(Menu::Main);
+                }
+            });
+        });
+}
+
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
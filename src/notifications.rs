@@ -0,0 +1,115 @@
+//! Short toast messages shown on top of every screen.
+
+use bevy::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Toast>();
+    app.add_event::<NotificationEvent>();
+
+    app.add_systems(Startup, spawn_notification_area);
+    app.add_systems(Update, (show_notifications, update_toasts).chain());
+}
+
+/// Event to show a toast message
+#[derive(Event, Clone, Debug)]
+pub struct NotificationEvent {
+    pub message: String,
+    pub duration: f32,
+}
+
+impl NotificationEvent {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            duration: TOAST_DURATION,
+        }
+    }
+}
+
+/// Container the toasts are stacked in
+#[derive(Component)]
+struct NotificationArea;
+
+/// A single toast message
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Toast {
+    pub timer: Timer,
+}
+
+fn spawn_notification_area(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Notification Area"),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(20.0),
+            left: Val::Px(0.0),
+            right: Val::Px(0.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(8.0),
+            ..default()
+        },
+        GlobalZIndex(100),
+        Pickable::IGNORE,
+        NotificationArea,
+    ));
+}
+
+fn show_notifications(
+    mut commands: Commands,
+    mut notifications: EventReader<NotificationEvent>,
+    area_query: Query<Entity, With<NotificationArea>>,
+) {
+    let Ok(area) = area_query.single() else {
+        return;
+    };
+
+    for notification in notifications.read() {
+        info!("Notification: {}", notification.message);
+
+        commands.entity(area).with_child((
+            Name::new("Toast"),
+            Node {
+                padding: UiRect::axes(Val::Px(20.0), Val::Px(10.0)),
+                max_width: Val::Px(500.0),
+                ..default()
+            },
+            BackgroundColor(TOAST_BACKGROUND),
+            BorderRadius::all(Val::Px(8.0)),
+            Text(notification.message.clone()),
+            TextFont {
+                font_size: 20.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Toast {
+                timer: Timer::from_seconds(notification.duration, TimerMode::Once),
+            },
+        ));
+    }
+}
+
+/// Count down toasts, fading them out before they disappear
+fn update_toasts(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut toast_query: Query<(Entity, &mut Toast, &mut BackgroundColor, &mut TextColor)>,
+) {
+    for (entity, mut toast, mut background, mut text_color) in &mut toast_query {
+        toast.timer.tick(time.delta());
+
+        if toast.timer.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let alpha = (toast.timer.remaining_secs() / TOAST_FADE_DURATION).min(1.0);
+        background.0 = TOAST_BACKGROUND.with_alpha(TOAST_BACKGROUND.alpha() * alpha);
+        text_color.0 = Color::WHITE.with_alpha(alpha);
+    }
+}
+
+const TOAST_DURATION: f32 = 5.0;
+const TOAST_FADE_DURATION: f32 = 0.5;
+const TOAST_BACKGROUND: Color = Color::srgba(0.1, 0.1, 0.2, 0.9);
@@ -1,3 +1,4 @@
+use crate::settings::DifficultyTuning;
 use bevy::prelude::*;
 
 /// Component for collectible option items on the map
@@ -36,9 +37,22 @@ impl OptionCollectible {
 #[reflect(Resource)]
 pub struct OptionSpawnTimer {
     pub timer: Timer,
+    /// Target count for each wrong option type
     pub options_per_type: usize,
+    /// Target count for the correct option
+    pub correct_options: usize,
     pub total_target_options: usize,
     pub option_lifetime: f32,
+    /// Number of wrong option types of the current question
+    pub wrong_types: usize,
+    /// Set by the difficulty director to make options expire sooner
+    pub lifetime_scale: f32,
+    /// Set by the difficulty director, added to every wrong option type
+    pub extra_wrong_options: usize,
+    /// Spawn tuning from the game config
+    pub placement: crate::config::OptionSpawnConfig,
+    /// Share of new options that get an `OptionMovement`
+    pub moving_share: f32,
 }
 
 impl Default for OptionSpawnTimer {
@@ -46,36 +60,111 @@ impl Default for OptionSpawnTimer {
         Self {
             timer: Timer::from_seconds(super::OPTION_SPAWN_INTERVAL, TimerMode::Repeating),
             options_per_type: super::OPTIONS_PER_TYPE,
+            correct_options: super::OPTIONS_PER_TYPE,
             total_target_options: 10, // Default target
             option_lifetime: super::OPTION_LIFETIME,
+            wrong_types: 0,
+            lifetime_scale: 1.0,
+            extra_wrong_options: 0,
+            placement: default(),
+            moving_share: super::MOVING_OPTION_SHARE,
         }
     }
 }
 
 impl OptionSpawnTimer {
+    /// Apply the spawn interval and lifetime of the current difficulty
+    pub fn apply_tuning(&mut self, tuning: &DifficultyTuning) {
+        self.option_lifetime = tuning.option_lifetime;
+        self.moving_share = tuning.moving_option_share;
+        let interval = std::time::Duration::from_secs_f32(tuning.option_spawn_interval);
+        if self.timer.duration() != interval {
+            self.timer.set_duration(interval);
+        }
+    }
+
     /// Calculate how many options should be on the map based on map size
     pub fn calculate_target_options(
         &mut self,
         map_width: usize,
         map_height: usize,
         option_types: usize,
+        correct_share: f32,
     ) {
         let map_area = map_width * map_height;
 
-        // Scale options based on map area
-        // For 25x30 (750 cells) = 10 options
-        // This gives us roughly 1 option per 75 cells
-        let base_density = 75.0; // cells per option
-        self.total_target_options = ((map_area as f32 / base_density) as usize).max(4); // Minimum 4 options
+        // Scale options based on map area, by default 10 options on 25x30 (750 cells)
+        let cells_per_option = self.placement.cells_per_option.max(1.0);
+        self.total_target_options = ((map_area as f32 / cells_per_option) as usize).max(4); // Minimum 4 options
 
-        // Distribute evenly across option types
-        self.options_per_type = (self.total_target_options / option_types.max(1)).max(1);
+        // Split the total between the correct option and the wrong option types
+        let option_types = option_types.max(1);
+        let wrong_types = option_types - 1;
+        self.wrong_types = wrong_types;
+        let correct_share = correct_share.clamp(0.0, 1.0);
+
+        if wrong_types == 0 {
+            self.correct_options = self.total_target_options;
+            self.options_per_type = 0;
+        } else {
+            self.correct_options =
+                ((self.total_target_options as f32 * correct_share).round() as usize).max(1);
+            self.options_per_type = (self
+                .total_target_options
+                .saturating_sub(self.correct_options)
+                / wrong_types)
+                .max(1);
+        }
 
         info!(
-            "Map {}x{} (area: {}) -> Target: {} total options, {} per type",
-            map_width, map_height, map_area, self.total_target_options, self.options_per_type
+            "Map {}x{} (area: {}) -> Target: {} total options, {} correct, {} per wrong type",
+            map_width,
+            map_height,
+            map_area,
+            self.total_target_options,
+            self.correct_options,
+            self.options_per_type
         );
     }
+
+    /// How many options of a type should be on the map at once
+    pub fn target_for(&self, is_correct: bool) -> usize {
+        if is_correct {
+            self.correct_options
+        } else {
+            self.options_per_type + self.extra_wrong_options
+        }
+    }
+
+    /// How many options should be on the map at once, including the director's extras
+    pub fn total_target(&self) -> usize {
+        self.total_target_options + self.extra_wrong_options * self.wrong_types
+    }
+
+    /// Lifetime of newly spawned options
+    pub fn lifetime(&self) -> f32 {
+        self.option_lifetime * self.lifetime_scale
+    }
+}
+
+/// Velocity of an option knocked away by a chain reaction, decays until the
+/// option settles back onto the grid
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+pub struct OptionImpulse {
+    pub velocity: Vec2,
+}
+
+/// How a moving option gets around the map
+#[derive(Component, Reflect, Clone, Copy, Debug)]
+#[reflect(Component)]
+pub enum OptionMovement {
+    /// Slides in a straight line and bounces off walls and the map edge
+    Drift { velocity: Vec2 },
+    /// Circles around a point next to its spawn cell
+    Orbit { center: Vec2, angle: f32 },
+    /// Backs away from players that come close, only wrong options do this
+    Flee,
 }
 
 /// Marker component for option visual elements
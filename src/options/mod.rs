@@ -1,9 +1,11 @@
+use crate::question::QuestionPhase;
 use bevy::prelude::*;
 
 mod components;
 mod systems;
 
 pub use components::*;
+pub use systems::find_empty_spawn_position;
 use systems::*;
 
 pub(super) fn plugin(app: &mut App) {
@@ -14,6 +16,8 @@ pub(super) fn plugin(app: &mut App) {
     app.register_type::<OptionGlow>();
     app.register_type::<OptionPulseRing>();
     app.register_type::<OptionSparkles>();
+    app.register_type::<OptionImpulse>();
+    app.register_type::<OptionMovement>();
 
     app.init_resource::<OptionSpawnTimer>();
 
@@ -21,17 +25,33 @@ pub(super) fn plugin(app: &mut App) {
         Update,
         (
             update_option_spawn_settings,
-            spawn_option_collectibles,
-            cleanup_expired_options,
+            spawn_option_collectibles.run_if(in_state(QuestionPhase::Asking)),
+            (
+                slow_expiry_near_handicapped_players,
+                cleanup_expired_options,
+            )
+                .chain(),
             clear_options_on_question_change,
             animate_option_collectibles,
             update_option_sparkles,
             enhance_correct_answer_effects,
             fade_expiring_options,
+            (scatter_options_from_explosions, apply_option_impulses).chain(),
+            (assign_option_movement, move_options).chain(),
         )
             .run_if(in_state(crate::screens::Screen::Gameplay))
             .in_set(crate::PausableSystems),
     );
+
+    // Reveal the answer between questions
+    app.add_systems(
+        Update,
+        flash_resolved_options
+            .after(animate_option_collectibles)
+            .after(fade_expiring_options)
+            .run_if(in_state(QuestionPhase::Resolving))
+            .in_set(crate::PausableSystems),
+    );
 }
 
 // Configuration constants for options
@@ -39,3 +59,20 @@ pub const OPTIONS_PER_TYPE: usize = 3;
 pub const OPTION_LIFETIME: f32 = 8.0; // Options last 8 seconds
 pub const OPTION_SPAWN_INTERVAL: f32 = 1.0; // Spawn every second
 pub const OPTION_FADE_DURATION: f32 = 2.0; // Start fading 2 seconds before expiration
+pub const HANDICAP_OPTION_RADIUS: f32 = 150.0; // Options this close to a handicapped player expire slower
+pub const CELLS_PER_OPTION: f32 = 75.0; // Map cells per option, 10 options on a 25x30 map
+pub const DEFAULT_CORRECT_OPTION_RADIUS: usize = 8; // Cells around each player kept stocked with a correct option
+pub const SPAWN_CANDIDATES: usize = 6; // Positions compared when picking a spawn spot
+pub const SPAWN_CLEARANCE: f32 = 80.0; // Distance from chains beyond which spots score the same
+pub const RESOLUTION_FLASH_SPEED: f32 = 12.0; // Radians per second for the answer flash
+pub const RESOLUTION_WRONG_ALPHA: f32 = 0.25; // Wrong options dim while the answer is shown
+pub const MOVING_OPTION_SHARE: f32 = 0.15; // Share of options that move on Normal
+pub const OPTION_DRIFT_SPEED: f32 = 20.0; // Pixels per second
+pub const OPTION_ORBIT_RADIUS: f32 = 12.0;
+pub const OPTION_ORBIT_SPEED: f32 = 1.5; // Radians per second
+pub const OPTION_FLEE_RADIUS: f32 = 120.0; // Players closer than this make wrong options back away
+pub const OPTION_FLEE_SPEED: f32 = 60.0; // Pixels per second, well below the player's speed
+pub const SHOCKWAVE_RADIUS: f32 = 120.0; // Options closer than this to an exploding segment are knocked away
+pub const SHOCKWAVE_IMPULSE: f32 = 420.0; // Speed in pixels per second right next to the blast
+pub const SHOCKWAVE_DAMPING: f32 = 5.0; // Exponential slowdown per second
+pub const SHOCKWAVE_SETTLE_SPEED: f32 = 15.0; // Below this speed the option snaps back onto the grid
@@ -1,10 +1,23 @@
-use super::OPTION_FADE_DURATION;
 use super::components::*;
+use super::{
+    HANDICAP_OPTION_RADIUS, OPTION_DRIFT_SPEED, OPTION_FADE_DURATION, OPTION_FLEE_RADIUS,
+    OPTION_FLEE_SPEED, OPTION_ORBIT_RADIUS, OPTION_ORBIT_SPEED, RESOLUTION_FLASH_SPEED,
+    RESOLUTION_WRONG_ALPHA, SHOCKWAVE_DAMPING, SHOCKWAVE_IMPULSE, SHOCKWAVE_RADIUS,
+    SHOCKWAVE_SETTLE_SPEED,
+};
 use crate::{
+    asset_cache::SharedAssetCache,
+    chain::{ChainSegment, ChainSegmentDestroyedEvent, FlyingToChain},
+    config::{GameConfig, OptionSpawnConfig},
     effects::SpawnCollectionEvent,
     map::{GridMap, GridPosition},
+    palette::OptionStyleRegistry,
+    player::{Player, PlayerIndex},
     question::QuestionSystem,
+    rng::GameRng,
     screens::Screen,
+    settings::GameSettings,
+    theme::prelude::*,
 };
 use bevy::prelude::*;
 use rand::Rng;
@@ -20,22 +33,15 @@ fn spawn_option_collectible(
     grid_map: &GridMap,
     current_time: f32,
     lifetime: f32,
+    styles: &OptionStyleRegistry,
+    asset_cache: &mut SharedAssetCache,
     meshes: &mut Assets<Mesh>,
     materials: &mut Assets<ColorMaterial>,
 ) {
     let world_pos = grid_map.grid_to_world(grid_pos.x, grid_pos.y);
 
     // Choose color based on option type
-    let base_colors = [
-        Color::srgb(0.3, 0.5, 0.8), // Blue
-        Color::srgb(0.8, 0.5, 0.3), // Orange
-        Color::srgb(0.5, 0.8, 0.3), // Green
-        Color::srgb(0.8, 0.3, 0.5), // Pink
-        Color::srgb(0.5, 0.3, 0.8), // Purple
-    ];
-
-    let color_index = option_id % base_colors.len();
-    let base_color = base_colors[color_index];
+    let base_color = styles.color(option_id);
 
     // Make correct answers brighter
     let display_color = if is_correct {
@@ -48,11 +54,11 @@ fn spawn_option_collectible(
         base_color
     };
 
-    // Create meshes and materials for all visual layers
-    let main_mesh = meshes.add(Circle::new(14.0));
+    // Meshes are shared between options; materials are animated per option
+    let main_mesh = asset_cache.option_mesh(styles, option_id, 14.0, meshes);
     let main_material = materials.add(ColorMaterial::from(display_color));
 
-    let glow_mesh = meshes.add(Circle::new(20.0));
+    let glow_mesh = asset_cache.circle_mesh(20.0, meshes);
     let glow_color = Color::srgba(
         display_color.to_srgba().red,
         display_color.to_srgba().green,
@@ -61,7 +67,7 @@ fn spawn_option_collectible(
     );
     let glow_material = materials.add(ColorMaterial::from(glow_color));
 
-    let pulse_mesh = meshes.add(Circle::new(30.0));
+    let pulse_mesh = asset_cache.circle_mesh(30.0, meshes);
     let pulse_color = Color::srgba(
         display_color.to_srgba().red,
         display_color.to_srgba().green,
@@ -96,7 +102,7 @@ fn spawn_option_collectible(
                     font_size: 14.0,
                     ..default()
                 },
-                TextColor(Color::WHITE),
+                TextColor(contrasting_text_color(display_color)),
                 Transform::from_translation(Vec3::new(0.0, 0.0, 0.3)),
             ),
             // Inner glow effect
@@ -120,13 +126,22 @@ fn spawn_option_collectible(
 }
 
 /// System to spawn option collectibles on the map
+#[allow(clippy::too_many_arguments)]
 pub fn spawn_option_collectibles(
     mut commands: Commands,
     time: Res<Time>,
     mut spawn_timer: ResMut<OptionSpawnTimer>,
     question_system: Option<Res<QuestionSystem>>,
     grid_map: Option<Res<GridMap>>,
-    existing_options: Query<(&OptionType, &GridPosition), With<OptionCollectible>>,
+    game_settings: Res<GameSettings>,
+    existing_options: Query<(&OptionCollectible, &GridPosition)>,
+    player_query: Query<(Entity, &Transform), With<Player>>,
+    segment_query: Query<&GlobalTransform, With<ChainSegment>>,
+    director: Res<crate::director::DifficultyDirector>,
+    flying_query: Query<&FlyingToChain>,
+    option_styles: Res<OptionStyleRegistry>,
+    mut game_rng: ResMut<GameRng>,
+    mut asset_cache: ResMut<SharedAssetCache>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
@@ -155,16 +170,96 @@ pub fn spawn_option_collectibles(
     let mut option_counts: HashMap<usize, usize> = HashMap::new();
     let mut occupied_positions: std::collections::HashSet<(usize, usize)> =
         std::collections::HashSet::new();
+    let mut correct_positions = Vec::new();
 
-    for (option_type, grid_pos) in &existing_options {
-        *option_counts.entry(option_type.option_id).or_insert(0) += 1;
+    for (option, grid_pos) in &existing_options {
+        *option_counts.entry(option.option_id).or_insert(0) += 1;
         occupied_positions.insert((grid_pos.x, grid_pos.y));
+        if option.is_correct {
+            correct_positions.push((grid_pos.x, grid_pos.y));
+        }
+    }
+
+    // Never spawn on top of objects flying to a chain, and keep away from chains
+    for flying in &flying_query {
+        for position in [flying.current_position(), flying.target_position] {
+            if let Some(cell) = grid_map.world_to_grid(position) {
+                occupied_positions.insert(cell);
+            }
+        }
     }
+    let avoid_positions: Vec<Vec2> = segment_query
+        .iter()
+        .map(|transform| transform.translation().xy())
+        .chain(flying_query.iter().map(FlyingToChain::current_position))
+        .collect();
+
+    let mut total_existing = existing_options.iter().count();
+    let placement = spawn_timer.placement.clone();
+
+    let correct_option_name = options
+        .iter()
+        .find(|option| option.id == current_question.option)
+        .map(|option| option.name.clone());
+
+    // Anti-frustration: make sure every player has a correct option within reach,
+    // and a few more around players the difficulty director sees struggling
+    if let Some(correct_option_name) = correct_option_name {
+        for (player_entity, player_transform) in &player_query {
+            let help = director.extra_correct_options(player_entity);
+            let mut radius = game_settings.gameplay.correct_option_radius;
+            if help > 0 {
+                radius = radius.max(crate::director::DIRECTOR_HELP_RADIUS);
+            }
+            if radius == 0 {
+                continue;
+            }
+            let Some(player_cell) = grid_map.world_to_grid(player_transform.translation.xy())
+            else {
+                continue;
+            };
+
+            let nearby_correct = correct_positions
+                .iter()
+                .filter(|&&cell| grid_distance(cell, player_cell) <= radius)
+                .count();
+
+            for _ in nearby_correct..1 + help {
+                let Some(spawn_pos) = find_empty_spawn_position(
+                    &grid_map,
+                    &occupied_positions,
+                    &avoid_positions,
+                    Some((player_cell, radius)),
+                    &placement,
+                    game_rng.gameplay(),
+                ) else {
+                    break;
+                };
+                spawn_option_collectible(
+                    &mut commands,
+                    current_question.option,
+                    correct_option_name.clone(),
+                    true,
+                    spawn_pos.clone(),
+                    &grid_map,
+                    current_time,
+                    spawn_timer.lifetime(),
+                    &option_styles,
+                    &mut asset_cache,
+                    &mut meshes,
+                    &mut materials,
+                );
 
-    let total_existing = existing_options.iter().count();
+                occupied_positions.insert((spawn_pos.x, spawn_pos.y));
+                correct_positions.push((spawn_pos.x, spawn_pos.y));
+                *option_counts.entry(current_question.option).or_insert(0) += 1;
+                total_existing += 1;
+            }
+        }
+    }
 
     // Don't spawn if we already have enough options total
-    if total_existing >= spawn_timer.total_target_options {
+    if total_existing >= spawn_timer.total_target() {
         return;
     }
 
@@ -172,17 +267,23 @@ pub fn spawn_option_collectibles(
     for option in options {
         let existing_count = option_counts.get(&option.id).copied().unwrap_or(0);
         let is_correct = option.id == current_question.option;
+        let target_count = spawn_timer.target_for(is_correct);
 
         // Check if we should spawn more of this type
         // Also check that we don't exceed the total target
-        if existing_count < spawn_timer.options_per_type
-            && total_existing < spawn_timer.total_target_options
-        {
-            let spawn_count = (spawn_timer.options_per_type - existing_count)
-                .min(spawn_timer.total_target_options - total_existing);
+        if existing_count < target_count && total_existing < spawn_timer.total_target() {
+            let spawn_count =
+                (target_count - existing_count).min(spawn_timer.total_target() - total_existing);
 
             for _ in 0..spawn_count {
-                if let Some(spawn_pos) = find_empty_spawn_position(&grid_map, &occupied_positions) {
+                if let Some(spawn_pos) = find_empty_spawn_position(
+                    &grid_map,
+                    &occupied_positions,
+                    &avoid_positions,
+                    None,
+                    &placement,
+                    game_rng.gameplay(),
+                ) {
                     spawn_option_collectible(
                         &mut commands,
                         option.id,
@@ -191,7 +292,9 @@ pub fn spawn_option_collectibles(
                         spawn_pos.clone(),
                         &grid_map,
                         current_time,
-                        spawn_timer.option_lifetime,
+                        spawn_timer.lifetime(),
+                        &option_styles,
+                        &mut asset_cache,
                         &mut meshes,
                         &mut materials,
                     );
@@ -204,6 +307,11 @@ pub fn spawn_option_collectibles(
     }
 }
 
+/// Chebyshev distance between two grid cells
+fn grid_distance(a: (usize, usize), b: (usize, usize)) -> usize {
+    a.0.abs_diff(b.0).max(a.1.abs_diff(b.1))
+}
+
 /// System to animate option collectibles with enhanced light effects
 pub fn animate_option_collectibles(
     time: Res<Time>,
@@ -234,18 +342,20 @@ pub fn animate_option_collectibles(
         (Without<OptionVisual>, Without<OptionGlow>),
     >,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    game_settings: Res<GameSettings>,
 ) {
     let time_factor = time.elapsed_secs();
+    let motion = game_settings.accessibility.effects_intensity.motion_scale();
 
     // Animate main option bodies
     for (mut transform, light_effect) in &mut options_query {
         // Base pulsing scale effect
-        let pulse = 1.0 + (time_factor * light_effect.pulse_speed).sin() * 0.15;
+        let pulse = 1.0 + (time_factor * light_effect.pulse_speed).sin() * 0.15 * motion;
         transform.scale = Vec3::splat(pulse);
 
         // Gentle floating motion
-        let float_offset_x = (time_factor * 0.8).sin() * 2.0;
-        let float_offset_y = (time_factor * 1.2).cos() * 1.5;
+        let float_offset_x = (time_factor * 0.8).sin() * 2.0 * motion;
+        let float_offset_y = (time_factor * 1.2).cos() * 1.5 * motion;
 
         // Apply floating offset
         transform.translation.x += float_offset_x * 0.1;
@@ -255,7 +365,7 @@ pub fn animate_option_collectibles(
     // Animate glow effects
     for (mut transform, material_handle) in &mut glow_query {
         // Glow pulse (slower than main pulse)
-        let glow_pulse = 1.0 + (time_factor * 1.5).sin() * 0.3;
+        let glow_pulse = 1.0 + (time_factor * 1.5).sin() * 0.3 * motion;
         transform.scale = Vec3::splat(glow_pulse);
 
         if let Some(material) = materials.get_mut(&material_handle.0) {
@@ -266,7 +376,7 @@ pub fn animate_option_collectibles(
 
     // Animate pulse rings
     for (mut transform, mut pulse_ring, material_handle) in &mut pulse_query {
-        pulse_ring.ring_phase += time.delta_secs() * 2.0;
+        pulse_ring.ring_phase += time.delta_secs() * 2.0 * motion;
         if pulse_ring.ring_phase > std::f32::consts::TAU {
             pulse_ring.ring_phase = 0.0;
         }
@@ -288,29 +398,38 @@ pub fn animate_option_collectibles(
 pub fn update_option_sparkles(
     time: Res<Time>,
     mut sparkle_query: Query<
-        (&Transform, &mut OptionSparkles, &OptionLightEffect),
+        (
+            &Transform,
+            &mut OptionSparkles,
+            &OptionLightEffect,
+            &ViewVisibility,
+        ),
         With<OptionCollectible>,
     >,
     mut collection_events: EventWriter<SpawnCollectionEvent>,
+    mut game_rng: ResMut<GameRng>,
+    game_settings: Res<GameSettings>,
 ) {
-    for (transform, mut sparkles, light_effect) in &mut sparkle_query {
-        sparkles.sparkle_timer.tick(time.delta());
+    let intensity = game_settings.accessibility.effects_intensity;
+    if intensity.particle_scale() <= 0.0 {
+        return;
+    }
+    let rng = game_rng.effects();
 
-        if sparkles.sparkle_timer.just_finished() {
-            // Simple intensity check using time-based randomness
-            let time_factor = time.elapsed_secs();
-            let pseudo_random = (time_factor * 13.7).fract(); // Simple pseudo-random
+    for (transform, mut sparkles, light_effect, view_visibility) in &mut sparkle_query {
+        sparkles.sparkle_timer.tick(time.delta());
 
-            if pseudo_random > sparkles.sparkle_intensity {
+        // Sparkles are purely cosmetic, skip options no camera can see
+        if sparkles.sparkle_timer.just_finished() && view_visibility.get() {
+            if rng.gen_range(0.0..1.0) > sparkles.sparkle_intensity {
                 continue;
             }
 
             let base_pos = transform.translation;
 
-            for i in 0..sparkles.sparkle_count {
-                // Use time and index for pseudo-random positioning
-                let angle = (time_factor * 2.0 + i as f32 * 2.1).fract() * std::f32::consts::TAU;
-                let distance = 15.0 + ((time_factor * 3.7 + i as f32).fract() * 10.0);
+            for _ in 0..intensity.particle_count(sparkles.sparkle_count) {
+                let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+                let distance = rng.gen_range(15.0..25.0);
 
                 let sparkle_pos = Vec3::new(
                     base_pos.x + angle.cos() * distance,
@@ -392,27 +511,111 @@ pub fn enhance_correct_answer_effects(
     }
 }
 
-/// Find an empty position to spawn an option
-fn find_empty_spawn_position(
+/// Find an empty position to spawn an option. A few free candidates are
+/// compared and the one furthest from `avoid_positions` wins, so options tend
+/// to appear in open space instead of right on top of a chain. With `area`
+/// set, candidates are limited to a square of cells around a center cell.
+pub fn find_empty_spawn_position(
     grid_map: &GridMap,
     occupied_positions: &std::collections::HashSet<(usize, usize)>,
+    avoid_positions: &[Vec2],
+    area: Option<((usize, usize), usize)>,
+    config: &OptionSpawnConfig,
+    rng: &mut impl Rng,
 ) -> Option<GridPosition> {
-    let mut rng = rand::thread_rng();
     let max_attempts = 50;
 
     // Use buffer based on map size - larger maps get smaller buffers
     let buffer = if grid_map.width > 30 { 1 } else { 2 };
+    let (mut min_x, mut max_x) = (buffer, grid_map.width.saturating_sub(buffer));
+    let (mut min_y, mut max_y) = (buffer, grid_map.height.saturating_sub(buffer));
+
+    if let Some(((center_x, center_y), radius)) = area {
+        min_x = min_x.max(center_x.saturating_sub(radius));
+        max_x = max_x.min(center_x + radius + 1);
+        min_y = min_y.max(center_y.saturating_sub(radius));
+        max_y = max_y.min(center_y + radius + 1);
+    }
+
+    if min_x >= max_x || min_y >= max_y {
+        return None;
+    }
+
+    let clearance = |x: usize, y: usize| {
+        let world_pos = grid_map.grid_to_world(x, y);
+        avoid_positions
+            .iter()
+            .map(|position| position.distance(world_pos))
+            .fold(config.spawn_clearance, f32::min)
+    };
+
+    let mut best: Option<(GridPosition, f32)> = None;
+    let mut candidates = 0;
 
     for _ in 0..max_attempts {
-        let x = rng.gen_range(buffer..grid_map.width.saturating_sub(buffer));
-        let y = rng.gen_range(buffer..grid_map.height.saturating_sub(buffer));
+        let x = rng.gen_range(min_x..max_x);
+        let y = rng.gen_range(min_y..max_y);
 
-        if !occupied_positions.contains(&(x, y)) {
-            return Some(GridPosition::new(x, y));
+        if occupied_positions.contains(&(x, y)) || grid_map.is_blocked(x, y) {
+            continue;
+        }
+
+        let score = clearance(x, y);
+        if best
+            .as_ref()
+            .is_none_or(|(_, best_score)| score > *best_score)
+        {
+            best = Some((GridPosition::new(x, y), score));
+        }
+
+        candidates += 1;
+        if candidates >= config.spawn_candidates || score >= config.spawn_clearance {
+            break;
         }
     }
 
-    None
+    best.map(|(position, _)| position)
+}
+
+/// System to let options near handicapped players age slower
+pub fn slow_expiry_near_handicapped_players(
+    time: Res<Time>,
+    game_settings: Res<GameSettings>,
+    player_query: Query<(&Transform, &PlayerIndex), With<Player>>,
+    mut options_query: Query<(&Transform, &mut OptionCollectible), Without<Player>>,
+) {
+    let slowed: Vec<(Vec2, f32)> = player_query
+        .iter()
+        .map(|(transform, index)| {
+            let multiplier = game_settings
+                .multiplayer
+                .handicap(index.0)
+                .option_expiry_multiplier;
+            (transform.translation.xy(), multiplier)
+        })
+        .filter(|(_, multiplier)| *multiplier > 1.0)
+        .collect();
+    if slowed.is_empty() {
+        return;
+    }
+
+    let delta = time.delta_secs();
+    for (transform, mut option) in &mut options_query {
+        let position = transform.translation.xy();
+        let Some(multiplier) = slowed
+            .iter()
+            .filter(|(player_position, _)| {
+                player_position.distance(position) <= HANDICAP_OPTION_RADIUS
+            })
+            .map(|(_, multiplier)| *multiplier)
+            .reduce(f32::max)
+        else {
+            continue;
+        };
+
+        // Pushing the spawn time back keeps the fade and expiry in step
+        option.spawn_time += delta * (1.0 - 1.0 / multiplier);
+    }
 }
 
 /// System to clean up expired option collectibles
@@ -470,11 +673,43 @@ pub fn fade_expiring_options(
     }
 }
 
+/// System to flash the correct option's collectibles and dim the others
+/// while the answer is revealed
+pub fn flash_resolved_options(
+    time: Res<Time>,
+    mut options_query: Query<
+        (
+            &OptionCollectible,
+            &mut Transform,
+            &MeshMaterial2d<ColorMaterial>,
+        ),
+        With<OptionVisual>,
+    >,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let flash = (time.elapsed_secs() * RESOLUTION_FLASH_SPEED).sin() * 0.5 + 0.5;
+
+    for (option, mut transform, material_handle) in &mut options_query {
+        let alpha = if option.is_correct {
+            transform.scale = Vec3::splat(1.0 + flash * 0.4);
+            0.5 + flash * 0.5
+        } else {
+            RESOLUTION_WRONG_ALPHA
+        };
+
+        if let Some(material) = materials.get_mut(&material_handle.0) {
+            material.color.set_alpha(alpha);
+        }
+    }
+}
+
 /// System to update option spawn settings based on map size
 pub fn update_option_spawn_settings(
     mut spawn_timer: ResMut<OptionSpawnTimer>,
     grid_map: Option<Res<GridMap>>,
     question_system: Option<Res<QuestionSystem>>,
+    game_settings: Res<GameSettings>,
+    config: Res<GameConfig>,
 ) {
     let Some(grid_map) = grid_map else {
         return;
@@ -484,11 +719,234 @@ pub fn update_option_spawn_settings(
         return;
     };
 
-    // Only update when map or question system changes
-    if !grid_map.is_changed() && !question_system.is_changed() {
+    // Only update when map, question system, settings or config change
+    if !grid_map.is_changed()
+        && !question_system.is_changed()
+        && !game_settings.is_changed()
+        && !config.is_changed()
+    {
         return;
     }
 
+    spawn_timer.apply_tuning(&game_settings.gameplay.tuning());
+    spawn_timer.placement = config.options.clone();
+
     let option_types = question_system.get_current_options().len();
-    spawn_timer.calculate_target_options(grid_map.width, grid_map.height, option_types);
+    let correct_share = game_settings
+        .gameplay
+        .difficulty
+        .correct_option_share(option_types);
+    spawn_timer.calculate_target_options(
+        grid_map.width,
+        grid_map.height,
+        option_types,
+        correct_share,
+    );
+}
+
+/// System to knock options away from exploding chain segments
+pub fn scatter_options_from_explosions(
+    mut commands: Commands,
+    mut destruction_events: EventReader<ChainSegmentDestroyedEvent>,
+    mut options_query: Query<
+        (Entity, &Transform, Option<&mut OptionImpulse>),
+        (With<OptionCollectible>, Without<FlyingToChain>),
+    >,
+) {
+    for event in destruction_events.read() {
+        for (entity, transform, impulse) in &mut options_query {
+            let offset = transform.translation.xy() - event.position;
+            let distance = offset.length();
+            if distance >= SHOCKWAVE_RADIUS {
+                continue;
+            }
+
+            // Stronger close to the blast, options right on top fly off in any direction
+            let direction = offset.try_normalize().unwrap_or(Vec2::Y);
+            let kick = direction * SHOCKWAVE_IMPULSE * (1.0 - distance / SHOCKWAVE_RADIUS);
+            match impulse {
+                Some(mut impulse) => impulse.velocity += kick,
+                None => {
+                    commands
+                        .entity(entity)
+                        .insert(OptionImpulse { velocity: kick });
+                }
+            }
+        }
+    }
+}
+
+/// System to move knocked options and snap them back onto a free grid cell once they settle
+pub fn apply_option_impulses(
+    mut commands: Commands,
+    time: Res<Time>,
+    grid_map: Option<Res<GridMap>>,
+    mut moving_query: Query<(
+        Entity,
+        &mut Transform,
+        &mut GridPosition,
+        &mut OptionImpulse,
+    )>,
+    resting_query: Query<&GridPosition, (With<OptionCollectible>, Without<OptionImpulse>)>,
+) {
+    let Some(grid_map) = grid_map else {
+        return;
+    };
+    let delta = time.delta_secs();
+    let mut occupied: std::collections::HashSet<(usize, usize)> = resting_query
+        .iter()
+        .map(|position| (position.x, position.y))
+        .collect();
+
+    for (entity, mut transform, mut grid_pos, mut impulse) in &mut moving_query {
+        let current = transform.translation.xy();
+        let next = current + impulse.velocity * delta;
+        impulse.velocity *= (-SHOCKWAVE_DAMPING * delta).exp();
+
+        // Obstacles and the map edge stop the option where it is
+        match grid_map.world_to_grid(next) {
+            Some((x, y)) if !grid_map.is_blocked(x, y) => {
+                transform.translation.x = next.x;
+                transform.translation.y = next.y;
+                grid_pos.x = x;
+                grid_pos.y = y;
+            }
+            _ => impulse.velocity = Vec2::ZERO,
+        }
+
+        if impulse.velocity.length() > SHOCKWAVE_SETTLE_SPEED {
+            continue;
+        }
+
+        // Settle on the cell it ended up in, or a free neighbour if another option sits there
+        let cell = (grid_pos.x, grid_pos.y);
+        let free_cell = std::iter::once(cell)
+            .chain([(-1, 0), (1, 0), (0, -1), (0, 1)].into_iter().filter_map(
+                |(dx, dy): (isize, isize)| {
+                    Some((
+                        cell.0.checked_add_signed(dx)?,
+                        cell.1.checked_add_signed(dy)?,
+                    ))
+                },
+            ))
+            .find(|&(x, y)| {
+                x < grid_map.width
+                    && y < grid_map.height
+                    && !grid_map.is_blocked(x, y)
+                    && !occupied.contains(&(x, y))
+            })
+            .unwrap_or(cell);
+
+        let settled = grid_map.grid_to_world(free_cell.0, free_cell.1);
+        transform.translation.x = settled.x;
+        transform.translation.y = settled.y;
+        *grid_pos = GridPosition::new(free_cell.0, free_cell.1);
+        occupied.insert(free_cell);
+        commands.entity(entity).remove::<OptionImpulse>();
+    }
+}
+
+/// System to set some of the newly spawned options moving
+pub fn assign_option_movement(
+    mut commands: Commands,
+    spawn_timer: Res<OptionSpawnTimer>,
+    mut game_rng: ResMut<GameRng>,
+    new_options: Query<(Entity, &Transform, &OptionCollectible), Added<OptionCollectible>>,
+) {
+    if spawn_timer.moving_share <= 0.0 {
+        return;
+    }
+
+    for (entity, transform, option) in &new_options {
+        let rng = game_rng.gameplay();
+        if !rng.gen_bool(spawn_timer.moving_share.clamp(0.0, 1.0) as f64) {
+            continue;
+        }
+
+        // Only wrong options run away, a fleeing correct answer would just be unfair
+        let patterns = if option.is_correct { 2 } else { 3 };
+        let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+        let movement = match rng.gen_range(0..patterns) {
+            0 => OptionMovement::Drift {
+                velocity: Vec2::from_angle(angle) * OPTION_DRIFT_SPEED,
+            },
+            1 => OptionMovement::Orbit {
+                center: transform.translation.xy() - Vec2::from_angle(angle) * OPTION_ORBIT_RADIUS,
+                angle,
+            },
+            _ => OptionMovement::Flee,
+        };
+        commands.entity(entity).insert(movement);
+    }
+}
+
+/// System to move drifting, orbiting and fleeing options, keeping their grid
+/// position current so spawning still sees which cells are taken
+pub fn move_options(
+    time: Res<Time>,
+    grid_map: Option<Res<GridMap>>,
+    player_query: Query<&Transform, (With<Player>, Without<OptionCollectible>)>,
+    mut option_query: Query<
+        (&mut Transform, &mut GridPosition, &mut OptionMovement),
+        (
+            With<OptionCollectible>,
+            Without<OptionImpulse>,
+            Without<FlyingToChain>,
+        ),
+    >,
+) {
+    let Some(grid_map) = grid_map else {
+        return;
+    };
+    let delta = time.delta_secs();
+
+    for (mut transform, mut grid_pos, mut movement) in &mut option_query {
+        let current = transform.translation.xy();
+        let next = match movement.as_mut() {
+            OptionMovement::Drift { velocity } => current + *velocity * delta,
+            OptionMovement::Orbit { center, angle } => {
+                // Something else moved the option, so circle around where it is now
+                if current.distance(*center + Vec2::from_angle(*angle) * OPTION_ORBIT_RADIUS)
+                    > OPTION_ORBIT_RADIUS
+                {
+                    *center = current - Vec2::from_angle(*angle) * OPTION_ORBIT_RADIUS;
+                }
+                *angle += OPTION_ORBIT_SPEED * delta;
+                *center + Vec2::from_angle(*angle) * OPTION_ORBIT_RADIUS
+            }
+            OptionMovement::Flee => {
+                let nearest = player_query
+                    .iter()
+                    .map(|player| player.translation.xy())
+                    .filter(|player| player.distance(current) < OPTION_FLEE_RADIUS)
+                    .min_by(|a, b| a.distance(current).total_cmp(&b.distance(current)));
+                match nearest {
+                    Some(player) => {
+                        current
+                            + (current - player).try_normalize().unwrap_or(Vec2::Y)
+                                * OPTION_FLEE_SPEED
+                                * delta
+                    }
+                    None => continue,
+                }
+            }
+        };
+
+        // Walls and the map edge stop an option, drifting ones bounce off
+        let Some((x, y)) = grid_map
+            .world_to_grid(next)
+            .filter(|&(x, y)| !grid_map.is_blocked(x, y))
+        else {
+            if let OptionMovement::Drift { velocity } = movement.as_mut() {
+                *velocity = -*velocity;
+            }
+            continue;
+        };
+
+        transform.translation.x = next.x;
+        transform.translation.y = next.y;
+        if grid_pos.x != x || grid_pos.y != y {
+            *grid_pos = GridPosition::new(x, y);
+        }
+    }
 }
@@ -0,0 +1,326 @@
+//! Option color palettes and shape markers, so options and chain segments can
+//! be told apart without relying on color alone. The `OptionStyleRegistry`
+//! is the one place that decides how an option id looks.
+
+use std::collections::HashMap;
+
+use bevy::{
+    asset::RenderAssetUsages,
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+
+use crate::{question::QuestionSystem, settings::GameSettings};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<ColorPalette>();
+    app.register_type::<OptionMarker>();
+
+    app.init_resource::<OptionStyleRegistry>();
+
+    app.add_systems(Startup, create_marker_atlas);
+    app.add_systems(PreUpdate, update_option_style_registry);
+}
+
+/// Selectable option color palettes
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorPalette {
+    #[default]
+    Default,
+    Deuteranopia,
+    Protanopia,
+    HighContrast,
+}
+
+impl ColorPalette {
+    pub const ALL: [ColorPalette; 4] = [
+        ColorPalette::Default,
+        ColorPalette::Deuteranopia,
+        ColorPalette::Protanopia,
+        ColorPalette::HighContrast,
+    ];
+
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index.min(Self::ALL.len() - 1)]
+    }
+
+    pub fn index(&self) -> usize {
+        Self::ALL.iter().position(|p| p == self).unwrap_or(0)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ColorPalette::Default => "Default",
+            ColorPalette::Deuteranopia => "Deuteranopia",
+            ColorPalette::Protanopia => "Protanopia",
+            ColorPalette::HighContrast => "High Contrast",
+        }
+    }
+
+    pub fn colors(&self) -> [Color; 5] {
+        match self {
+            ColorPalette::Default => [
+                Color::srgb(0.3, 0.5, 0.8), // Blue
+                Color::srgb(0.8, 0.5, 0.3), // Orange
+                Color::srgb(0.5, 0.8, 0.3), // Green
+                Color::srgb(0.8, 0.3, 0.5), // Pink
+                Color::srgb(0.5, 0.3, 0.8), // Purple
+            ],
+            // Blue/orange based, avoids red-green pairs
+            ColorPalette::Deuteranopia => [
+                Color::srgb(0.0, 0.45, 0.7),  // Blue
+                Color::srgb(0.9, 0.6, 0.0),   // Orange
+                Color::srgb(0.35, 0.7, 0.9),  // Sky blue
+                Color::srgb(0.95, 0.9, 0.25), // Yellow
+                Color::srgb(0.8, 0.6, 0.7),   // Reddish purple
+            ],
+            // Avoids reds, which read as dark for protanopes
+            ColorPalette::Protanopia => [
+                Color::srgb(0.0, 0.45, 0.7),  // Blue
+                Color::srgb(0.95, 0.9, 0.25), // Yellow
+                Color::srgb(0.35, 0.7, 0.9),  // Sky blue
+                Color::srgb(0.6, 0.6, 0.6),   // Grey
+                Color::srgb(0.9, 0.6, 0.0),   // Orange
+            ],
+            ColorPalette::HighContrast => [
+                Color::srgb(1.0, 1.0, 1.0), // White
+                Color::srgb(1.0, 0.9, 0.0), // Yellow
+                Color::srgb(0.0, 0.9, 1.0), // Cyan
+                Color::srgb(1.0, 0.2, 0.9), // Magenta
+                Color::srgb(0.3, 1.0, 0.2), // Lime
+            ],
+        }
+    }
+}
+
+/// Get the color of an option id in the given palette
+pub fn option_color(palette: ColorPalette, option_id: usize) -> Color {
+    let colors = palette.colors();
+    colors[option_id % colors.len()]
+}
+
+/// Shape marker shown for an option when shape markers are enabled
+#[derive(Reflect, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OptionMarker {
+    Circle,
+    Square,
+    Diamond,
+    Triangle,
+    Hexagon,
+}
+
+impl OptionMarker {
+    pub const ALL: [OptionMarker; 5] = [
+        OptionMarker::Circle,
+        OptionMarker::Square,
+        OptionMarker::Diamond,
+        OptionMarker::Triangle,
+        OptionMarker::Hexagon,
+    ];
+
+    pub fn for_option(option_id: usize) -> Self {
+        Self::ALL[option_id % Self::ALL.len()]
+    }
+
+    /// Mesh roughly covering the same area as a circle of `radius`
+    pub fn mesh(&self, radius: f32) -> Mesh {
+        match self {
+            OptionMarker::Circle => Circle::new(radius).into(),
+            OptionMarker::Square => Rectangle::new(radius * 1.7, radius * 1.7).into(),
+            OptionMarker::Diamond => Rhombus::new(radius * 2.2, radius * 2.2).into(),
+            OptionMarker::Triangle => RegularPolygon::new(radius * 1.3, 3).into(),
+            OptionMarker::Hexagon => RegularPolygon::new(radius * 1.1, 6).into(),
+        }
+    }
+
+    /// Whether a point in the unit square (-1..1) lies inside the marker
+    fn contains(&self, point: Vec2) -> bool {
+        let x = point.x.abs();
+        let y = point.y;
+        let sqrt3 = 3.0_f32.sqrt();
+        match self {
+            OptionMarker::Circle => point.length() <= 0.9,
+            OptionMarker::Square => x.max(y.abs()) <= 0.75,
+            OptionMarker::Diamond => x + y.abs() <= 0.95,
+            OptionMarker::Triangle => y >= -0.475 && sqrt3 * x + y <= 0.95,
+            OptionMarker::Hexagon => x <= 0.95 * sqrt3 / 2.0 && x / sqrt3 + y.abs() <= 0.95,
+        }
+    }
+}
+
+/// Color and marker of a single option
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OptionStyle {
+    pub color: Color,
+    pub marker: OptionMarker,
+}
+
+/// Stable styles per option id for the current question set. Options are
+/// styled in id order, so sparse ids still get distinct colors as long as
+/// the set fits the palette.
+#[derive(Resource, Default, Debug)]
+pub struct OptionStyleRegistry {
+    palette: ColorPalette,
+    shape_markers: bool,
+    styles: HashMap<usize, OptionStyle>,
+}
+
+impl OptionStyleRegistry {
+    /// Assign styles to the given option ids
+    pub fn assign(
+        &mut self,
+        palette: ColorPalette,
+        shape_markers: bool,
+        option_ids: impl IntoIterator<Item = usize>,
+    ) {
+        let mut option_ids: Vec<usize> = option_ids.into_iter().collect();
+        option_ids.sort_unstable();
+        option_ids.dedup();
+
+        self.palette = palette;
+        self.shape_markers = shape_markers;
+        self.styles = option_ids
+            .into_iter()
+            .enumerate()
+            .map(|(slot, option_id)| (option_id, Self::style_for_slot(palette, slot)))
+            .collect();
+    }
+
+    fn style_for_slot(palette: ColorPalette, slot: usize) -> OptionStyle {
+        OptionStyle {
+            color: option_color(palette, slot),
+            marker: OptionMarker::for_option(slot),
+        }
+    }
+
+    /// Style of an option id, falling back to the id itself for unknown options
+    pub fn style(&self, option_id: usize) -> OptionStyle {
+        self.styles
+            .get(&option_id)
+            .copied()
+            .unwrap_or_else(|| Self::style_for_slot(self.palette, option_id))
+    }
+
+    pub fn color(&self, option_id: usize) -> Color {
+        self.style(option_id).color
+    }
+
+    pub fn marker(&self, option_id: usize) -> OptionMarker {
+        self.style(option_id).marker
+    }
+
+    pub fn shape_markers(&self) -> bool {
+        self.shape_markers
+    }
+
+    /// Marker shape used for an option id's mesh, `None` for a plain circle
+    pub fn shape(&self, option_id: usize) -> Option<OptionMarker> {
+        self.shape_markers.then(|| self.marker(option_id))
+    }
+}
+
+/// Mesh for a marker shape, or a circle without one
+pub fn shape_mesh(shape: Option<OptionMarker>, radius: f32) -> Mesh {
+    match shape {
+        Some(marker) => marker.mesh(radius),
+        None => Circle::new(radius).into(),
+    }
+}
+
+/// System to restyle options when the question set or the display settings change
+fn update_option_style_registry(
+    question_system: Option<Res<QuestionSystem>>,
+    game_settings: Res<GameSettings>,
+    mut registry: ResMut<OptionStyleRegistry>,
+) {
+    let question_changed = question_system.as_ref().is_some_and(|q| q.is_changed());
+    if !question_changed && !game_settings.is_changed() && !registry.is_added() {
+        return;
+    }
+
+    let option_ids = question_system
+        .iter()
+        .flat_map(|q| q.get_current_options().iter().map(|option| option.id))
+        .collect::<Vec<_>>();
+
+    registry.assign(
+        game_settings.display.color_palette,
+        game_settings.display.shape_markers,
+        option_ids,
+    );
+}
+
+/// White marker sprites for UI, packed side by side into one atlas image so
+/// every marker shares a texture. Tinted with `ImageNode::color`.
+#[derive(Resource, Debug)]
+pub struct MarkerAtlas {
+    image: Handle<Image>,
+    layout: Handle<TextureAtlasLayout>,
+}
+
+impl MarkerAtlas {
+    /// UI image showing a marker
+    pub fn image_node(&self, marker: OptionMarker) -> ImageNode {
+        let index = OptionMarker::ALL
+            .iter()
+            .position(|m| *m == marker)
+            .unwrap_or(0);
+        ImageNode::from_atlas_image(
+            self.image.clone(),
+            TextureAtlas {
+                layout: self.layout.clone(),
+                index,
+            },
+        )
+    }
+}
+
+fn create_marker_atlas(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    mut layouts: ResMut<Assets<TextureAtlasLayout>>,
+) {
+    let size = MARKER_IMAGE_SIZE;
+    let columns = OptionMarker::ALL.len() as u32;
+    let width = size * columns;
+    let mut data = vec![0; (width * size * 4) as usize];
+
+    for (column, marker) in OptionMarker::ALL.iter().enumerate() {
+        for y in 0..size {
+            for x in 0..size {
+                let point = Vec2::new(
+                    (x as f32 + 0.5) / size as f32 * 2.0 - 1.0,
+                    1.0 - (y as f32 + 0.5) / size as f32 * 2.0,
+                );
+                if marker.contains(point) {
+                    let offset = ((y * width + column as u32 * size + x) * 4) as usize;
+                    data[offset..offset + 4].copy_from_slice(&[255, 255, 255, 255]);
+                }
+            }
+        }
+    }
+
+    let image = images.add(Image::new(
+        Extent3d {
+            width,
+            height: size,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    ));
+    let layout = layouts.add(TextureAtlasLayout::from_grid(
+        UVec2::splat(size),
+        columns,
+        1,
+        None,
+        None,
+    ));
+
+    commands.insert_resource(MarkerAtlas { image, layout });
+}
+
+// Palette constants
+pub const MARKER_IMAGE_SIZE: u32 = 32; // Pixels per side of each marker in the atlas
@@ -0,0 +1,137 @@
+//! Performance overlay: FPS, a frame time graph and entity counts, shown when
+//! `show_fps` is enabled. It ships in release builds too, so slow phones can
+//! be diagnosed on the web build without a dev build.
+
+use bevy::{
+    diagnostic::{DiagnosticsStore, EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin},
+    prelude::*,
+};
+use bevy_egui::{EguiContextPass, egui};
+
+use crate::{
+    chain::PlayerChain,
+    effects::{ChainExplosionEffect, CollectionEffect},
+    player::{Player, PlayerIndex},
+    settings::GameSettings,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+        app.add_plugins(FrameTimeDiagnosticsPlugin::default());
+    }
+    if !app.is_plugin_added::<EntityCountDiagnosticsPlugin>() {
+        app.add_plugins(EntityCountDiagnosticsPlugin);
+    }
+
+    app.add_systems(
+        EguiContextPass,
+        perf_hud_egui_ui.run_if(|game_settings: Res<GameSettings>| game_settings.display.show_fps),
+    );
+}
+
+fn perf_hud_egui_ui(
+    mut contexts: bevy_egui::EguiContexts,
+    diagnostics: Res<DiagnosticsStore>,
+    game_settings: Res<GameSettings>,
+    effect_query: Query<(), Or<(With<ChainExplosionEffect>, With<CollectionEffect>)>>,
+    player_query: Query<(&PlayerIndex, &PlayerChain), With<Player>>,
+) {
+    let fps = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FPS)
+        .and_then(|fps| fps.smoothed());
+    let frame_time = diagnostics.get(&FrameTimeDiagnosticsPlugin::FRAME_TIME);
+    let entity_count = diagnostics
+        .get(&EntityCountDiagnosticsPlugin::ENTITY_COUNT)
+        .and_then(|count| count.value());
+
+    let mut chains: Vec<(usize, usize)> = player_query
+        .iter()
+        .map(|(index, chain)| (index.0, chain.segments.len()))
+        .collect();
+    chains.sort_unstable();
+
+    let ctx = contexts.ctx_mut();
+    egui::Area::new(egui::Id::new("perf_hud"))
+        .anchor(egui::Align2::RIGHT_TOP, [-10.0, 60.0])
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.set_width(PERF_GRAPH_WIDTH);
+
+                ui.monospace(format!(
+                    "FPS {:>5.1}   {:>5.1} ms",
+                    fps.unwrap_or_default(),
+                    frame_time
+                        .and_then(|frame_time| frame_time.smoothed())
+                        .unwrap_or_default()
+                ));
+
+                let samples: Vec<f64> = frame_time
+                    .map(|frame_time| frame_time.values().copied().collect())
+                    .unwrap_or_default();
+                frame_time_graph(ui, &samples);
+
+                ui.monospace(format!(
+                    "Entities  {}",
+                    entity_count.map_or(0, |count| count as usize)
+                ));
+                ui.monospace(format!("Particles {}", effect_query.iter().count()));
+                for (index, length) in chains {
+                    ui.monospace(format!(
+                        "{:<10.10}{} segments",
+                        game_settings.multiplayer.player_name(index),
+                        length
+                    ));
+                }
+            });
+        });
+}
+
+/// Bars for the recent frame times, with a line at the 60 FPS budget
+fn frame_time_graph(ui: &mut egui::Ui, samples: &[f64]) {
+    let (rect, _) = ui.allocate_exact_size(
+        egui::vec2(PERF_GRAPH_WIDTH, PERF_GRAPH_HEIGHT),
+        egui::Sense::hover(),
+    );
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 2.0, egui::Color32::from_black_alpha(120));
+
+    let bar_width = rect.width() / samples.len().max(1) as f32;
+    for (i, &milliseconds) in samples.iter().enumerate() {
+        let height = (milliseconds / PERF_GRAPH_MAX_MS).min(1.0) as f32 * rect.height();
+        let color = if milliseconds > SLOW_FRAME_MS {
+            egui::Color32::RED
+        } else if milliseconds > TARGET_FRAME_MS {
+            egui::Color32::YELLOW
+        } else {
+            egui::Color32::GREEN
+        };
+
+        let left = rect.left() + i as f32 * bar_width;
+        painter.rect_filled(
+            egui::Rect::from_min_max(
+                egui::pos2(left, rect.bottom() - height),
+                egui::pos2(left + bar_width, rect.bottom()),
+            ),
+            0.0,
+            color,
+        );
+    }
+
+    let budget_y = rect.bottom() - (TARGET_FRAME_MS / PERF_GRAPH_MAX_MS) as f32 * rect.height();
+    painter.hline(
+        rect.x_range(),
+        budget_y,
+        egui::Stroke::new(1.0, egui::Color32::GRAY),
+    );
+}
+
+// Performance HUD constants
+pub const PERF_GRAPH_WIDTH: f32 = 180.0;
+pub const PERF_GRAPH_HEIGHT: f32 = 40.0;
+/// Frame time at the top of the graph
+pub const PERF_GRAPH_MAX_MS: f64 = 50.0;
+/// 60 FPS
+pub const TARGET_FRAME_MS: f64 = 1000.0 / 60.0;
+/// 30 FPS
+pub const SLOW_FRAME_MS: f64 = 1000.0 / 30.0;
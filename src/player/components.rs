@@ -3,6 +3,7 @@ use bevy::prelude::*;
 /// The main player character
 #[derive(Component, Reflect)]
 #[reflect(Component)]
+#[require(crate::fixed_step::FixedStepPosition)]
 pub struct Player;
 
 /// Controller for player movement
@@ -10,16 +11,81 @@ pub struct Player;
 #[reflect(Component)]
 pub struct PlayerController {
     pub move_speed: f32,
+    /// Speed without any temporary boosts, set from the difficulty
+    pub base_speed: f32,
     pub movement_input: Vec2,
     pub can_move: bool,
+    pub movement_model: MovementModel,
+    /// Current velocity, carried between frames by the momentum model
+    pub velocity: Vec2,
+}
+
+impl PlayerController {
+    pub fn new(base_speed: f32) -> Self {
+        Self {
+            move_speed: base_speed,
+            base_speed,
+            ..default()
+        }
+    }
 }
 
 impl Default for PlayerController {
     fn default() -> Self {
         Self {
             move_speed: super::PLAYER_MOVE_SPEED,
+            base_speed: super::PLAYER_MOVE_SPEED,
             movement_input: Vec2::ZERO,
             can_move: true,
+            movement_model: MovementModel::default(),
+            velocity: Vec2::ZERO,
+        }
+    }
+}
+
+/// How input turns into movement
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MovementModel {
+    /// The player moves at full speed in the input direction right away
+    #[default]
+    Direct,
+    /// The player accelerates toward the input direction and slides to a stop
+    Momentum,
+}
+
+impl MovementModel {
+    pub const ALL: [MovementModel; 2] = [MovementModel::Direct, MovementModel::Momentum];
+
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index.min(Self::ALL.len() - 1)]
+    }
+
+    pub fn index(&self) -> usize {
+        Self::ALL.iter().position(|m| m == self).unwrap_or(0)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            MovementModel::Direct => "Direct",
+            MovementModel::Momentum => "Momentum",
+        }
+    }
+
+    /// Velocity after `delta` seconds of steering toward `target`, the input
+    /// direction at the current top speed. Momentum never overshoots the target,
+    /// so the top speed is also the maximum speed.
+    pub fn steer(&self, velocity: Vec2, target: Vec2, delta: f32) -> Vec2 {
+        match self {
+            MovementModel::Direct => target,
+            MovementModel::Momentum => {
+                // Speeding up in the input direction is quicker than sliding to a stop
+                let rate = if target == Vec2::ZERO {
+                    super::MOMENTUM_FRICTION
+                } else {
+                    super::MOMENTUM_ACCELERATION
+                };
+                velocity + (target - velocity).clamp_length_max(rate * delta)
+            }
         }
     }
 }
@@ -38,6 +104,9 @@ pub struct PlayerStats {
     pub wrong_answers: u32,
     pub current_streak: u32,
     pub best_streak: u32,
+    /// One-use shields earned with answer streaks, each absorbs a wrong answer
+    /// or a run into the player's own chain
+    pub shields: u32,
 }
 
 /// Component for player visual effects
@@ -158,6 +227,15 @@ pub struct OptionCollectedEvent {
     pub option_id: usize,
     pub is_correct: bool,
     pub option_text: String,
+    /// A streak shield absorbed the penalty of this wrong answer
+    pub shielded: bool,
+}
+
+/// Event fired when a streak shield is used up
+#[derive(Event)]
+pub struct ShieldShatteredEvent {
+    pub player_entity: Entity,
+    pub position: Vec2,
 }
 
 /// Event for player visual feedback
@@ -178,3 +256,175 @@ pub enum PlayerVisualEventType {
 #[derive(Component, Reflect)]
 #[reflect(Component)]
 pub struct PlayerIndex(pub usize);
+
+/// Streak-driven state of a player. A streak crossing the fever threshold
+/// starts a timed fever; a wrong answer or the timer running out ends it.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+pub enum FeverState {
+    #[default]
+    Normal,
+    Fever {
+        timer: Timer,
+    },
+}
+
+impl FeverState {
+    pub fn start() -> Self {
+        Self::Fever {
+            timer: Timer::from_seconds(super::FEVER_DURATION, TimerMode::Once),
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        matches!(self, Self::Fever { .. })
+    }
+
+    /// Seconds of fever left, zero when not in fever
+    pub fn remaining(&self) -> f32 {
+        match self {
+            Self::Normal => 0.0,
+            Self::Fever { timer } => timer.remaining_secs(),
+        }
+    }
+
+    /// Multiplier applied to points while in fever
+    pub fn score_multiplier(&self) -> u32 {
+        if self.is_active() {
+            super::FEVER_SCORE_MULTIPLIER
+        } else {
+            1
+        }
+    }
+
+    /// Multiplier applied to movement speed while in fever
+    pub fn speed_multiplier(&self) -> f32 {
+        if self.is_active() {
+            super::FEVER_SPEED_MULTIPLIER
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Event sent when a player enters or leaves fever
+#[derive(Event, Debug)]
+pub struct FeverEvent {
+    pub player_entity: Entity,
+    pub started: bool,
+}
+
+/// Chain magnet ability. Merges charge it; once charged, the interact input
+/// pulls nearby correct options toward the player, followed by a cooldown.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+pub struct ChainMagnet {
+    pub charge: u32,
+    pub pull: Option<Timer>,
+    pub cooldown: Option<Timer>,
+}
+
+impl ChainMagnet {
+    pub fn add_charge(&mut self) {
+        self.charge = (self.charge + 1).min(super::CHAIN_MAGNET_REQUIRED_MERGES);
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.charge >= super::CHAIN_MAGNET_REQUIRED_MERGES
+            && self.pull.is_none()
+            && self.cooldown.is_none()
+    }
+
+    pub fn is_pulling(&self) -> bool {
+        self.pull.is_some()
+    }
+
+    pub fn activate(&mut self) {
+        self.charge = 0;
+        self.pull = Some(Timer::from_seconds(
+            super::CHAIN_MAGNET_PULL_DURATION,
+            TimerMode::Once,
+        ));
+    }
+
+    /// Share of the merges needed before the magnet can be used
+    pub fn charge_fraction(&self) -> f32 {
+        self.charge as f32 / super::CHAIN_MAGNET_REQUIRED_MERGES as f32
+    }
+
+    /// Progress through the cooldown, `None` when not cooling down
+    pub fn cooldown_fraction(&self) -> Option<f32> {
+        self.cooldown.as_ref().map(Timer::fraction)
+    }
+}
+
+/// Short burst of speed followed by a cooldown
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+pub struct Dash {
+    pub active: Option<Timer>,
+    pub cooldown: Option<Timer>,
+}
+
+impl Dash {
+    pub fn is_ready(&self) -> bool {
+        self.active.is_none() && self.cooldown.is_none()
+    }
+
+    pub fn start(&mut self) {
+        self.active = Some(Timer::from_seconds(super::DASH_DURATION, TimerMode::Once));
+    }
+
+    /// Dashing players pass through their own chain
+    pub fn is_active(&self) -> bool {
+        self.active.is_some()
+    }
+
+    /// Progress through the cooldown, `None` when not cooling down
+    pub fn cooldown_fraction(&self) -> Option<f32> {
+        self.cooldown.as_ref().map(Timer::fraction)
+    }
+
+    /// Multiplier applied to movement speed while dashing
+    pub fn speed_multiplier(&self) -> f32 {
+        if self.active.is_some() {
+            super::DASH_SPEED_MULTIPLIER
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Fading afterimage left behind by a dashing player
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct DashTrail {
+    pub timer: Timer,
+}
+
+/// Event to make a player dash if the dash is ready
+#[derive(Event, Debug)]
+pub struct PlayerDashEvent {
+    pub player_entity: Entity,
+}
+
+/// Event to move a player to a fresh spawn point, e.g. after being eliminated
+#[derive(Event)]
+pub struct PlayerRespawnEvent {
+    pub player_entity: Entity,
+}
+
+/// Component granting collision immunity right after (re)spawning
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct SpawnProtection {
+    pub timer: Timer,
+}
+
+impl Default for SpawnProtection {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(super::SPAWN_PROTECTION_DURATION, TimerMode::Once),
+        }
+    }
+}
@@ -4,8 +4,8 @@ mod components;
 mod systems;
 
 pub use components::*;
-pub use systems::spawn_player;
 use systems::*;
+pub use systems::{move_player, spawn_player};
 
 pub(super) fn plugin(app: &mut App) {
     app.register_type::<Player>();
@@ -18,10 +18,19 @@ pub(super) fn plugin(app: &mut App) {
     app.register_type::<PlayerEnergyParticles>();
     app.register_type::<PlayerTrail>();
     app.register_type::<PlayerIndex>();
+    app.register_type::<SpawnProtection>();
+    app.register_type::<FeverState>();
+    app.register_type::<ChainMagnet>();
+    app.register_type::<Dash>();
+    app.register_type::<DashTrail>();
 
     // Register the events
     app.add_event::<OptionCollectedEvent>();
     app.add_event::<PlayerVisualEvent>();
+    app.add_event::<PlayerRespawnEvent>();
+    app.add_event::<FeverEvent>();
+    app.add_event::<PlayerDashEvent>();
+    app.add_event::<ShieldShatteredEvent>();
 
     // Ensure player spawns AFTER map setup
     app.add_systems(
@@ -32,20 +41,94 @@ pub(super) fn plugin(app: &mut App) {
     app.add_systems(
         Update,
         (
+            apply_custom_key_bindings
+                .in_set(crate::AppSystems::RecordInput)
+                .before(handle_player_input),
             handle_player_input.in_set(crate::AppSystems::RecordInput),
-            move_player.in_set(crate::AppSystems::Update),
             collect_options.in_set(crate::AppSystems::Update),
             animate_player.in_set(crate::AppSystems::Update),
             update_player_energy_particles.in_set(crate::AppSystems::Update),
             update_player_trail.in_set(crate::AppSystems::Update),
             handle_player_visual_events.in_set(crate::AppSystems::Update),
             handle_collection_events.in_set(crate::AppSystems::Update),
+            update_fever_state
+                .in_set(crate::AppSystems::Update)
+                .after(handle_collection_events),
+            respawn_players.in_set(crate::AppSystems::Update),
+            update_spawn_protection.in_set(crate::AppSystems::TickTimers),
+            blink_protected_players.in_set(crate::AppSystems::Update),
         )
             .run_if(in_state(crate::screens::Screen::Gameplay))
             .in_set(crate::PausableSystems),
     );
+
+    app.add_systems(
+        Update,
+        (
+            charge_chain_magnets.in_set(crate::AppSystems::Update),
+            activate_chain_magnets.in_set(crate::AppSystems::RecordInput),
+            // Interact dashes unless a charged magnet takes the press
+            dash_on_interact
+                .in_set(crate::AppSystems::RecordInput)
+                .before(activate_chain_magnets),
+            pull_chain_magnets.in_set(crate::AppSystems::Update),
+            update_dash.in_set(crate::AppSystems::Update),
+            (spawn_dash_trail, fade_dash_trail)
+                .chain()
+                .in_set(crate::AppSystems::Update),
+            draw_dash_cooldown_meters.in_set(crate::AppSystems::Update),
+            handle_shield_shattered_events.in_set(crate::AppSystems::Update),
+        )
+            .run_if(in_state(crate::screens::Screen::Gameplay))
+            .in_set(crate::PausableSystems),
+    );
+
+    // Movement runs at a fixed rate, see `fixed_step`
+    app.add_systems(
+        FixedUpdate,
+        move_player
+            .in_set(crate::AppSystems::Update)
+            .run_if(in_state(crate::screens::Screen::Gameplay))
+            .in_set(crate::PausableSystems),
+    );
 }
 
 // Configuration constants
 pub const PLAYER_MOVE_SPEED: f32 = 200.0; // pixels per second
 pub const PLAYER_SIZE: f32 = 20.0;
+pub const SPAWN_PROTECTION_DURATION: f32 = 2.0; // Seconds of collision immunity after respawning
+pub const PROTECTION_BLINK_RATE: f32 = 8.0; // Visibility flips per second while protected
+pub const SPAWN_EDGE_MARGIN: usize = 5; // Cells kept free along the map border
+pub const SPAWN_CANDIDATE_STEP: usize = 2; // Grid step between spawn candidates
+
+// Fever constants
+pub const FEVER_STREAK_THRESHOLD: u32 = 5; // Every N correct answers in a row starts a fever
+pub const FEVER_DURATION: f32 = 8.0;
+pub const FEVER_SCORE_MULTIPLIER: u32 = 2;
+pub const FEVER_SPEED_MULTIPLIER: f32 = 1.25;
+
+// Chain magnet constants
+pub const CHAIN_MAGNET_REQUIRED_MERGES: u32 = 3; // Merges needed to charge the magnet
+pub const CHAIN_MAGNET_RADIUS: f32 = 250.0;
+pub const CHAIN_MAGNET_PULL_DURATION: f32 = 1.5;
+pub const CHAIN_MAGNET_COOLDOWN: f32 = 10.0;
+
+// Streak shield constants
+pub const SHIELD_STREAK_INTERVAL: u32 = 5; // Every N correct answers in a row grant a shield
+pub const MAX_STREAK_SHIELDS: u32 = 3;
+pub const SHIELD_COLOR: Color = Color::srgb(0.45, 0.8, 1.0);
+
+// Momentum movement constants
+pub const MOMENTUM_ACCELERATION: f32 = 900.0; // Pixels per second squared toward the input
+pub const MOMENTUM_FRICTION: f32 = 450.0; // Pixels per second squared of slowdown without input
+pub const MOMENTUM_REST_SPEED: f32 = 2.0; // Slower than this counts as standing still
+
+// Dash constants
+pub const DASH_DURATION: f32 = 0.15;
+pub const DASH_SPEED_MULTIPLIER: f32 = 3.0;
+pub const DASH_COOLDOWN: f32 = 3.0;
+pub const DASH_METER_RADIUS: f32 = PLAYER_SIZE + 8.0; // Cooldown ring around the player
+pub const DASH_METER_COLOR: Color = Color::srgba(0.85, 0.95, 1.0, 0.6);
+pub const DASH_TRAIL_DURATION: f32 = 0.3; // Seconds an afterimage takes to fade
+pub const DASH_TRAIL_COLOR: Color = Color::srgba(0.85, 0.95, 1.0, 0.45);
+pub const DASH_TRAIL_FADE_STEPS: u32 = 6; // Shared materials an afterimage fades through
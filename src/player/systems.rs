@@ -1,18 +1,23 @@
 use super::components::*;
 use crate::{
+    effects::{EntityPool, PoolKind},
     map::{GridMap, GridPosition},
     options::{OptionCollectible, OptionType},
     screens::Screen,
-    settings::GameSettings,
+    settings::{CustomKeyBindings, GameSettings, KeyAction, KeyBindings},
 };
 use bevy::prelude::*;
-use konnektoren_bevy::input::{InputController, PlayerInputMapping};
+use konnektoren_bevy::input::{
+    InputController, PlayerInputMapping,
+    device::{AvailableInputDevices, InputDevice},
+};
 
 /// System to spawn the player at the center of the grid with enhanced visuals
 pub fn spawn_player(
     mut commands: Commands,
     grid_map: Option<Res<GridMap>>,
     game_settings: Res<GameSettings>,
+    key_bindings: Res<CustomKeyBindings>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
@@ -21,16 +26,19 @@ pub fn spawn_player(
         return;
     };
 
-    let player_count = game_settings.multiplayer.player_count;
+    // Keep the starting spots close to the center so everyone starts on screen
+    let start_radius = (grid_map.width.min(grid_map.height) / 6) as f32 * grid_map.cell_size;
+    let mut taken_positions = Vec::new();
 
     for (player_index, player_settings) in game_settings.multiplayer.players.iter().enumerate() {
         if !player_settings.enabled {
             continue;
         }
 
-        // Calculate spawn position based on player count
-        let spawn_pos = calculate_player_spawn_position(player_index, player_count, &grid_map);
+        // Spread players out as far from each other as the start area allows
+        let spawn_pos = select_spawn_position(&grid_map, &taken_positions, start_radius);
         let world_pos = grid_map.grid_to_world(spawn_pos.x, spawn_pos.y);
+        taken_positions.push(world_pos);
 
         let player_effects = PlayerEffects {
             base_color: player_settings.color,
@@ -38,7 +46,7 @@ pub fn spawn_player(
         };
 
         // Create main player visual
-        let main_mesh = meshes.add(Circle::new(super::PLAYER_SIZE));
+        let main_mesh = meshes.add(player_settings.shape.mesh(super::PLAYER_SIZE));
         let main_material = materials.add(ColorMaterial::from(player_settings.color));
 
         // Create visual effect entities
@@ -69,7 +77,13 @@ pub fn spawn_player(
             .spawn((
                 Name::new(format!("Player {}", player_index + 1)),
                 Player,
-                PlayerController::default(),
+                FeverState::default(),
+                ChainMagnet::default(),
+                Dash::default(),
+                PlayerController {
+                    movement_model: game_settings.gameplay.movement_model,
+                    ..PlayerController::new(game_settings.gameplay.tuning().player_move_speed)
+                },
                 PlayerStats::default(),
                 PlayerVisual,
                 Transform::from_translation(Vec3::new(world_pos.x, world_pos.y, 2.0)),
@@ -88,11 +102,20 @@ pub fn spawn_player(
                 player_id: player_index as u32,
                 ..Default::default()
             },
-            PlayerInputMapping {
+        ));
+
+        // Keyboard players with custom keys are driven by `apply_custom_key_bindings`
+        // and swipe and two-thumb players by the game's own touch controls
+        let custom_keys = key_bindings.is_custom(player_index)
+            && key_bindings
+                .bindings_for(player_index, &player_settings.input)
+                .is_some();
+        if !custom_keys && !player_settings.input.uses_game_touch_controls() {
+            commands.entity(player_entity).insert(PlayerInputMapping {
                 player_id: player_index as u32,
                 ..Default::default()
-            },
-        ));
+            });
+        }
 
         // Configure camera target with appropriate weight and priority
         let camera_target = crate::camera::CameraTarget { weight: 1.0 };
@@ -148,28 +171,161 @@ pub fn spawn_player(
     }
 }
 
-fn calculate_player_spawn_position(
-    player_index: usize,
-    total_players: usize,
-    grid_map: &GridMap,
-) -> GridPosition {
-    let center_x = grid_map.width / 2;
-    let center_y = grid_map.height / 2;
+/// Pick the grid cell within `max_distance` of the center that is farthest from
+/// everything in `avoid`. With nothing to avoid the free cell closest to the center is used.
+fn select_spawn_position(grid_map: &GridMap, avoid: &[Vec2], max_distance: f32) -> GridPosition {
+    let center = GridPosition::new(grid_map.width / 2, grid_map.height / 2);
+    let center_world = grid_map.grid_to_world(center.x, center.y);
+    let center_free = !grid_map.is_area_blocked(center_world, super::PLAYER_SIZE);
+    if avoid.is_empty() && center_free {
+        return center;
+    }
 
-    if total_players == 1 {
-        GridPosition::new(center_x, center_y)
-    } else {
-        // Spread players around the center
-        let angle = (player_index as f32 / total_players as f32) * std::f32::consts::TAU;
-        let radius = (grid_map.width.min(grid_map.height) / 6) as f32;
+    let margin = super::SPAWN_EDGE_MARGIN;
+    let mut best = center;
+    let mut best_score = f32::MIN;
 
-        let offset_x = (angle.cos() * radius) as i32;
-        let offset_y = (angle.sin() * radius) as i32;
+    for x in (margin..grid_map.width.saturating_sub(margin)).step_by(super::SPAWN_CANDIDATE_STEP) {
+        for y in
+            (margin..grid_map.height.saturating_sub(margin)).step_by(super::SPAWN_CANDIDATE_STEP)
+        {
+            let candidate = grid_map.grid_to_world(x, y);
+            if candidate.length() > max_distance
+                || grid_map.is_area_blocked(candidate, super::PLAYER_SIZE)
+            {
+                continue;
+            }
 
-        let spawn_x = (center_x as i32 + offset_x).clamp(1, grid_map.width as i32 - 2) as usize;
-        let spawn_y = (center_y as i32 + offset_y).clamp(1, grid_map.height as i32 - 2) as usize;
+            // Prefer clearance from others, or closeness to the center when alone
+            let score = if avoid.is_empty() {
+                -candidate.length()
+            } else {
+                avoid
+                    .iter()
+                    .map(|position| candidate.distance(*position))
+                    .fold(f32::MAX, f32::min)
+            };
+
+            if score > best_score {
+                best_score = score;
+                best = GridPosition::new(x, y);
+            }
+        }
+    }
+
+    best
+}
+
+/// System to move respawning players to the safest spot on the map
+pub fn respawn_players(
+    mut commands: Commands,
+    grid_map: Option<Res<GridMap>>,
+    mut respawn_events: EventReader<PlayerRespawnEvent>,
+    mut explosion_events: EventWriter<crate::effects::SpawnExplosionEvent>,
+    mut visual_events: EventWriter<PlayerVisualEvent>,
+    mut player_query: Query<
+        (
+            Entity,
+            &mut Transform,
+            &mut GridPosition,
+            &mut PlayerController,
+            &PlayerEffects,
+        ),
+        With<Player>,
+    >,
+    segment_query: Query<&Transform, (With<crate::chain::ChainSegment>, Without<Player>)>,
+) {
+    let Some(grid_map) = grid_map else {
+        return;
+    };
+
+    for event in respawn_events.read() {
+        // Other players and every chain segment count as hazards
+        let mut hazards: Vec<Vec2> = player_query
+            .iter()
+            .filter(|(entity, ..)| *entity != event.player_entity)
+            .map(|(_, transform, ..)| transform.translation.xy())
+            .collect();
+        hazards.extend(segment_query.iter().map(|t| t.translation.xy()));
+
+        let Ok((_, mut transform, mut grid_pos, mut controller, effects)) =
+            player_query.get_mut(event.player_entity)
+        else {
+            continue;
+        };
 
-        GridPosition::new(spawn_x, spawn_y)
+        let spawn_pos = select_spawn_position(&grid_map, &hazards, f32::INFINITY);
+        let world_pos = grid_map.grid_to_world(spawn_pos.x, spawn_pos.y);
+
+        transform.translation.x = world_pos.x;
+        transform.translation.y = world_pos.y;
+        *grid_pos = spawn_pos;
+        controller.movement_input = Vec2::ZERO;
+        controller.velocity = Vec2::ZERO;
+
+        commands
+            .entity(event.player_entity)
+            .insert(SpawnProtection::default());
+
+        explosion_events.write(crate::effects::SpawnExplosionEvent {
+            position: transform.translation,
+            color: effects.base_color,
+            intensity: 1.0,
+        });
+        visual_events.write(PlayerVisualEvent {
+            player_entity: event.player_entity,
+            event_type: PlayerVisualEventType::Boost {
+                duration: super::SPAWN_PROTECTION_DURATION,
+                intensity: 1.0,
+            },
+        });
+
+        info!(
+            "Respawned player {:?} at ({}, {})",
+            event.player_entity, spawn_pos.x, spawn_pos.y
+        );
+    }
+}
+
+/// System to count down spawn protection
+pub fn update_spawn_protection(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut player_query: Query<(Entity, &mut SpawnProtection), With<Player>>,
+) {
+    for (entity, mut protection) in &mut player_query {
+        if protection.timer.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<SpawnProtection>();
+        }
+    }
+}
+
+/// System to blink players while spawn protection or steal invulnerability
+/// lasts. One system owns the visibility so overlapping protections blink in
+/// step and the player only shows steadily once both have run out.
+pub fn blink_protected_players(
+    time: Res<Time>,
+    mut player_query: Query<
+        (
+            &mut Visibility,
+            Option<&SpawnProtection>,
+            Option<&crate::chain::StealInvulnerability>,
+        ),
+        With<Player>,
+    >,
+) {
+    let blink_on = (time.elapsed_secs() * super::PROTECTION_BLINK_RATE) as u32 % 2 == 0;
+
+    for (mut visibility, spawn_protection, steal_invulnerability) in &mut player_query {
+        // A finished timer may not have been removed yet
+        let protected = spawn_protection.is_some_and(|protection| !protection.timer.finished())
+            || steal_invulnerability.is_some_and(|protection| !protection.timer.finished());
+
+        visibility.set_if_neq(if protected && !blink_on {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        });
     }
 }
 
@@ -177,29 +333,71 @@ fn calculate_player_spawn_position(
 pub fn move_player(
     time: Res<Time>,
     grid_map: Option<Res<GridMap>>,
-    mut player_query: Query<(&PlayerController, &mut GridPosition, &mut Transform), With<Player>>,
+    mut player_query: Query<
+        (
+            &mut PlayerController,
+            &FeverState,
+            &Dash,
+            &mut GridPosition,
+            &mut Transform,
+        ),
+        With<Player>,
+    >,
 ) {
     let Some(grid_map) = grid_map else {
         return;
     };
 
-    for (controller, mut grid_pos, mut transform) in &mut player_query {
-        if controller.movement_input == Vec2::ZERO {
+    for (mut controller, fever, dash, mut grid_pos, mut transform) in &mut player_query {
+        // Stunned and frozen players stop dead, whatever the movement model
+        if !controller.can_move {
+            controller.velocity = Vec2::ZERO;
             continue;
         }
 
-        // Calculate movement delta
-        let movement_delta = controller.movement_input * controller.move_speed * time.delta_secs();
+        let speed = controller.move_speed * fever.speed_multiplier() * dash.speed_multiplier();
+        let target = controller.movement_input * speed;
+        let velocity =
+            controller
+                .movement_model
+                .steer(controller.velocity, target, time.delta_secs());
+        controller.velocity = velocity;
+        if velocity.length_squared() < super::MOMENTUM_REST_SPEED * super::MOMENTUM_REST_SPEED {
+            controller.velocity = Vec2::ZERO;
+            continue;
+        }
 
-        // Update world position
-        let new_world_pos = Vec2::new(
-            transform.translation.x + movement_delta.x,
-            transform.translation.y + movement_delta.y,
-        );
+        // Calculate movement delta
+        let movement_delta = velocity * time.delta_secs();
+
+        // Update world position, sliding along walls instead of entering them
+        let current_pos = transform.translation.xy();
+        let Some(wrapped_world_pos) = [
+            movement_delta,
+            Vec2::new(movement_delta.x, 0.0),
+            Vec2::new(0.0, movement_delta.y),
+        ]
+        .into_iter()
+        .map(|delta| {
+            handle_map_wraparound(
+                current_pos + delta,
+                grid_map.half_width(),
+                grid_map.half_height(),
+            )
+        })
+        .find(|position| !grid_map.is_area_blocked(*position, super::PLAYER_SIZE * 0.7)) else {
+            controller.velocity = Vec2::ZERO;
+            continue;
+        };
 
-        // Handle wraparound using grid map dimensions
-        let wrapped_world_pos =
-            handle_map_wraparound(new_world_pos, grid_map.half_width(), grid_map.half_height());
+        // A wall takes away the momentum going into it
+        let moved = wrapped_world_pos - current_pos;
+        if moved.x.abs() < f32::EPSILON {
+            controller.velocity.x = 0.0;
+        }
+        if moved.y.abs() < f32::EPSILON {
+            controller.velocity.y = 0.0;
+        }
 
         // Update transform
         transform.translation.x = wrapped_world_pos.x;
@@ -213,6 +411,38 @@ pub fn move_player(
     }
 }
 
+/// System to drive keyboard players with customized key bindings. Their
+/// `PlayerInputMapping` is left out at spawn so this is the only writer.
+pub fn apply_custom_key_bindings(
+    game_settings: Res<GameSettings>,
+    key_bindings: Res<CustomKeyBindings>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut player_query: Query<
+        (&PlayerIndex, &mut InputController),
+        (
+            With<Player>,
+            Without<PlayerInputMapping>,
+            Without<crate::bot::BotController>,
+        ),
+    >,
+) {
+    for (player_index, mut input_controller) in &mut player_query {
+        let Some(bindings) = game_settings
+            .multiplayer
+            .players
+            .get(player_index.0)
+            .and_then(|player| key_bindings.bindings_for(player_index.0, &player.input))
+        else {
+            continue;
+        };
+
+        let movement = bindings.movement(&keyboard);
+        if input_controller.movement != movement {
+            input_controller.movement = movement;
+        }
+    }
+}
+
 /// System to handle player input using the new input system
 pub fn handle_player_input(
     mut player_query: Query<
@@ -257,22 +487,32 @@ pub fn collect_options(
     mut commands: Commands,
     mut event_writer: EventWriter<OptionCollectedEvent>,
     mut collection_effects: EventWriter<crate::effects::SpawnCollectionEvent>,
-    mut player_query: Query<(Entity, &Transform), With<Player>>,
+    mut shield_events: EventWriter<ShieldShatteredEvent>,
+    spatial_index: Res<crate::spatial::SpatialIndex>,
+    mut player_query: Query<(Entity, &Transform, &mut PlayerStats), With<Player>>,
     option_query: Query<
-        (Entity, &Transform, &OptionCollectible, &OptionType),
+        (&Transform, &OptionCollectible, &OptionType),
         (Without<Player>, With<crate::options::OptionVisual>),
     >,
 ) {
-    for (player_entity, player_transform) in &mut player_query {
-        for (option_entity, option_transform, collectible, option_type) in &option_query {
-            // Calculate distance between player and option
-            let distance = player_transform
-                .translation
-                .xy()
-                .distance(option_transform.translation.xy());
+    // Collection radius (player size + option size)
+    let collection_radius = super::PLAYER_SIZE + 14.0; // Option size is 14.0
+    let mut collected = Vec::new();
+
+    for (player_entity, player_transform, mut stats) in &mut player_query {
+        let player_pos = player_transform.translation.xy();
+
+        for option_entity in spatial_index.options.near(player_pos, collection_radius) {
+            if collected.contains(&option_entity) {
+                continue;
+            }
+            let Ok((option_transform, collectible, option_type)) = option_query.get(option_entity)
+            else {
+                continue;
+            };
 
-            // Collection radius (player size + option size)
-            let collection_radius = super::PLAYER_SIZE + 14.0; // Option size is 14.0
+            // Calculate distance between player and option
+            let distance = player_pos.distance(option_transform.translation.xy());
 
             if distance <= collection_radius {
                 // Spawn collection effect
@@ -287,16 +527,28 @@ pub fn collect_options(
                     }),
                 });
 
+                // A streak shield takes the hit for a wrong answer
+                let shielded = !collectible.is_correct && stats.shields > 0;
+                if shielded {
+                    stats.shields -= 1;
+                    shield_events.write(ShieldShatteredEvent {
+                        player_entity,
+                        position: player_pos,
+                    });
+                }
+
                 // Send collection event
                 event_writer.write(OptionCollectedEvent {
                     player_entity,
                     option_id: option_type.option_id,
                     is_correct: collectible.is_correct,
                     option_text: collectible.option_text.clone(),
+                    shielded,
                 });
 
                 // Remove the collected option
                 commands.entity(option_entity).despawn();
+                collected.push(option_entity);
 
                 info!("Player collected option: {}", collectible.option_text);
             }
@@ -329,8 +581,10 @@ pub fn animate_player(
         (Without<Player>, Without<PlayerGlow>),
     >,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    game_settings: Res<GameSettings>,
 ) {
     let time_factor = time.elapsed_secs();
+    let motion = game_settings.accessibility.effects_intensity.motion_scale();
 
     for (controller, mut transform, mut effects, children) in &mut player_query {
         // Update boost timer
@@ -352,7 +606,8 @@ pub fn animate_player(
         } else {
             1.0
         };
-        let pulse = 1.0 + (time_factor * effects.pulse_speed).sin() * 0.1 * movement_intensity;
+        let pulse =
+            1.0 + (time_factor * effects.pulse_speed).sin() * 0.1 * movement_intensity * motion;
         transform.scale = Vec3::splat(pulse);
 
         // Rotation based on movement
@@ -373,7 +628,8 @@ pub fn animate_player(
         for child in children.iter() {
             if let Ok((mut glow_transform, material_handle)) = glow_query.get_mut(child) {
                 // Glow pulsing (offset from main pulse)
-                let glow_pulse = 1.0 + (time_factor * effects.pulse_speed * 1.3).sin() * 0.2;
+                let glow_pulse =
+                    1.0 + (time_factor * effects.pulse_speed * 1.3).sin() * 0.2 * motion;
                 glow_transform.scale = Vec3::splat(glow_pulse);
 
                 // Only update material color occasionally to reduce performance impact
@@ -394,7 +650,7 @@ pub fn animate_player(
 
             if let Ok((mut aura_transform, mut aura, material_handle)) = aura_query.get_mut(child) {
                 // Aura rotation and pulsing
-                aura.aura_phase += time.delta_secs() * 1.5;
+                aura.aura_phase += time.delta_secs() * 1.5 * motion;
                 if aura.aura_phase > std::f32::consts::TAU {
                     aura.aura_phase = 0.0;
                 }
@@ -403,7 +659,7 @@ pub fn animate_player(
                 aura_transform.rotation = Quat::from_rotation_z(aura.aura_phase);
 
                 // Breathing aura effect
-                let aura_scale = 1.0 + (time_factor * 1.0).sin() * 0.15;
+                let aura_scale = 1.0 + (time_factor * 1.0).sin() * 0.15 * motion;
                 aura_transform.scale = Vec3::splat(aura_scale);
 
                 // Only update material color occasionally
@@ -428,14 +684,26 @@ pub fn animate_player(
 /// System to create energy particles around the player (OPTIMIZED)
 pub fn update_player_energy_particles(
     time: Res<Time>,
-    mut player_query: Query<(&Transform, &mut PlayerEnergyParticles, &PlayerEffects), With<Player>>,
+    mut player_query: Query<
+        (
+            &Transform,
+            &mut PlayerEnergyParticles,
+            &PlayerEffects,
+            &ViewVisibility,
+        ),
+        With<Player>,
+    >,
     mut particle_events: EventWriter<crate::effects::SpawnCollectionEvent>,
+    game_settings: Res<GameSettings>,
 ) {
-    for (transform, mut particles, effects) in &mut player_query {
+    let intensity = game_settings.accessibility.effects_intensity;
+
+    for (transform, mut particles, effects, view_visibility) in &mut player_query {
         particles.particle_timer.tick(time.delta());
 
-        // Reduce particle frequency and only spawn when energy is high
+        // Reduce particle frequency and only spawn when energy is high and on screen
         if particles.particle_timer.just_finished()
+            && view_visibility.get()
             && effects.energy_level > 0.7
             && !effects.is_boosted
         // Disable regular particles during boost to reduce spam
@@ -445,6 +713,7 @@ pub fn update_player_energy_particles(
 
             // Reduce particle count
             let particle_count = if effects.energy_level > 0.9 { 1 } else { 0 };
+            let particle_count = intensity.particle_count(particle_count);
 
             for i in 0..particle_count {
                 // Create orbital particle positions
@@ -476,12 +745,13 @@ pub fn update_player_trail(
             &mut PlayerTrail,
             &PlayerController,
             &PlayerEffects,
+            &ViewVisibility,
         ),
         With<Player>,
     >,
     mut trail_events: EventWriter<crate::effects::SpawnCollectionEvent>,
 ) {
-    for (transform, mut trail, controller, effects) in &mut player_query {
+    for (transform, mut trail, controller, effects, view_visibility) in &mut player_query {
         trail.trail_timer.tick(time.delta());
 
         // Only create trail when moving and reduce frequency
@@ -508,6 +778,11 @@ pub fn update_player_trail(
                 trail.trail_positions.remove(0);
             }
 
+            // Skip the particles themselves while the player is off-screen
+            if !view_visibility.get() {
+                continue;
+            }
+
             // Create fewer trail particles
             for (i, (pos, age)) in trail.trail_positions.iter().enumerate().rev() {
                 if i % 5 == 0 {
@@ -565,6 +840,375 @@ pub fn handle_player_visual_events(
     }
 }
 
+/// System to drive the fever state machine from answers and its timer
+pub fn update_fever_state(
+    time: Res<Time>,
+    mut collection_events: EventReader<OptionCollectedEvent>,
+    mut fever_events: EventWriter<FeverEvent>,
+    mut player_query: Query<
+        (Entity, &mut FeverState, &PlayerStats, &mut PlayerEffects),
+        With<Player>,
+    >,
+) {
+    for event in collection_events.read() {
+        let Ok((_, mut fever, stats, _)) = player_query.get_mut(event.player_entity) else {
+            continue;
+        };
+
+        if !event.is_correct {
+            if fever.is_active() {
+                *fever = FeverState::Normal;
+                fever_events.write(FeverEvent {
+                    player_entity: event.player_entity,
+                    started: false,
+                });
+            }
+            continue;
+        }
+
+        if stats.current_streak > 0 && stats.current_streak % super::FEVER_STREAK_THRESHOLD == 0 {
+            if !fever.is_active() {
+                fever_events.write(FeverEvent {
+                    player_entity: event.player_entity,
+                    started: true,
+                });
+            }
+            // Another threshold during fever extends it
+            *fever = FeverState::start();
+        }
+    }
+
+    for (entity, mut fever, _, mut effects) in &mut player_query {
+        let FeverState::Fever { timer } = fever.as_mut() else {
+            continue;
+        };
+
+        timer.tick(time.delta());
+        if timer.finished() {
+            *fever = FeverState::Normal;
+            fever_events.write(FeverEvent {
+                player_entity: entity,
+                started: false,
+            });
+            continue;
+        }
+
+        // Keep the rainbow boost visuals running for the whole fever
+        if !effects.is_boosted {
+            effects.boost(timer.remaining_secs(), 1.0);
+        }
+    }
+}
+
+/// System to charge chain magnets from completed merges
+pub fn charge_chain_magnets(
+    mut merge_events: EventReader<crate::chain::ChainMergeCompletedEvent>,
+    mut magnet_query: Query<&mut ChainMagnet, With<Player>>,
+) {
+    for event in merge_events.read() {
+        if let Ok(mut magnet) = magnet_query.get_mut(event.player_entity) {
+            magnet.add_charge();
+        }
+    }
+}
+
+/// System to activate a charged chain magnet on the player's interact input
+pub fn activate_chain_magnets(
+    game_settings: Res<GameSettings>,
+    key_bindings: Res<CustomKeyBindings>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    available_devices: Res<AvailableInputDevices>,
+    gamepads: Query<&Gamepad>,
+    mut player_query: Query<
+        (&PlayerIndex, &Transform, &mut ChainMagnet),
+        (With<Player>, Without<crate::bot::BotController>),
+    >,
+    mut popup_events: EventWriter<crate::popups::SpawnTextPopupEvent>,
+) {
+    for (player_index, transform, mut magnet) in &mut player_query {
+        if !magnet.is_ready() {
+            continue;
+        }
+
+        let Some(player_settings) = game_settings.multiplayer.players.get(player_index.0) else {
+            continue;
+        };
+        let input = &player_settings.input;
+        let bindings = key_bindings.bindings_for(player_index.0, input);
+        let pressed = std::iter::once(&input.primary_input)
+            .chain(input.secondary_input.as_ref())
+            .any(|device| {
+                interact_just_pressed(
+                    device,
+                    bindings.as_ref(),
+                    &keyboard,
+                    &mouse,
+                    &available_devices,
+                    &gamepads,
+                )
+            });
+
+        if pressed {
+            magnet.activate();
+            popup_events.write(crate::popups::SpawnTextPopupEvent::new(
+                transform.translation.xy() + Vec2::Y * 40.0,
+                "Magnet!",
+                Color::srgb(0.4, 0.8, 1.0),
+                crate::popups::PopupStyle::Combo,
+            ));
+        }
+    }
+}
+
+/// The interact binding of each input device
+fn interact_just_pressed(
+    device: &InputDevice,
+    bindings: Option<&KeyBindings>,
+    keyboard: &ButtonInput<KeyCode>,
+    mouse: &ButtonInput<MouseButton>,
+    available_devices: &AvailableInputDevices,
+    gamepads: &Query<&Gamepad>,
+) -> bool {
+    match device {
+        InputDevice::Keyboard(scheme) => {
+            let interact = bindings.map_or_else(
+                || KeyBindings::for_scheme(scheme).get(KeyAction::Interact),
+                |bindings| bindings.get(KeyAction::Interact),
+            );
+            keyboard.just_pressed(interact)
+        }
+        InputDevice::Mouse => mouse.just_pressed(MouseButton::Right),
+        InputDevice::Gamepad(index) => available_devices
+            .gamepads
+            .get(*index as usize)
+            .and_then(|&entity| gamepads.get(entity).ok())
+            .is_some_and(|gamepad| gamepad.just_pressed(GamepadButton::South)),
+        _ => false,
+    }
+}
+
+/// System to dash with the interact binding while the chain magnet isn't charged
+pub fn dash_on_interact(
+    game_settings: Res<GameSettings>,
+    key_bindings: Res<CustomKeyBindings>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    available_devices: Res<AvailableInputDevices>,
+    gamepads: Query<&Gamepad>,
+    player_query: Query<
+        (Entity, &PlayerIndex, &Dash, &ChainMagnet),
+        (With<Player>, Without<crate::bot::BotController>),
+    >,
+    mut dash_events: EventWriter<PlayerDashEvent>,
+) {
+    for (player_entity, player_index, dash, magnet) in &player_query {
+        if !dash.is_ready() || magnet.is_ready() {
+            continue;
+        }
+
+        let Some(player_settings) = game_settings.multiplayer.players.get(player_index.0) else {
+            continue;
+        };
+        let input = &player_settings.input;
+        let bindings = key_bindings.bindings_for(player_index.0, input);
+        let pressed = std::iter::once(&input.primary_input)
+            .chain(input.secondary_input.as_ref())
+            .any(|device| {
+                interact_just_pressed(
+                    device,
+                    bindings.as_ref(),
+                    &keyboard,
+                    &mouse,
+                    &available_devices,
+                    &gamepads,
+                )
+            });
+
+        if pressed {
+            dash_events.write(PlayerDashEvent { player_entity });
+        }
+    }
+}
+
+/// System to start requested dashes and count down active dashes and cooldowns
+pub fn update_dash(
+    time: Res<Time>,
+    mut dash_events: EventReader<PlayerDashEvent>,
+    mut player_query: Query<(&mut Dash, &mut PlayerEffects), With<Player>>,
+) {
+    for event in dash_events.read() {
+        let Ok((mut dash, mut effects)) = player_query.get_mut(event.player_entity) else {
+            continue;
+        };
+        if dash.is_ready() {
+            dash.start();
+            effects.boost(super::DASH_DURATION, 1.0);
+        }
+    }
+
+    for (mut dash, _) in &mut player_query {
+        if let Some(active) = dash.active.as_mut() {
+            if active.tick(time.delta()).finished() {
+                dash.active = None;
+                dash.cooldown = Some(Timer::from_seconds(super::DASH_COOLDOWN, TimerMode::Once));
+            }
+        } else if let Some(cooldown) = dash.cooldown.as_mut() {
+            if cooldown.tick(time.delta()).finished() {
+                dash.cooldown = None;
+            }
+        }
+    }
+}
+
+/// System to leave fading afterimages behind dashing players
+pub fn spawn_dash_trail(
+    mut commands: Commands,
+    mut pool: ResMut<EntityPool>,
+    mut asset_cache: ResMut<crate::asset_cache::SharedAssetCache>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    player_query: Query<(&Transform, &Dash, &ViewVisibility), With<Player>>,
+) {
+    for (transform, dash, view_visibility) in &player_query {
+        if !dash.is_active() || !view_visibility.get() {
+            continue;
+        }
+
+        let position = transform.translation.xy();
+        let bundle = (
+            MeshMaterial2d(asset_cache.material(super::DASH_TRAIL_COLOR, &mut materials)),
+            Transform::from_translation(position.extend(transform.translation.z - 0.1)),
+            DashTrail {
+                timer: Timer::from_seconds(super::DASH_TRAIL_DURATION, TimerMode::Once),
+            },
+            Visibility::Inherited,
+        );
+        match pool.acquire(PoolKind::DashTrail) {
+            Some(entity) => {
+                commands.entity(entity).insert(bundle);
+            }
+            None => {
+                commands.spawn((
+                    Name::new("Dash Trail"),
+                    Mesh2d(asset_cache.circle_mesh(super::PLAYER_SIZE * 0.8, &mut meshes)),
+                    bundle,
+                    StateScoped(Screen::Gameplay),
+                ));
+            }
+        }
+    }
+}
+
+/// System to shrink and fade dash afterimages, releasing them when done.
+/// The fade steps through a few shared materials instead of changing one in place.
+pub fn fade_dash_trail(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut pool: ResMut<EntityPool>,
+    mut asset_cache: ResMut<crate::asset_cache::SharedAssetCache>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut trail_query: Query<(
+        Entity,
+        &mut DashTrail,
+        &mut Transform,
+        &mut MeshMaterial2d<ColorMaterial>,
+    )>,
+) {
+    for (entity, mut trail, mut transform, mut material) in &mut trail_query {
+        if trail.timer.tick(time.delta()).finished() {
+            pool.release::<DashTrail>(&mut commands, PoolKind::DashTrail, entity);
+            continue;
+        }
+
+        let remaining = 1.0 - trail.timer.fraction();
+        transform.scale = Vec3::splat(0.5 + remaining * 0.5);
+
+        let steps = super::DASH_TRAIL_FADE_STEPS as f32;
+        let faded = (remaining * steps).ceil() / steps;
+        let color = super::DASH_TRAIL_COLOR.with_alpha(super::DASH_TRAIL_COLOR.alpha() * faded);
+        let handle = asset_cache.material(color, &mut materials);
+        if material.0 != handle {
+            material.0 = handle;
+        }
+    }
+}
+
+/// System to draw a ring around each player that fills up as the dash recharges
+pub fn draw_dash_cooldown_meters(
+    mut gizmos: Gizmos,
+    player_query: Query<(&Transform, &Dash, &ViewVisibility), With<Player>>,
+) {
+    for (transform, dash, view_visibility) in &player_query {
+        let Some(fraction) = dash.cooldown_fraction() else {
+            continue;
+        };
+        if !view_visibility.get() {
+            continue;
+        }
+
+        gizmos.arc_2d(
+            Isometry2d::from_translation(transform.translation.xy()),
+            fraction * std::f32::consts::TAU,
+            super::DASH_METER_RADIUS,
+            super::DASH_METER_COLOR,
+        );
+    }
+}
+
+/// System to pull nearby correct options toward players with an active magnet
+pub fn pull_chain_magnets(
+    time: Res<Time>,
+    mut player_query: Query<(&Transform, &mut ChainMagnet), With<Player>>,
+    mut option_query: Query<(&mut Transform, &OptionCollectible), Without<Player>>,
+) {
+    for (player_transform, mut magnet) in &mut player_query {
+        if let Some(cooldown) = magnet.cooldown.as_mut() {
+            cooldown.tick(time.delta());
+            if cooldown.finished() {
+                magnet.cooldown = None;
+            }
+        }
+
+        let Some(pull) = magnet.pull.as_mut() else {
+            continue;
+        };
+        pull.tick(time.delta());
+
+        // Close the remaining gap evenly so options arrive as the pull ends
+        let remaining = pull.remaining_secs();
+        let step_fraction = if remaining > 0.0 {
+            (time.delta_secs() / remaining).min(1.0)
+        } else {
+            1.0
+        };
+
+        if pull.finished() {
+            magnet.pull = None;
+            magnet.cooldown = Some(Timer::from_seconds(
+                super::CHAIN_MAGNET_COOLDOWN,
+                TimerMode::Once,
+            ));
+        }
+
+        let player_pos = player_transform.translation.xy();
+        for (mut option_transform, option) in &mut option_query {
+            if !option.is_correct {
+                continue;
+            }
+
+            let offset = player_pos - option_transform.translation.xy();
+            if offset.length() > super::CHAIN_MAGNET_RADIUS {
+                continue;
+            }
+
+            let movement = offset * step_fraction;
+            option_transform.translation.x += movement.x;
+            option_transform.translation.y += movement.y;
+        }
+    }
+}
+
 /// System to handle option collection events and provide enhanced feedback (OPTIMIZED)
 pub fn handle_collection_events(
     mut collection_events: EventReader<OptionCollectedEvent>,
@@ -582,6 +1226,14 @@ pub fn handle_collection_events(
                     stats.best_streak = stats.current_streak;
                 }
 
+                // Every few correct answers in a row earn a shield
+                if stats.current_streak % super::SHIELD_STREAK_INTERVAL == 0
+                    && stats.shields < super::MAX_STREAK_SHIELDS
+                {
+                    stats.shields += 1;
+                    info!("🛡 Shield earned, {} ready", stats.shields);
+                }
+
                 info!(
                     "✅ Correct! Collected '{}' (ID: {}) - Streak: {}",
                     event.option_text, event.option_id, stats.current_streak
@@ -636,3 +1288,27 @@ pub fn handle_collection_events(
         }
     }
 }
+
+/// System to shatter a used-up streak shield with a burst of shards and a popup
+pub fn handle_shield_shattered_events(
+    mut shield_events: EventReader<ShieldShatteredEvent>,
+    mut celebration_events: EventWriter<crate::effects::SpawnCelebrationEvent>,
+    mut popup_events: EventWriter<crate::popups::SpawnTextPopupEvent>,
+    locale: Res<crate::locale::Locale>,
+) {
+    for event in shield_events.read() {
+        info!("🛡 Shield of {:?} shattered", event.player_entity);
+
+        celebration_events.write(crate::effects::SpawnCelebrationEvent::new(
+            event.position.extend(0.0),
+            crate::effects::CelebrationKind::FireworkRing,
+            super::SHIELD_COLOR,
+        ));
+        popup_events.write(crate::popups::SpawnTextPopupEvent::new(
+            event.position + Vec2::Y * 20.0,
+            locale.text("hud.shield_shattered"),
+            super::SHIELD_COLOR,
+            crate::popups::PopupStyle::Combo,
+        ));
+    }
+}
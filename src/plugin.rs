@@ -61,6 +61,77 @@ impl Plugin for AppPlugin {
             gameplay::plugin,
             theme::plugin,
             effects::plugin,
+            #[cfg(not(feature = "minimal-web"))]
+            leaderboard::plugin,
+        ));
+
+        app.add_plugins((
+            launch::plugin,
+            quick_play::plugin,
+            powerups::plugin,
+            bot::plugin,
+            notifications::plugin,
+            session::plugin,
+            round::plugin,
+            #[cfg(not(feature = "minimal-web"))]
+            bug_report::plugin,
+            popups::plugin,
+            stats::plugin,
+            achievements::plugin,
+            review::plugin,
+            gestures::plugin,
+            game_feel::plugin,
+        ));
+
+        app.add_plugins((
+            palette::plugin,
+            rng::plugin,
+            locale::plugin,
+            #[cfg(not(feature = "minimal-web"))]
+            accessibility::plugin,
+            spatial::plugin,
+            asset_cache::plugin,
+            suspend::plugin,
+            #[cfg(not(feature = "minimal-web"))]
+            profile::plugin,
+            daily::plugin,
+            hazards::plugin,
+            letters::plugin,
+            feed::plugin,
+            perf_hud::plugin,
+            safe_area::plugin,
+            touch_controls::plugin,
+        ));
+
+        app.add_plugins((
+            #[cfg(not(feature = "minimal-web"))]
+            challenge_import::plugin,
+            config::plugin,
+            director::plugin,
+            display::plugin,
+            explanation::plugin,
+            #[cfg(not(feature = "minimal-web"))]
+            export::plugin,
+            fixed_step::plugin,
+            gamepad_hotplug::plugin,
+            idle::plugin,
+            match_play::plugin,
+            practice::plugin,
+            #[cfg(not(feature = "minimal-web"))]
+            pronunciation::plugin,
+            #[cfg(not(feature = "minimal-web"))]
+            screenshot::plugin,
+            sudden_death::plugin,
+            #[cfg(not(feature = "minimal-web"))]
+            victory_lap::plugin,
+        ));
+
+        app.add_plugins((
+            auto_pause::plugin,
+            preload::plugin,
+            transitions::plugin,
+            #[cfg(target_family = "wasm")]
+            web_bridge::plugin,
         ));
 
         // Order new `AppSystems` variants by adding them here:
@@ -73,10 +144,20 @@ impl Plugin for AppPlugin {
             )
                 .chain(),
         );
+        app.configure_sets(
+            FixedUpdate,
+            (
+                AppSystems::TickTimers,
+                AppSystems::RecordInput,
+                AppSystems::Update,
+            )
+                .chain(),
+        );
 
         // Set up the `Pause` state.
         app.init_state::<Pause>();
         app.configure_sets(Update, PausableSystems.run_if(in_state(Pause(false))));
+        app.configure_sets(FixedUpdate, PausableSystems.run_if(in_state(Pause(false))));
 
         // Initialize game state
         app.register_type::<game_state::GameState>();
@@ -0,0 +1,71 @@
+use bevy::prelude::*;
+
+/// Event to show rising, fading text in the game world
+#[derive(Event, Clone, Debug)]
+pub struct SpawnTextPopupEvent {
+    pub position: Vec2,
+    pub text: String,
+    pub color: Color,
+    pub style: PopupStyle,
+}
+
+impl SpawnTextPopupEvent {
+    pub fn new(position: Vec2, text: impl Into<String>, color: Color, style: PopupStyle) -> Self {
+        Self {
+            position,
+            text: text.into(),
+            color,
+            style,
+        }
+    }
+}
+
+/// How prominent a popup is
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PopupStyle {
+    /// Regular point gains
+    #[default]
+    Score,
+    /// Point losses
+    Penalty,
+    /// Merge combos and other multipliers
+    Combo,
+    /// Streak and other milestones
+    Milestone,
+}
+
+impl PopupStyle {
+    pub fn font_size(&self) -> f32 {
+        match self {
+            PopupStyle::Score | PopupStyle::Penalty => 14.0,
+            PopupStyle::Combo => 18.0,
+            PopupStyle::Milestone => 24.0,
+        }
+    }
+
+    pub fn duration(&self) -> f32 {
+        match self {
+            PopupStyle::Score | PopupStyle::Penalty => 0.9,
+            PopupStyle::Combo => 1.2,
+            PopupStyle::Milestone => 1.6,
+        }
+    }
+
+    /// Penalties sink instead of rising
+    pub fn rise_direction(&self) -> f32 {
+        match self {
+            PopupStyle::Penalty => -0.5,
+            _ => 1.0,
+        }
+    }
+}
+
+/// Floating text that rises and fades out
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct TextPopup {
+    pub timer: Timer,
+    pub color: Color,
+    pub style: PopupStyle,
+    pub drift: f32,
+}
@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+mod components;
+mod systems;
+
+pub use components::*;
+use systems::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<TextPopup>();
+
+    app.add_event::<SpawnTextPopupEvent>();
+
+    app.add_systems(
+        Update,
+        (
+            spawn_text_popups.in_set(crate::AppSystems::Update),
+            animate_text_popups.in_set(crate::AppSystems::Update),
+        )
+            .run_if(in_state(crate::screens::Screen::Gameplay))
+            .in_set(crate::PausableSystems),
+    );
+}
+
+// Popup constants
+pub const POPUP_Z: f32 = 6.0;
+pub const POPUP_RISE_SPEED: f32 = 40.0;
+pub const POPUP_DRIFT: f32 = 12.0; // Max sideways drift so stacked popups don't overlap
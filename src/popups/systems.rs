@@ -0,0 +1,72 @@
+use super::components::*;
+use crate::effects::{EntityPool, PoolKind};
+use crate::screens::Screen;
+use bevy::prelude::*;
+use rand::Rng;
+
+/// System to spawn popup text entities from popup events
+pub fn spawn_text_popups(
+    mut commands: Commands,
+    mut popup_events: EventReader<SpawnTextPopupEvent>,
+    mut pool: ResMut<EntityPool>,
+) {
+    let mut rng = rand::thread_rng();
+
+    for event in popup_events.read() {
+        let bundle = (
+            Name::new(format!("Text Popup: {}", event.text)),
+            Text2d::new(event.text.clone()),
+            TextFont {
+                font_size: event.style.font_size(),
+                ..default()
+            },
+            TextColor(event.color),
+            Transform::from_translation(event.position.extend(super::POPUP_Z)),
+            Visibility::Inherited,
+            TextPopup {
+                timer: Timer::from_seconds(event.style.duration(), TimerMode::Once),
+                color: event.color,
+                style: event.style,
+                drift: rng.gen_range(-super::POPUP_DRIFT..super::POPUP_DRIFT),
+            },
+        );
+
+        match pool.acquire(PoolKind::Popup) {
+            Some(entity) => {
+                commands.entity(entity).insert(bundle);
+            }
+            None => {
+                commands.spawn((bundle, StateScoped(Screen::Gameplay)));
+            }
+        }
+    }
+}
+
+/// System to float popups, fade them out and return them to the pool when done
+pub fn animate_text_popups(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut pool: ResMut<EntityPool>,
+    mut popup_query: Query<(Entity, &mut TextPopup, &mut Transform, &mut TextColor)>,
+) {
+    for (entity, mut popup, mut transform, mut text_color) in &mut popup_query {
+        popup.timer.tick(time.delta());
+        let progress = popup.timer.fraction();
+
+        transform.translation.y +=
+            super::POPUP_RISE_SPEED * popup.style.rise_direction() * time.delta_secs();
+        transform.translation.x += popup.drift * time.delta_secs();
+
+        // Milestones pop in before settling
+        if popup.style == PopupStyle::Milestone {
+            let pop = 1.0 + (1.0 - (progress * 4.0).min(1.0)) * 0.5;
+            transform.scale = Vec3::splat(pop);
+        }
+
+        text_color.0 = popup.color.with_alpha(1.0 - progress * progress);
+
+        if popup.timer.finished() {
+            pool.release::<TextPopup>(&mut commands, PoolKind::Popup, entity);
+        }
+    }
+}
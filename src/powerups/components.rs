@@ -0,0 +1,164 @@
+use bevy::prelude::*;
+
+/// The different kinds of power-ups that can spawn on the map
+#[derive(Reflect, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PowerUpKind {
+    SpeedBoost,
+    ChainShield,
+    ScoreMultiplier,
+    Magnet,
+}
+
+impl PowerUpKind {
+    pub const ALL: [PowerUpKind; 4] = [
+        PowerUpKind::SpeedBoost,
+        PowerUpKind::ChainShield,
+        PowerUpKind::ScoreMultiplier,
+        PowerUpKind::Magnet,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PowerUpKind::SpeedBoost => "Speed",
+            PowerUpKind::ChainShield => "Shield",
+            PowerUpKind::ScoreMultiplier => "x2 Score",
+            PowerUpKind::Magnet => "Magnet",
+        }
+    }
+
+    /// Short label drawn on the pickup itself
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            PowerUpKind::SpeedBoost => "SPD",
+            PowerUpKind::ChainShield => "SHD",
+            PowerUpKind::ScoreMultiplier => "x2",
+            PowerUpKind::Magnet => "MAG",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            PowerUpKind::SpeedBoost => Color::srgb(0.2, 0.9, 1.0), // Cyan
+            PowerUpKind::ChainShield => Color::srgb(0.7, 0.8, 1.0), // Silver blue
+            PowerUpKind::ScoreMultiplier => Color::srgb(1.0, 0.85, 0.2), // Gold
+            PowerUpKind::Magnet => Color::srgb(1.0, 0.3, 0.9),     // Magenta
+        }
+    }
+
+    /// How long the effect lasts once collected
+    pub fn duration(&self) -> f32 {
+        match self {
+            PowerUpKind::SpeedBoost => 5.0,
+            PowerUpKind::ChainShield => 8.0,
+            PowerUpKind::ScoreMultiplier => 10.0,
+            PowerUpKind::Magnet => 6.0,
+        }
+    }
+}
+
+/// Component for power-up pickups lying on the map
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct PowerUpCollectible {
+    pub kind: PowerUpKind,
+    pub spawn_time: f32,
+    pub lifetime: f32,
+}
+
+impl PowerUpCollectible {
+    pub fn new(kind: PowerUpKind, spawn_time: f32) -> Self {
+        Self {
+            kind,
+            spawn_time,
+            lifetime: super::POWERUP_LIFETIME,
+        }
+    }
+
+    pub fn is_expired(&self, current_time: f32) -> bool {
+        current_time - self.spawn_time > self.lifetime
+    }
+}
+
+/// A power-up effect currently running on a player
+#[derive(Reflect, Clone, Debug)]
+pub struct ActivePowerUp {
+    pub kind: PowerUpKind,
+    pub timer: Timer,
+}
+
+/// Component holding the power-up effects active on a player
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+pub struct ActivePowerUps {
+    pub effects: Vec<ActivePowerUp>,
+}
+
+impl ActivePowerUps {
+    /// Start an effect, or refresh its duration if it is already running
+    pub fn activate(&mut self, kind: PowerUpKind) {
+        let timer = Timer::from_seconds(kind.duration(), TimerMode::Once);
+        if let Some(effect) = self.effects.iter_mut().find(|e| e.kind == kind) {
+            effect.timer = timer;
+        } else {
+            self.effects.push(ActivePowerUp { kind, timer });
+        }
+    }
+
+    pub fn has(&self, kind: PowerUpKind) -> bool {
+        self.effects.iter().any(|e| e.kind == kind)
+    }
+
+    pub fn remaining(&self, kind: PowerUpKind) -> Option<f32> {
+        self.effects
+            .iter()
+            .find(|e| e.kind == kind)
+            .map(|e| e.timer.remaining_secs())
+    }
+
+    pub fn score_multiplier(&self) -> u32 {
+        if self.has(PowerUpKind::ScoreMultiplier) {
+            super::SCORE_MULTIPLIER
+        } else {
+            1
+        }
+    }
+}
+
+/// Timer for spawning power-ups
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
+pub struct PowerUpSpawnTimer {
+    pub timer: Timer,
+    pub spawn_chance: f32,
+    pub max_on_map: usize,
+}
+
+impl Default for PowerUpSpawnTimer {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(super::POWERUP_SPAWN_INTERVAL, TimerMode::Repeating),
+            spawn_chance: super::POWERUP_SPAWN_CHANCE,
+            max_on_map: super::MAX_POWERUPS_ON_MAP,
+        }
+    }
+}
+
+/// Event fired when a player picks up a power-up
+#[derive(Event)]
+pub struct PowerUpCollectedEvent {
+    pub player_entity: Entity,
+    pub kind: PowerUpKind,
+}
+
+/// Component for the HUD row showing active power-ups
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct PowerUpHud;
+
+/// Component for a single active power-up icon in the HUD
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct PowerUpHudIcon {
+    pub player_entity: Entity,
+    pub kind: PowerUpKind,
+}
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+mod components;
+mod systems;
+
+pub use components::*;
+use systems::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<PowerUpKind>();
+    app.register_type::<PowerUpCollectible>();
+    app.register_type::<ActivePowerUps>();
+    app.register_type::<PowerUpSpawnTimer>();
+    app.register_type::<PowerUpHud>();
+    app.register_type::<PowerUpHudIcon>();
+
+    app.add_event::<PowerUpCollectedEvent>();
+
+    app.init_resource::<PowerUpSpawnTimer>();
+
+    app.add_systems(
+        OnEnter(crate::screens::Screen::Gameplay),
+        (reset_power_up_spawner, setup_power_up_hud),
+    );
+
+    app.add_systems(
+        Update,
+        (
+            update_active_power_ups.in_set(crate::AppSystems::TickTimers),
+            (
+                spawn_power_ups,
+                animate_and_expire_power_ups,
+                collect_power_ups,
+                apply_collected_power_ups,
+                apply_magnet,
+                update_power_up_hud,
+            )
+                .chain()
+                .in_set(crate::AppSystems::Update),
+        )
+            .run_if(in_state(crate::screens::Screen::Gameplay))
+            .in_set(crate::PausableSystems),
+    );
+}
+
+// Configuration constants for power-ups
+pub const POWERUP_SPAWN_INTERVAL: f32 = 12.0; // Try to spawn every 12 seconds
+pub const POWERUP_SPAWN_CHANCE: f32 = 0.6; // Chance a spawn attempt succeeds
+pub const MAX_POWERUPS_ON_MAP: usize = 2;
+pub const POWERUP_LIFETIME: f32 = 10.0; // Pickups vanish after 10 seconds
+pub const POWERUP_SIZE: f32 = 12.0;
+pub const SPEED_BOOST_MULTIPLIER: f32 = 1.5;
+pub const SCORE_MULTIPLIER: u32 = 2;
+pub const MAGNET_RADIUS: f32 = 180.0;
+pub const MAGNET_PULL_SPEED: f32 = 150.0; // pixels per second
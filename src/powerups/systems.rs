@@ -0,0 +1,391 @@
+use super::components::*;
+use crate::{
+    effects::SpawnCollectionEvent,
+    map::{GridMap, GridPosition},
+    options::OptionCollectible,
+    player::{
+        PLAYER_SIZE, Player, PlayerController, PlayerIndex, PlayerVisualEvent,
+        PlayerVisualEventType,
+    },
+    popups::{PopupStyle, SpawnTextPopupEvent},
+    rng::GameRng,
+    screens::Screen,
+    settings::GameSettings,
+};
+use bevy::prelude::*;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use std::collections::HashSet;
+
+/// System to reset power-up spawning when entering gameplay
+pub fn reset_power_up_spawner(mut spawn_timer: ResMut<PowerUpSpawnTimer>) {
+    *spawn_timer = PowerUpSpawnTimer::default();
+}
+
+/// System to set up the active power-up HUD
+pub fn setup_power_up_hud(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Power-Up HUD"),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(20.0),
+            left: Val::Px(0.0),
+            right: Val::Px(0.0),
+            justify_content: JustifyContent::Center,
+            column_gap: Val::Px(8.0),
+            ..default()
+        },
+        Pickable::IGNORE,
+        PowerUpHud,
+        StateScoped(Screen::Gameplay),
+    ));
+}
+
+/// System to occasionally spawn power-ups on free grid cells
+pub fn spawn_power_ups(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut spawn_timer: ResMut<PowerUpSpawnTimer>,
+    grid_map: Option<Res<GridMap>>,
+    existing_power_ups: Query<&GridPosition, With<PowerUpCollectible>>,
+    existing_options: Query<&GridPosition, With<OptionCollectible>>,
+    mut game_rng: ResMut<GameRng>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    spawn_timer.timer.tick(time.delta());
+
+    if !spawn_timer.timer.just_finished() {
+        return;
+    }
+
+    let Some(grid_map) = grid_map else {
+        return;
+    };
+
+    if existing_power_ups.iter().count() >= spawn_timer.max_on_map {
+        return;
+    }
+
+    let rng = game_rng.gameplay();
+    if !rng.gen_bool(spawn_timer.spawn_chance as f64) {
+        return;
+    }
+
+    let occupied: HashSet<(usize, usize)> = existing_power_ups
+        .iter()
+        .chain(existing_options.iter())
+        .map(|pos| (pos.x, pos.y))
+        .collect();
+
+    let Some(grid_pos) = find_free_position(&grid_map, &occupied, rng) else {
+        return;
+    };
+
+    let kind = *PowerUpKind::ALL.choose(rng).unwrap();
+    let world_pos = grid_map.grid_to_world(grid_pos.x, grid_pos.y);
+    let color = kind.color();
+
+    commands.spawn((
+        Name::new(format!("Power-Up: {}", kind.name())),
+        Mesh2d(meshes.add(Rectangle::new(
+            super::POWERUP_SIZE * 1.6,
+            super::POWERUP_SIZE * 1.6,
+        ))),
+        MeshMaterial2d(materials.add(ColorMaterial::from(color))),
+        Transform::from_translation(world_pos.extend(1.0))
+            .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+        grid_pos,
+        PowerUpCollectible::new(kind, time.elapsed_secs()),
+        StateScoped(Screen::Gameplay),
+        children![(
+            Name::new("Power-Up Label"),
+            Text2d::new(kind.symbol()),
+            TextFont {
+                font_size: 11.0,
+                ..default()
+            },
+            TextColor(Color::BLACK),
+            // Undo the parent's diamond rotation so the label stays upright
+            Transform::from_translation(Vec3::new(0.0, 0.0, 0.3))
+                .with_rotation(Quat::from_rotation_z(-std::f32::consts::FRAC_PI_4)),
+        )],
+    ));
+
+    info!("Spawned power-up {} at {:?}", kind.name(), world_pos);
+}
+
+/// Find a random grid cell not occupied by options or other power-ups
+fn find_free_position(
+    grid_map: &GridMap,
+    occupied: &HashSet<(usize, usize)>,
+    rng: &mut impl Rng,
+) -> Option<GridPosition> {
+    for _ in 0..50 {
+        let x = rng.gen_range(1..grid_map.width.saturating_sub(1).max(2));
+        let y = rng.gen_range(1..grid_map.height.saturating_sub(1).max(2));
+        if !occupied.contains(&(x, y)) && !grid_map.is_blocked(x, y) {
+            return Some(GridPosition::new(x, y));
+        }
+    }
+    None
+}
+
+/// System to spin power-ups and remove them once they expire
+pub fn animate_and_expire_power_ups(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut power_up_query: Query<(Entity, &mut Transform, &PowerUpCollectible)>,
+) {
+    let current_time = time.elapsed_secs();
+
+    for (entity, mut transform, power_up) in &mut power_up_query {
+        if power_up.is_expired(current_time) {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let age = current_time - power_up.spawn_time;
+        let scale = 1.0 + (age * 4.0).sin() * 0.1;
+        transform.scale = Vec3::splat(scale);
+    }
+}
+
+/// System to let players pick up power-ups
+pub fn collect_power_ups(
+    mut commands: Commands,
+    mut collected_events: EventWriter<PowerUpCollectedEvent>,
+    mut collection_effects: EventWriter<SpawnCollectionEvent>,
+    player_query: Query<(Entity, &Transform), With<Player>>,
+    power_up_query: Query<(Entity, &Transform, &PowerUpCollectible), Without<Player>>,
+) {
+    let mut collected = HashSet::new();
+
+    for (player_entity, player_transform) in &player_query {
+        for (power_up_entity, power_up_transform, power_up) in &power_up_query {
+            if collected.contains(&power_up_entity) {
+                continue;
+            }
+
+            let distance = player_transform
+                .translation
+                .xy()
+                .distance(power_up_transform.translation.xy());
+
+            if distance <= PLAYER_SIZE + super::POWERUP_SIZE {
+                collected.insert(power_up_entity);
+
+                collection_effects.write(SpawnCollectionEvent {
+                    position: power_up_transform.translation,
+                    color: power_up.kind.color(),
+                });
+
+                collected_events.write(PowerUpCollectedEvent {
+                    player_entity,
+                    kind: power_up.kind,
+                });
+
+                commands.entity(power_up_entity).despawn();
+            }
+        }
+    }
+}
+
+/// System to start power-up effects on the collecting player
+pub fn apply_collected_power_ups(
+    mut commands: Commands,
+    mut collected_events: EventReader<PowerUpCollectedEvent>,
+    mut visual_events: EventWriter<PlayerVisualEvent>,
+    mut popup_events: EventWriter<SpawnTextPopupEvent>,
+    mut player_query: Query<
+        (
+            &mut PlayerController,
+            &Transform,
+            Option<&mut ActivePowerUps>,
+        ),
+        With<Player>,
+    >,
+) {
+    for event in collected_events.read() {
+        let Ok((mut controller, transform, active)) = player_query.get_mut(event.player_entity)
+        else {
+            continue;
+        };
+
+        match active {
+            Some(mut active) => active.activate(event.kind),
+            None => {
+                let mut active = ActivePowerUps::default();
+                active.activate(event.kind);
+                commands.entity(event.player_entity).insert(active);
+            }
+        }
+
+        if event.kind == PowerUpKind::SpeedBoost {
+            controller.move_speed = controller.base_speed * super::SPEED_BOOST_MULTIPLIER;
+        }
+
+        visual_events.write(PlayerVisualEvent {
+            player_entity: event.player_entity,
+            event_type: PlayerVisualEventType::Boost {
+                duration: event.kind.duration(),
+                intensity: 1.0,
+            },
+        });
+
+        popup_events.write(SpawnTextPopupEvent::new(
+            transform.translation.xy() + Vec2::Y * 20.0,
+            event.kind.name(),
+            event.kind.color(),
+            PopupStyle::Milestone,
+        ));
+
+        info!(
+            "Player {:?} activated power-up {}",
+            event.player_entity,
+            event.kind.name()
+        );
+    }
+}
+
+/// System to tick active power-ups and revert their effects when they run out
+pub fn update_active_power_ups(
+    time: Res<Time>,
+    mut player_query: Query<(&mut ActivePowerUps, &mut PlayerController)>,
+) {
+    for (mut active, mut controller) in &mut player_query {
+        for effect in &mut active.effects {
+            effect.timer.tick(time.delta());
+        }
+
+        let speed_was_active = active.has(PowerUpKind::SpeedBoost);
+        active.effects.retain(|effect| !effect.timer.finished());
+
+        if speed_was_active && !active.has(PowerUpKind::SpeedBoost) {
+            controller.move_speed = controller.base_speed;
+        }
+    }
+}
+
+/// System to pull nearby correct options toward players with an active magnet
+pub fn apply_magnet(
+    time: Res<Time>,
+    player_query: Query<(&Transform, &ActivePowerUps), With<Player>>,
+    mut option_query: Query<(&mut Transform, &OptionCollectible), Without<Player>>,
+) {
+    for (player_transform, active) in &player_query {
+        if !active.has(PowerUpKind::Magnet) {
+            continue;
+        }
+
+        let player_pos = player_transform.translation.xy();
+
+        for (mut option_transform, option) in &mut option_query {
+            if !option.is_correct {
+                continue;
+            }
+
+            let offset = player_pos - option_transform.translation.xy();
+            let distance = offset.length();
+            if distance > super::MAGNET_RADIUS || distance < f32::EPSILON {
+                continue;
+            }
+
+            let step = (super::MAGNET_PULL_SPEED * time.delta_secs()).min(distance);
+            let movement = offset / distance * step;
+            option_transform.translation.x += movement.x;
+            option_transform.translation.y += movement.y;
+        }
+    }
+}
+
+/// System to keep the HUD icons in sync with the players' active power-ups
+pub fn update_power_up_hud(
+    mut commands: Commands,
+    game_settings: Res<GameSettings>,
+    hud_query: Query<Entity, With<PowerUpHud>>,
+    player_query: Query<(Entity, &ActivePowerUps, Option<&PlayerIndex>), With<Player>>,
+    mut icon_query: Query<(Entity, &PowerUpHudIcon, &mut Text)>,
+) {
+    let Ok(hud_entity) = hud_query.single() else {
+        return;
+    };
+
+    let show_player = game_settings.multiplayer.player_count > 1;
+
+    // Update or remove existing icons
+    let mut shown = HashSet::new();
+    for (icon_entity, icon, mut text) in &mut icon_query {
+        let remaining = player_query
+            .get(icon.player_entity)
+            .ok()
+            .and_then(|(_, active, _)| active.remaining(icon.kind));
+
+        match remaining {
+            Some(remaining) => {
+                let label = hud_label(icon, remaining, show_player, &player_query);
+                if text.0 != label {
+                    text.0 = label;
+                }
+                shown.insert((icon.player_entity, icon.kind));
+            }
+            None => commands.entity(icon_entity).despawn(),
+        }
+    }
+
+    // Add icons for newly activated power-ups
+    for (player_entity, active, _) in &player_query {
+        for effect in &active.effects {
+            if shown.contains(&(player_entity, effect.kind)) {
+                continue;
+            }
+
+            let icon = PowerUpHudIcon {
+                player_entity,
+                kind: effect.kind,
+            };
+            let label = hud_label(
+                &icon,
+                effect.timer.remaining_secs(),
+                show_player,
+                &player_query,
+            );
+
+            commands.entity(hud_entity).with_child((
+                Name::new(format!("Power-Up Icon: {}", effect.kind.name())),
+                Node {
+                    padding: UiRect::axes(Val::Px(10.0), Val::Px(5.0)),
+                    ..default()
+                },
+                BackgroundColor(effect.kind.color().with_alpha(0.85)),
+                BorderRadius::all(Val::Px(6.0)),
+                Text(label),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::BLACK),
+                icon,
+            ));
+        }
+    }
+}
+
+/// Build the HUD text for a power-up icon
+fn hud_label(
+    icon: &PowerUpHudIcon,
+    remaining: f32,
+    show_player: bool,
+    player_query: &Query<(Entity, &ActivePowerUps, Option<&PlayerIndex>), With<Player>>,
+) -> String {
+    let seconds = remaining.ceil() as u32;
+    let player_index = player_query
+        .get(icon.player_entity)
+        .ok()
+        .and_then(|(_, _, index)| index.map(|i| i.0));
+
+    match (show_player, player_index) {
+        (true, Some(index)) => format!("P{} {} {}s", index + 1, icon.kind.name(), seconds),
+        _ => format!("{} {}s", icon.kind.name(), seconds),
+    }
+}
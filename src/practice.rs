@@ -0,0 +1,140 @@
+//! Practice mode: a round without pressure. There is no game timer, each
+//! question stays until someone collects a correct option or skips it, wrong
+//! answers explain themselves with the question's help text and no points are
+//! lost. Practice lasts until the players go back to the title screen, so a
+//! restart keeps practicing.
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*, ui::Val::*};
+
+use crate::{
+    locale::Locale,
+    player::{OptionCollectedEvent, Player},
+    popups::{PopupStyle, SpawnTextPopupEvent},
+    question::{QuestionPhase, QuestionSystem, SkipQuestionEvent},
+    screens::Screen,
+    settings::GameSettings,
+    theme::widget,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<PracticeRound>();
+    app.add_event::<PlayPracticeEvent>();
+
+    app.add_systems(Update, start_practice.run_if(on_event::<PlayPracticeEvent>));
+    app.add_systems(OnEnter(Screen::Title), end_practice);
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        spawn_skip_button.run_if(resource_exists::<PracticeRound>),
+    );
+    app.add_systems(
+        Update,
+        (
+            skip_question.run_if(input_just_pressed(PRACTICE_SKIP_KEY)),
+            skip_answered_question,
+            // The explanation cards of learning feedback already show the help text
+            explain_wrong_answers.run_if(|game_settings: Res<GameSettings>| {
+                !game_settings.gameplay.learning_feedback
+            }),
+        )
+            .in_set(crate::AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay))
+            .run_if(in_state(QuestionPhase::Asking))
+            .run_if(resource_exists::<PracticeRound>)
+            .in_set(crate::PausableSystems),
+    );
+}
+
+/// Event to start a practice round with the selected challenge and topics
+#[derive(Event)]
+pub struct PlayPracticeEvent;
+
+/// Resource present while practicing
+#[derive(Resource, Reflect, Default, Debug)]
+#[reflect(Resource)]
+pub struct PracticeRound;
+
+fn start_practice(
+    mut commands: Commands,
+    mut events: EventReader<PlayPracticeEvent>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    events.clear();
+
+    info!("Starting practice round");
+    commands.insert_resource(PracticeRound);
+    next_screen.set(Screen::Gameplay);
+}
+
+fn end_practice(mut commands: Commands) {
+    commands.remove_resource::<PracticeRound>();
+}
+
+fn spawn_skip_button(mut commands: Commands, locale: Res<Locale>) {
+    commands.spawn((
+        Name::new("Skip Question Button"),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Px(20.0),
+            left: Px(70.0),
+            ..default()
+        },
+        StateScoped(Screen::Gameplay),
+        children![widget::button_medium(
+            locale.text("hud.skip_question"),
+            request_skip
+        )],
+    ));
+}
+
+fn request_skip(_: Trigger<Pointer<Click>>, mut skip_events: EventWriter<SkipQuestionEvent>) {
+    skip_events.write(SkipQuestionEvent);
+}
+
+fn skip_question(mut skip_events: EventWriter<SkipQuestionEvent>) {
+    skip_events.write(SkipQuestionEvent);
+}
+
+/// Without a question timer, a correct answer is what moves on to the next question
+fn skip_answered_question(
+    mut collected_events: EventReader<OptionCollectedEvent>,
+    mut skip_events: EventWriter<SkipQuestionEvent>,
+) {
+    if collected_events.read().any(|event| event.is_correct) {
+        skip_events.write(SkipQuestionEvent);
+    }
+}
+
+/// Show the help text of the question above players who collected a wrong option
+fn explain_wrong_answers(
+    mut collected_events: EventReader<OptionCollectedEvent>,
+    question_system: Option<Res<QuestionSystem>>,
+    player_query: Query<&Transform, With<Player>>,
+    mut popup_events: EventWriter<SpawnTextPopupEvent>,
+) {
+    let help = question_system
+        .as_ref()
+        .and_then(|question_system| question_system.get_current_question())
+        .map(|question| question.help.clone())
+        .unwrap_or_default();
+
+    for event in collected_events.read() {
+        if event.is_correct || help.is_empty() {
+            continue;
+        }
+        let Ok(transform) = player_query.get(event.player_entity) else {
+            continue;
+        };
+
+        popup_events.write(SpawnTextPopupEvent::new(
+            transform.translation.xy() + Vec2::Y * PRACTICE_EXPLANATION_OFFSET,
+            help.clone(),
+            PRACTICE_EXPLANATION_COLOR,
+            PopupStyle::Milestone,
+        ));
+    }
+}
+
+// Practice constants
+pub const PRACTICE_SKIP_KEY: KeyCode = KeyCode::Tab;
+pub const PRACTICE_EXPLANATION_OFFSET: f32 = 30.0; // Above the player
+pub const PRACTICE_EXPLANATION_COLOR: Color = Color::srgb(0.55, 0.8, 1.0);
@@ -0,0 +1,147 @@
+//! Warm-up while the loading screen is up: the meshes and materials the board
+//! is built from, the particle effects in the round's colors and the glyphs
+//! of the HUD fonts are created before gameplay starts, so the first options,
+//! merges and bursts don't each stall a frame building their assets.
+//!
+//! The warmed meshes and materials are held until the round ends, otherwise
+//! the shared asset cache would drop them again before anything used them.
+
+use bevy::prelude::*;
+
+use crate::{
+    asset_cache::SharedAssetCache,
+    palette::{OptionMarker, option_color},
+    screens::Screen,
+    settings::GameSettings,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        OnEnter(Screen::Loading),
+        (warm_shared_assets, warm_particle_effects, warm_font_glyphs),
+    );
+    app.add_systems(OnExit(Screen::Gameplay), release_preloaded_assets);
+}
+
+/// Resource keeping the warmed shared assets alive until the round ends
+#[derive(Resource, Debug, Default)]
+pub struct PreloadedAssets {
+    pub meshes: Vec<Handle<Mesh>>,
+    pub materials: Vec<Handle<ColorMaterial>>,
+}
+
+/// Colors the round is played in: the options of the current palette and
+/// the players
+fn round_colors(game_settings: &GameSettings) -> Vec<Color> {
+    let palette = game_settings.display.color_palette;
+    (0..palette.colors().len())
+        .map(|slot| option_color(palette, slot))
+        .chain(
+            game_settings
+                .multiplayer
+                .players
+                .iter()
+                .map(|player| player.color),
+        )
+        .collect()
+}
+
+fn warm_shared_assets(
+    mut commands: Commands,
+    game_settings: Res<GameSettings>,
+    mut asset_cache: ResMut<SharedAssetCache>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let shapes = std::iter::once(None).chain(OptionMarker::ALL.iter().copied().map(Some));
+    let radii = PRELOAD_OPTION_RADII.iter().copied().chain(
+        (1..=PRELOAD_SEGMENT_LEVELS)
+            .map(|level| crate::chain::CHAIN_SEGMENT_SIZE * (1.0 + (level - 1) as f32 * 0.5)),
+    );
+
+    let mut preloaded = PreloadedAssets::default();
+    for radius in radii {
+        for shape in shapes.clone() {
+            preloaded
+                .meshes
+                .push(asset_cache.shape_mesh(shape, radius, &mut meshes));
+        }
+    }
+    for radius in PRELOAD_CIRCLE_RADII {
+        preloaded
+            .meshes
+            .push(asset_cache.circle_mesh(radius, &mut meshes));
+    }
+    for color in round_colors(&game_settings) {
+        preloaded
+            .materials
+            .push(asset_cache.material(color, &mut materials));
+    }
+
+    debug!(
+        "Preloaded {} meshes and {} materials",
+        preloaded.meshes.len(),
+        preloaded.materials.len()
+    );
+    commands.insert_resource(preloaded);
+}
+
+#[cfg(feature = "particles")]
+fn warm_particle_effects(
+    game_settings: Res<GameSettings>,
+    mut particle_effects: ResMut<crate::effects::ParticleEffects>,
+    mut effects: ResMut<Assets<bevy_hanabi::EffectAsset>>,
+) {
+    for color in round_colors(&game_settings) {
+        particle_effects.explosion(color, &mut effects);
+        particle_effects.collection(color, &mut effects);
+    }
+}
+
+#[cfg(not(feature = "particles"))]
+fn warm_particle_effects() {}
+
+/// Lay out every character the HUD may show once per font size, invisibly,
+/// so their glyphs are already rasterized into the font atlas
+fn warm_font_glyphs(mut commands: Commands) {
+    commands
+        .spawn((
+            Name::new("Font Glyph Warm-up"),
+            Node {
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            Pickable::IGNORE,
+            StateScoped(Screen::Loading),
+        ))
+        .with_children(|parent| {
+            for font_size in PRELOAD_FONT_SIZES {
+                parent.spawn((
+                    Text::new(PRELOAD_GLYPHS),
+                    TextFont {
+                        font_size,
+                        ..default()
+                    },
+                    TextColor(Color::NONE),
+                ));
+            }
+        });
+}
+
+fn release_preloaded_assets(mut commands: Commands) {
+    commands.remove_resource::<PreloadedAssets>();
+}
+
+// Preload constants
+pub const PRELOAD_OPTION_RADII: [f32; 1] = [14.0]; // Options on the board
+pub const PRELOAD_CIRCLE_RADII: [f32; 4] = [
+    20.0, // Option glow
+    30.0, // Option pulse
+    crate::player::PLAYER_SIZE * 0.8,
+    crate::chain::REACTION_TELEGRAPH_RADIUS,
+];
+pub const PRELOAD_SEGMENT_LEVELS: u32 = 3; // Chain segment sizes up to this merge level
+pub const PRELOAD_FONT_SIZES: [f32; 7] = [12.0, 14.0, 16.0, 18.0, 20.0, 28.0, 40.0];
+pub const PRELOAD_GLYPHS: &str =
+    "ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÜ abcdefghijklmnopqrstuvwxyzäöüß 0123456789 .,:;!?+-×/%()'\"";
@@ -0,0 +1,482 @@
+//! Optional Konnektoren profile: once a player connects their account, round
+//! results are submitted to the Konnektoren API and the profile's display
+//! name is used for player one. Results are queued and kept between sessions
+//! until the API accepts them, so rounds played offline are sent later.
+//! Questions flagged as confusing are queued and sent the same way.
+//!
+//! Network access needs the `profile-sync` feature. Without it results stay
+//! queued and nothing is sent.
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bevy::{prelude::*, time::common_conditions::on_timer};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    daily::DailyRound,
+    gameplay::{GameTimerEvent, GameplayScore},
+    launch::LaunchOptions,
+    locale::Locale,
+    notifications::NotificationEvent,
+    player::PlayerIndex,
+    question::{QuestionFeedbackEvent, QuestionFeedbackVote},
+    round::RoundInfo,
+    screens::Screen,
+    settings::GameSettings,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<ProfileAccount>();
+    app.init_resource::<ProfileSync>();
+    app.init_resource::<ProfileTokenInput>();
+    app.add_event::<ProfileLoginEvent>();
+    app.add_event::<ProfileLogoutEvent>();
+
+    app.add_systems(Startup, load_profile_account);
+    app.add_systems(
+        Update,
+        (
+            handle_profile_login.run_if(on_event::<ProfileLoginEvent>),
+            handle_profile_logout.run_if(on_event::<ProfileLogoutEvent>),
+            queue_game_results.run_if(in_state(Screen::Gameplay)),
+            queue_question_feedback.run_if(on_event::<QuestionFeedbackEvent>),
+            (submit_queued_results, submit_queued_feedback)
+                .chain()
+                .run_if(on_timer(Duration::from_secs_f32(PROFILE_SYNC_INTERVAL))),
+            receive_profile_responses,
+        )
+            .chain(),
+    );
+}
+
+/// A finished round as sent to the profile API
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ProfileResult {
+    pub challenge_id: String,
+    pub score: i32,
+    /// Percentage of correct answers
+    pub accuracy: f32,
+    /// RFC 3339 time the round ended
+    pub played_at: String,
+    /// Date of the daily challenge, when the round was one
+    #[serde(default)]
+    pub daily: Option<String>,
+}
+
+/// Resource with the connected account and the results not yet sent
+#[derive(Resource, Serialize, Deserialize, Default, Debug)]
+#[serde(default)]
+pub struct ProfileAccount {
+    pub token: Option<String>,
+    pub display_name: Option<String>,
+    pub pending_results: Vec<ProfileResult>,
+    /// Confusing-question votes not yet sent
+    pub pending_feedback: Vec<QuestionFeedbackVote>,
+}
+
+impl ProfileAccount {
+    pub fn is_connected(&self) -> bool {
+        self.token.is_some()
+    }
+
+    fn save(&self) {
+        crate::storage::save(PROFILE_STORAGE_KEY, self);
+    }
+}
+
+/// Answer from a request, handed back from the network callback
+#[derive(Debug)]
+#[cfg_attr(not(feature = "profile-sync"), allow(dead_code))]
+enum ProfileResponse {
+    Submitted(usize),
+    FeedbackSubmitted(usize),
+    DisplayName(String),
+    Unauthorized,
+    Failed(String),
+}
+
+/// Resource tracking requests in flight
+#[derive(Resource, Default)]
+pub struct ProfileSync {
+    responses: Arc<Mutex<Vec<ProfileResponse>>>,
+    submitting: bool,
+}
+
+impl ProfileSync {
+    #[cfg(feature = "profile-sync")]
+    fn push_response(responses: &Mutex<Vec<ProfileResponse>>, response: ProfileResponse) {
+        if let Ok(mut responses) = responses.lock() {
+            responses.push(response);
+        }
+    }
+
+    fn take_responses(&self) -> Vec<ProfileResponse> {
+        self.responses
+            .lock()
+            .map(|mut responses| std::mem::take(&mut *responses))
+            .unwrap_or_default()
+    }
+}
+
+/// Text typed into the token box of the profile menu
+#[derive(Resource, Default)]
+pub struct ProfileTokenInput(pub String);
+
+/// Event to connect the profile with an access token
+#[derive(Event)]
+pub struct ProfileLoginEvent(pub String);
+
+/// Event to disconnect the profile and forget unsent results
+#[derive(Event)]
+pub struct ProfileLogoutEvent;
+
+fn load_profile_account(
+    mut account: ResMut<ProfileAccount>,
+    launch_options: Res<LaunchOptions>,
+    mut login_events: EventWriter<ProfileLoginEvent>,
+) {
+    if let Some(stored) = crate::storage::load::<ProfileAccount>(PROFILE_STORAGE_KEY) {
+        *account = stored;
+    }
+
+    // Coming back from the web login with a fresh token
+    if let Some(token) = &launch_options.profile_token {
+        login_events.write(ProfileLoginEvent(token.clone()));
+    }
+}
+
+fn handle_profile_login(
+    mut events: EventReader<ProfileLoginEvent>,
+    mut account: ResMut<ProfileAccount>,
+    sync: Res<ProfileSync>,
+) {
+    let Some(ProfileLoginEvent(token)) = events.read().last() else {
+        return;
+    };
+    let token = token.trim();
+    if token.is_empty() {
+        return;
+    }
+
+    account.token = Some(token.to_string());
+    account.save();
+    info!("Connected Konnektoren profile");
+
+    fetch_display_name(token, sync.responses.clone());
+}
+
+fn handle_profile_logout(
+    mut events: EventReader<ProfileLogoutEvent>,
+    mut account: ResMut<ProfileAccount>,
+    locale: Res<Locale>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    events.clear();
+    *account = ProfileAccount::default();
+    account.save();
+    notifications.write(NotificationEvent::new(locale.text("profile.disconnected")));
+}
+
+/// Queue player one's result when a round ends while a profile is connected
+fn queue_game_results(
+    mut timer_events: EventReader<GameTimerEvent>,
+    mut account: ResMut<ProfileAccount>,
+    gameplay_score: Res<GameplayScore>,
+    round_info: Option<Res<RoundInfo>>,
+    daily: Option<Res<DailyRound>>,
+    player_query: Query<&PlayerIndex>,
+) {
+    let ended = timer_events
+        .read()
+        .any(|event| matches!(event, GameTimerEvent::GameEnded));
+    if !ended || !account.is_connected() {
+        return;
+    }
+    let Some(round_info) = round_info else {
+        return;
+    };
+
+    let Some(score) = gameplay_score
+        .players
+        .iter()
+        .find(|(entity, _)| player_query.get(**entity).is_ok_and(|index| index.0 == 0))
+        .map(|(_, score)| score)
+    else {
+        return;
+    };
+
+    let answers = score.correct_answers + score.wrong_answers;
+    let accuracy = if answers > 0 {
+        score.correct_answers as f32 / answers as f32 * 100.0
+    } else {
+        0.0
+    };
+
+    account.pending_results.push(ProfileResult {
+        challenge_id: round_info.challenge_id.clone(),
+        score: score.total_score,
+        accuracy,
+        played_at: chrono::Utc::now().to_rfc3339(),
+        daily: daily.map(|daily| daily.date.clone()),
+    });
+
+    // Keep the offline queue bounded, dropping the oldest results first
+    let overflow = account
+        .pending_results
+        .len()
+        .saturating_sub(MAX_PENDING_RESULTS);
+    account.pending_results.drain(..overflow);
+    account.save();
+}
+
+/// Queue confusing-question votes while a profile is connected
+fn queue_question_feedback(
+    mut feedback_events: EventReader<QuestionFeedbackEvent>,
+    mut account: ResMut<ProfileAccount>,
+) {
+    if !account.is_connected() {
+        feedback_events.clear();
+        return;
+    }
+
+    account
+        .pending_feedback
+        .extend(feedback_events.read().map(|event| event.vote.clone()));
+
+    // Keep the offline queue bounded, dropping the oldest votes first
+    let overflow = account
+        .pending_feedback
+        .len()
+        .saturating_sub(MAX_PENDING_FEEDBACK);
+    account.pending_feedback.drain(..overflow);
+    account.save();
+}
+
+fn submit_queued_results(account: Res<ProfileAccount>, mut sync: ResMut<ProfileSync>) {
+    if sync.submitting || account.pending_results.is_empty() {
+        return;
+    }
+    let Some(token) = &account.token else {
+        return;
+    };
+
+    let batch =
+        &account.pending_results[..account.pending_results.len().min(MAX_RESULTS_PER_SUBMIT)];
+    sync.submitting = submit_results(token, batch, sync.responses.clone());
+}
+
+/// Send queued votes once no results are being sent
+fn submit_queued_feedback(account: Res<ProfileAccount>, mut sync: ResMut<ProfileSync>) {
+    if sync.submitting || account.pending_feedback.is_empty() {
+        return;
+    }
+    let Some(token) = &account.token else {
+        return;
+    };
+
+    let batch =
+        &account.pending_feedback[..account.pending_feedback.len().min(MAX_RESULTS_PER_SUBMIT)];
+    sync.submitting = submit_feedback(token, batch, sync.responses.clone());
+}
+
+fn receive_profile_responses(
+    mut sync: ResMut<ProfileSync>,
+    mut account: ResMut<ProfileAccount>,
+    mut game_settings: ResMut<GameSettings>,
+    locale: Res<Locale>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    for response in sync.take_responses() {
+        match response {
+            ProfileResponse::Submitted(count) => {
+                sync.submitting = false;
+                // New results are only ever appended, so the sent ones are still in front
+                let count = count.min(account.pending_results.len());
+                account.pending_results.drain(..count);
+                account.save();
+                info!("Submitted {} results to the Konnektoren profile", count);
+            }
+            ProfileResponse::FeedbackSubmitted(count) => {
+                sync.submitting = false;
+                let count = count.min(account.pending_feedback.len());
+                account.pending_feedback.drain(..count);
+                account.save();
+                info!(
+                    "Submitted {} question votes to the Konnektoren profile",
+                    count
+                );
+            }
+            ProfileResponse::DisplayName(name) => {
+                if let Some(player) = game_settings.multiplayer.players.first_mut() {
+                    player.name = name.clone();
+                }
+                // The name stays out of the notification, which also feeds bug reports
+                notifications.write(NotificationEvent::new(locale.text("profile.signed_in")));
+                account.display_name = Some(name);
+                account.save();
+            }
+            ProfileResponse::Unauthorized => {
+                sync.submitting = false;
+                // Keep the queued results so they can be sent after logging in again
+                account.token = None;
+                account.display_name = None;
+                account.save();
+                notifications.write(NotificationEvent::new(locale.text("profile.login_expired")));
+            }
+            ProfileResponse::Failed(err) => {
+                sync.submitting = false;
+                debug!("Profile request failed, retrying later: {}", err);
+            }
+        }
+    }
+}
+
+fn api_url(path: &str) -> String {
+    let base = option_env!("KONNEKTOREN_API_URL").unwrap_or(DEFAULT_PROFILE_API_URL);
+    format!("{}{}", base.trim_end_matches('/'), path)
+}
+
+/// Page on konnektoren.help that logs in and returns to the game with `?token=`
+#[cfg(target_family = "wasm")]
+pub fn web_login_url() -> Option<String> {
+    let href = web_sys::window()?.location().href().ok()?;
+    Some(format!(
+        "{}?redirect={}",
+        PROFILE_LOGIN_URL,
+        crate::url::percent_encode(&href)
+    ))
+}
+
+#[cfg(feature = "profile-sync")]
+fn submit_results(
+    token: &str,
+    results: &[ProfileResult],
+    responses: Arc<Mutex<Vec<ProfileResponse>>>,
+) -> bool {
+    let mut request = match ehttp::Request::json(api_url("/results"), &results) {
+        Ok(request) => request,
+        Err(err) => {
+            warn!("Failed to serialize profile results: {}", err);
+            return false;
+        }
+    };
+    request
+        .headers
+        .insert("Authorization", format!("Bearer {}", token));
+
+    let count = results.len();
+    ehttp::fetch(request, move |result| {
+        let response = match result {
+            Ok(response) if response.ok => ProfileResponse::Submitted(count),
+            Ok(response) if response.status == 401 => ProfileResponse::Unauthorized,
+            Ok(response) => ProfileResponse::Failed(format!("HTTP {}", response.status)),
+            Err(err) => ProfileResponse::Failed(err),
+        };
+        ProfileSync::push_response(&responses, response);
+    });
+    true
+}
+
+#[cfg(feature = "profile-sync")]
+fn submit_feedback(
+    token: &str,
+    votes: &[QuestionFeedbackVote],
+    responses: Arc<Mutex<Vec<ProfileResponse>>>,
+) -> bool {
+    let mut request = match ehttp::Request::json(api_url("/feedback"), &votes) {
+        Ok(request) => request,
+        Err(err) => {
+            warn!("Failed to serialize question feedback: {}", err);
+            return false;
+        }
+    };
+    request
+        .headers
+        .insert("Authorization", format!("Bearer {}", token));
+
+    let count = votes.len();
+    ehttp::fetch(request, move |result| {
+        let response = match result {
+            Ok(response) if response.ok => ProfileResponse::FeedbackSubmitted(count),
+            Ok(response) if response.status == 401 => ProfileResponse::Unauthorized,
+            Ok(response) => ProfileResponse::Failed(format!("HTTP {}", response.status)),
+            Err(err) => ProfileResponse::Failed(err),
+        };
+        ProfileSync::push_response(&responses, response);
+    });
+    true
+}
+
+#[cfg(feature = "profile-sync")]
+fn fetch_display_name(token: &str, responses: Arc<Mutex<Vec<ProfileResponse>>>) {
+    #[derive(Deserialize)]
+    struct Profile {
+        name: String,
+    }
+
+    let mut request = ehttp::Request::get(api_url("/profile"));
+    request
+        .headers
+        .insert("Authorization", format!("Bearer {}", token));
+
+    ehttp::fetch(request, move |result| {
+        let response = match result {
+            Ok(response) if response.ok => match response.json::<Profile>() {
+                Ok(profile) => ProfileResponse::DisplayName(profile.name),
+                Err(err) => ProfileResponse::Failed(err.to_string()),
+            },
+            Ok(response) if response.status == 401 => ProfileResponse::Unauthorized,
+            Ok(response) => ProfileResponse::Failed(format!("HTTP {}", response.status)),
+            Err(err) => ProfileResponse::Failed(err),
+        };
+        ProfileSync::push_response(&responses, response);
+    });
+}
+
+#[cfg(not(feature = "profile-sync"))]
+fn submit_results(
+    _token: &str,
+    results: &[ProfileResult],
+    _responses: Arc<Mutex<Vec<ProfileResponse>>>,
+) -> bool {
+    debug!(
+        "Profile sync disabled, keeping {} results queued for {}",
+        results.len(),
+        api_url("/results")
+    );
+    false
+}
+
+#[cfg(not(feature = "profile-sync"))]
+fn submit_feedback(
+    _token: &str,
+    votes: &[QuestionFeedbackVote],
+    _responses: Arc<Mutex<Vec<ProfileResponse>>>,
+) -> bool {
+    debug!(
+        "Profile sync disabled, keeping {} question votes queued for {}",
+        votes.len(),
+        api_url("/feedback")
+    );
+    false
+}
+
+#[cfg(not(feature = "profile-sync"))]
+fn fetch_display_name(_token: &str, _responses: Arc<Mutex<Vec<ProfileResponse>>>) {
+    debug!(
+        "Profile sync disabled, not fetching {}",
+        api_url("/profile")
+    );
+}
+
+// Profile constants
+pub const PROFILE_STORAGE_KEY: &str = "profile";
+pub const DEFAULT_PROFILE_API_URL: &str = "https://api.konnektoren.help/v1";
+pub const PROFILE_LOGIN_URL: &str = "https://konnektoren.help/login";
+pub const PROFILE_SYNC_INTERVAL: f32 = 30.0; // Seconds between attempts to send queued results
+pub const MAX_RESULTS_PER_SUBMIT: usize = 20;
+pub const MAX_PENDING_RESULTS: usize = 200; // Oldest unsent results are dropped beyond this
+pub const MAX_PENDING_FEEDBACK: usize = 200; // Oldest unsent question votes are dropped beyond this
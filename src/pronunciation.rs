@@ -0,0 +1,163 @@
+//! Pronunciation of collected words: a correct option plays the spoken German
+//! word. Recordings are looked up per word under `audio/pronunciation/`, named
+//! after the option text in lower case with umlauts spelled out and everything
+//! else that isn't a letter or digit replaced by `_` ("für" → `fuer.ogg`).
+//! Words without a recording are read by the platform text-to-speech instead.
+
+use std::collections::{HashMap, HashSet};
+
+use bevy::{asset::LoadState, audio::Volume, prelude::*};
+
+use crate::{
+    accessibility::SpeakEvent, audio::SoundEffect, locale::Language, player::OptionCollectedEvent,
+    question::QuestionSystem, screens::Screen, settings::GameSettings,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<Pronunciations>();
+
+    app.add_systems(OnEnter(Screen::Gameplay), preload_pronunciations);
+    app.add_systems(
+        Update,
+        (pronounce_collected_words, play_pending_pronunciations)
+            .chain()
+            .in_set(crate::AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay))
+            .run_if(|game_settings: Res<GameSettings>| game_settings.audio.pronunciation),
+    );
+}
+
+/// Recordings of the words seen so far
+#[derive(Resource, Default)]
+pub struct Pronunciations {
+    clips: HashMap<String, Handle<AudioSource>>,
+    /// Words that have no recording, read by text-to-speech
+    missing: HashSet<String>,
+    /// Words waiting for their recording to load, with the time they were collected
+    pending: Vec<(String, f32)>,
+}
+
+impl Pronunciations {
+    fn clip(&mut self, word: &str, asset_server: &AssetServer) -> Handle<AudioSource> {
+        self.clips
+            .entry(word.to_string())
+            .or_insert_with(|| asset_server.load(pronunciation_path(word)))
+            .clone()
+    }
+}
+
+/// Asset path of the recording of a word
+pub fn pronunciation_path(word: &str) -> String {
+    let mut name = String::new();
+    for c in word.trim().to_lowercase().chars() {
+        match c {
+            'ä' => name.push_str("ae"),
+            'ö' => name.push_str("oe"),
+            'ü' => name.push_str("ue"),
+            'ß' => name.push_str("ss"),
+            c if c.is_alphanumeric() => name.push(c),
+            _ => name.push('_'),
+        }
+    }
+    format!("{}/{}.ogg", PRONUNCIATION_DIRECTORY, name)
+}
+
+/// Start loading the recordings of the challenge's options before they are collected
+fn preload_pronunciations(
+    game_settings: Res<GameSettings>,
+    question_system: Option<Res<QuestionSystem>>,
+    asset_server: Res<AssetServer>,
+    mut pronunciations: ResMut<Pronunciations>,
+) {
+    pronunciations.pending.clear();
+    if !game_settings.audio.pronunciation {
+        return;
+    }
+    let Some(question_system) = question_system else {
+        return;
+    };
+
+    for option in question_system.get_current_options() {
+        if !pronunciations.missing.contains(&option.name) {
+            pronunciations.clip(&option.name, &asset_server);
+        }
+    }
+}
+
+fn pronounce_collected_words(
+    mut collected_events: EventReader<OptionCollectedEvent>,
+    asset_server: Res<AssetServer>,
+    time: Res<Time>,
+    mut pronunciations: ResMut<Pronunciations>,
+) {
+    for event in collected_events.read() {
+        if !event.is_correct {
+            continue;
+        }
+        let word = event.option_text.trim();
+        if word.is_empty() {
+            continue;
+        }
+
+        // Played by the next system as soon as the recording is ready
+        pronunciations.clip(word, &asset_server);
+        pronunciations
+            .pending
+            .push((word.to_string(), time.elapsed_secs()));
+    }
+}
+
+fn play_pending_pronunciations(
+    mut commands: Commands,
+    game_settings: Res<GameSettings>,
+    asset_server: Res<AssetServer>,
+    time: Res<Time>,
+    mut pronunciations: ResMut<Pronunciations>,
+    mut speak_events: EventWriter<SpeakEvent>,
+) {
+    if pronunciations.pending.is_empty() {
+        return;
+    }
+
+    let volume = game_settings.audio.pronunciation_volume;
+    let now = time.elapsed_secs();
+    let pending = std::mem::take(&mut pronunciations.pending);
+    for (word, collected_at) in pending {
+        let handle = pronunciations.clip(&word, &asset_server);
+        let late = now - collected_at > PRONUNCIATION_MAX_DELAY;
+        let recorded = !pronunciations.missing.contains(&word);
+
+        match asset_server.load_state(&handle) {
+            LoadState::Loaded if recorded && !late => {
+                commands.spawn((
+                    Name::new("Pronunciation"),
+                    AudioPlayer(handle),
+                    PlaybackSettings::DESPAWN.with_volume(Volume::Linear(volume)),
+                    SoundEffect,
+                ));
+                continue;
+            }
+            LoadState::Failed(_) => {
+                debug!("No recording for '{}', using text-to-speech", word);
+                pronunciations.missing.insert(word.clone());
+            }
+            LoadState::Loading | LoadState::NotLoaded if recorded && !late => {
+                pronunciations.pending.push((word, collected_at));
+                continue;
+            }
+            _ => {}
+        }
+
+        // Announcing collections already reads every word out loud
+        if !game_settings.accessibility.announce_collections && volume > 0.0 {
+            speak_events.write(SpeakEvent {
+                text: word,
+                language: Language::German,
+            });
+        }
+    }
+}
+
+// Pronunciation constants
+pub const PRONUNCIATION_DIRECTORY: &str = "audio/pronunciation";
+pub const PRONUNCIATION_MAX_DELAY: f32 = 0.5; // Seconds a word may wait for its recording
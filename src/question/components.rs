@@ -2,7 +2,19 @@ use bevy::prelude::*;
 use konnektoren_core::challenges::multiple_choice::{
     MultipleChoice, MultipleChoiceOption, Question,
 };
-use rand::{Rng, SeedableRng, rngs::StdRng};
+use rand::{Rng, rngs::StdRng};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Phase of the current question. Between two questions there is a short
+/// resolution phase where spawning stops and the answer is revealed.
+#[derive(SubStates, Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[source(crate::screens::Screen = crate::screens::Screen::Gameplay)]
+pub enum QuestionPhase {
+    #[default]
+    Asking,
+    Resolving,
+}
 
 /// Resource that manages the overall question system
 #[derive(Resource, Clone)]
@@ -12,12 +24,14 @@ pub struct QuestionSystem {
     pub options: Vec<MultipleChoiceOption>,
     pub question_order: Vec<usize>,
     pub rng: StdRng,
+    /// Name of the challenge, shown as the category in the question banner
+    pub category: String,
+    /// Topics this round is limited to, empty when the whole challenge is played
+    pub topics: Vec<String>,
 }
 
 impl QuestionSystem {
-    pub fn new(multiple_choice: &MultipleChoice, seed: u64) -> Self {
-        let mut rng = StdRng::seed_from_u64(seed);
-
+    pub fn new(multiple_choice: &MultipleChoice, mut rng: StdRng) -> Self {
         // Create randomized question order
         let mut question_order: Vec<usize> = (0..multiple_choice.questions.len()).collect();
 
@@ -33,14 +47,53 @@ impl QuestionSystem {
             options: multiple_choice.options.clone(),
             question_order,
             rng,
+            category: multiple_choice.name.clone(),
+            topics: Vec::new(),
         }
     }
 
+    /// Only ask questions from the given topics. Returns false and keeps the
+    /// whole challenge if its questions cover fewer than `MIN_TOPICS` of them,
+    /// since every question of a single topic has the same answer.
+    pub fn restrict_to_topics(&mut self, topics: &[String]) -> bool {
+        let question_order: Vec<usize> = self
+            .question_order
+            .iter()
+            .copied()
+            .filter(|&index| {
+                self.questions
+                    .get(index)
+                    .and_then(|question| question_topic(question, &self.options))
+                    .is_some_and(|topic| topics.iter().any(|t| t == topic))
+            })
+            .collect();
+        let covered: HashSet<&str> = question_order
+            .iter()
+            .filter_map(|&index| self.questions.get(index))
+            .filter_map(|question| question_topic(question, &self.options))
+            .collect();
+        if covered.len() < super::MIN_TOPICS {
+            return false;
+        }
+
+        self.question_order = question_order;
+        self.current_question_index = 0;
+        self.topics = topics.to_vec();
+        true
+    }
+
     pub fn get_current_question(&self) -> Option<&Question> {
         let shuffled_index = self.question_order.get(self.current_question_index)?;
         self.questions.get(*shuffled_index)
     }
 
+    /// Index of the current question within the challenge
+    pub fn current_question_id(&self) -> Option<usize> {
+        self.question_order
+            .get(self.current_question_index)
+            .copied()
+    }
+
     pub fn get_current_options(&self) -> &Vec<MultipleChoiceOption> {
         &self.options
     }
@@ -63,6 +116,87 @@ impl QuestionSystem {
     }
 }
 
+/// Topic of a question: the name of the option that answers it. Challenges
+/// don't carry separate tags, but their options already group the questions,
+/// e.g. causal or concessive connectors. The flip side is that a topic is an
+/// answer, so a round needs at least `MIN_TOPICS` of them to ask anything.
+pub fn question_topic<'a>(
+    question: &Question,
+    options: &'a [MultipleChoiceOption],
+) -> Option<&'a str> {
+    options
+        .iter()
+        .find(|option| option.id == question.option)
+        .map(|option| option.name.as_str())
+}
+
+/// All topics of a challenge with their number of questions, in option order
+pub fn challenge_topics(multiple_choice: &MultipleChoice) -> Vec<(String, usize)> {
+    multiple_choice
+        .options
+        .iter()
+        .map(|option| {
+            let count = multiple_choice
+                .questions
+                .iter()
+                .filter(|question| question.option == option.id)
+                .count();
+            (option.name.clone(), count)
+        })
+        .filter(|(_, count)| *count > 0)
+        .collect()
+}
+
+/// Resource holding the topics picked before a round, for a single challenge
+#[derive(Resource, Reflect, Default, Clone, Debug)]
+#[reflect(Resource)]
+pub struct TopicFilter {
+    /// Challenge the topics were picked from
+    pub challenge_id: Option<String>,
+    /// Topics to play, empty plays the whole challenge
+    pub topics: Vec<String>,
+}
+
+impl TopicFilter {
+    /// Topics picked for this challenge, empty if none were or they belong to another one
+    pub fn topics_for(&self, challenge_id: &str) -> &[String] {
+        if self.challenge_id.as_deref() == Some(challenge_id) {
+            &self.topics
+        } else {
+            &[]
+        }
+    }
+
+    pub fn toggle(&mut self, challenge_id: &str, topic: &str) {
+        if self.challenge_id.as_deref() != Some(challenge_id) {
+            self.challenge_id = Some(challenge_id.to_string());
+            self.topics.clear();
+        }
+
+        if let Some(position) = self.topics.iter().position(|t| t == topic) {
+            self.topics.remove(position);
+        } else {
+            self.topics.push(topic.to_string());
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.topics.clear();
+    }
+
+    /// Whether the picked topics make a round: all of them, or enough that
+    /// the questions don't all share one answer
+    pub fn is_playable(&self, challenge_id: &str) -> bool {
+        let topics = self.topics_for(challenge_id);
+        topics.is_empty() || topics.len() >= super::MIN_TOPICS
+    }
+}
+
+/// Component for the question overlay panel
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct QuestionOverlay;
+
 /// Timer component for question changes
 #[derive(Component, Reflect)]
 #[reflect(Component)]
@@ -71,6 +205,18 @@ pub struct QuestionTimer {
     pub fade_timer: Timer,
     pub is_fading: bool,
     pub fade_in: bool,
+    pub resolution_timer: Timer,
+    /// Runs while the transition banner presents a new question
+    pub banner_timer: Timer,
+}
+
+impl QuestionTimer {
+    pub fn new(question_duration: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(question_duration, TimerMode::Repeating),
+            ..default()
+        }
+    }
 }
 
 impl Default for QuestionTimer {
@@ -80,15 +226,53 @@ impl Default for QuestionTimer {
             fade_timer: Timer::from_seconds(super::QUESTION_FADE_DURATION, TimerMode::Once),
             is_fading: false,
             fade_in: true,
+            resolution_timer: Timer::from_seconds(
+                super::QUESTION_RESOLUTION_DURATION,
+                TimerMode::Once,
+            ),
+            banner_timer: Timer::from_seconds(super::QUESTION_BANNER_DURATION, TimerMode::Once),
         }
     }
 }
 
+impl QuestionTimer {
+    /// Share of the current question's time that is left, from 1.0 down to 0.0
+    pub fn fraction_remaining(&self) -> f32 {
+        self.timer.fraction_remaining()
+    }
+
+    /// The question is about to change and players should be warned
+    pub fn is_expiring(&self) -> bool {
+        (self.is_fading && !self.fade_in)
+            || self.timer.remaining_secs() <= super::QUESTION_WARNING_TIME
+    }
+}
+
+/// Component for the fill of the question countdown bar
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct QuestionCountdownFill;
+
 /// Component for the question display UI
 #[derive(Component, Reflect)]
 #[reflect(Component)]
 pub struct QuestionDisplay;
 
+/// Component for the full-width banner announcing a new question
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct QuestionBanner;
+
+/// Component for the question text inside the banner
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct QuestionBannerText;
+
+/// Component for the category text inside the banner
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct QuestionBannerCategory;
+
 /// Component for the help text display
 #[derive(Component, Reflect)]
 #[reflect(Component)]
@@ -109,3 +293,80 @@ impl Default for QuestionSeed {
         Self(seed)
     }
 }
+
+/// A single "this question is confusing" vote
+#[derive(Reflect, Clone, Debug, Serialize, Deserialize)]
+pub struct QuestionFeedbackVote {
+    pub challenge_id: String,
+    pub question_id: usize,
+    pub question: String,
+}
+
+/// Resource holding the confusing-question votes, persisted between sessions
+#[derive(Resource, Reflect, Default, Clone, Debug, Serialize, Deserialize)]
+#[reflect(Resource)]
+pub struct QuestionFeedback {
+    pub votes: Vec<QuestionFeedbackVote>,
+}
+
+impl QuestionFeedback {
+    pub fn load() -> Self {
+        crate::storage::load(super::QUESTION_FEEDBACK_STORAGE_KEY).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        crate::storage::save(super::QUESTION_FEEDBACK_STORAGE_KEY, self);
+    }
+
+    pub fn has_vote(&self, challenge_id: &str, question_id: usize) -> bool {
+        self.votes
+            .iter()
+            .any(|v| v.challenge_id == challenge_id && v.question_id == question_id)
+    }
+
+    /// Record a vote, returning false if this question was already flagged
+    pub fn add_vote(&mut self, vote: QuestionFeedbackVote) -> bool {
+        if self.has_vote(&vote.challenge_id, vote.question_id) {
+            return false;
+        }
+        self.votes.push(vote);
+        true
+    }
+}
+
+/// Event fired when a player flags the current question as confusing
+#[derive(Event, Clone, Debug)]
+pub struct QuestionFeedbackEvent {
+    pub vote: QuestionFeedbackVote,
+}
+
+/// Event sent when a new question is shown, including the first one of a round
+#[derive(Event, Debug)]
+pub struct QuestionChangedEvent;
+
+/// Event to end the current question early and reveal its answer
+#[derive(Event, Debug)]
+pub struct SkipQuestionEvent;
+
+/// Event sent when a question ends and its answer is revealed
+#[derive(Event, Debug)]
+pub struct QuestionResolvedEvent {
+    pub correct_option_id: usize,
+    /// Players whose last collection during the question was correct
+    pub rewarded_players: Vec<Entity>,
+}
+
+/// Resource remembering whether each player's last collection this question was correct
+#[derive(Resource, Default, Debug)]
+pub struct QuestionCollections {
+    pub last_correct: HashMap<Entity, bool>,
+}
+
+/// Event sent by the feedback button on the question overlay
+#[derive(Event)]
+pub struct FlagQuestionRequested;
+
+/// Component for the feedback button label on the question overlay
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct QuestionFeedbackLabel;
@@ -1,5 +1,6 @@
 use crate::game_state::GameState;
-use bevy::prelude::*;
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use konnektoren_bevy::assets::ChallengeAsset;
 
 mod components;
 pub mod systems;
@@ -10,8 +11,29 @@ use systems::*;
 pub(super) fn plugin(app: &mut App) {
     // Only register types that implement Reflect
     app.register_type::<QuestionTimer>();
+    app.register_type::<QuestionOverlay>();
+    app.register_type::<QuestionCountdownFill>();
     app.register_type::<QuestionDisplay>();
     app.register_type::<QuestionHelpDisplay>();
+    app.register_type::<QuestionBanner>();
+    app.register_type::<QuestionBannerText>();
+    app.register_type::<QuestionBannerCategory>();
+    app.register_type::<QuestionFeedback>();
+    app.register_type::<TopicFilter>();
+    app.register_type::<QuestionFeedbackLabel>();
+
+    app.add_event::<QuestionFeedbackEvent>();
+    app.add_event::<FlagQuestionRequested>();
+    app.add_event::<QuestionChangedEvent>();
+    app.add_event::<QuestionResolvedEvent>();
+    app.add_event::<SkipQuestionEvent>();
+
+    app.add_sub_state::<QuestionPhase>();
+    app.init_resource::<QuestionCollections>();
+    app.init_resource::<TopicFilter>();
+
+    app.init_resource::<QuestionFeedback>();
+    app.add_systems(Startup, load_question_feedback);
 
     app.add_systems(
         OnEnter(crate::screens::Screen::Gameplay),
@@ -22,13 +44,50 @@ pub(super) fn plugin(app: &mut App) {
         Update,
         (
             update_question_timer.in_set(crate::AppSystems::TickTimers),
+            track_question_collections.in_set(crate::AppSystems::Update),
             update_question_display.in_set(crate::AppSystems::Update),
+            update_question_countdown.in_set(crate::AppSystems::Update),
+            update_question_banner.in_set(crate::AppSystems::Update),
+            record_question_feedback
+                .run_if(on_event::<FlagQuestionRequested>.or(input_just_pressed(KeyCode::KeyF)))
+                .in_set(crate::AppSystems::Update),
+            update_question_feedback_label.in_set(crate::AppSystems::Update),
         )
             .run_if(in_state(crate::screens::Screen::Gameplay))
             .run_if(resource_exists::<QuestionSystem>)
             .in_set(crate::PausableSystems),
     );
+
+    // Also while paused, so edits made meanwhile aren't missed
+    app.add_systems(
+        Update,
+        reload_changed_challenge
+            .run_if(on_event::<AssetEvent<ChallengeAsset>>)
+            .run_if(resource_exists::<QuestionSystem>)
+            .in_set(crate::AppSystems::Update)
+            .run_if(in_state(crate::screens::Screen::Gameplay)),
+    );
+
+    app.add_systems(
+        Update,
+        update_question_overlay_layout
+            .in_set(crate::AppSystems::Update)
+            .run_if(in_state(crate::screens::Screen::Gameplay)),
+    );
 }
 
 pub const QUESTION_DURATION: f32 = 10.0; // seconds
 pub const QUESTION_FADE_DURATION: f32 = 0.5; // seconds for fade in/out
+pub const QUESTION_RESOLUTION_DURATION: f32 = 1.5; // seconds the answer is shown between questions
+pub const QUESTION_WARNING_TIME: f32 = 3.0; // seconds left when the countdown turns red
+pub const QUESTION_BANNER_SLIDE_DURATION: f32 = 0.35; // seconds for the banner to slide in
+pub const QUESTION_BANNER_HOLD_DURATION: f32 = 1.2; // seconds the new question stays centered
+pub const QUESTION_BANNER_SHRINK_DURATION: f32 = 0.45; // seconds to shrink into the overlay
+pub const QUESTION_BANNER_DURATION: f32 = QUESTION_BANNER_SLIDE_DURATION
+    + QUESTION_BANNER_HOLD_DURATION
+    + QUESTION_BANNER_SHRINK_DURATION;
+pub const QUESTION_BANNER_FONT_SIZE: f32 = 40.0;
+pub const QUESTION_BANNER_CATEGORY_FONT_SIZE: f32 = 20.0;
+pub const QUESTION_BANNER_TOP: f32 = 35.0; // percent of the screen height while centered
+pub const QUESTION_FEEDBACK_STORAGE_KEY: &str = "question_feedback";
+pub const MIN_TOPICS: usize = 2; // a topic is an answer, one alone would answer every question
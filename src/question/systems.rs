@@ -1,5 +1,8 @@
 use super::components::*;
-use crate::{game_state::GameState, resources::MultipleChoiceChallenge, screens::Screen};
+use crate::{
+    feed::GameFeedEvent, game_state::GameState, locale::Locale, resources::MultipleChoiceChallenge,
+    rng::GameRng, screens::Screen, theme::prelude::*,
+};
 use bevy::prelude::*;
 use konnektoren_bevy::assets::*;
 
@@ -7,9 +10,14 @@ use konnektoren_bevy::assets::*;
 pub fn setup_question_system(
     mut commands: Commands,
     time: Res<Time>,
-    game_state: Res<GameState>,
+    mut game_state: ResMut<GameState>,
+    mut game_rng: ResMut<GameRng>,
+    mut collections: ResMut<QuestionCollections>,
+    game_settings: Res<crate::settings::GameSettings>,
+    topic_filter: Res<TopicFilter>,
     asset_registry: Option<Res<KonnektorenAssetRegistry>>,
     challenge_assets: Option<Res<Assets<ChallengeAsset>>>,
+    mut question_events: EventWriter<QuestionChangedEvent>,
 ) {
     // Wait for game state to be ready
     if !game_state.is_ready() {
@@ -17,6 +25,15 @@ pub fn setup_question_system(
         return;
     }
 
+    // A shared round brings its own seed, otherwise use Bevy's elapsed time
+    // (works on all platforms)
+    let seeded = game_state.round_seed.is_some();
+    let seed = game_state
+        .round_seed
+        .take()
+        .unwrap_or((time.elapsed_secs() * 1000000.0) as u64);
+    let round_topics = game_state.round_topics.take();
+
     let Some(challenge_id) = &game_state.current_challenge_id else {
         error!("No challenge ID available in game state");
         return;
@@ -43,84 +60,316 @@ pub fn setup_question_system(
         challenge_id
     );
 
-    // Use Bevy's elapsed time as seed (works on all platforms)
-    let seed = (time.elapsed_secs() * 1000000.0) as u64;
+    // Everything random in this round follows the round seed
+    game_rng.reseed(seed);
 
     // Initialize the question system
-    let question_system = QuestionSystem::new(multiple_choice, seed);
+    let mut question_system = QuestionSystem::new(
+        multiple_choice,
+        game_rng.derive(crate::rng::QUESTION_ORDER_SALT),
+    );
+
+    // A resumed round keeps its topics. Other seeded rounds are replayed by
+    // others, so they always use the whole challenge.
+    let topics: &[String] = match &round_topics {
+        Some(topics) => topics.as_slice(),
+        None if seeded => &[],
+        None => topic_filter.topics_for(challenge_id),
+    };
+    if !topics.is_empty() {
+        if question_system.restrict_to_topics(topics) {
+            info!("Playing only the topics {:?}", topics);
+        } else {
+            warn!(
+                "Not enough questions for the topics {:?}, playing all of them",
+                topics
+            );
+        }
+    }
 
     // Spawn the question UI
-    spawn_question_ui(&mut commands, &question_system);
+    spawn_question_ui(
+        &mut commands,
+        &question_system,
+        game_settings.gameplay.tuning().question_duration,
+    );
 
     // Insert the question system as a resource
     commands.insert_resource(question_system);
+    collections.last_correct.clear();
+    question_events.write(QuestionChangedEvent);
+
+    // Remember what this round was played with so it can be shared
+    commands.insert_resource(crate::round::RoundInfo::new(
+        seed,
+        challenge_id.clone(),
+        &game_settings,
+    ));
 
     // Also insert the challenge resource for other systems that might need it
     commands.insert_resource(multiple_choice_challenge);
 }
 
 /// Spawn the question UI overlay
-fn spawn_question_ui(commands: &mut Commands, question_system: &QuestionSystem) {
+fn spawn_question_ui(
+    commands: &mut Commands,
+    question_system: &QuestionSystem,
+    question_duration: f32,
+) {
     let current_question = question_system
         .get_current_question()
         .expect("Should have at least one question");
 
+    let overlay = commands
+        .spawn((
+            Name::new("Question Overlay"),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(20.0),
+                left: Val::Px(20.0),
+                right: Val::Px(20.0),
+                padding: UiRect::all(Val::Px(20.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(10.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)), // Semi-transparent background
+            BorderRadius::all(Val::Px(10.0)),
+            StateScoped(Screen::Gameplay),
+            QuestionOverlay,
+            QuestionTimer::new(question_duration),
+            children![
+                // Question text
+                (
+                    Name::new("Question Text"),
+                    Text(current_question.question.clone()),
+                    TextFont {
+                        font_size: 24.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                    QuestionDisplay,
+                ),
+                // Countdown bar, shrinks until the question changes
+                (
+                    Name::new("Question Countdown"),
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Px(6.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.15)),
+                    BorderRadius::all(Val::Px(3.0)),
+                    children![(
+                        Name::new("Question Countdown Fill"),
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(countdown_color(1.0)),
+                        BorderRadius::all(Val::Px(3.0)),
+                        QuestionCountdownFill,
+                    )],
+                ),
+                // Help text
+                (
+                    Name::new("Help Text"),
+                    Text(if current_question.help.is_empty() {
+                        "Choose the correct answer...".to_string()
+                    } else {
+                        current_question.help.clone()
+                    }),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgba(0.8, 0.8, 0.8, 0.8)),
+                    QuestionHelpDisplay,
+                ),
+            ],
+        ))
+        .id();
+
+    // Banner announcing each new question before it settles into the overlay
     commands.spawn((
-        Name::new("Question Overlay"),
+        Name::new("Question Banner"),
         Node {
             position_type: PositionType::Absolute,
-            top: Val::Px(20.0),
-            left: Val::Px(20.0),
-            right: Val::Px(20.0),
-            padding: UiRect::all(Val::Px(20.0)),
+            top: Val::Percent(super::QUESTION_BANNER_TOP),
+            left: Val::Percent(0.0),
+            width: Val::Percent(100.0),
+            padding: UiRect::axes(Val::Px(20.0), Val::Px(24.0)),
             flex_direction: FlexDirection::Column,
-            row_gap: Val::Px(10.0),
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(8.0),
             ..default()
         },
-        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)), // Semi-transparent background
-        BorderRadius::all(Val::Px(10.0)),
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+        Visibility::Hidden,
+        Pickable::IGNORE,
         StateScoped(Screen::Gameplay),
-        QuestionTimer::default(),
+        QuestionBanner,
         children![
-            // Question text
             (
-                Name::new("Question Text"),
-                Text(current_question.question.clone()),
+                Name::new("Question Banner Category"),
+                Text(question_system.category.clone()),
                 TextFont {
-                    font_size: 24.0,
+                    font_size: super::QUESTION_BANNER_CATEGORY_FONT_SIZE,
                     ..default()
                 },
-                TextColor(Color::WHITE),
-                QuestionDisplay,
+                TextColor(Color::srgba(0.8, 0.8, 0.8, 0.9)),
+                QuestionBannerCategory,
             ),
-            // Help text
             (
-                Name::new("Help Text"),
-                Text(if current_question.help.is_empty() {
-                    "Choose the correct answer...".to_string()
-                } else {
-                    current_question.help.clone()
-                }),
+                Name::new("Question Banner Text"),
+                Text(current_question.question.clone()),
                 TextFont {
-                    font_size: 16.0,
+                    font_size: super::QUESTION_BANNER_FONT_SIZE,
                     ..default()
                 },
-                TextColor(Color::srgba(0.8, 0.8, 0.8, 0.8)),
-                QuestionHelpDisplay,
+                TextLayout::new_with_justify(JustifyText::Center),
+                TextColor(Color::WHITE),
+                QuestionBannerText,
             ),
         ],
     ));
+
+    // Small button to flag the current question as confusing
+    commands
+        .spawn((
+            Name::new("Question Feedback Button"),
+            Button,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(10.0),
+                right: Val::Px(10.0),
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                ..default()
+            },
+            BorderRadius::all(Val::Px(6.0)),
+            BackgroundColor(ui_palette::BUTTON_BACKGROUND),
+            InteractionPalette {
+                none: ui_palette::BUTTON_BACKGROUND,
+                hovered: ui_palette::BUTTON_HOVERED_BACKGROUND,
+                pressed: ui_palette::BUTTON_PRESSED_BACKGROUND,
+            },
+            ChildOf(overlay),
+            children![(
+                Name::new("Question Feedback Label"),
+                Text("Confusing? (F)".to_string()),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(ui_palette::BUTTON_TEXT),
+                Pickable::IGNORE,
+                QuestionFeedbackLabel,
+            )],
+        ))
+        .observe(request_question_flag);
+}
+
+fn request_question_flag(
+    _: Trigger<Pointer<Click>>,
+    mut flag_requests: EventWriter<FlagQuestionRequested>,
+) {
+    flag_requests.write(FlagQuestionRequested);
+}
+
+/// System to load stored question feedback on startup
+pub fn load_question_feedback(mut commands: Commands) {
+    commands.insert_resource(QuestionFeedback::load());
+}
+
+/// System to record a confusing-question vote for the current question
+pub fn record_question_feedback(
+    mut flag_requests: EventReader<FlagQuestionRequested>,
+    mut feedback_events: EventWriter<QuestionFeedbackEvent>,
+    mut feedback: ResMut<QuestionFeedback>,
+    question_system: Res<QuestionSystem>,
+    game_state: Res<GameState>,
+) {
+    flag_requests.clear();
+
+    let Some(challenge_id) = game_state.current_challenge_id.clone() else {
+        return;
+    };
+    let Some((question_id, question)) = question_system
+        .current_question_id()
+        .zip(question_system.get_current_question())
+    else {
+        return;
+    };
+
+    let vote = QuestionFeedbackVote {
+        challenge_id,
+        question_id,
+        question: question.question.clone(),
+    };
+
+    if feedback.add_vote(vote.clone()) {
+        feedback.save();
+        info!(
+            "Flagged question {} of '{}' as confusing",
+            vote.question_id, vote.challenge_id
+        );
+        feedback_events.write(QuestionFeedbackEvent { vote });
+    }
+}
+
+/// System to show whether the current question has already been flagged
+pub fn update_question_feedback_label(
+    feedback: Res<QuestionFeedback>,
+    question_system: Res<QuestionSystem>,
+    game_state: Res<GameState>,
+    mut label_query: Query<&mut Text, With<QuestionFeedbackLabel>>,
+) {
+    if !feedback.is_changed() && !question_system.is_changed() {
+        return;
+    }
+
+    let flagged = game_state
+        .current_challenge_id
+        .as_deref()
+        .zip(question_system.current_question_id())
+        .is_some_and(|(challenge_id, question_id)| feedback.has_vote(challenge_id, question_id));
+
+    for mut text in &mut label_query {
+        text.0 = if flagged {
+            "Flagged, thanks!".to_string()
+        } else {
+            "Confusing? (F)".to_string()
+        };
+    }
 }
 
 /// System to update the question timer and handle question changes
 pub fn update_question_timer(
     time: Res<Time>,
+    phase: Res<State<QuestionPhase>>,
+    mut next_phase: ResMut<NextState<QuestionPhase>>,
     mut question_system: ResMut<QuestionSystem>,
+    mut collections: ResMut<QuestionCollections>,
     mut timer_query: Query<&mut QuestionTimer>,
+    mut question_events: EventWriter<QuestionChangedEvent>,
+    mut resolved_events: EventWriter<QuestionResolvedEvent>,
+    mut skip_events: EventReader<SkipQuestionEvent>,
+    practice: Option<Res<crate::practice::PracticeRound>>,
+    locale: Res<Locale>,
+    mut feed_events: EventWriter<GameFeedEvent>,
 ) {
+    let skipped = skip_events.read().count() > 0;
+
     for mut question_timer in &mut timer_query {
-        // Update main timer
-        question_timer.timer.tick(time.delta());
+        // The question timer stands still while the answer is revealed, and
+        // while practicing, where questions only end when answered or skipped
+        if *phase.get() == QuestionPhase::Asking && !question_timer.is_fading && practice.is_none()
+        {
+            question_timer.timer.tick(time.delta());
+        }
+
+        question_timer.banner_timer.tick(time.delta());
 
         // Handle fading
         if question_timer.is_fading {
@@ -130,8 +379,15 @@ pub fn update_question_timer(
                 if !question_timer.fade_in {
                     // Fade out finished, change question and start fade in
                     question_system.advance_question();
+                    question_events.write(QuestionChangedEvent);
+                    feed_events.write(GameFeedEvent::new(locale.text("feed.question_changed")));
+                    collections.last_correct.clear();
+                    next_phase.set(QuestionPhase::Asking);
+                    question_timer.timer.reset();
                     question_timer.fade_in = true;
                     question_timer.fade_timer.reset();
+                    // Announce the new question while it fades in
+                    question_timer.banner_timer.reset();
                 } else {
                     // Fade in finished, stop fading
                     question_timer.is_fading = false;
@@ -139,12 +395,150 @@ pub fn update_question_timer(
             }
         }
 
-        // Check if it's time to change question
-        if question_timer.timer.just_finished() && !question_timer.is_fading {
-            // Start fade out
-            question_timer.is_fading = true;
-            question_timer.fade_in = false;
-            question_timer.fade_timer.reset();
+        // Time is up, reveal the answer before moving on
+        let time_up =
+            question_timer.timer.just_finished() || (skipped && !question_timer.is_fading);
+        if time_up && *phase.get() == QuestionPhase::Asking {
+            let correct_option_id = question_system
+                .get_current_question()
+                .map_or(0, |question| question.option);
+            let rewarded_players = collections
+                .last_correct
+                .iter()
+                .filter(|(_, correct)| **correct)
+                .map(|(entity, _)| *entity)
+                .collect();
+
+            resolved_events.write(QuestionResolvedEvent {
+                correct_option_id,
+                rewarded_players,
+            });
+            next_phase.set(QuestionPhase::Resolving);
+            question_timer.resolution_timer.reset();
+        }
+
+        // Resolution finished, fade out to the next question
+        if *phase.get() == QuestionPhase::Resolving && !question_timer.is_fading {
+            question_timer.resolution_timer.tick(time.delta());
+
+            if question_timer.resolution_timer.finished() {
+                question_timer.is_fading = true;
+                question_timer.fade_in = false;
+                question_timer.fade_timer.reset();
+            }
+        }
+    }
+}
+
+/// System to pick up edits to the running challenge's file. The question pool
+/// is rebuilt around the current question, which refreshes the question overlay
+/// and the legend, and options on the board that no longer match the challenge
+/// are removed.
+pub fn reload_changed_challenge(
+    mut commands: Commands,
+    mut asset_events: EventReader<AssetEvent<ChallengeAsset>>,
+    game_state: Res<GameState>,
+    asset_registry: Option<Res<KonnektorenAssetRegistry>>,
+    challenge_assets: Option<Res<Assets<ChallengeAsset>>>,
+    mut question_system: ResMut<QuestionSystem>,
+    mut option_query: Query<(Entity, &mut crate::options::OptionCollectible)>,
+    locale: Res<Locale>,
+    mut feed_events: EventWriter<GameFeedEvent>,
+) {
+    let (Some(registry), Some(assets)) = (asset_registry, challenge_assets) else {
+        asset_events.clear();
+        return;
+    };
+    let Some(challenge_id) = &game_state.current_challenge_id else {
+        asset_events.clear();
+        return;
+    };
+    let Some(handle) = registry.get_challenge_handle(challenge_id) else {
+        asset_events.clear();
+        return;
+    };
+
+    let modified = asset_events
+        .read()
+        .any(|event| event.is_modified(handle.id()));
+    if !modified {
+        return;
+    }
+
+    let Some(challenge) = assets
+        .get(handle)
+        .and_then(MultipleChoiceChallenge::from_asset)
+    else {
+        warn!(
+            "Challenge '{}' changed but is no longer multiple choice, keeping the old questions",
+            challenge_id
+        );
+        return;
+    };
+    let multiple_choice = challenge.get();
+    if multiple_choice.questions.is_empty() {
+        warn!(
+            "Challenge '{}' changed but has no questions, keeping the old ones",
+            challenge_id
+        );
+        return;
+    }
+
+    // Carry on with the same order, position and topics where they still fit
+    let current_question = question_system.current_question_id();
+    let mut reloaded = QuestionSystem::new(multiple_choice, question_system.rng.clone());
+    if !question_system.topics.is_empty() {
+        reloaded.restrict_to_topics(&question_system.topics);
+    }
+    if let Some(index) = current_question.and_then(|id| {
+        reloaded
+            .question_order
+            .iter()
+            .position(|&order| order == id)
+    }) {
+        reloaded.current_question_index = index;
+    }
+
+    let correct_option = reloaded
+        .get_current_question()
+        .map(|question| question.option);
+    let mut removed = 0;
+    for (entity, mut option) in &mut option_query {
+        let still_valid = reloaded.options.iter().any(|candidate| {
+            candidate.id == option.option_id && candidate.name == option.option_text
+        });
+        if still_valid {
+            option.is_correct = Some(option.option_id) == correct_option;
+        } else {
+            commands.entity(entity).despawn();
+            removed += 1;
+        }
+    }
+
+    info!(
+        "Reloaded challenge '{}': {} questions, {} options, {} stale options removed",
+        challenge_id,
+        reloaded.questions.len(),
+        reloaded.options.len(),
+        removed
+    );
+
+    *question_system = reloaded;
+    commands.insert_resource(challenge);
+    feed_events.write(GameFeedEvent::new(locale.text("feed.challenge_reloaded")));
+}
+
+/// System to remember whether each player's last collection this question was correct
+pub fn track_question_collections(
+    mut collected_events: EventReader<crate::player::OptionCollectedEvent>,
+    mut collections: ResMut<QuestionCollections>,
+    phase: Res<State<QuestionPhase>>,
+) {
+    for event in collected_events.read() {
+        if *phase.get() == QuestionPhase::Asking {
+            collections
+                .last_correct
+                .insert(event.player_entity, event.is_correct);
         }
     }
 }
@@ -196,3 +590,137 @@ pub fn update_question_display(
         }
     }
 }
+
+/// System to shrink the countdown bar with the question timer
+pub fn update_question_countdown(
+    phase: Res<State<QuestionPhase>>,
+    timer_query: Query<&QuestionTimer>,
+    mut fill_query: Query<(&mut Node, &mut BackgroundColor), With<QuestionCountdownFill>>,
+) {
+    let Ok(question_timer) = timer_query.single() else {
+        return;
+    };
+
+    // Empty while the answer is revealed, then refill with the next question
+    let remaining = if *phase.get() == QuestionPhase::Resolving {
+        0.0
+    } else {
+        question_timer.fraction_remaining()
+    };
+
+    for (mut node, mut background) in &mut fill_query {
+        node.width = Val::Percent(remaining * 100.0);
+        background.0 = countdown_color(remaining);
+    }
+}
+
+/// System to slide the question banner in, hold it and shrink it into the overlay
+pub fn update_question_banner(
+    question_system: Res<QuestionSystem>,
+    hud_layout: Res<crate::gameplay::HudLayout>,
+    timer_query: Query<&QuestionTimer>,
+    mut banner_query: Query<
+        (&mut Node, &mut BackgroundColor, &mut Visibility),
+        With<QuestionBanner>,
+    >,
+    mut text_query: Query<
+        (&mut Text, &mut TextFont, &mut TextColor),
+        (With<QuestionBannerText>, Without<QuestionBannerCategory>),
+    >,
+    mut category_query: Query<
+        (&mut Text, &mut TextColor),
+        (With<QuestionBannerCategory>, Without<QuestionBannerText>),
+    >,
+) {
+    let Ok(question_timer) = timer_query.single() else {
+        return;
+    };
+
+    if question_system.is_changed() {
+        if let Some(current_question) = question_system.get_current_question() {
+            for (mut text, _, _) in &mut text_query {
+                text.0 = current_question.question.clone();
+            }
+        }
+        for (mut text, _) in &mut category_query {
+            text.0 = question_system.category.clone();
+        }
+    }
+
+    let banner_timer = &question_timer.banner_timer;
+    if banner_timer.finished() {
+        for (_, _, mut visibility) in &mut banner_query {
+            *visibility = Visibility::Hidden;
+        }
+        return;
+    }
+
+    let elapsed = banner_timer.elapsed_secs();
+    let slide = (elapsed / super::QUESTION_BANNER_SLIDE_DURATION).min(1.0);
+    let shrink_start = super::QUESTION_BANNER_SLIDE_DURATION + super::QUESTION_BANNER_HOLD_DURATION;
+    let shrink =
+        ((elapsed - shrink_start) / super::QUESTION_BANNER_SHRINK_DURATION).clamp(0.0, 1.0);
+
+    // Ease out of the slide, ease into the shrink
+    let slide = 1.0 - (1.0 - slide).powi(3);
+    let shrink = shrink * shrink;
+
+    // Portrait screens keep the overlay at the bottom
+    let overlay_top = if hud_layout.portrait { 80.0 } else { 2.0 };
+    let top = super::QUESTION_BANNER_TOP.lerp(overlay_top, shrink);
+    let width = 100.0_f32.lerp(40.0, shrink);
+    let left = (slide - 1.0) * 100.0 + 2.0 * shrink;
+    let alpha = 1.0 - shrink;
+
+    for (mut node, mut background, mut visibility) in &mut banner_query {
+        *visibility = Visibility::Inherited;
+        node.top = Val::Percent(top);
+        node.left = Val::Percent(left);
+        node.width = Val::Percent(width);
+        *background = BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85 * alpha));
+    }
+    for (_, mut font, mut color) in &mut text_query {
+        font.font_size = super::QUESTION_BANNER_FONT_SIZE.lerp(24.0, shrink);
+        *color = TextColor(Color::WHITE.with_alpha(alpha));
+    }
+    for (_, mut color) in &mut category_query {
+        *color = TextColor(Color::srgba(0.8, 0.8, 0.8, 0.9 * alpha));
+    }
+}
+
+/// Green while there is plenty of time, shifting through yellow to red near expiry
+fn countdown_color(remaining: f32) -> Color {
+    let hue = remaining.clamp(0.0, 1.0) * 120.0;
+    Color::hsl(hue, 0.8, 0.5)
+}
+
+/// System to move the question overlay to the bottom of portrait screens
+pub fn update_question_overlay_layout(
+    hud_layout: Res<crate::gameplay::HudLayout>,
+    added_overlays: Query<(), Added<QuestionOverlay>>,
+    mut overlay_query: Query<&mut Node, With<QuestionOverlay>>,
+) {
+    if !hud_layout.is_changed() && added_overlays.is_empty() {
+        return;
+    }
+
+    let safe_area = hud_layout.safe_area;
+    for mut node in &mut overlay_query {
+        node.left = Val::Px(20.0 + safe_area.left);
+        node.right = Val::Px(20.0 + safe_area.right);
+        if hud_layout.portrait {
+            // Sit above the joystick instead of under the player's thumb
+            let clearance = if hud_layout.touch_controls {
+                crate::gameplay::TOUCH_CONTROLS_CLEARANCE
+            } else {
+                0.0
+            };
+            node.top = Val::Auto;
+            node.bottom = Val::Px(20.0 + safe_area.bottom + clearance);
+        } else {
+            node.top = Val::Px(20.0 + safe_area.top);
+            node.bottom = Val::Auto;
+        }
+        node.padding = UiRect::all(Val::Px(if hud_layout.compact { 10.0 } else { 20.0 }));
+    }
+}
@@ -0,0 +1,70 @@
+//! Quick play: jump straight into a single-player round, skipping all menus.
+
+use bevy::prelude::*;
+use konnektoren_bevy::assets::*;
+
+use crate::{
+    game_state::{GameState, random_level_challenge},
+    launch::LaunchOptions,
+    screens::Screen,
+    settings::GameSettings,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_event::<QuickPlayEvent>();
+
+    app.add_systems(OnEnter(Screen::Title), quick_play_from_launch_options);
+    app.add_systems(Update, start_quick_play.run_if(on_event::<QuickPlayEvent>));
+}
+
+/// Event to start a single-player round with a random challenge
+#[derive(Event)]
+pub struct QuickPlayEvent;
+
+/// Honor `--quick` / `?quick` the first time the title screen is reached
+fn quick_play_from_launch_options(
+    mut launch_options: ResMut<LaunchOptions>,
+    mut quick_play_events: EventWriter<QuickPlayEvent>,
+) {
+    if launch_options.quick_play {
+        launch_options.quick_play = false;
+        quick_play_events.write(QuickPlayEvent);
+    }
+}
+
+fn start_quick_play(
+    mut quick_play_events: EventReader<QuickPlayEvent>,
+    mut game_settings: ResMut<GameSettings>,
+    mut game_state: ResMut<GameState>,
+    launch_options: Res<LaunchOptions>,
+    asset_registry: Option<Res<KonnektorenAssetRegistry>>,
+    level_assets: Option<Res<Assets<LevelAsset>>>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    quick_play_events.clear();
+
+    // Keep the last used settings for player one, but play alone
+    game_settings.multiplayer.set_single_player();
+
+    // A challenge given at launch is played as is
+    if launch_options.challenge_id.is_some() {
+        info!("Starting quick play");
+        next_screen.set(Screen::Loading);
+        return;
+    }
+
+    // If the level is still loading, it will pick a random challenge once it arrives
+    game_state.random_challenge = true;
+    let level_asset = asset_registry
+        .as_ref()
+        .and_then(|registry| registry.get_level_handle(&game_state.current_level_id))
+        .zip(level_assets.as_ref())
+        .and_then(|(handle, assets)| assets.get(handle));
+
+    if let Some(challenge_id) = level_asset.and_then(random_level_challenge) {
+        game_state.select_challenge(challenge_id);
+    }
+
+    info!("Starting quick play");
+    next_screen.set(Screen::Loading);
+}
@@ -0,0 +1,134 @@
+//! Answer history for the post-game review: every question shown during the
+//! round together with the options players collected while it was active.
+
+use bevy::prelude::*;
+
+use crate::{
+    Pause,
+    bot::BotController,
+    gameplay::GameTimerEvent,
+    match_play::MatchState,
+    menus::Menu,
+    player::{OptionCollectedEvent, PlayerIndex},
+    question::{QuestionChangedEvent, QuestionSystem},
+    screens::Screen,
+    settings::GameSettings,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<AnswerHistory>();
+
+    app.add_systems(OnEnter(Screen::Gameplay), reset_answer_history);
+    app.add_systems(
+        Update,
+        (
+            (record_questions, record_answers)
+                .chain()
+                .run_if(resource_exists::<QuestionSystem>),
+            open_review_on_game_end,
+        )
+            .in_set(crate::AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// Resource with the questions and answers of the current or last round
+#[derive(Resource, Default, Debug)]
+pub struct AnswerHistory {
+    pub questions: Vec<AnsweredQuestion>,
+}
+
+#[derive(Clone, Debug)]
+pub struct AnsweredQuestion {
+    pub question: String,
+    pub correct_answer: String,
+    pub answers: Vec<CollectedAnswer>,
+}
+
+#[derive(Clone, Debug)]
+pub struct CollectedAnswer {
+    pub player_name: String,
+    pub option_text: String,
+    pub is_correct: bool,
+}
+
+fn reset_answer_history(mut history: ResMut<AnswerHistory>) {
+    history.questions.clear();
+}
+
+fn record_questions(
+    mut question_events: EventReader<QuestionChangedEvent>,
+    question_system: Res<QuestionSystem>,
+    mut history: ResMut<AnswerHistory>,
+) {
+    for _ in question_events.read() {
+        let Some(question) = question_system.get_current_question() else {
+            continue;
+        };
+
+        let correct_answer = question_system
+            .get_current_options()
+            .iter()
+            .find(|option| option.id == question.option)
+            .map(|option| option.name.clone())
+            .unwrap_or_default();
+
+        history.questions.push(AnsweredQuestion {
+            question: question.question.clone(),
+            correct_answer,
+            answers: Vec::new(),
+        });
+    }
+}
+
+fn record_answers(
+    mut collection_events: EventReader<OptionCollectedEvent>,
+    mut history: ResMut<AnswerHistory>,
+    game_settings: Res<GameSettings>,
+    player_query: Query<(&PlayerIndex, &Name, Has<BotController>)>,
+) {
+    for event in collection_events.read() {
+        let Some(current) = history.questions.last_mut() else {
+            continue;
+        };
+
+        let player_name = match player_query.get(event.player_entity) {
+            Ok((_, name, true)) => name.to_string(),
+            Ok((player_index, _, false)) => game_settings
+                .multiplayer
+                .players
+                .get(player_index.0)
+                .map(|player| player.name.clone())
+                .unwrap_or_else(|| format!("Player {}", player_index.0 + 1)),
+            Err(_) => "Player".to_string(),
+        };
+
+        current.answers.push(CollectedAnswer {
+            player_name,
+            option_text: event.option_text.clone(),
+            is_correct: event.is_correct,
+        });
+    }
+}
+
+/// Show the review once the round is over, or the round summary during a match
+fn open_review_on_game_end(
+    mut timer_events: EventReader<GameTimerEvent>,
+    match_state: Option<Res<MatchState>>,
+    mut next_menu: ResMut<NextState<Menu>>,
+    mut next_pause: ResMut<NextState<Pause>>,
+) {
+    for event in timer_events.read() {
+        match event {
+            GameTimerEvent::GameEnded => {
+                next_pause.set(Pause(true));
+                next_menu.set(if match_state.is_some() {
+                    Menu::RoundSummary
+                } else {
+                    Menu::Review
+                });
+            }
+            GameTimerEvent::SuddenDeath => {}
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Seedable game-wide randomness. Every round reseeds `GameRng` from its
+//! round seed, so a run can be reproduced for replays, tests and networked
+//! play by reusing that seed.
+
+use bevy::prelude::*;
+use rand::{SeedableRng, rngs::StdRng};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<GameRng>();
+}
+
+/// Seeded random number generator shared by gameplay systems.
+///
+/// Gameplay and cosmetic effects draw from separate streams, so effects that
+/// only run for visible entities can't shift what happens in the round.
+#[derive(Resource, Debug)]
+pub struct GameRng {
+    seed: u64,
+    gameplay: StdRng,
+    effects: StdRng,
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            gameplay: StdRng::seed_from_u64(seed),
+            effects: StdRng::seed_from_u64(seed ^ EFFECTS_STREAM_SALT),
+        }
+    }
+
+    /// Restart both streams from a new seed
+    pub fn reseed(&mut self, seed: u64) {
+        *self = Self::new(seed);
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Stream for anything that affects the outcome of a round
+    pub fn gameplay(&mut self) -> &mut StdRng {
+        &mut self.gameplay
+    }
+
+    /// Stream for purely cosmetic randomness
+    pub fn effects(&mut self) -> &mut StdRng {
+        &mut self.effects
+    }
+
+    /// Independent generator derived from the seed, for systems that keep
+    /// their own stream (e.g. the question order)
+    pub fn derive(&self, salt: u64) -> StdRng {
+        StdRng::seed_from_u64(self.seed ^ salt)
+    }
+}
+
+// RNG constants
+pub const EFFECTS_STREAM_SALT: u64 = 0x9E37_79B9_7F4A_7C15;
+pub const QUESTION_ORDER_SALT: u64 = 0; // Kept at 0 so existing share codes keep their question order
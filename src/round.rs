@@ -0,0 +1,398 @@
+//! Round identity: the seed, rules and challenge a round was played with,
+//! packed into a share code so others can play the very same round. The
+//! rules cover every gameplay setting and the mode; the recipient's own
+//! settings are set aside for the round and put back afterwards.
+
+use bevy::prelude::*;
+
+use crate::{
+    chain::{ChainOverflowRule, ReactionSpreadRule},
+    game_state::GameState,
+    gameplay::GameTimerEvent,
+    map::{MapPreset, MapTheme},
+    notifications::NotificationEvent,
+    player::MovementModel,
+    screens::Screen,
+    settings::{
+        Difficulty, DifficultyTuning, GameSettings, GameplaySettings, Handicap, MAX_PLAYERS,
+        MultiplayerSettings,
+    },
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<RoundInfo>();
+    app.init_resource::<ShareCodeInput>();
+    app.add_event::<PlaySharedRoundEvent>();
+
+    app.add_systems(
+        Update,
+        (
+            start_shared_round.run_if(on_event::<PlaySharedRoundEvent>),
+            announce_share_code.run_if(in_state(Screen::Gameplay)),
+        ),
+    );
+    app.add_systems(OnEnter(Screen::Title), restore_settings);
+}
+
+/// Resource describing the round currently being played
+#[derive(Resource, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Resource)]
+pub struct RoundInfo {
+    pub seed: u64,
+    pub challenge_id: String,
+    pub rules: RoundRules,
+    pub version: String,
+}
+
+impl RoundInfo {
+    pub fn new(seed: u64, challenge_id: String, game_settings: &GameSettings) -> Self {
+        Self {
+            seed,
+            challenge_id,
+            rules: RoundRules::from_settings(game_settings),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+
+    /// Encode as `<version>/<challenge>/<rules>/<seed in hex>`, see
+    /// `RoundRules::code` for the rules
+    pub fn share_code(&self) -> String {
+        format!(
+            "{}/{}/{}/{:x}",
+            self.version,
+            self.challenge_id,
+            self.rules.code(),
+            self.seed
+        )
+    }
+
+    pub fn from_share_code(code: &str) -> Option<Self> {
+        let (version, rest) = code.trim().split_once('/')?;
+        let (rest, seed) = rest.rsplit_once('/')?;
+        let (challenge_id, rules) = rest.rsplit_once('/')?;
+
+        if version.is_empty() || challenge_id.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            seed: u64::from_str_radix(seed, 16).ok()?,
+            challenge_id: challenge_id.to_string(),
+            rules: RoundRules::from_code(rules)?,
+            version: version.to_string(),
+        })
+    }
+}
+
+/// Everything that changes how a round plays: the gameplay settings and the
+/// mode. Player names, colors, inputs and handicaps aren't part of it.
+#[derive(Reflect, Clone, Debug, PartialEq)]
+pub struct RoundRules {
+    /// Gameplay settings, the tuning only kept for a `Custom` difficulty.
+    /// The map theme only changes the look, `Random` resolves the same way
+    /// from the seed.
+    pub gameplay: GameplaySettings,
+    /// Players including bots, 1 for a single player round
+    pub player_count: usize,
+    pub bot_count: usize,
+    pub bot_difficulty: Difficulty,
+    pub coop_shared_chain: bool,
+    pub match_rounds: u32,
+    pub chain_steal_enabled: bool,
+    pub chain_steal_segments: usize,
+}
+
+impl Default for RoundRules {
+    fn default() -> Self {
+        Self::from_settings(&GameSettings::default())
+    }
+}
+
+impl RoundRules {
+    pub fn from_settings(game_settings: &GameSettings) -> Self {
+        let multiplayer = &game_settings.multiplayer;
+        let mut gameplay = game_settings.gameplay.clone();
+        if gameplay.difficulty != Difficulty::Custom {
+            gameplay.custom_tuning = DifficultyTuning::default();
+        }
+
+        Self {
+            gameplay,
+            player_count: if multiplayer.enabled {
+                multiplayer.player_count
+            } else {
+                1
+            },
+            bot_count: multiplayer.bot_count,
+            bot_difficulty: multiplayer.bot_difficulty,
+            coop_shared_chain: multiplayer.coop_shared_chain,
+            match_rounds: multiplayer.match_rounds,
+            chain_steal_enabled: multiplayer.chain_steal_enabled,
+            chain_steal_segments: multiplayer.chain_steal_segments,
+        }
+    }
+
+    /// Play under these rules, keeping the players' own names, colors and inputs
+    pub fn apply(&self, game_settings: &mut GameSettings) {
+        game_settings.gameplay = self.gameplay.clone();
+
+        let multiplayer = &mut game_settings.multiplayer;
+        if self.player_count > 1 {
+            multiplayer.enabled = true;
+            multiplayer.set_player_count(self.player_count);
+        } else {
+            multiplayer.set_single_player();
+        }
+        multiplayer.bot_count = self.bot_count;
+        multiplayer.bot_difficulty = self.bot_difficulty;
+        multiplayer.coop_shared_chain = self.coop_shared_chain;
+        multiplayer.match_rounds = self.match_rounds;
+        multiplayer.chain_steal_enabled = self.chain_steal_enabled;
+        multiplayer.chain_steal_segments = self.chain_steal_segments;
+    }
+
+    /// Comma separated values in a fixed order, flags as 0 or 1 and choices by
+    /// index, followed by the tuning values of a custom difficulty
+    pub fn code(&self) -> String {
+        let gameplay = &self.gameplay;
+        let mut values = vec![
+            gameplay.difficulty.index() as f32,
+            gameplay.map_preset.index() as f32,
+            gameplay.map_theme.index() as f32,
+            gameplay.cursed_segments as u8 as f32,
+            gameplay.respawn_after_chain_reaction as u8 as f32,
+            gameplay.hazards as u8 as f32,
+            gameplay.learning_feedback as u8 as f32,
+            gameplay.movement_model.index() as f32,
+            gameplay.max_chain_length as f32,
+            gameplay.chain_overflow.index() as f32,
+            gameplay.reaction_spread.index() as f32,
+            gameplay.reaction_spread_limit as f32,
+            gameplay.correct_option_radius as f32,
+            gameplay.director_intensity as f32,
+            self.player_count as f32,
+            self.bot_count as f32,
+            self.bot_difficulty.index() as f32,
+            self.coop_shared_chain as u8 as f32,
+            self.match_rounds as f32,
+            self.chain_steal_enabled as u8 as f32,
+            self.chain_steal_segments as f32,
+        ];
+        if gameplay.difficulty == Difficulty::Custom {
+            let tuning = &gameplay.custom_tuning;
+            values.extend([
+                tuning.question_duration,
+                tuning.option_lifetime,
+                tuning.option_spawn_interval,
+                tuning.player_move_speed,
+                tuning.hazard_spawn_interval,
+                tuning.moving_option_share,
+            ]);
+        }
+
+        values
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Read the rules of a share code. Older codes only carry the difficulty,
+    /// the map and the custom tuning, everything else is played at the defaults.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let values = code
+            .split(',')
+            .map(|value| value.parse::<f32>().ok().filter(|value| value.is_finite()))
+            .collect::<Option<Vec<_>>>()?;
+        let (rules, tuning) = match values.len() {
+            1 | 3 => (values.as_slice(), None),
+            9 => (&values[..3], Some(&values[3..])),
+            ROUND_RULE_COUNT => (values.as_slice(), None),
+            len if len == ROUND_RULE_COUNT + ROUND_TUNING_COUNT => (
+                &values[..ROUND_RULE_COUNT],
+                Some(&values[ROUND_RULE_COUNT..]),
+            ),
+            _ => return None,
+        };
+
+        let difficulty = rules[0] as usize;
+        if difficulty >= Difficulty::ALL.len() {
+            return None;
+        }
+        let difficulty = Difficulty::from_index(difficulty);
+        let custom_tuning = match tuning {
+            Some(
+                &[
+                    question_duration,
+                    option_lifetime,
+                    option_spawn_interval,
+                    player_move_speed,
+                    hazard_spawn_interval,
+                    moving_option_share,
+                ],
+            ) => Some(DifficultyTuning {
+                question_duration,
+                option_lifetime,
+                option_spawn_interval,
+                player_move_speed,
+                hazard_spawn_interval,
+                moving_option_share,
+            }),
+            _ => None,
+        };
+        if custom_tuning.is_some() != (difficulty == Difficulty::Custom) {
+            return None;
+        }
+
+        let defaults = Self::default();
+        let value = |index: usize| rules.get(index).copied();
+        let count = |index: usize, default: usize| value(index).map_or(default, |v| v as usize);
+        let flag = |index: usize, default: bool| value(index).map_or(default, |v| v != 0.0);
+        let gameplay = &defaults.gameplay;
+
+        Some(Self {
+            gameplay: GameplaySettings {
+                difficulty,
+                map_preset: MapPreset::from_index(count(1, gameplay.map_preset.index())),
+                map_theme: MapTheme::from_index(count(2, gameplay.map_theme.index())),
+                cursed_segments: flag(3, gameplay.cursed_segments),
+                respawn_after_chain_reaction: flag(4, gameplay.respawn_after_chain_reaction),
+                hazards: flag(5, gameplay.hazards),
+                learning_feedback: flag(6, gameplay.learning_feedback),
+                movement_model: MovementModel::from_index(count(
+                    7,
+                    gameplay.movement_model.index(),
+                )),
+                custom_tuning: custom_tuning.unwrap_or_default(),
+                max_chain_length: count(8, gameplay.max_chain_length),
+                chain_overflow: ChainOverflowRule::from_index(count(
+                    9,
+                    gameplay.chain_overflow.index(),
+                )),
+                reaction_spread: ReactionSpreadRule::from_index(count(
+                    10,
+                    gameplay.reaction_spread.index(),
+                )),
+                reaction_spread_limit: count(11, gameplay.reaction_spread_limit),
+                correct_option_radius: count(12, gameplay.correct_option_radius),
+                director_intensity: count(13, gameplay.director_intensity as usize) as u32,
+            },
+            player_count: count(14, defaults.player_count).clamp(1, MAX_PLAYERS),
+            bot_count: count(15, defaults.bot_count),
+            bot_difficulty: Difficulty::from_index(count(16, defaults.bot_difficulty.index())),
+            coop_shared_chain: flag(17, defaults.coop_shared_chain),
+            match_rounds: count(18, defaults.match_rounds as usize) as u32,
+            chain_steal_enabled: flag(19, defaults.chain_steal_enabled),
+            chain_steal_segments: count(20, defaults.chain_steal_segments),
+        })
+    }
+}
+
+/// The player's own settings, set aside while a round is played under rules
+/// from elsewhere: a share code, the daily challenge or a saved game. They
+/// come back on the title screen, so restarts and further match rounds keep
+/// the borrowed rules.
+#[derive(Resource, Debug)]
+pub struct SettingsBackup {
+    multiplayer: MultiplayerSettings,
+    gameplay: GameplaySettings,
+}
+
+impl SettingsBackup {
+    /// Set the settings aside, unless they already are. Starting twice before
+    /// the round begins mustn't set aside the borrowed rules instead.
+    pub fn store(
+        commands: &mut Commands,
+        existing: Option<&SettingsBackup>,
+        game_settings: &GameSettings,
+    ) {
+        if existing.is_none() {
+            commands.insert_resource(SettingsBackup {
+                multiplayer: game_settings.multiplayer.clone(),
+                gameplay: game_settings.gameplay.clone(),
+            });
+        }
+    }
+}
+
+/// Text typed into the share code box on the main menu
+#[derive(Resource, Default)]
+pub struct ShareCodeInput(pub String);
+
+/// Event to start a round with the same conditions as a shared one
+#[derive(Event)]
+pub struct PlaySharedRoundEvent(pub RoundInfo);
+
+fn start_shared_round(
+    mut commands: Commands,
+    mut events: EventReader<PlaySharedRoundEvent>,
+    mut game_state: ResMut<GameState>,
+    mut game_settings: ResMut<GameSettings>,
+    backup: Option<Res<SettingsBackup>>,
+    mut notifications: EventWriter<NotificationEvent>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    let Some(PlaySharedRoundEvent(round)) = events.read().last() else {
+        return;
+    };
+
+    if round.version != env!("CARGO_PKG_VERSION") {
+        notifications.write(NotificationEvent::new(format!(
+            "This round was shared from version {}, results may differ",
+            round.version
+        )));
+    }
+
+    // Same rules as the original round, handicaps would skew the comparison
+    SettingsBackup::store(&mut commands, backup.as_deref(), &game_settings);
+    round.rules.apply(&mut game_settings);
+    for player in &mut game_settings.multiplayer.players {
+        player.handicap = Handicap::default();
+    }
+    game_state.random_challenge = false;
+    game_state.select_challenge(round.challenge_id.clone());
+    game_state.round_seed = Some(round.seed);
+
+    info!("Starting shared round {}", round.share_code());
+    next_screen.set(Screen::Loading);
+}
+
+/// Show the share code once the round is over
+fn announce_share_code(
+    mut timer_events: EventReader<GameTimerEvent>,
+    round: Option<Res<RoundInfo>>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    let Some(round) = round else {
+        return;
+    };
+
+    for event in timer_events.read() {
+        if let GameTimerEvent::GameEnded = event {
+            notifications.write(NotificationEvent {
+                message: format!("Share this round: {}", round.share_code()),
+                duration: 10.0,
+            });
+        }
+    }
+}
+
+/// Put the player's own settings back after a round under borrowed rules
+fn restore_settings(
+    mut commands: Commands,
+    backup: Option<Res<SettingsBackup>>,
+    mut game_settings: ResMut<GameSettings>,
+) {
+    let Some(backup) = backup else {
+        return;
+    };
+
+    game_settings.multiplayer = backup.multiplayer.clone();
+    game_settings.gameplay = backup.gameplay.clone();
+    commands.remove_resource::<SettingsBackup>();
+    info!("Restored the player's own settings");
+}
+
+// Round constants
+pub const ROUND_RULE_COUNT: usize = 21; // Values in the rules of a share code before the tuning
+pub const ROUND_TUNING_COUNT: usize = 6;
@@ -0,0 +1,96 @@
+//! Safe-area insets: on phones with notches, rounded corners or a home
+//! indicator the browser reports how much of each screen edge is covered, and
+//! the gameplay HUD keeps out of those strips.
+
+use bevy::{prelude::*, window::WindowResized};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<SafeAreaInsets>();
+    app.init_resource::<SafeAreaInsets>();
+
+    app.add_systems(Startup, update_safe_area_insets);
+    // Insets change with the orientation, which shows up as a resize
+    app.add_systems(
+        Update,
+        update_safe_area_insets.run_if(on_event::<WindowResized>),
+    );
+}
+
+/// Resource with the covered strip along each screen edge, in logical pixels
+#[derive(Resource, Reflect, Clone, Copy, Debug, Default, PartialEq)]
+#[reflect(Resource)]
+pub struct SafeAreaInsets {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+impl SafeAreaInsets {
+    /// Insets divided by the UI scale, ready to be added to `Val::Px` offsets
+    pub fn scaled(&self, ui_scale: f32) -> Self {
+        let scale = ui_scale.max(f32::EPSILON);
+        Self {
+            top: self.top / scale,
+            right: self.right / scale,
+            bottom: self.bottom / scale,
+            left: self.left / scale,
+        }
+    }
+}
+
+fn update_safe_area_insets(mut insets: ResMut<SafeAreaInsets>) {
+    let current = read_safe_area_insets();
+    if *insets != current {
+        info!("Safe area insets: {:?}", current);
+        *insets = current;
+    }
+}
+
+/// The browser only exposes the insets to CSS, so a hidden probe element is
+/// padded with `env(safe-area-inset-*)` and its computed padding read back
+#[cfg(target_family = "wasm")]
+fn read_safe_area_insets() -> SafeAreaInsets {
+    let read = || -> Option<SafeAreaInsets> {
+        let window = web_sys::window()?;
+        let document = window.document()?;
+        let body = document.body()?;
+
+        let probe = document.create_element("div").ok()?;
+        probe
+            .set_attribute(
+                "style",
+                "position:fixed;visibility:hidden;pointer-events:none;\
+                 padding-top:env(safe-area-inset-top,0px);\
+                 padding-right:env(safe-area-inset-right,0px);\
+                 padding-bottom:env(safe-area-inset-bottom,0px);\
+                 padding-left:env(safe-area-inset-left,0px);",
+            )
+            .ok()?;
+        body.append_child(&probe).ok()?;
+
+        let style = window.get_computed_style(&probe).ok().flatten();
+        let padding = |property: &str| {
+            style
+                .as_ref()
+                .and_then(|style| style.get_property_value(property).ok())
+                .and_then(|value| value.trim_end_matches("px").parse::<f32>().ok())
+                .unwrap_or(0.0)
+        };
+        let insets = SafeAreaInsets {
+            top: padding("padding-top"),
+            right: padding("padding-right"),
+            bottom: padding("padding-bottom"),
+            left: padding("padding-left"),
+        };
+
+        probe.remove();
+        Some(insets)
+    };
+    read().unwrap_or_default()
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn read_safe_area_insets() -> SafeAreaInsets {
+    SafeAreaInsets::default()
+}
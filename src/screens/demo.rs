@@ -0,0 +1,85 @@
+//! Attract mode: a bot-only demo match shown after the title screen has been
+//! idle for a while. Any input returns to the title screen.
+
+use bevy::prelude::*;
+
+use crate::screens::{GameplayMode, Screen};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(GameplayMode::Demo), spawn_demo_overlay);
+
+    app.add_systems(
+        Update,
+        (blink_demo_prompt, end_demo).run_if(in_state(GameplayMode::Demo)),
+    );
+}
+
+/// Marker for the blinking "Press any key" prompt
+#[derive(Component)]
+struct DemoPrompt;
+
+/// How long the demo match keeps running before returning to the title
+#[derive(Component)]
+struct DemoTimer(Timer);
+
+fn spawn_demo_overlay(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Demo Overlay"),
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            bottom: Val::Px(60.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        Pickable::IGNORE,
+        GlobalZIndex(2),
+        DemoTimer(Timer::from_seconds(DEMO_DURATION, TimerMode::Once)),
+        StateScoped(Screen::Gameplay),
+        children![(
+            Name::new("Demo Prompt"),
+            Text("DEMO - Press any key".to_string()),
+            TextFont {
+                font_size: 32.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            DemoPrompt,
+        )],
+    ));
+}
+
+fn blink_demo_prompt(time: Res<Time>, mut prompt_query: Query<&mut TextColor, With<DemoPrompt>>) {
+    let alpha = 0.6 + 0.4 * (time.elapsed_secs() * 3.0).sin();
+    for mut color in &mut prompt_query {
+        color.0 = Color::srgba(1.0, 1.0, 1.0, alpha);
+    }
+}
+
+fn end_demo(
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    gamepads: Query<&Gamepad>,
+    mut timer_query: Query<&mut DemoTimer>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    let had_input = keyboard.get_just_pressed().next().is_some()
+        || mouse.get_just_pressed().next().is_some()
+        || touches.any_just_pressed()
+        || gamepads
+            .iter()
+            .any(|gamepad| gamepad.get_just_pressed().next().is_some());
+
+    let timed_out = timer_query
+        .iter_mut()
+        .any(|mut timer| timer.0.tick(time.delta()).finished());
+
+    if had_input || timed_out {
+        next_screen.set(Screen::Title);
+    }
+}
+
+// Demo constants
+const DEMO_DURATION: f32 = 60.0; // Shorter than a round, so a demo never reaches the results
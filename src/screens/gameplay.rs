@@ -1,24 +1,42 @@
 use bevy::{input::common_conditions::input_just_pressed, prelude::*, ui::Val::*};
 
-use crate::{Pause, menus::Menu, screens::Screen};
+use crate::{
+    Pause,
+    menus::Menu,
+    screens::{GameplayMode, Screen},
+    settings::{CustomKeyBindings, GameSettings, KeyAction},
+    theme::widget,
+};
 
 pub(super) fn plugin(app: &mut App) {
     // Remove the demo level spawn and don't spawn anything special
     // The player, map, and options will be spawned by their respective plugins
 
-    // Toggle pause on key press.
+    app.add_event::<PauseRequested>();
+
+    // On-screen pause button for touch devices
+    app.add_systems(OnEnter(GameplayMode::Interactive), spawn_pause_button);
+
+    // Toggle pause on key press, gamepad start or the pause button.
     app.add_systems(
         Update,
         (
             (pause, spawn_pause_overlay, open_pause_menu).run_if(
-                in_state(Screen::Gameplay)
+                in_state(GameplayMode::Interactive)
                     .and(in_state(Menu::None))
-                    .and(input_just_pressed(KeyCode::KeyP).or(input_just_pressed(KeyCode::Escape))),
+                    .and(
+                        input_just_pressed(KeyCode::KeyP)
+                            .or(input_just_pressed(KeyCode::Escape))
+                            .or(gamepad_start_just_pressed)
+                            .or(player_pause_just_pressed)
+                            .or(on_event::<PauseRequested>),
+                    ),
             ),
             close_menu.run_if(
                 in_state(Screen::Gameplay)
                     .and(not(in_state(Menu::None)))
-                    .and(input_just_pressed(KeyCode::KeyP)),
+                    .and(not(in_state(Menu::KeyBindings)))
+                    .and(input_just_pressed(KeyCode::KeyP).or(gamepad_start_just_pressed)),
             ),
         ),
     );
@@ -29,6 +47,50 @@ pub(super) fn plugin(app: &mut App) {
     );
 }
 
+/// Event to pause the round and open the pause menu, e.g. sent by the
+/// on-screen pause button
+#[derive(Event)]
+pub struct PauseRequested;
+
+fn gamepad_start_just_pressed(gamepads: Query<&Gamepad>) -> bool {
+    gamepads
+        .iter()
+        .any(|gamepad| gamepad.just_pressed(GamepadButton::Start))
+}
+
+/// Pause keys from the keyboard players' (possibly customized) key bindings
+fn player_pause_just_pressed(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    game_settings: Res<GameSettings>,
+    key_bindings: Res<CustomKeyBindings>,
+) -> bool {
+    game_settings
+        .multiplayer
+        .players
+        .iter()
+        .enumerate()
+        .filter_map(|(player_index, player)| key_bindings.bindings_for(player_index, &player.input))
+        .any(|bindings| keyboard.just_pressed(bindings.get(KeyAction::Pause)))
+}
+
+fn spawn_pause_button(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Pause Button"),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Px(20.0),
+            left: Px(20.0),
+            ..default()
+        },
+        StateScoped(Screen::Gameplay),
+        children![widget::button_small("II", request_pause)],
+    ));
+}
+
+fn request_pause(_: Trigger<Pointer<Click>>, mut pause_requests: EventWriter<PauseRequested>) {
+    pause_requests.write(PauseRequested);
+}
+
 fn unpause(mut next_pause: ResMut<NextState<Pause>>) {
     next_pause.set(Pause(false));
 }
@@ -1,10 +1,15 @@
 //! A loading screen during which game assets are loaded if necessary.
 //! This reduces stuttering, especially for audio on Wasm.
+//!
+//! A progress bar follows the resource assets, the level and the challenge,
+//! gameplay tips rotate underneath, and the screen stays up for a moment even
+//! when everything is cached so it doesn't just flicker past. Assets that
+//! failed to load are named so a broken install can be told apart from a slow one.
 
 use crate::game_state::GameState;
 use bevy::prelude::*;
 
-use crate::{screens::Screen, theme::prelude::*};
+use crate::{asset_tracking::ResourceHandles, locale::Locale, screens::Screen, theme::prelude::*};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Screen::Loading), spawn_loading_screen);
@@ -13,28 +18,84 @@ pub(super) fn plugin(app: &mut App) {
         Update,
         (
             update_loading_text,
-            enter_gameplay_screen.run_if(in_state(Screen::Loading).and(all_assets_loaded)),
+            update_loading_progress,
+            rotate_loading_tips,
+            // Gameplay takes over once the loading screen has faded out
+            enter_gameplay_screen.run_if(
+                in_state(Screen::Loading)
+                    .and(all_assets_loaded)
+                    .and(minimum_display_elapsed)
+                    .and(crate::transitions::loading_cover_closed),
+            ),
             handle_loading_timeout,
-        ),
+        )
+            .run_if(in_state(Screen::Loading)),
     );
 }
 
-fn spawn_loading_screen(mut commands: Commands) {
+fn spawn_loading_screen(mut commands: Commands, game_state: Res<GameState>, locale: Res<Locale>) {
     commands.spawn((
         widget::ui_root("Loading Screen"),
         StateScoped(Screen::Loading),
-        LoadingTimeout(Timer::from_seconds(10.0, TimerMode::Once)), // 10 second timeout
+        LoadingTimeout(Timer::from_seconds(LOADING_TIMEOUT, TimerMode::Once)),
+        MinimumDisplay(Timer::from_seconds(
+            LOADING_MIN_DISPLAY_TIME,
+            TimerMode::Once,
+        )),
         children![
-            widget::label("Loading Level A1..."),
+            widget::label(locale.format(
+                "loading.title",
+                &[("level", &level_name(&game_state.current_level_id))]
+            )),
+            (
+                Name::new("Loading Progress Bar"),
+                Node {
+                    width: Val::Px(LOADING_BAR_WIDTH),
+                    height: Val::Px(LOADING_BAR_HEIGHT),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.15)),
+                BorderRadius::all(Val::Px(LOADING_BAR_HEIGHT / 2.0)),
+                children![(
+                    Name::new("Loading Progress Fill"),
+                    Node {
+                        width: Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(1.0, 0.85, 0.2)),
+                    BorderRadius::all(Val::Px(LOADING_BAR_HEIGHT / 2.0)),
+                    LoadingProgressFill,
+                )],
+            ),
             (
                 Name::new("Loading Details"),
-                Text("Preparing challenges...".to_string()),
+                Text(locale.text("loading.preparing").to_string()),
                 TextFont {
                     font_size: 16.0,
                     ..default()
                 },
                 TextColor(Color::srgb(0.7, 0.7, 0.7)),
                 LoadingDetails,
+            ),
+            (
+                Name::new("Loading Tip"),
+                Text(locale.text("loading.tip_1").to_string()),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextLayout::new_with_justify(JustifyText::Center),
+                TextColor(Color::srgb(0.85, 0.85, 0.95)),
+                Node {
+                    max_width: Val::Px(LOADING_BAR_WIDTH * 1.5),
+                    margin: UiRect::top(Val::Px(24.0)),
+                    ..default()
+                },
+                LoadingTip {
+                    index: 0,
+                    timer: Timer::from_seconds(LOADING_TIP_INTERVAL, TimerMode::Repeating),
+                },
             )
         ],
     ));
@@ -46,8 +107,27 @@ struct LoadingDetails;
 #[derive(Component)]
 struct LoadingTimeout(Timer);
 
+/// Keeps the loading screen up long enough to be read
+#[derive(Component)]
+struct MinimumDisplay(Timer);
+
+#[derive(Component)]
+struct LoadingProgressFill;
+
+#[derive(Component)]
+struct LoadingTip {
+    index: usize,
+    timer: Timer,
+}
+
+/// `level-a1` as `A1`
+fn level_name(level_id: &str) -> String {
+    level_id.trim_start_matches("level-").to_uppercase()
+}
+
 fn update_loading_text(
     game_state: Res<GameState>,
+    locale: Res<Locale>,
     mut loading_query: Query<&mut Text, With<LoadingDetails>>,
 ) {
     if !game_state.is_changed() {
@@ -56,35 +136,81 @@ fn update_loading_text(
 
     for mut text in &mut loading_query {
         if game_state.level_loaded && game_state.challenge_loaded {
-            text.0 = "Ready to play!".to_string();
+            text.0 = locale.text("loading.ready").to_string();
         } else if game_state.level_loaded {
-            text.0 = format!(
-                "Loading challenge: {}...",
-                game_state
-                    .current_challenge_id
-                    .as_deref()
-                    .unwrap_or("unknown")
-            );
+            let challenge = game_state
+                .current_challenge_id
+                .as_deref()
+                .unwrap_or(locale.text("loading.unknown"));
+            text.0 = locale.format("loading.challenge", &[("challenge", &challenge)]);
         } else {
-            text.0 = format!("Loading level: {}...", game_state.current_level_id);
+            text.0 = locale.format("loading.level", &[("level", &game_state.current_level_id)]);
+        }
+    }
+}
+
+fn update_loading_progress(
+    game_state: Res<GameState>,
+    handles: Res<ResourceHandles>,
+    mut fill_query: Query<&mut Node, With<LoadingProgressFill>>,
+) {
+    let progress = game_state.loading_progress(&handles);
+    for mut node in &mut fill_query {
+        node.width = Val::Percent(progress * 100.0);
+    }
+}
+
+fn rotate_loading_tips(
+    time: Res<Time>,
+    locale: Res<Locale>,
+    mut tip_query: Query<(&mut Text, &mut LoadingTip)>,
+) {
+    for (mut text, mut tip) in &mut tip_query {
+        if !tip.timer.tick(time.delta()).just_finished() {
+            continue;
         }
+        tip.index = (tip.index + 1) % LOADING_TIP_COUNT;
+        text.0 = locale
+            .text(&format!("loading.tip_{}", tip.index + 1))
+            .to_string();
     }
 }
 
 fn handle_loading_timeout(
     time: Res<Time>,
-    mut timeout_query: Query<&mut LoadingTimeout>,
+    mut timeout_query: Query<(&mut LoadingTimeout, &mut MinimumDisplay)>,
     mut loading_query: Query<&mut Text, With<LoadingDetails>>,
     game_state: Res<GameState>,
+    handles: Res<ResourceHandles>,
+    locale: Res<Locale>,
 ) {
-    for mut timeout in &mut timeout_query {
+    for (mut timeout, mut minimum_display) in &mut timeout_query {
+        minimum_display.0.tick(time.delta());
         timeout.0.tick(time.delta());
 
-        if timeout.0.just_finished() && !game_state.is_ready() {
+        if timeout.0.just_finished() && !(game_state.is_ready() && handles.is_all_done()) {
+            // Name what is missing, failed assets first
+            let mut missing: Vec<String> = handles.failed().to_vec();
+            missing.extend(handles.waiting_paths());
+            if !game_state.level_loaded {
+                missing.push(format!("level {}", game_state.current_level_id));
+            } else if !game_state.challenge_loaded {
+                missing.push(format!(
+                    "challenge {}",
+                    game_state
+                        .current_challenge_id
+                        .as_deref()
+                        .unwrap_or("unknown")
+                ));
+            }
+
             for mut text in &mut loading_query {
-                text.0 = "Failed to load assets. Please check that asset files exist.".to_string();
+                text.0 = locale.format("loading.failed", &[("missing", &missing.join(", "))]);
             }
-            error!("Loading timeout - assets failed to load within 10 seconds");
+            error!(
+                "Loading timeout - assets failed to load within {} seconds: {:?}",
+                LOADING_TIMEOUT, missing
+            );
         }
     }
 }
@@ -93,6 +219,18 @@ fn enter_gameplay_screen(mut next_screen: ResMut<NextState<Screen>>) {
     next_screen.set(Screen::Gameplay);
 }
 
-fn all_assets_loaded(game_state: Res<GameState>) -> bool {
-    game_state.is_ready()
+fn all_assets_loaded(game_state: Res<GameState>, handles: Res<ResourceHandles>) -> bool {
+    game_state.is_ready() && handles.is_all_done()
+}
+
+fn minimum_display_elapsed(display_query: Query<&MinimumDisplay>) -> bool {
+    display_query.iter().all(|display| display.0.finished())
 }
+
+// Loading screen constants
+const LOADING_TIMEOUT: f32 = 10.0; // Seconds before missing assets are reported
+const LOADING_MIN_DISPLAY_TIME: f32 = 1.0;
+const LOADING_TIP_INTERVAL: f32 = 3.0;
+const LOADING_TIP_COUNT: usize = 6;
+const LOADING_BAR_WIDTH: f32 = 320.0;
+const LOADING_BAR_HEIGHT: f32 = 12.0;
@@ -0,0 +1,309 @@
+//! Local multiplayer lobby: every free gamepad, keyboard scheme or the touch
+//! screen joins as the next player with a single press, a second press marks
+//! that player ready, and the round starts once everyone who joined is ready.
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use bevy_egui::{
+    EguiContextPass,
+    egui::{self, Widget},
+};
+use konnektoren_bevy::{
+    input::{
+        InputDeviceAssignment,
+        device::{AvailableInputDevices, InputDevice, KeyboardScheme},
+    },
+    prelude::*,
+};
+
+use crate::{
+    locale::Locale,
+    screens::Screen,
+    settings::{CustomKeyBindings, GameSettings, KeyAction, KeyBindings, MAX_PLAYERS},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<Lobby>();
+
+    app.add_systems(OnEnter(Screen::Lobby), reset_lobby);
+    app.add_systems(
+        Update,
+        (
+            join_lobby,
+            start_when_ready,
+            leave_lobby.run_if(input_just_pressed(KeyCode::Escape)),
+        )
+            .chain()
+            .run_if(in_state(Screen::Lobby)),
+    );
+    app.add_systems(
+        EguiContextPass,
+        lobby_egui_ui.run_if(in_state(Screen::Lobby)),
+    );
+}
+
+/// A player who joined the lobby
+#[derive(Clone, Debug)]
+pub struct LobbySlot {
+    pub device: InputDevice,
+    pub ready: bool,
+}
+
+/// Resource with the players that joined, in join order
+#[derive(Resource, Default, Debug)]
+pub struct Lobby {
+    pub slots: Vec<LobbySlot>,
+}
+
+impl Lobby {
+    /// Join with `device`, or toggle ready if it already joined
+    fn press(&mut self, device: InputDevice) {
+        if let Some(slot) = self.slots.iter_mut().find(|slot| slot.device == device) {
+            slot.ready = !slot.ready;
+            info!(
+                "{} is {}ready",
+                device.name(),
+                if slot.ready { "" } else { "not " }
+            );
+        } else if self.slots.len() < MAX_PLAYERS {
+            info!(
+                "{} joined as player {}",
+                device.name(),
+                self.slots.len() + 1
+            );
+            self.slots.push(LobbySlot {
+                device,
+                ready: false,
+            });
+        }
+    }
+
+    fn all_ready(&self) -> bool {
+        !self.slots.is_empty() && self.slots.iter().all(|slot| slot.ready)
+    }
+}
+
+/// Keyboard schemes that can join the lobby
+fn keyboard_schemes() -> [KeyboardScheme; 3] {
+    [
+        KeyboardScheme::WASD,
+        KeyboardScheme::Arrows,
+        KeyboardScheme::IJKL,
+    ]
+}
+
+/// Movement keys that join with `scheme`: the remapped keys of the player slot
+/// set up for that scheme, or the scheme's default keys
+fn join_keys(
+    scheme: &KeyboardScheme,
+    game_settings: &GameSettings,
+    key_bindings: &CustomKeyBindings,
+) -> [KeyCode; 4] {
+    let device = InputDevice::Keyboard(scheme.clone());
+    let bindings = game_settings
+        .multiplayer
+        .players
+        .iter()
+        .enumerate()
+        .find(|(_, player)| player.input.primary_input == device)
+        .and_then(|(index, player)| key_bindings.bindings_for(index, &player.input))
+        .unwrap_or_else(|| KeyBindings::for_scheme(scheme));
+
+    [
+        KeyAction::Up,
+        KeyAction::Left,
+        KeyAction::Down,
+        KeyAction::Right,
+    ]
+    .map(|action| bindings.get(action))
+}
+
+fn reset_lobby(mut lobby: ResMut<Lobby>) {
+    *lobby = Lobby::default();
+}
+
+fn join_lobby(
+    mut lobby: ResMut<Lobby>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    game_settings: Res<GameSettings>,
+    key_bindings: Res<CustomKeyBindings>,
+    touches: Res<Touches>,
+    available_devices: Res<AvailableInputDevices>,
+    gamepads: Query<&Gamepad>,
+) {
+    for (index, &entity) in available_devices.gamepads.iter().enumerate() {
+        let Ok(gamepad) = gamepads.get(entity) else {
+            continue;
+        };
+        let device = InputDevice::Gamepad(index as u32);
+        if gamepad.just_pressed(GamepadButton::South) {
+            lobby.press(device);
+        } else if gamepad.just_pressed(GamepadButton::East) {
+            lobby.slots.retain(|slot| slot.device != device);
+        }
+    }
+
+    for scheme in keyboard_schemes() {
+        let keys = join_keys(&scheme, &game_settings, &key_bindings);
+        if keyboard.any_just_pressed(keys) {
+            lobby.press(InputDevice::Keyboard(scheme));
+        }
+    }
+
+    if touches.iter_just_pressed().next().is_some() {
+        lobby.press(InputDevice::Touch);
+    }
+}
+
+/// Hand the joined devices to the player slots and start the round
+fn start_when_ready(
+    lobby: Res<Lobby>,
+    mut game_settings: ResMut<GameSettings>,
+    mut key_bindings: ResMut<CustomKeyBindings>,
+    mut assignment: ResMut<InputDeviceAssignment>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    if !lobby.all_ready() {
+        return;
+    }
+
+    // Remapped keys follow their keyboard scheme to whichever slot it joined as
+    let remapped: Vec<(InputDevice, KeyBindings)> = game_settings
+        .multiplayer
+        .players
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| key_bindings.is_custom(*index))
+        .filter_map(|(index, player)| {
+            let bindings = key_bindings.bindings_for(index, &player.input)?;
+            Some((player.input.primary_input.clone(), bindings))
+        })
+        .collect();
+
+    let multiplayer = &mut game_settings.multiplayer;
+    let count = lobby.slots.len();
+    multiplayer.auto_detect_players = false;
+    multiplayer.bot_count = 0;
+    if count == 1 {
+        multiplayer.set_single_player();
+    } else {
+        multiplayer.enabled = true;
+        multiplayer.set_player_count(count);
+    }
+
+    assignment.assignments.clear();
+    key_bindings
+        .players
+        .retain(|index, _| *index >= lobby.slots.len());
+    for (index, slot) in lobby.slots.iter().enumerate() {
+        if let Some((_, bindings)) = remapped.iter().find(|(device, _)| *device == slot.device) {
+            key_bindings.players.insert(index, bindings.clone());
+        }
+    }
+    for (player, slot) in multiplayer.players.iter_mut().zip(&lobby.slots) {
+        player.input.primary_input = slot.device.clone();
+        player.input.secondary_input = None;
+        player.input.allow_multiple_devices = false;
+        assignment.assign_device(player.player_id, slot.device.clone());
+    }
+
+    info!(
+        "Everyone in the lobby is ready, starting with {} players",
+        count
+    );
+    next_screen.set(Screen::Loading);
+}
+
+fn leave_lobby(mut next_screen: ResMut<NextState<Screen>>) {
+    next_screen.set(Screen::Title);
+}
+
+fn lobby_egui_ui(
+    mut contexts: bevy_egui::EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    locale: Res<Locale>,
+    lobby: Res<Lobby>,
+    game_settings: Res<GameSettings>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    let ctx = contexts.ctx_mut();
+
+    egui::CentralPanel::default()
+        .frame(egui::Frame::NONE.fill(theme.base_100))
+        .show(ctx, |ui| {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+            ui.vertical_centered(|ui| {
+                ResponsiveText::new(
+                    locale.text("lobby.title"),
+                    ResponsiveFontSize::Title,
+                    theme.primary,
+                )
+                .responsive(&responsive)
+                .strong()
+                .ui(ui);
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                ui.label(locale.text("lobby.hint"));
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+                ui.horizontal_wrapped(|ui| {
+                    for index in 0..MAX_PLAYERS {
+                        egui::Frame::group(ui.style())
+                            .inner_margin(12.0)
+                            .show(ui, |ui| {
+                                ui.set_width(LOBBY_CARD_WIDTH);
+                                ui.vertical_centered(|ui| {
+                                    lobby_card(ui, &theme, &locale, &game_settings, &lobby, index);
+                                });
+                            });
+                    }
+                });
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+                if ThemedButton::new(locale.text("lobby.back"), &theme)
+                    .responsive(&responsive)
+                    .width(250.0)
+                    .show(ui)
+                    .clicked()
+                {
+                    next_screen.set(Screen::Title);
+                }
+            });
+        });
+}
+
+fn lobby_card(
+    ui: &mut egui::Ui,
+    theme: &KonnektorenTheme,
+    locale: &Locale,
+    game_settings: &GameSettings,
+    lobby: &Lobby,
+    index: usize,
+) {
+    let Some(slot) = lobby.slots.get(index) else {
+        ui.label(egui::RichText::new(format!("Player {}", index + 1)).weak());
+        ui.label(locale.text("lobby.empty_slot"));
+        return;
+    };
+
+    ui.label(
+        egui::RichText::new(game_settings.multiplayer.player_name(index))
+            .strong()
+            .color(theme.primary),
+    );
+    ui.label(slot.device.name());
+    if slot.ready {
+        ui.label(
+            egui::RichText::new(locale.text("lobby.ready"))
+                .strong()
+                .color(egui::Color32::from_rgb(90, 200, 110)),
+        );
+    } else {
+        ui.label(locale.text("lobby.press_to_ready"));
+    }
+}
+
+// Lobby constants
+pub const LOBBY_CARD_WIDTH: f32 = 150.0;
@@ -1,18 +1,25 @@
 //! The game's main screen states and transitions between them.
 
+mod demo;
 mod gameplay;
 mod loading;
+mod lobby;
 mod splash;
 mod title;
 
 use bevy::prelude::*;
 
+pub use gameplay::PauseRequested;
+
 pub(super) fn plugin(app: &mut App) {
     app.init_state::<Screen>();
+    app.add_sub_state::<GameplayMode>();
 
     app.add_plugins((
+        demo::plugin,
         gameplay::plugin,
         loading::plugin,
+        lobby::plugin,
         splash::plugin,
         title::plugin,
     ));
@@ -25,6 +32,18 @@ pub enum Screen {
     #[default]
     Splash,
     Title,
+    /// Players join with their own device and ready up before a local match.
+    Lobby,
     Loading,
     Gameplay,
 }
+
+/// Who is playing while on the gameplay screen.
+#[derive(SubStates, Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[source(Screen = Screen::Gameplay)]
+pub enum GameplayMode {
+    #[default]
+    Interactive,
+    /// Attract mode: bots play a demo match until any input is received.
+    Demo,
+}
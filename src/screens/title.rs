@@ -1,12 +1,36 @@
 //! The title screen that appears after the splash screen.
 
-use bevy::prelude::*;
+use bevy::{input::mouse::MouseMotion, prelude::*};
 
-use crate::{menus::Menu, screens::Screen};
+use crate::{
+    game_state::GameState,
+    menus::Menu,
+    screens::{GameplayMode, Screen},
+};
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_systems(OnEnter(Screen::Title), open_main_menu);
+    app.init_resource::<IdleTimer>();
+
+    app.add_systems(OnEnter(Screen::Title), (open_main_menu, reset_idle_timer));
     app.add_systems(OnExit(Screen::Title), close_menu);
+
+    app.add_systems(
+        Update,
+        start_demo_when_idle.run_if(in_state(Screen::Title).and(in_state(Menu::Main))),
+    );
+}
+
+/// Time without input on the title screen before the attract mode starts
+#[derive(Resource, Debug)]
+pub struct IdleTimer(pub Timer);
+
+impl Default for IdleTimer {
+    fn default() -> Self {
+        Self(Timer::from_seconds(
+            ATTRACT_MODE_IDLE_SECONDS,
+            TimerMode::Once,
+        ))
+    }
 }
 
 fn open_main_menu(mut next_menu: ResMut<NextState<Menu>>) {
@@ -16,3 +40,43 @@ fn open_main_menu(mut next_menu: ResMut<NextState<Menu>>) {
 fn close_menu(mut next_menu: ResMut<NextState<Menu>>) {
     next_menu.set(Menu::None);
 }
+
+fn reset_idle_timer(mut idle_timer: ResMut<IdleTimer>) {
+    idle_timer.0.reset();
+}
+
+fn start_demo_when_idle(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    touches: Res<Touches>,
+    gamepads: Query<&Gamepad>,
+    mut idle_timer: ResMut<IdleTimer>,
+    mut next_screen: ResMut<NextState<Screen>>,
+    mut next_mode: ResMut<NextState<GameplayMode>>,
+) {
+    let had_input = keyboard.get_pressed().next().is_some()
+        || mouse.get_pressed().next().is_some()
+        || mouse_motion.read().count() > 0
+        || touches.iter().next().is_some()
+        || gamepads
+            .iter()
+            .any(|gamepad| gamepad.get_pressed().next().is_some());
+
+    // The demo skips the loading screen, so it has to wait for the assets
+    if had_input || !game_state.is_ready() {
+        idle_timer.0.reset();
+        return;
+    }
+
+    if idle_timer.0.tick(time.delta()).just_finished() {
+        info!("No input on the title screen, starting the attract mode");
+        next_screen.set(Screen::Gameplay);
+        next_mode.set(GameplayMode::Demo);
+    }
+}
+
+// Attract mode constants
+pub const ATTRACT_MODE_IDLE_SECONDS: f32 = 30.0;
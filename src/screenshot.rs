@@ -0,0 +1,292 @@
+//! Photo mode: F12 or the camera button in the HUD puts a share card with the
+//! score, the challenge and a snapshot of the chain on screen, captures the
+//! frame and saves it. Native builds write a PNG to the pictures folder, web
+//! builds hand it to the browser as a download, so learners can share their
+//! results from konnektoren.help.
+
+use bevy::{
+    input::common_conditions::input_just_pressed,
+    prelude::*,
+    render::view::screenshot::{Screenshot, ScreenshotCaptured, save_to_disk},
+};
+
+use crate::{
+    chain::{ChainSegment, PlayerChain},
+    gameplay::GameplayScore,
+    locale::Locale,
+    notifications::NotificationEvent,
+    question::QuestionSystem,
+    screens::Screen,
+    theme::prelude::*,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_event::<ScreenshotRequested>();
+
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_screenshot_button);
+    app.add_systems(
+        Update,
+        (
+            request_screenshot.run_if(input_just_pressed(SCREENSHOT_KEY)),
+            show_share_card.run_if(on_event::<ScreenshotRequested>),
+            capture_share_card.run_if(resource_exists::<PendingScreenshot>),
+        )
+            .chain()
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// Event to capture the screen with a share card on top
+#[derive(Event)]
+pub struct ScreenshotRequested;
+
+/// Frames left until the share card has been laid out and drawn
+#[derive(Resource, Debug)]
+struct PendingScreenshot(u32);
+
+/// The card with the round's results, only on screen while capturing
+#[derive(Component, Debug)]
+struct ShareCard;
+
+#[derive(Component, Debug)]
+struct ScreenshotButton;
+
+fn spawn_screenshot_button(mut commands: Commands) {
+    commands
+        .spawn((
+            Name::new("Screenshot Button"),
+            Button,
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(10.0),
+                right: Val::Px(10.0),
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                ..default()
+            },
+            BorderRadius::all(Val::Px(6.0)),
+            BackgroundColor(ui_palette::BUTTON_BACKGROUND),
+            InteractionPalette {
+                none: ui_palette::BUTTON_BACKGROUND,
+                hovered: ui_palette::BUTTON_HOVERED_BACKGROUND,
+                pressed: ui_palette::BUTTON_PRESSED_BACKGROUND,
+            },
+            ScreenshotButton,
+            StateScoped(Screen::Gameplay),
+            children![(
+                Name::new("Screenshot Button Label"),
+                Text("Photo (F12)".to_string()),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(ui_palette::BUTTON_TEXT),
+                Pickable::IGNORE,
+            )],
+        ))
+        .observe(
+            |_: Trigger<Pointer<Click>>, mut requests: EventWriter<ScreenshotRequested>| {
+                requests.write(ScreenshotRequested);
+            },
+        );
+}
+
+fn request_screenshot(mut requests: EventWriter<ScreenshotRequested>) {
+    requests.write(ScreenshotRequested);
+}
+
+fn show_share_card(
+    mut commands: Commands,
+    mut requests: EventReader<ScreenshotRequested>,
+    pending: Option<Res<PendingScreenshot>>,
+    gameplay_score: Res<GameplayScore>,
+    question_system: Option<Res<QuestionSystem>>,
+    locale: Res<Locale>,
+    chain_query: Query<&PlayerChain>,
+    segment_query: Query<&ChainSegment>,
+    mut button_query: Query<&mut Visibility, With<ScreenshotButton>>,
+) {
+    requests.clear();
+    if pending.is_some() {
+        return;
+    }
+
+    let best = gameplay_score
+        .players
+        .values()
+        .max_by_key(|score| score.total_score);
+    let score_line = best.map_or_else(String::new, |score| {
+        locale.format(
+            "screenshot.score",
+            &[("name", &score.player_name), ("score", &score.total_score)],
+        )
+    });
+    let challenge = question_system
+        .as_ref()
+        .map_or_else(String::new, |question_system| {
+            question_system.category.clone()
+        });
+
+    // Snapshot of the longest chain, head first
+    let segments: Vec<&ChainSegment> = chain_query
+        .iter()
+        .max_by_key(|chain| chain.segments.len())
+        .map(|chain| {
+            chain
+                .segments
+                .iter()
+                .filter_map(|&segment| segment_query.get(segment).ok())
+                .take(SHARE_CARD_MAX_SEGMENTS)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let card = commands
+        .spawn((
+            Name::new("Share Card"),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(50.0),
+                bottom: Val::Px(40.0),
+                width: Val::Px(SHARE_CARD_WIDTH),
+                margin: UiRect::left(Val::Px(-SHARE_CARD_WIDTH / 2.0)),
+                padding: UiRect::all(Val::Px(16.0)),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 0.9)),
+            BorderColor(Color::srgb(1.0, 0.85, 0.2)),
+            BorderRadius::all(Val::Px(12.0)),
+            GlobalZIndex(10),
+            Pickable::IGNORE,
+            ShareCard,
+            StateScoped(Screen::Gameplay),
+            children![
+                (
+                    Name::new("Share Card Challenge"),
+                    Text(challenge),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                ),
+                (
+                    Name::new("Share Card Score"),
+                    Text(score_line),
+                    TextFont {
+                        font_size: 28.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ),
+                (
+                    Name::new("Share Card Footer"),
+                    Text("konnektoren.help".to_string()),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(1.0, 0.85, 0.2)),
+                ),
+            ],
+        ))
+        .id();
+
+    let chain_row = commands
+        .spawn((
+            Name::new("Share Card Chain"),
+            Node {
+                flex_wrap: FlexWrap::Wrap,
+                justify_content: JustifyContent::Center,
+                column_gap: Val::Px(4.0),
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+        ))
+        .id();
+    for segment in segments {
+        let chip = commands
+            .spawn((
+                Name::new("Share Card Segment"),
+                Node {
+                    padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(segment.base_color),
+                BorderRadius::all(Val::Px(8.0)),
+                children![(
+                    Text(segment.option_text.clone()),
+                    TextFont {
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(contrasting_text_color(segment.base_color)),
+                )],
+            ))
+            .id();
+        commands.entity(chain_row).add_child(chip);
+    }
+    commands.entity(card).insert_children(2, &[chain_row]);
+
+    for mut visibility in &mut button_query {
+        *visibility = Visibility::Hidden;
+    }
+    commands.insert_resource(PendingScreenshot(SHARE_CARD_CAPTURE_DELAY_FRAMES));
+}
+
+fn capture_share_card(mut commands: Commands, mut pending: ResMut<PendingScreenshot>) {
+    if pending.0 > 0 {
+        pending.0 -= 1;
+        return;
+    }
+
+    commands.remove_resource::<PendingScreenshot>();
+    let path = screenshot_path();
+    info!("Taking a screenshot: {}", path);
+    commands
+        .spawn(Screenshot::primary_window())
+        .observe(save_to_disk(path))
+        .observe(finish_share_card);
+}
+
+/// Take the card down again once the frame is captured
+fn finish_share_card(
+    _: Trigger<ScreenshotCaptured>,
+    mut commands: Commands,
+    locale: Res<Locale>,
+    card_query: Query<Entity, With<ShareCard>>,
+    mut button_query: Query<&mut Visibility, With<ScreenshotButton>>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    for entity in &card_query {
+        commands.entity(entity).despawn();
+    }
+    for mut visibility in &mut button_query {
+        *visibility = Visibility::Inherited;
+    }
+    notifications.write(NotificationEvent::new(locale.text("screenshot.saved")));
+}
+
+/// File name with the time of capture. On the web only the name matters, the
+/// browser downloads it.
+fn screenshot_path() -> String {
+    let file_name = format!(
+        "konnektoren-{}.png",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    );
+
+    #[cfg(all(not(target_family = "wasm"), feature = "persistence"))]
+    if let Some(pictures) = dirs::picture_dir() {
+        return pictures.join(file_name).to_string_lossy().into_owned();
+    }
+
+    file_name
+}
+
+// Screenshot constants
+const SCREENSHOT_KEY: KeyCode = KeyCode::F12;
+pub const SHARE_CARD_WIDTH: f32 = 420.0;
+pub const SHARE_CARD_MAX_SEGMENTS: usize = 24;
+pub const SHARE_CARD_CAPTURE_DELAY_FRAMES: u32 = 2; // Let the card be laid out and drawn first
@@ -0,0 +1,101 @@
+//! Healthy-play helpers: an optional real-time clock during gameplay and a
+//! reminder of how long the current session has been going.
+
+use bevy::prelude::*;
+
+use crate::{notifications::NotificationEvent, screens::Screen, settings::GameSettings};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<SessionReminder>();
+    app.init_resource::<SessionReminder>();
+
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_clock);
+    app.add_systems(
+        Update,
+        (
+            update_clock.run_if(in_state(Screen::Gameplay)),
+            remind_session_length,
+        ),
+    );
+}
+
+/// Resource tracking when the next session reminder is due
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+pub struct SessionReminder {
+    /// Minutes of play already announced
+    pub announced_minutes: u32,
+}
+
+/// Component for the clock text in the gameplay HUD
+#[derive(Component)]
+struct ClockDisplay;
+
+fn spawn_clock(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Clock"),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(20.0),
+            right: Val::Px(20.0),
+            padding: UiRect::axes(Val::Px(10.0), Val::Px(5.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        BorderRadius::all(Val::Px(6.0)),
+        Text::default(),
+        TextFont {
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Visibility::Hidden,
+        Pickable::IGNORE,
+        ClockDisplay,
+        StateScoped(Screen::Gameplay),
+    ));
+}
+
+fn update_clock(
+    game_settings: Res<GameSettings>,
+    mut clock_query: Query<(&mut Text, &mut Visibility), With<ClockDisplay>>,
+) {
+    let show_clock = game_settings.display.show_clock;
+    let now = chrono::Local::now().format("%H:%M").to_string();
+
+    for (mut text, mut visibility) in &mut clock_query {
+        let target_visibility = if show_clock {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        visibility.set_if_neq(target_visibility);
+
+        if show_clock && text.0 != now {
+            text.0 = now.clone();
+        }
+    }
+}
+
+/// Show a toast every `session_reminder_minutes` of real time
+fn remind_session_length(
+    time: Res<Time<Real>>,
+    game_settings: Res<GameSettings>,
+    mut reminder: ResMut<SessionReminder>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    let interval = game_settings.display.session_reminder_minutes;
+    if interval == 0 {
+        return;
+    }
+
+    let played_minutes = (time.elapsed_secs() / 60.0) as u32;
+    let next_reminder = (reminder.announced_minutes / interval + 1) * interval;
+
+    if played_minutes >= next_reminder {
+        reminder.announced_minutes = played_minutes;
+        notifications.write(NotificationEvent::new(format!(
+            "You've been playing {played_minutes} minutes. Time for a short break?"
+        )));
+    }
+}
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use konnektoren_bevy::input::device::{InputDevice, KeyboardScheme};
+use std::collections::BTreeMap;
 
 /// Main game settings resource
 #[derive(Resource, Reflect, Clone, Debug, Default)]
@@ -8,6 +9,8 @@ pub struct GameSettings {
     pub multiplayer: MultiplayerSettings,
     pub audio: AudioSettings,
     pub display: DisplaySettings,
+    pub gameplay: GameplaySettings,
+    pub accessibility: AccessibilitySettings,
 }
 
 /// Multiplayer configuration
@@ -18,6 +21,24 @@ pub struct MultiplayerSettings {
     pub auto_detect_players: bool,
     pub auto_assign_inputs: bool,
     pub players: Vec<PlayerSettings>,
+    /// Running into another player's chain tail steals segments from it, off
+    /// unless picked in the settings
+    pub chain_steal_enabled: bool,
+    /// Number of tail segments taken per steal
+    pub chain_steal_segments: usize,
+    /// Seconds both players are protected from further steals afterwards
+    pub chain_steal_invulnerability: f32,
+    /// Number of player slots, counted from the last one, played by bots
+    pub bot_count: usize,
+    pub bot_difficulty: Difficulty,
+    /// Co-op: all players feed one team chain instead of their own
+    pub coop_shared_chain: bool,
+    /// Seconds without input before a player counts as idle, 0 disables it
+    pub idle_timeout_seconds: u32,
+    /// A bot plays for idle players until they are back
+    pub idle_bot_takeover: bool,
+    /// Rounds of a best-of match, 1 plays a single regular round
+    pub match_rounds: u32,
 }
 
 impl Default for MultiplayerSettings {
@@ -28,6 +49,15 @@ impl Default for MultiplayerSettings {
             auto_detect_players: false,
             auto_assign_inputs: false,
             players: vec![PlayerSettings::default()],
+            chain_steal_enabled: false,
+            chain_steal_segments: 3,
+            chain_steal_invulnerability: 2.0,
+            bot_count: 0,
+            bot_difficulty: Difficulty::Normal,
+            coop_shared_chain: false,
+            idle_timeout_seconds: crate::idle::DEFAULT_IDLE_TIMEOUT_SECONDS,
+            idle_bot_takeover: false,
+            match_rounds: crate::match_play::DEFAULT_MATCH_ROUNDS,
         };
         settings.setup_default_player_configs();
         settings
@@ -35,11 +65,49 @@ impl Default for MultiplayerSettings {
 }
 
 impl MultiplayerSettings {
+    /// Name of the player in slot `player_index`, or a numbered default
+    pub fn player_name(&self, player_index: usize) -> String {
+        self.players
+            .get(player_index)
+            .map(|player| player.name.trim())
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("Player {}", player_index + 1))
+    }
+
     pub fn set_player_count(&mut self, count: usize) {
         let count = count.clamp(1, super::MAX_PLAYERS);
         self.player_count = count;
+
+        // Players who stay keep the name, color and shape they picked
+        let customized: Vec<(String, Color, PlayerShape)> = self
+            .players
+            .iter()
+            .take(count)
+            .map(|player| (player.name.clone(), player.color, player.shape))
+            .collect();
+
         self.players.resize_with(count, PlayerSettings::default);
         self.setup_default_player_configs();
+
+        let kept = customized.len();
+        for (player, (name, color, shape)) in self.players.iter_mut().zip(customized) {
+            player.name = name;
+            player.color = color;
+            player.shape = shape;
+        }
+
+        // New players don't get a color someone already picked
+        for index in kept..count {
+            if self.color_taken(self.players[index].color, index) {
+                if let Some(&free) = super::PLAYER_COLORS
+                    .iter()
+                    .find(|&&color| !self.color_taken(color, index))
+                {
+                    self.players[index].color = free;
+                }
+            }
+        }
     }
 
     pub fn enable_multiplayer(&mut self, enabled: bool) {
@@ -51,6 +119,29 @@ impl MultiplayerSettings {
         }
     }
 
+    /// Switch to single player while keeping the first player's configuration
+    pub fn set_single_player(&mut self) {
+        self.enabled = false;
+        self.player_count = 1;
+        if self.players.is_empty() {
+            self.players.push(PlayerSettings::default());
+        }
+        self.players.truncate(1);
+        self.players[0].enabled = true;
+    }
+
+    /// Several players compete for the best score rather than sharing a team chain
+    pub fn is_competitive(&self) -> bool {
+        self.enabled && self.player_count > 1 && !self.coop_shared_chain
+    }
+
+    /// Whether the player slot at `player_index` is filled by a bot.
+    /// At least one slot always stays with a human player.
+    pub fn is_bot(&self, player_index: usize) -> bool {
+        let bots = self.bot_count.min(self.player_count.saturating_sub(1));
+        player_index >= self.player_count - bots
+    }
+
     fn setup_default_player_configs(&mut self) {
         for (i, player) in self.players.iter_mut().enumerate() {
             player.player_id = i as u32;
@@ -62,13 +153,24 @@ impl MultiplayerSettings {
     }
 
     fn default_player_color(index: usize) -> Color {
-        let colors = [
-            Color::srgb(1.0, 0.8, 0.2), // Yellow
-            Color::srgb(0.2, 0.8, 1.0), // Blue
-            Color::srgb(1.0, 0.2, 0.4), // Red
-            Color::srgb(0.2, 1.0, 0.4), // Green
-        ];
-        colors[index % colors.len()]
+        super::PLAYER_COLORS[index % super::MAX_PLAYERS]
+    }
+
+    /// Another enabled player already plays in `color`
+    pub fn color_taken(&self, color: Color, except_index: usize) -> bool {
+        self.players
+            .iter()
+            .enumerate()
+            .take(self.player_count)
+            .any(|(index, player)| index != except_index && player.color == color)
+    }
+
+    /// Handicap of the player in slot `index`
+    pub fn handicap(&self, index: usize) -> Handicap {
+        self.players
+            .get(index)
+            .map(|player| player.handicap)
+            .unwrap_or_default()
     }
 }
 
@@ -78,8 +180,12 @@ pub struct PlayerSettings {
     pub player_id: u32,
     pub name: String,
     pub color: Color,
+    /// Shape of the player's avatar
+    pub shape: PlayerShape,
     pub input: InputSettings,
     pub enabled: bool,
+    /// Evens out mixed-skill play, e.g. a child against an adult
+    pub handicap: Handicap,
 }
 
 impl Default for PlayerSettings {
@@ -88,8 +194,80 @@ impl Default for PlayerSettings {
             player_id: 0,
             name: "Player 1".to_string(),
             color: Color::srgb(1.0, 0.8, 0.2),
+            shape: PlayerShape::Circle,
             input: InputSettings::default(),
             enabled: true,
+            handicap: Handicap::default(),
+        }
+    }
+}
+
+/// Advantages given to a single player, all 1.0 for an even game
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub struct Handicap {
+    /// Scales the points the player earns
+    pub score_multiplier: f32,
+    /// Options near the player expire this many times slower
+    pub option_expiry_multiplier: f32,
+    /// Stretches the time between merges that keeps a combo going
+    pub merge_window_multiplier: f32,
+}
+
+impl Default for Handicap {
+    fn default() -> Self {
+        Self {
+            score_multiplier: 1.0,
+            option_expiry_multiplier: 1.0,
+            merge_window_multiplier: 1.0,
+        }
+    }
+}
+
+impl Handicap {
+    /// Points after the score multiplier, only gains are scaled
+    pub fn scale_points(&self, points: i32) -> i32 {
+        if points > 0 {
+            (points as f32 * self.score_multiplier).round() as i32
+        } else {
+            points
+        }
+    }
+}
+
+/// Avatar shapes players can pick from
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PlayerShape {
+    #[default]
+    Circle,
+    Square,
+    Triangle,
+    Diamond,
+}
+
+impl PlayerShape {
+    pub const ALL: [PlayerShape; 4] = [
+        PlayerShape::Circle,
+        PlayerShape::Square,
+        PlayerShape::Triangle,
+        PlayerShape::Diamond,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PlayerShape::Circle => "Circle",
+            PlayerShape::Square => "Square",
+            PlayerShape::Triangle => "Triangle",
+            PlayerShape::Diamond => "Diamond",
+        }
+    }
+
+    /// Mesh of the avatar body fitting a circle of `radius`
+    pub fn mesh(&self, radius: f32) -> Mesh {
+        match self {
+            PlayerShape::Circle => Circle::new(radius).into(),
+            PlayerShape::Square => Rectangle::from_length(radius * 1.6).into(),
+            PlayerShape::Triangle => RegularPolygon::new(radius * 1.15, 3).into(),
+            PlayerShape::Diamond => Rhombus::new(radius * 2.0, radius * 2.0).into(),
         }
     }
 }
@@ -100,6 +278,7 @@ pub struct InputSettings {
     pub primary_input: InputDevice,
     pub secondary_input: Option<InputDevice>,
     pub allow_multiple_devices: bool,
+    pub touch_style: TouchControlStyle,
 }
 
 impl Default for InputSettings {
@@ -108,6 +287,7 @@ impl Default for InputSettings {
             primary_input: InputDevice::Keyboard(KeyboardScheme::WASD),
             secondary_input: None,
             allow_multiple_devices: true,
+            touch_style: TouchControlStyle::Joystick,
         }
     }
 }
@@ -119,25 +299,79 @@ impl InputSettings {
                 primary_input: InputDevice::Keyboard(KeyboardScheme::WASD),
                 secondary_input: Some(InputDevice::Mouse),
                 allow_multiple_devices: true,
+                touch_style: TouchControlStyle::Joystick,
             },
             1 => Self {
                 primary_input: InputDevice::Keyboard(KeyboardScheme::Arrows),
                 secondary_input: None,
                 allow_multiple_devices: false,
+                touch_style: TouchControlStyle::Joystick,
             },
             2 => Self {
                 primary_input: InputDevice::Gamepad(0),
                 secondary_input: None,
                 allow_multiple_devices: false,
+                touch_style: TouchControlStyle::Joystick,
             },
             3 => Self {
                 primary_input: InputDevice::Gamepad(1),
                 secondary_input: None,
                 allow_multiple_devices: false,
+                touch_style: TouchControlStyle::Joystick,
             },
             _ => Self::default(),
         }
     }
+
+    /// Whether the player steers with swipe gestures instead of the virtual joystick
+    pub fn uses_swipe(&self) -> bool {
+        matches!(self.primary_input, InputDevice::Touch)
+            && self.touch_style == TouchControlStyle::Swipe
+    }
+
+    /// Whether the player steers with an on-screen joystick, in either layout
+    pub fn uses_joystick(&self) -> bool {
+        matches!(self.primary_input, InputDevice::Touch)
+            && self.touch_style != TouchControlStyle::Swipe
+    }
+
+    /// Whether the player uses the two-thumb layout of joystick and dash button
+    pub fn uses_two_thumb(&self) -> bool {
+        matches!(self.primary_input, InputDevice::Touch)
+            && self.touch_style == TouchControlStyle::TwoThumb
+    }
+
+    /// Whether the game reads this player's touches itself instead of the input mapping
+    pub fn uses_game_touch_controls(&self) -> bool {
+        self.uses_swipe() || self.uses_two_thumb()
+    }
+}
+
+/// How a touch player steers
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum TouchControlStyle {
+    #[default]
+    Joystick,
+    /// Swipe to set a direction that is kept until the next swipe; double-tap to dash
+    Swipe,
+    /// Joystick bottom-left for the left thumb, dash button bottom-right for the right
+    TwoThumb,
+}
+
+impl TouchControlStyle {
+    pub const ALL: [TouchControlStyle; 3] = [
+        TouchControlStyle::Joystick,
+        TouchControlStyle::Swipe,
+        TouchControlStyle::TwoThumb,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TouchControlStyle::Joystick => "Joystick",
+            TouchControlStyle::Swipe => "Swipe",
+            TouchControlStyle::TwoThumb => "Two Thumbs",
+        }
+    }
 }
 
 /// Audio settings
@@ -146,6 +380,9 @@ pub struct AudioSettings {
     pub master_volume: f32,
     pub music_volume: f32,
     pub sfx_volume: f32,
+    /// Say correct words out loud when they are collected
+    pub pronunciation: bool,
+    pub pronunciation_volume: f32,
 }
 
 impl Default for AudioSettings {
@@ -154,6 +391,8 @@ impl Default for AudioSettings {
             master_volume: 1.0,
             music_volume: 0.8,
             sfx_volume: 1.0,
+            pronunciation: true,
+            pronunciation_volume: 1.0,
         }
     }
 }
@@ -161,19 +400,599 @@ impl Default for AudioSettings {
 /// Display settings
 #[derive(Reflect, Clone, Debug)]
 pub struct DisplaySettings {
+    /// Windowed, borderless or exclusive fullscreen
+    pub window_mode: crate::display::DisplayMode,
+    /// Index into `display::RESOLUTIONS`, used for the windowed size on native
+    pub resolution_index: usize,
     pub vsync: bool,
     pub show_fps: bool,
+    /// Show the real-time clock during gameplay
+    pub show_clock: bool,
+    /// Remind players how long they have been playing, 0 disables it
+    pub session_reminder_minutes: u32,
+    /// Brief slow-motion on chain reactions and big merges
+    pub slow_motion: bool,
+    /// Camera shake on explosions
+    pub screen_shake: bool,
+    /// Colors used for options and chain segments
+    pub color_palette: crate::palette::ColorPalette,
+    /// Give every option its own shape in addition to its color
+    pub shape_markers: bool,
+    /// Language of the menus and HUD
+    pub language: crate::locale::Language,
+    /// Size of the gameplay HUD in percent
+    pub hud_scale_percent: u32,
+    /// Size of all UI, menus included, in percent
+    pub ui_scale_percent: u32,
 }
 
 impl Default for DisplaySettings {
     fn default() -> Self {
         Self {
+            window_mode: crate::display::DisplayMode::Windowed,
+            resolution_index: 0,
             vsync: true,
             show_fps: false,
+            show_clock: false,
+            session_reminder_minutes: 30,
+            slow_motion: true,
+            screen_shake: true,
+            color_palette: crate::palette::ColorPalette::Default,
+            shape_markers: false,
+            language: crate::locale::Language::English,
+            hud_scale_percent: crate::gameplay::DEFAULT_HUD_SCALE_PERCENT,
+            ui_scale_percent: crate::display::DEFAULT_UI_SCALE_PERCENT,
+        }
+    }
+}
+
+/// Accessibility settings
+#[derive(Reflect, Clone, Debug, Default)]
+pub struct AccessibilitySettings {
+    /// Read each new question out loud
+    pub read_questions: bool,
+    /// Read collected options out loud
+    pub announce_collections: bool,
+    /// Show on-screen captions for game events
+    pub captions: bool,
+    /// How much animation and particles the game shows
+    pub effects_intensity: EffectsIntensity,
+}
+
+/// How much decorative motion the game shows
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EffectsIntensity {
+    #[default]
+    Full,
+    /// Gentler pulsing and floating, fewer particles
+    Reduced,
+    /// No floating, pulsing, sparkles or screen shake
+    Minimal,
+}
+
+impl EffectsIntensity {
+    pub const ALL: [EffectsIntensity; 3] = [
+        EffectsIntensity::Full,
+        EffectsIntensity::Reduced,
+        EffectsIntensity::Minimal,
+    ];
+
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index.min(Self::ALL.len() - 1)]
+    }
+
+    pub fn index(&self) -> usize {
+        Self::ALL.iter().position(|i| i == self).unwrap_or(0)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            EffectsIntensity::Full => "Full",
+            EffectsIntensity::Reduced => "Reduced",
+            EffectsIntensity::Minimal => "Minimal",
+        }
+    }
+
+    /// Factor for the amplitude of pulsing, floating and other idle animations
+    pub fn motion_scale(&self) -> f32 {
+        match self {
+            EffectsIntensity::Full => 1.0,
+            EffectsIntensity::Reduced => 0.4,
+            EffectsIntensity::Minimal => 0.0,
+        }
+    }
+
+    /// Factor for the number of decorative particles
+    pub fn particle_scale(&self) -> f32 {
+        match self {
+            EffectsIntensity::Full => 1.0,
+            EffectsIntensity::Reduced => 0.5,
+            EffectsIntensity::Minimal => 0.0,
+        }
+    }
+
+    /// Scale a particle count, keeping at least one unless particles are off
+    pub fn particle_count(&self, count: usize) -> usize {
+        (count as f32 * self.particle_scale()).ceil() as usize
+    }
+
+    pub fn allows_screen_shake(&self) -> bool {
+        *self != EffectsIntensity::Minimal
+    }
+}
+
+/// Gameplay tuning settings
+#[derive(Reflect, Clone, Debug, PartialEq)]
+pub struct GameplaySettings {
+    pub difficulty: Difficulty,
+    pub map_preset: crate::map::MapPreset,
+    pub map_theme: crate::map::MapTheme,
+    /// Wrong answers attach a cursed segment that blocks merges until a
+    /// streak of correct answers burns it off
+    pub cursed_segments: bool,
+    /// Respawn players at a safe spot after a chain reaction instead of
+    /// letting them carry on from where they crashed
+    pub respawn_after_chain_reaction: bool,
+    /// Error blobs spawn during the round and chase the players
+    pub hazards: bool,
+    /// Wrong collections hold the player for a moment and show the question,
+    /// the correct connector and the help text
+    pub learning_feedback: bool,
+    /// Whether players move at full speed right away or build up momentum
+    pub movement_model: crate::player::MovementModel,
+    /// Values used when the difficulty is set to `Custom`
+    pub custom_tuning: DifficultyTuning,
+    /// Maximum number of segments in a chain
+    pub max_chain_length: usize,
+    /// What happens when a chain grows past `max_chain_length`
+    pub chain_overflow: crate::chain::ChainOverflowRule,
+    /// How far chain reactions spread from the hit segment
+    pub reaction_spread: crate::chain::ReactionSpreadRule,
+    /// Segments either side of the hit that react under the limited rule
+    pub reaction_spread_limit: usize,
+    /// Keep a correct option within this many cells of every player, 0 disables it
+    pub correct_option_radius: usize,
+    /// How strongly the difficulty director adapts to the players, in percent, 0 disables it
+    pub director_intensity: u32,
+}
+
+impl Default for GameplaySettings {
+    fn default() -> Self {
+        Self {
+            difficulty: Difficulty::default(),
+            map_preset: crate::map::MapPreset::default(),
+            map_theme: crate::map::MapTheme::default(),
+            cursed_segments: false,
+            respawn_after_chain_reaction: false,
+            hazards: false,
+            learning_feedback: false,
+            movement_model: crate::player::MovementModel::default(),
+            custom_tuning: DifficultyTuning::default(),
+            max_chain_length: crate::chain::DEFAULT_MAX_CHAIN_LENGTH,
+            chain_overflow: crate::chain::ChainOverflowRule::default(),
+            reaction_spread: crate::chain::ReactionSpreadRule::default(),
+            reaction_spread_limit: crate::chain::DEFAULT_REACTION_SPREAD_LIMIT,
+            correct_option_radius: crate::options::DEFAULT_CORRECT_OPTION_RADIUS,
+            director_intensity: crate::director::DEFAULT_DIRECTOR_INTENSITY,
         }
     }
 }
 
+impl GameplaySettings {
+    /// Tuning values for the selected difficulty
+    pub fn tuning(&self) -> DifficultyTuning {
+        match self.difficulty {
+            Difficulty::Custom => self.custom_tuning,
+            difficulty => difficulty.tuning(),
+        }
+    }
+}
+
+/// Runtime values the difficulty feeds into the question, options and player modules
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub struct DifficultyTuning {
+    pub question_duration: f32,
+    pub option_lifetime: f32,
+    pub option_spawn_interval: f32,
+    pub player_move_speed: f32,
+    /// Seconds between hazard spawns at the start of a round
+    pub hazard_spawn_interval: f32,
+    /// Share of spawned options that drift, orbit or flee instead of sitting still
+    pub moving_option_share: f32,
+}
+
+impl Default for DifficultyTuning {
+    fn default() -> Self {
+        Self {
+            question_duration: crate::question::QUESTION_DURATION,
+            option_lifetime: crate::options::OPTION_LIFETIME,
+            option_spawn_interval: crate::options::OPTION_SPAWN_INTERVAL,
+            player_move_speed: crate::player::PLAYER_MOVE_SPEED,
+            hazard_spawn_interval: crate::hazards::HAZARD_SPAWN_INTERVAL,
+            moving_option_share: crate::options::MOVING_OPTION_SHARE,
+        }
+    }
+}
+
+/// Difficulty levels that tune how the game plays
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Custom,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 4] = [
+        Difficulty::Easy,
+        Difficulty::Normal,
+        Difficulty::Hard,
+        Difficulty::Custom,
+    ];
+
+    /// The fixed difficulties, without `Custom`
+    pub const PRESETS: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index.min(Self::ALL.len() - 1)]
+    }
+
+    pub fn index(&self) -> usize {
+        Self::ALL.iter().position(|d| d == self).unwrap_or(1)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+            Difficulty::Custom => "Custom",
+        }
+    }
+
+    /// Preset tuning values. `Custom` falls back to the normal values; use
+    /// `GameplaySettings::tuning` to get the player's own.
+    pub fn tuning(&self) -> DifficultyTuning {
+        let normal = DifficultyTuning::default();
+        match self {
+            Difficulty::Easy => DifficultyTuning {
+                question_duration: normal.question_duration * 1.4,
+                option_lifetime: normal.option_lifetime * 1.4,
+                option_spawn_interval: normal.option_spawn_interval * 1.2,
+                player_move_speed: normal.player_move_speed * 0.85,
+                hazard_spawn_interval: normal.hazard_spawn_interval * 1.5,
+                moving_option_share: 0.0,
+            },
+            Difficulty::Normal | Difficulty::Custom => normal,
+            Difficulty::Hard => DifficultyTuning {
+                question_duration: normal.question_duration * 0.7,
+                option_lifetime: normal.option_lifetime * 0.75,
+                option_spawn_interval: normal.option_spawn_interval * 0.8,
+                player_move_speed: normal.player_move_speed * 1.2,
+                hazard_spawn_interval: normal.hazard_spawn_interval * 0.6,
+                moving_option_share: normal.moving_option_share * 2.0,
+            },
+        }
+    }
+
+    /// Share of spawned options that should be the correct answer.
+    /// Normal keeps every option type equally represented, Easy favours the
+    /// correct answer (1:1) and Hard floods the map with wrong ones (1:4).
+    pub fn correct_option_share(&self, option_types: usize) -> f32 {
+        let equal_share = 1.0 / option_types.max(1) as f32;
+        match self {
+            Difficulty::Easy => equal_share.max(0.5),
+            Difficulty::Normal | Difficulty::Custom => equal_share,
+            Difficulty::Hard => equal_share.min(0.2),
+        }
+    }
+
+    /// Seconds a bot waits before picking a new target
+    pub fn bot_reaction_delay(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.8,
+            Difficulty::Normal | Difficulty::Custom => 0.4,
+            Difficulty::Hard => 0.15,
+        }
+    }
+
+    /// Chance that a bot goes for a wrong option instead of the correct one
+    pub fn bot_mistake_rate(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.3,
+            Difficulty::Normal | Difficulty::Custom => 0.15,
+            Difficulty::Hard => 0.05,
+        }
+    }
+}
+
+/// Player actions that can be bound to keyboard keys
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum KeyAction {
+    Up,
+    Down,
+    Left,
+    Right,
+    Pause,
+    Interact,
+}
+
+impl KeyAction {
+    pub const ALL: [KeyAction; 6] = [
+        KeyAction::Up,
+        KeyAction::Down,
+        KeyAction::Left,
+        KeyAction::Right,
+        KeyAction::Pause,
+        KeyAction::Interact,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyAction::Up => "Up",
+            KeyAction::Down => "Down",
+            KeyAction::Left => "Left",
+            KeyAction::Right => "Right",
+            KeyAction::Pause => "Pause",
+            KeyAction::Interact => "Interact",
+        }
+    }
+
+    fn index(&self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|action| action == self)
+            .unwrap_or(0)
+    }
+}
+
+/// The keys a keyboard player uses, one per `KeyAction`
+#[derive(Reflect, Clone, PartialEq, Debug)]
+pub struct KeyBindings {
+    keys: [KeyCode; 6],
+}
+
+impl KeyBindings {
+    /// Keys of a built-in keyboard scheme
+    pub fn for_scheme(scheme: &KeyboardScheme) -> Self {
+        let keys = match scheme {
+            KeyboardScheme::Arrows => [
+                KeyCode::ArrowUp,
+                KeyCode::ArrowDown,
+                KeyCode::ArrowLeft,
+                KeyCode::ArrowRight,
+                KeyCode::KeyP,
+                KeyCode::ShiftRight,
+            ],
+            KeyboardScheme::IJKL => [
+                KeyCode::KeyI,
+                KeyCode::KeyK,
+                KeyCode::KeyJ,
+                KeyCode::KeyL,
+                KeyCode::KeyO,
+                KeyCode::KeyU,
+            ],
+            _ => [
+                KeyCode::KeyW,
+                KeyCode::KeyS,
+                KeyCode::KeyA,
+                KeyCode::KeyD,
+                KeyCode::Escape,
+                KeyCode::KeyE,
+            ],
+        };
+        Self { keys }
+    }
+
+    pub fn get(&self, action: KeyAction) -> KeyCode {
+        self.keys[action.index()]
+    }
+
+    pub fn set(&mut self, action: KeyAction, key: KeyCode) {
+        self.keys[action.index()] = key;
+    }
+
+    /// The action bound to `key`, if any
+    pub fn action_for(&self, key: KeyCode) -> Option<KeyAction> {
+        KeyAction::ALL
+            .into_iter()
+            .find(|action| self.get(*action) == key)
+    }
+
+    /// Movement direction from the currently held keys
+    pub fn movement(&self, keyboard: &ButtonInput<KeyCode>) -> Vec2 {
+        let axis = |positive: KeyAction, negative: KeyAction| {
+            keyboard.pressed(self.get(positive)) as i32 as f32
+                - keyboard.pressed(self.get(negative)) as i32 as f32
+        };
+        Vec2::new(
+            axis(KeyAction::Right, KeyAction::Left),
+            axis(KeyAction::Up, KeyAction::Down),
+        )
+        .normalize_or_zero()
+    }
+}
+
+/// Key bindings players customized on the key bindings screen, by player slot.
+/// They only apply while the slot is played with a keyboard.
+#[derive(Resource, Reflect, Clone, Default, Debug)]
+#[reflect(Resource)]
+pub struct CustomKeyBindings {
+    pub players: BTreeMap<usize, KeyBindings>,
+}
+
+impl CustomKeyBindings {
+    /// The keys used by a player slot, or `None` if it is not on a keyboard
+    pub fn bindings_for(&self, player_index: usize, input: &InputSettings) -> Option<KeyBindings> {
+        let InputDevice::Keyboard(scheme) = &input.primary_input else {
+            return None;
+        };
+        Some(
+            self.players
+                .get(&player_index)
+                .cloned()
+                .unwrap_or_else(|| KeyBindings::for_scheme(scheme)),
+        )
+    }
+
+    pub fn is_custom(&self, player_index: usize) -> bool {
+        self.players.contains_key(&player_index)
+    }
+
+    /// Keys are stored by name so the format doesn't depend on `KeyCode` serialization
+    pub fn load() -> Self {
+        let stored: BTreeMap<usize, BTreeMap<String, String>> =
+            crate::storage::load(super::KEY_BINDINGS_STORAGE_KEY).unwrap_or_default();
+
+        let players = stored
+            .into_iter()
+            .filter_map(|(player_index, keys)| {
+                let mut bindings = KeyBindings::for_scheme(&KeyboardScheme::WASD);
+                for action in KeyAction::ALL {
+                    let key = keys
+                        .get(action.name())
+                        .and_then(|name| key_from_name(name))?;
+                    bindings.set(action, key);
+                }
+                Some((player_index, bindings))
+            })
+            .collect();
+
+        Self { players }
+    }
+
+    pub fn save(&self) {
+        let stored: BTreeMap<usize, BTreeMap<String, String>> = self
+            .players
+            .iter()
+            .map(|(player_index, bindings)| {
+                let keys = KeyAction::ALL
+                    .into_iter()
+                    .map(|action| {
+                        (
+                            action.name().to_string(),
+                            format!("{:?}", bindings.get(action)),
+                        )
+                    })
+                    .collect();
+                (*player_index, keys)
+            })
+            .collect();
+
+        crate::storage::save(super::KEY_BINDINGS_STORAGE_KEY, &stored);
+    }
+}
+
+/// Keys that can be assigned on the key bindings screen
+pub const BINDABLE_KEYS: &[KeyCode] = &[
+    KeyCode::KeyA,
+    KeyCode::KeyB,
+    KeyCode::KeyC,
+    KeyCode::KeyD,
+    KeyCode::KeyE,
+    KeyCode::KeyF,
+    KeyCode::KeyG,
+    KeyCode::KeyH,
+    KeyCode::KeyI,
+    KeyCode::KeyJ,
+    KeyCode::KeyK,
+    KeyCode::KeyL,
+    KeyCode::KeyM,
+    KeyCode::KeyN,
+    KeyCode::KeyO,
+    KeyCode::KeyP,
+    KeyCode::KeyQ,
+    KeyCode::KeyR,
+    KeyCode::KeyS,
+    KeyCode::KeyT,
+    KeyCode::KeyU,
+    KeyCode::KeyV,
+    KeyCode::KeyW,
+    KeyCode::KeyX,
+    KeyCode::KeyY,
+    KeyCode::KeyZ,
+    KeyCode::Digit0,
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+    KeyCode::Digit8,
+    KeyCode::Digit9,
+    KeyCode::ArrowUp,
+    KeyCode::ArrowDown,
+    KeyCode::ArrowLeft,
+    KeyCode::ArrowRight,
+    KeyCode::Numpad0,
+    KeyCode::Numpad1,
+    KeyCode::Numpad2,
+    KeyCode::Numpad3,
+    KeyCode::Numpad4,
+    KeyCode::Numpad5,
+    KeyCode::Numpad6,
+    KeyCode::Numpad7,
+    KeyCode::Numpad8,
+    KeyCode::Numpad9,
+    KeyCode::Space,
+    KeyCode::Enter,
+    KeyCode::Tab,
+    KeyCode::Backspace,
+    KeyCode::ShiftLeft,
+    KeyCode::ShiftRight,
+    KeyCode::ControlLeft,
+    KeyCode::ControlRight,
+    KeyCode::AltLeft,
+    KeyCode::AltRight,
+    KeyCode::Comma,
+    KeyCode::Period,
+    KeyCode::Slash,
+    KeyCode::Semicolon,
+    KeyCode::Quote,
+    KeyCode::BracketLeft,
+    KeyCode::BracketRight,
+    KeyCode::Minus,
+    KeyCode::Equal,
+];
+
+/// Keys the game itself listens to during a round, with what they do. Escape
+/// isn't bindable at all, it cancels a capture on the key bindings screen.
+pub const RESERVED_HOTKEYS: &[(KeyCode, &str)] = &[
+    (KeyCode::KeyP, "Pause"),
+    (KeyCode::Escape, "Pause"),
+    (KeyCode::KeyF, "Flag question"),
+    (KeyCode::Tab, "Skip question (practice)"),
+];
+
+/// What the game uses `key` for by itself, if anything
+pub fn reserved_hotkey(key: KeyCode) -> Option<&'static str> {
+    RESERVED_HOTKEYS
+        .iter()
+        .find(|(reserved, _)| *reserved == key)
+        .map(|(_, usage)| *usage)
+}
+
+fn key_from_name(name: &str) -> Option<KeyCode> {
+    // Reserved keys still load, the WASD scheme pauses on Escape
+    BINDABLE_KEYS
+        .iter()
+        .copied()
+        .chain(RESERVED_HOTKEYS.iter().map(|(key, _)| *key))
+        .find(|key| format!("{:?}", key) == name)
+}
+
+/// Short, readable label for a key, e.g. "W" instead of "KeyW"
+pub fn key_label(key: KeyCode) -> String {
+    let name = format!("{:?}", key);
+    name.strip_prefix("Key")
+        .or_else(|| name.strip_prefix("Digit"))
+        .map(str::to_string)
+        .unwrap_or(name)
+}
+
 /// Resource to track device selection state
 #[derive(Resource, Reflect, Default)]
 #[reflect(Resource)]
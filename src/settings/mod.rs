@@ -12,15 +12,22 @@ pub(super) fn plugin(app: &mut App) {
     app.register_type::<GameSettings>()
         .register_type::<PlayerSettings>()
         .register_type::<InputSettings>()
+        .register_type::<PlayerShape>()
         .register_type::<MultiplayerSettings>()
+        .register_type::<GameplaySettings>()
+        .register_type::<Difficulty>()
         .register_type::<AvailableInputDevices>()
-        .register_type::<DeviceSelectionState>();
+        .register_type::<DeviceSelectionState>()
+        .register_type::<CustomKeyBindings>();
 
     // Initialize resources
     app.init_resource::<GameSettings>()
         .init_resource::<AvailableInputDevices>()
         .init_resource::<DeviceSelectionState>()
-        .init_resource::<DeviceWarningTracker>();
+        .init_resource::<DeviceWarningTracker>()
+        .init_resource::<CustomKeyBindings>();
+
+    app.add_systems(Startup, load_key_bindings);
 
     // Only input device systems
     app.add_systems(
@@ -34,3 +41,16 @@ pub(super) fn plugin(app: &mut App) {
 }
 
 pub const MAX_PLAYERS: usize = 4;
+/// Colors players can pick from, the first `MAX_PLAYERS` are the defaults
+pub const PLAYER_COLORS: [Color; 8] = [
+    Color::srgb(1.0, 0.8, 0.2), // Yellow
+    Color::srgb(0.2, 0.8, 1.0), // Blue
+    Color::srgb(1.0, 0.2, 0.4), // Red
+    Color::srgb(0.2, 1.0, 0.4), // Green
+    Color::srgb(0.7, 0.4, 1.0), // Purple
+    Color::srgb(1.0, 0.5, 0.1), // Orange
+    Color::srgb(1.0, 0.5, 0.8), // Pink
+    Color::srgb(0.9, 0.9, 0.9), // White
+];
+pub const MAX_HANDICAP_MULTIPLIER: f32 = 3.0;
+pub const KEY_BINDINGS_STORAGE_KEY: &str = "key_bindings";
@@ -209,3 +209,12 @@ pub fn track_manual_assignments(
         game_settings.multiplayer.auto_assign_inputs = false;
     }
 }
+
+/// System to load the stored custom key bindings at startup
+pub fn load_key_bindings(mut key_bindings: ResMut<CustomKeyBindings>) {
+    *key_bindings = CustomKeyBindings::load();
+    info!(
+        "Loaded custom key bindings for {} players",
+        key_bindings.players.len()
+    );
+}
@@ -0,0 +1,96 @@
+//! Grid-bucket spatial index used as a broad phase for collision checks.
+//! Options and chain segments are bucketed by position once per fixed step, so
+//! pickups and chain collisions only look at entities in nearby cells.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::{chain::ChainSegment, options::OptionCollectible, screens::Screen};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<SpatialIndex>();
+
+    app.add_systems(OnExit(Screen::Gameplay), clear_spatial_index);
+    app.add_systems(
+        FixedUpdate,
+        rebuild_spatial_index
+            .in_set(crate::AppSystems::RecordInput)
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(crate::PausableSystems),
+    );
+}
+
+/// Entities bucketed into square cells of a fixed size
+#[derive(Debug, Default)]
+pub struct SpatialGrid {
+    buckets: HashMap<IVec2, Vec<Entity>>,
+}
+
+impl SpatialGrid {
+    fn cell(position: Vec2) -> IVec2 {
+        (position / SPATIAL_CELL_SIZE).floor().as_ivec2()
+    }
+
+    pub fn clear(&mut self) {
+        // Keep the bucket allocations around for the next rebuild
+        for bucket in self.buckets.values_mut() {
+            bucket.clear();
+        }
+    }
+
+    pub fn insert(&mut self, entity: Entity, position: Vec2) {
+        self.buckets
+            .entry(Self::cell(position))
+            .or_default()
+            .push(entity);
+    }
+
+    /// Entities in every cell touched by a circle around `position`. This is a
+    /// broad phase: callers still check the exact distance. The radius is
+    /// padded so entities that moved since the last rebuild are still found.
+    pub fn near(&self, position: Vec2, radius: f32) -> impl Iterator<Item = Entity> + '_ {
+        let padding = Vec2::splat(radius + SPATIAL_QUERY_MARGIN);
+        let min = Self::cell(position - padding);
+        let max = Self::cell(position + padding);
+
+        (min.y..=max.y)
+            .flat_map(move |y| (min.x..=max.x).map(move |x| IVec2::new(x, y)))
+            .filter_map(|cell| self.buckets.get(&cell))
+            .flatten()
+            .copied()
+    }
+}
+
+/// Resource with the positions of options and chain segments, rebuilt every fixed step
+#[derive(Resource, Debug, Default)]
+pub struct SpatialIndex {
+    pub options: SpatialGrid,
+    pub segments: SpatialGrid,
+}
+
+fn rebuild_spatial_index(
+    mut index: ResMut<SpatialIndex>,
+    option_query: Query<(Entity, &Transform), With<OptionCollectible>>,
+    segment_query: Query<(Entity, &Transform), With<ChainSegment>>,
+) {
+    let index = index.as_mut();
+
+    index.options.clear();
+    for (entity, transform) in &option_query {
+        index.options.insert(entity, transform.translation.xy());
+    }
+
+    index.segments.clear();
+    for (entity, transform) in &segment_query {
+        index.segments.insert(entity, transform.translation.xy());
+    }
+}
+
+fn clear_spatial_index(mut index: ResMut<SpatialIndex>) {
+    *index = SpatialIndex::default();
+}
+
+// Spatial index constants
+pub const SPATIAL_CELL_SIZE: f32 = 64.0; // About two option pickups across
+pub const SPATIAL_QUERY_MARGIN: f32 = 24.0; // Covers movement between rebuild and query
@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Answer counts for a single grammar topic (challenge)
+#[derive(Reflect, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TopicStatistics {
+    pub correct_answers: u32,
+    pub wrong_answers: u32,
+}
+
+impl TopicStatistics {
+    pub fn accuracy(&self) -> f32 {
+        accuracy(self.correct_answers, self.wrong_answers)
+    }
+}
+
+/// Resource with lifetime statistics of the human players on this device
+#[derive(Resource, Reflect, Clone, Debug, Default, Serialize, Deserialize)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct PlayerStatistics {
+    pub games_played: u32,
+    pub correct_answers: u32,
+    pub wrong_answers: u32,
+    pub longest_chain: usize,
+    pub total_merges: u32,
+    pub topics: BTreeMap<String, TopicStatistics>,
+}
+
+impl PlayerStatistics {
+    pub fn record_answer(&mut self, topic: &str, is_correct: bool) {
+        let topic = self.topics.entry(topic.to_string()).or_default();
+        if is_correct {
+            self.correct_answers += 1;
+            topic.correct_answers += 1;
+        } else {
+            self.wrong_answers += 1;
+            topic.wrong_answers += 1;
+        }
+    }
+
+    /// Average accuracy over all answers, in percent
+    pub fn accuracy(&self) -> f32 {
+        accuracy(self.correct_answers, self.wrong_answers)
+    }
+
+    pub fn load() -> Self {
+        crate::storage::load(super::STATISTICS_STORAGE_KEY).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        crate::storage::save(super::STATISTICS_STORAGE_KEY, self);
+    }
+}
+
+fn accuracy(correct: u32, wrong: u32) -> f32 {
+    let total = correct + wrong;
+    if total > 0 {
+        correct as f32 / total as f32 * 100.0
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+mod components;
+mod systems;
+
+pub use components::*;
+use systems::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<PlayerStatistics>();
+    app.register_type::<TopicStatistics>();
+
+    app.init_resource::<PlayerStatistics>();
+
+    app.add_systems(Startup, load_statistics);
+
+    app.add_systems(
+        Update,
+        (
+            record_answers,
+            record_merges,
+            record_longest_chain,
+            record_game_end,
+        )
+            .in_set(crate::AppSystems::Update)
+            .run_if(in_state(crate::screens::Screen::Gameplay)),
+    );
+
+    // Keep progress from rounds that are quit before the timer runs out
+    app.add_systems(OnExit(crate::screens::Screen::Gameplay), save_statistics);
+}
+
+pub const STATISTICS_STORAGE_KEY: &str = "statistics";
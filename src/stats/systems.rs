@@ -0,0 +1,86 @@
+use super::components::*;
+use crate::{
+    bot::BotController,
+    chain::{ChainMergeEvent, PlayerChain},
+    game_state::GameState,
+    gameplay::GameTimerEvent,
+    player::{OptionCollectedEvent, Player},
+};
+use bevy::prelude::*;
+
+/// System to load the stored statistics at startup
+pub fn load_statistics(mut commands: Commands) {
+    let statistics = PlayerStatistics::load();
+    info!(
+        "Loaded statistics for {} games played",
+        statistics.games_played
+    );
+    commands.insert_resource(statistics);
+}
+
+/// System to count answers per grammar topic. Bots are left out.
+pub fn record_answers(
+    mut collection_events: EventReader<OptionCollectedEvent>,
+    mut statistics: ResMut<PlayerStatistics>,
+    game_state: Res<GameState>,
+    bot_query: Query<(), With<BotController>>,
+) {
+    let topic = game_state
+        .current_challenge_id
+        .as_deref()
+        .unwrap_or("unknown");
+
+    for event in collection_events.read() {
+        if bot_query.contains(event.player_entity) {
+            continue;
+        }
+        statistics.record_answer(topic, event.is_correct);
+    }
+}
+
+/// System to count merges made by human players
+pub fn record_merges(
+    mut merge_events: EventReader<ChainMergeEvent>,
+    mut statistics: ResMut<PlayerStatistics>,
+    bot_query: Query<(), With<BotController>>,
+) {
+    for event in merge_events.read() {
+        if !bot_query.contains(event.player_entity) {
+            statistics.total_merges += 1;
+        }
+    }
+}
+
+/// System to keep track of the longest chain ever built
+pub fn record_longest_chain(
+    mut statistics: ResMut<PlayerStatistics>,
+    chain_query: Query<&PlayerChain, (With<Player>, Without<BotController>, Changed<PlayerChain>)>,
+) {
+    for chain in &chain_query {
+        if chain.segments.len() > statistics.longest_chain {
+            statistics.longest_chain = chain.segments.len();
+        }
+    }
+}
+
+/// System to count finished games and store the statistics
+pub fn record_game_end(
+    mut timer_events: EventReader<GameTimerEvent>,
+    mut statistics: ResMut<PlayerStatistics>,
+) {
+    for event in timer_events.read() {
+        match event {
+            GameTimerEvent::GameEnded => {
+                statistics.games_played += 1;
+                statistics.save();
+                info!("Recorded game {} to statistics", statistics.games_played);
+            }
+            GameTimerEvent::SuddenDeath => {}
+        }
+    }
+}
+
+/// System to store the statistics when leaving gameplay
+pub fn save_statistics(statistics: Res<PlayerStatistics>) {
+    statistics.save();
+}
@@ -0,0 +1,109 @@
+//! Small key/value persistence for data that should survive between sessions.
+//!
+//! With the `persistence` feature, native builds store RON files in the platform
+//! config directory and web builds store the same RON text in `localStorage`.
+//! Without it, nothing is stored and every load comes back empty.
+
+use bevy::prelude::*;
+use serde::{Serialize, de::DeserializeOwned};
+
+#[cfg(feature = "persistence")]
+const STORAGE_NAMESPACE: &str = "konnektoren-chain-game";
+
+/// Load a value stored under `key`, returning `None` if it is missing or unreadable.
+#[cfg(feature = "persistence")]
+pub fn load<T: DeserializeOwned>(key: &str) -> Option<T> {
+    let contents = read_raw(key)?;
+    match ron::from_str(&contents) {
+        Ok(value) => Some(value),
+        Err(err) => {
+            warn!("Failed to parse stored '{}': {}", key, err);
+            None
+        }
+    }
+}
+
+/// Store a value under `key`, logging instead of failing if storage is unavailable.
+#[cfg(feature = "persistence")]
+pub fn save<T: Serialize>(key: &str, value: &T) {
+    let contents = match ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default()) {
+        Ok(contents) => contents,
+        Err(err) => {
+            warn!("Failed to serialize '{}': {}", key, err);
+            return;
+        }
+    };
+
+    if write_raw(key, &contents) {
+        info!("Saved '{}'", key);
+    }
+}
+
+#[cfg(not(feature = "persistence"))]
+pub fn load<T: DeserializeOwned>(_key: &str) -> Option<T> {
+    None
+}
+
+#[cfg(not(feature = "persistence"))]
+pub fn save<T: Serialize>(key: &str, _value: &T) {
+    debug!("Persistence disabled, not saving '{}'", key);
+}
+
+#[cfg(all(feature = "persistence", not(target_family = "wasm")))]
+fn storage_path(key: &str) -> Option<std::path::PathBuf> {
+    dirs::config_dir().map(|dir| dir.join(STORAGE_NAMESPACE).join(format!("{key}.ron")))
+}
+
+#[cfg(all(feature = "persistence", not(target_family = "wasm")))]
+fn read_raw(key: &str) -> Option<String> {
+    let path = storage_path(key)?;
+    std::fs::read_to_string(path).ok()
+}
+
+#[cfg(all(feature = "persistence", not(target_family = "wasm")))]
+fn write_raw(key: &str, contents: &str) -> bool {
+    let Some(path) = storage_path(key) else {
+        warn!("No config directory available, cannot save '{}'", key);
+        return false;
+    };
+
+    if let Some(parent) = path.parent() {
+        if let Err(err) = std::fs::create_dir_all(parent) {
+            warn!("Failed to create {}: {}", parent.display(), err);
+            return false;
+        }
+    }
+
+    match std::fs::write(&path, contents) {
+        Ok(()) => true,
+        Err(err) => {
+            warn!("Failed to write {}: {}", path.display(), err);
+            false
+        }
+    }
+}
+
+#[cfg(all(feature = "persistence", target_family = "wasm"))]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+#[cfg(all(feature = "persistence", target_family = "wasm"))]
+fn read_raw(key: &str) -> Option<String> {
+    local_storage()?
+        .get_item(&format!("{STORAGE_NAMESPACE}:{key}"))
+        .ok()
+        .flatten()
+}
+
+#[cfg(all(feature = "persistence", target_family = "wasm"))]
+fn write_raw(key: &str, contents: &str) -> bool {
+    let Some(storage) = local_storage() else {
+        warn!("localStorage not available, cannot save '{}'", key);
+        return false;
+    };
+
+    storage
+        .set_item(&format!("{STORAGE_NAMESPACE}:{key}"), contents)
+        .is_ok()
+}
@@ -0,0 +1,176 @@
+//! Sudden death: when time runs out with the lead shared, the tied leaders
+//! play on. The first of them to collect a correct option wins, and a leader
+//! whose own chain blows up drops out, handing the win to the last one left.
+//! Questions rotate faster meanwhile and a flashing banner shows what is at
+//! stake.
+
+use bevy::prelude::*;
+
+use crate::{
+    chain::ChainReactionEvent,
+    gameplay::{GameTimer, GameTimerEvent, GameplayScore},
+    locale::Locale,
+    player::OptionCollectedEvent,
+    question::QuestionTimer,
+    screens::Screen,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<SuddenDeath>();
+    app.register_type::<SuddenDeathBanner>();
+
+    app.add_systems(
+        Update,
+        (
+            start_sudden_death,
+            resolve_sudden_death.run_if(resource_exists::<SuddenDeath>),
+            flash_sudden_death_banner,
+        )
+            .chain()
+            .in_set(crate::AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(crate::PausableSystems),
+    );
+    app.add_systems(OnExit(Screen::Gameplay), end_sudden_death);
+}
+
+/// Resource present while a tied round is being settled
+#[derive(Resource, Reflect, Clone, Debug, Default)]
+#[reflect(Resource)]
+pub struct SuddenDeath {
+    /// Tied leaders still in the running
+    pub contenders: Vec<Entity>,
+}
+
+/// Banner announcing sudden death
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct SuddenDeathBanner;
+
+fn start_sudden_death(
+    mut commands: Commands,
+    mut timer_events: EventReader<GameTimerEvent>,
+    gameplay_score: Res<GameplayScore>,
+    locale: Res<Locale>,
+    mut question_timer_query: Query<&mut QuestionTimer>,
+) {
+    if !timer_events
+        .read()
+        .any(|event| matches!(event, GameTimerEvent::SuddenDeath))
+    {
+        return;
+    }
+
+    let contenders = gameplay_score.leaders();
+    info!("Sudden death between {} players", contenders.len());
+    commands.insert_resource(SuddenDeath { contenders });
+
+    for mut question_timer in &mut question_timer_query {
+        let duration =
+            question_timer.timer.duration().as_secs_f32() * SUDDEN_DEATH_QUESTION_SPEEDUP;
+        question_timer
+            .timer
+            .set_duration(std::time::Duration::from_secs_f32(duration));
+    }
+
+    commands.spawn((
+        Name::new("Sudden Death Banner"),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(SUDDEN_DEATH_BANNER_TOP),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        Pickable::IGNORE,
+        StateScoped(Screen::Gameplay),
+        SuddenDeathBanner,
+        children![(
+            Name::new("Sudden Death Text"),
+            Text::new(locale.text("hud.sudden_death")),
+            TextFont {
+                font_size: SUDDEN_DEATH_BANNER_FONT_SIZE,
+                ..default()
+            },
+            TextColor(SUDDEN_DEATH_BANNER_COLOR),
+        )],
+    ));
+}
+
+fn resolve_sudden_death(
+    mut commands: Commands,
+    mut collected_events: EventReader<OptionCollectedEvent>,
+    mut reaction_events: EventReader<ChainReactionEvent>,
+    mut sudden_death: ResMut<SuddenDeath>,
+    mut gameplay_score: ResMut<GameplayScore>,
+    mut game_timer: ResMut<GameTimer>,
+    mut timer_events: EventWriter<GameTimerEvent>,
+    banner_query: Query<Entity, With<SuddenDeathBanner>>,
+) {
+    let mut winner = collected_events
+        .read()
+        .find(|event| event.is_correct && sudden_death.contenders.contains(&event.player_entity))
+        .map(|event| event.player_entity);
+
+    if winner.is_none() {
+        for event in reaction_events.read() {
+            sudden_death
+                .contenders
+                .retain(|&entity| entity != event.player_entity);
+        }
+        if let [last] = sudden_death.contenders.as_slice() {
+            winner = Some(*last);
+        }
+    }
+
+    // Everyone left blew up at once, nobody can settle it anymore
+    let Some(winner) = winner.or_else(|| {
+        sudden_death
+            .contenders
+            .is_empty()
+            .then(|| gameplay_score.leaders().first().copied())
+            .flatten()
+    }) else {
+        return;
+    };
+
+    // The decisive play always puts the winner in front
+    if let Some(score) = gameplay_score.get_player_score_mut(winner) {
+        score.total_score += SUDDEN_DEATH_BONUS_POINTS;
+        info!("{} wins sudden death", score.player_name);
+    }
+
+    game_timer.sudden_death = false;
+    timer_events.write(GameTimerEvent::GameEnded);
+    commands.remove_resource::<SuddenDeath>();
+    for banner in &banner_query {
+        commands.entity(banner).despawn();
+    }
+}
+
+fn flash_sudden_death_banner(
+    time: Res<Time>,
+    banner_query: Query<&Children, With<SuddenDeathBanner>>,
+    mut text_query: Query<&mut TextColor>,
+) {
+    let alpha = 0.6 + 0.4 * (time.elapsed_secs() * SUDDEN_DEATH_FLASH_SPEED).sin();
+    for children in &banner_query {
+        for &child in children {
+            if let Ok(mut color) = text_query.get_mut(child) {
+                color.0 = SUDDEN_DEATH_BANNER_COLOR.with_alpha(alpha);
+            }
+        }
+    }
+}
+
+fn end_sudden_death(mut commands: Commands) {
+    commands.remove_resource::<SuddenDeath>();
+}
+
+// Sudden death constants
+pub const SUDDEN_DEATH_QUESTION_SPEEDUP: f32 = 0.5; // Share of the usual question time
+pub const SUDDEN_DEATH_BONUS_POINTS: i32 = 1; // Breaks the tie for the winner
+pub const SUDDEN_DEATH_FLASH_SPEED: f32 = 8.0;
+pub const SUDDEN_DEATH_BANNER_TOP: f32 = 30.0; // Percent of the window height
+pub const SUDDEN_DEATH_BANNER_FONT_SIZE: f32 = 56.0;
+pub const SUDDEN_DEATH_BANNER_COLOR: Color = Color::srgb(1.0, 0.25, 0.25);
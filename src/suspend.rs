@@ -0,0 +1,465 @@
+//! Suspend and resume: the essential state of a round in progress is saved
+//! when the game is paused or loses focus, and "Continue" on the main menu
+//! rebuilds the round from that snapshot, under the rules, daily challenge
+//! and match it was played in.
+
+use std::{collections::HashMap, time::Duration};
+
+use bevy::{prelude::*, window::WindowFocused};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Pause,
+    asset_cache::SharedAssetCache,
+    chain::{ChainSegment, CursedSegment, MovementTrail, PlayerChain},
+    daily::DailyRound,
+    game_state::GameState,
+    gameplay::{GameTimer, GameTimerEvent, GameplayScore, PlayerScore},
+    locale::Locale,
+    match_play::MatchState,
+    notifications::NotificationEvent,
+    palette::OptionStyleRegistry,
+    player::{Player, PlayerIndex},
+    question::{QuestionChangedEvent, QuestionSystem},
+    round::{RoundInfo, RoundRules, SettingsBackup},
+    screens::{GameplayMode, Screen},
+    settings::{Difficulty, GameSettings},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<SavedGameSlot>();
+    app.add_event::<ResumeSavedGameEvent>();
+
+    app.add_systems(Startup, load_saved_game);
+    app.add_systems(OnEnter(Pause(true)), suspend_on_pause);
+    app.add_systems(
+        Update,
+        (
+            start_saved_game.run_if(on_event::<ResumeSavedGameEvent>),
+            // The bot-only attract demo is nobody's game to continue
+            suspend_on_focus_lost.run_if(in_state(GameplayMode::Interactive)),
+            discard_finished_game.run_if(in_state(Screen::Gameplay)),
+            restore_saved_game.in_set(crate::AppSystems::Update).run_if(
+                in_state(Screen::Gameplay)
+                    .and(resource_exists::<PendingResume>)
+                    .and(resource_exists::<QuestionSystem>),
+            ),
+        ),
+    );
+}
+
+/// Snapshot of a round in progress
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SavedGame {
+    pub version: String,
+    pub challenge_id: String,
+    pub difficulty: usize,
+    /// Rules of the round as in a share code, empty in saves from before
+    /// they were kept, which only restore the difficulty
+    #[serde(default)]
+    pub rules: String,
+    /// Date of the daily challenge the round belongs to
+    #[serde(default)]
+    pub daily: Option<String>,
+    /// Match the round is part of
+    #[serde(default)]
+    pub match_state: Option<MatchState>,
+    pub seed: u64,
+    pub time_remaining: f32,
+    pub question_index: usize,
+    /// Topics the round was limited to, empty for the whole challenge
+    #[serde(default)]
+    pub topics: Vec<String>,
+    pub players: Vec<SavedPlayer>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SavedPlayer {
+    pub player_index: usize,
+    pub position: [f32; 2],
+    pub total_score: i32,
+    pub correct_answers: u32,
+    pub wrong_answers: u32,
+    pub current_streak: u32,
+    pub best_streak: u32,
+    pub collection_count: u32,
+    pub merges: u32,
+    /// Chain from head to tail
+    pub chain: Vec<SavedSegment>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SavedSegment {
+    pub option_id: usize,
+    pub option_text: String,
+    pub color: [f32; 4],
+    pub cursed: bool,
+    // Saves from before merged segments were kept come back as plain level 1 segments
+    #[serde(default)]
+    pub position: Option<[f32; 2]>,
+    #[serde(default = "default_segment_level")]
+    pub level: u32,
+    #[serde(default = "default_segment_level")]
+    pub merge_value: u32,
+    #[serde(default)]
+    pub durability: u32,
+    /// Player slots whose collections went into the segment
+    #[serde(default)]
+    pub contributors: Vec<usize>,
+    #[serde(default)]
+    pub category: Option<String>,
+}
+
+fn default_segment_level() -> u32 {
+    1
+}
+
+/// Resource holding the suspended round, if there is one
+#[derive(Resource, Default, Debug)]
+pub struct SavedGameSlot(pub Option<SavedGame>);
+
+impl SavedGameSlot {
+    fn store(&mut self, saved: Option<SavedGame>) {
+        crate::storage::save(SAVED_GAME_STORAGE_KEY, &saved);
+        self.0 = saved;
+    }
+}
+
+/// Event to continue the suspended round
+#[derive(Event)]
+pub struct ResumeSavedGameEvent;
+
+/// Snapshot waiting to be applied once the resumed round has been set up
+#[derive(Resource, Debug)]
+struct PendingResume(SavedGame);
+
+fn load_saved_game(mut slot: ResMut<SavedGameSlot>) {
+    slot.0 = crate::storage::load::<Option<SavedGame>>(SAVED_GAME_STORAGE_KEY).flatten();
+}
+
+/// Everything needed to take a snapshot of the running round
+#[derive(bevy::ecs::system::SystemParam)]
+struct RoundSnapshot<'w, 's> {
+    round_info: Option<Res<'w, RoundInfo>>,
+    daily: Option<Res<'w, DailyRound>>,
+    match_state: Option<Res<'w, MatchState>>,
+    question_system: Option<Res<'w, QuestionSystem>>,
+    game_timer: Res<'w, GameTimer>,
+    gameplay_score: Res<'w, GameplayScore>,
+    pending: Option<Res<'w, PendingResume>>,
+    player_query: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static PlayerIndex,
+            &'static Transform,
+            &'static PlayerChain,
+        ),
+        With<Player>,
+    >,
+    segment_query: Query<
+        'w,
+        's,
+        (
+            &'static ChainSegment,
+            &'static Transform,
+            Has<CursedSegment>,
+        ),
+    >,
+    index_query: Query<'w, 's, &'static PlayerIndex>,
+}
+
+impl RoundSnapshot<'_, '_> {
+    fn take(&self) -> Option<SavedGame> {
+        // Nothing worth resuming once time is up, or before a resume has been applied
+        if self.game_timer.is_overtime || self.pending.is_some() {
+            return None;
+        }
+        let round_info = self.round_info.as_ref()?;
+        let question_system = self.question_system.as_ref()?;
+
+        let players = self
+            .player_query
+            .iter()
+            .map(|(entity, player_index, transform, chain)| {
+                let score = self.gameplay_score.players.get(&entity);
+                SavedPlayer {
+                    player_index: player_index.0,
+                    position: transform.translation.xy().to_array(),
+                    total_score: score.map_or(0, |score| score.total_score),
+                    correct_answers: score.map_or(0, |score| score.correct_answers),
+                    wrong_answers: score.map_or(0, |score| score.wrong_answers),
+                    current_streak: score.map_or(0, |score| score.current_streak),
+                    best_streak: score.map_or(0, |score| score.best_streak),
+                    collection_count: score.map_or(0, |score| score.collection_count),
+                    merges: score.map_or(0, |score| score.merges),
+                    chain: chain
+                        .segments
+                        .iter()
+                        .filter_map(|&segment| self.segment_query.get(segment).ok())
+                        .map(|(segment, transform, cursed)| SavedSegment {
+                            option_id: segment.option_id,
+                            option_text: segment.option_text.clone(),
+                            color: segment.base_color.to_srgba().to_f32_array(),
+                            cursed,
+                            position: Some(transform.translation.xy().to_array()),
+                            level: segment.level,
+                            merge_value: segment.merge_value,
+                            durability: segment.durability,
+                            contributors: segment
+                                .contributors
+                                .iter()
+                                .filter_map(|&entity| self.index_query.get(entity).ok())
+                                .map(|index| index.0)
+                                .collect(),
+                            category: segment.category.clone(),
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        Some(SavedGame {
+            version: round_info.version.clone(),
+            challenge_id: round_info.challenge_id.clone(),
+            difficulty: round_info.rules.gameplay.difficulty.index(),
+            rules: round_info.rules.code(),
+            daily: self.daily.as_ref().map(|daily| daily.date.clone()),
+            match_state: self.match_state.as_deref().cloned(),
+            seed: round_info.seed,
+            time_remaining: self.game_timer.time_remaining,
+            question_index: question_system.current_question_index,
+            topics: question_system.topics.clone(),
+            players,
+        })
+    }
+}
+
+fn suspend_on_pause(snapshot: RoundSnapshot, mut slot: ResMut<SavedGameSlot>) {
+    if let Some(saved) = snapshot.take() {
+        info!("Suspended round of challenge {}", saved.challenge_id);
+        slot.store(Some(saved));
+    }
+}
+
+fn suspend_on_focus_lost(
+    mut focus_events: EventReader<WindowFocused>,
+    snapshot: RoundSnapshot,
+    mut slot: ResMut<SavedGameSlot>,
+) {
+    if !focus_events.read().any(|event| !event.focused) {
+        return;
+    }
+
+    if let Some(saved) = snapshot.take() {
+        info!(
+            "Window lost focus, suspended round of challenge {}",
+            saved.challenge_id
+        );
+        slot.store(Some(saved));
+    }
+}
+
+/// A round that reached its end can't be continued any more
+fn discard_finished_game(
+    mut timer_events: EventReader<GameTimerEvent>,
+    mut slot: ResMut<SavedGameSlot>,
+) {
+    let ended = timer_events
+        .read()
+        .any(|event| matches!(event, GameTimerEvent::GameEnded));
+    if ended && slot.0.is_some() {
+        slot.store(None);
+    }
+}
+
+fn start_saved_game(
+    mut commands: Commands,
+    mut events: EventReader<ResumeSavedGameEvent>,
+    slot: Res<SavedGameSlot>,
+    mut game_state: ResMut<GameState>,
+    mut game_settings: ResMut<GameSettings>,
+    backup: Option<Res<SettingsBackup>>,
+    locale: Res<Locale>,
+    mut notifications: EventWriter<NotificationEvent>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    events.clear();
+    let Some(saved) = slot.0.clone() else {
+        return;
+    };
+
+    if saved.version != env!("CARGO_PKG_VERSION") {
+        notifications.write(NotificationEvent::new(
+            locale.format("suspend.other_version", &[("version", &saved.version)]),
+        ));
+    }
+
+    // The round continues under the rules it was started with, the player's
+    // own settings come back on the title screen
+    SettingsBackup::store(&mut commands, backup.as_deref(), &game_settings);
+    match RoundRules::from_code(&saved.rules) {
+        Some(rules) => rules.apply(&mut game_settings),
+        None => {
+            game_settings.gameplay.difficulty =
+                Difficulty::from_index(saved.difficulty.min(Difficulty::ALL.len() - 1));
+        }
+    }
+    if let Some(date) = &saved.daily {
+        commands.insert_resource(DailyRound::for_date(date.clone()));
+    }
+    if let Some(mut match_state) = saved.match_state.clone() {
+        // Starting the round counts it again
+        match_state.round = match_state.round.saturating_sub(1);
+        commands.insert_resource(match_state);
+    }
+
+    // Same challenge and seed, so the map and question order come back as they were
+    game_state.random_challenge = false;
+    game_state.select_challenge(saved.challenge_id.clone());
+    game_state.round_seed = Some(saved.seed);
+    game_state.round_topics = Some(saved.topics.clone());
+
+    info!(
+        "Continuing suspended round of challenge {}",
+        saved.challenge_id
+    );
+    commands.insert_resource(PendingResume(saved));
+    next_screen.set(Screen::Loading);
+}
+
+/// Assets needed to spawn restored chain segments
+#[derive(bevy::ecs::system::SystemParam)]
+struct SegmentAssets<'w> {
+    option_styles: Res<'w, OptionStyleRegistry>,
+    asset_cache: ResMut<'w, SharedAssetCache>,
+    meshes: ResMut<'w, Assets<Mesh>>,
+    materials: ResMut<'w, Assets<ColorMaterial>>,
+}
+
+/// Put scores, timer, question, positions and chains back once the round is set up
+fn restore_saved_game(
+    mut commands: Commands,
+    pending: Res<PendingResume>,
+    game_settings: Res<GameSettings>,
+    mut question_system: ResMut<QuestionSystem>,
+    mut game_timer: ResMut<GameTimer>,
+    mut gameplay_score: ResMut<GameplayScore>,
+    mut player_query: Query<
+        (
+            Entity,
+            &PlayerIndex,
+            &mut Transform,
+            &mut PlayerChain,
+            Option<&mut MovementTrail>,
+        ),
+        With<Player>,
+    >,
+    mut segment_assets: SegmentAssets,
+    locale: Res<Locale>,
+    mut question_events: EventWriter<QuestionChangedEvent>,
+    mut notifications: EventWriter<NotificationEvent>,
+) {
+    let saved = &pending.0;
+
+    let elapsed = (game_timer.game_duration - saved.time_remaining).max(0.0);
+    game_timer
+        .timer
+        .set_elapsed(Duration::from_secs_f32(elapsed));
+    game_timer.time_remaining = saved.time_remaining;
+
+    if !question_system.question_order.is_empty() {
+        question_system.current_question_index =
+            saved.question_index % question_system.question_order.len();
+        question_events.write(QuestionChangedEvent);
+    }
+
+    let slots: HashMap<usize, Entity> = player_query
+        .iter()
+        .map(|(entity, player_index, ..)| (player_index.0, entity))
+        .collect();
+
+    for saved_player in &saved.players {
+        if !slots.contains_key(&saved_player.player_index) {
+            warn!(
+                "Player {} of the suspended round has no slot any more, leaving them out",
+                saved_player.player_index + 1
+            );
+        }
+    }
+
+    for (entity, player_index, mut transform, mut player_chain, trail) in &mut player_query {
+        let Some(saved_player) = saved
+            .players
+            .iter()
+            .find(|player| player.player_index == player_index.0)
+        else {
+            continue;
+        };
+
+        let position = Vec2::from_array(saved_player.position);
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+        if let Some(mut trail) = trail {
+            trail.positions.clear();
+        }
+
+        let player_name = game_settings.multiplayer.player_name(player_index.0);
+        let mut score = PlayerScore::new(player_name);
+        score.total_score = saved_player.total_score;
+        score.correct_answers = saved_player.correct_answers;
+        score.wrong_answers = saved_player.wrong_answers;
+        score.current_streak = saved_player.current_streak;
+        score.best_streak = saved_player.best_streak;
+        score.collection_count = saved_player.collection_count;
+        score.merges = saved_player.merges;
+        gameplay_score.players.insert(entity, score);
+
+        // Segments come back where they were, merged levels and all, rather
+        // than being collected again, which could set off fresh merges
+        for saved_segment in &saved_player.chain {
+            let mut segment = ChainSegment::new(
+                player_chain.segments.len(),
+                saved_segment.option_text.clone(),
+                saved_segment.option_id,
+                Srgba::from_f32_array(saved_segment.color).into(),
+            );
+            segment.level = saved_segment.level.clamp(1, 3);
+            segment.merge_value = saved_segment.merge_value.max(1);
+            segment.durability = saved_segment.durability;
+            segment.category = saved_segment.category.clone();
+            segment.contributors = saved_segment
+                .contributors
+                .iter()
+                .filter_map(|slot| slots.get(slot).copied())
+                .collect();
+            if segment.contributors.is_empty() {
+                segment.contributors.push(entity);
+            }
+
+            crate::chain::spawn_chain_segment(
+                &mut commands,
+                entity,
+                saved_segment.position.map_or(position, Vec2::from_array),
+                segment,
+                saved_segment.cursed,
+                &mut player_chain,
+                &segment_assets.option_styles,
+                &mut segment_assets.asset_cache,
+                &mut segment_assets.meshes,
+                &mut segment_assets.materials,
+            );
+        }
+    }
+
+    info!(
+        "Restored suspended round of challenge {}",
+        saved.challenge_id
+    );
+    notifications.write(NotificationEvent::new(locale.text("suspend.welcome_back")));
+    commands.remove_resource::<PendingResume>();
+}
+
+// Suspend constants
+pub const SAVED_GAME_STORAGE_KEY: &str = "saved_game";
@@ -0,0 +1,22 @@
+//! Helpers to keep text readable on colored backgrounds.
+
+use bevy::prelude::*;
+
+/// Relative luminance of a color as defined by WCAG, from 0.0 (black) to 1.0 (white).
+pub fn relative_luminance(color: Color) -> f32 {
+    let linear = color.to_linear();
+    0.2126 * linear.red + 0.7152 * linear.green + 0.0722 * linear.blue
+}
+
+/// Black or white, whichever has the higher contrast ratio against `background`.
+pub fn contrasting_text_color(background: Color) -> Color {
+    let luminance = relative_luminance(background);
+    let contrast_with_white = 1.05 / (luminance + 0.05);
+    let contrast_with_black = (luminance + 0.05) / 0.05;
+
+    if contrast_with_black > contrast_with_white {
+        Color::BLACK
+    } else {
+        Color::WHITE
+    }
+}
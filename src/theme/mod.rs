@@ -3,13 +3,17 @@
 // Unused utilities may trigger this lints undesirably.
 #![allow(dead_code)]
 
+pub mod contrast;
 pub mod interaction;
 pub mod palette;
 pub mod widget;
 
 #[allow(unused_imports)]
 pub mod prelude {
-    pub use super::{interaction::InteractionPalette, palette as ui_palette, widget};
+    pub use super::{
+        contrast::contrasting_text_color, interaction::InteractionPalette, palette as ui_palette,
+        widget,
+    };
 }
 
 use bevy::prelude::*;
@@ -0,0 +1,289 @@
+//! Two-thumb touch layout: a joystick bottom-left for the left thumb and a
+//! dash button bottom-right for the right one. Every touch is tracked by its
+//! own id, so steering and dashing work at the same time.
+
+use bevy::prelude::*;
+use konnektoren_bevy::input::{InputController, PlayerInputMapping};
+
+use crate::{
+    bot::BotController,
+    gameplay::HudLayout,
+    player::{Dash, Player, PlayerDashEvent, PlayerIndex},
+    screens::Screen,
+    settings::GameSettings,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<TwoThumbState>();
+
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        (reset_two_thumb_state, spawn_two_thumb_controls),
+    );
+    app.add_systems(
+        Update,
+        (
+            handle_two_thumb_touches
+                .in_set(crate::AppSystems::RecordInput)
+                .in_set(crate::PausableSystems),
+            (update_two_thumb_layout, update_two_thumb_visuals).in_set(crate::AppSystems::Update),
+        )
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// Touches currently holding the joystick and the dash button
+#[derive(Resource, Default, Debug)]
+pub struct TwoThumbState {
+    stick_touch: Option<u64>,
+    button_touch: Option<u64>,
+    /// Stick deflection, y up, at most length 1
+    movement: Vec2,
+}
+
+/// Round base the joystick knob moves in
+#[derive(Component)]
+struct TwoThumbStick;
+
+#[derive(Component)]
+struct TwoThumbKnob;
+
+#[derive(Component)]
+struct TwoThumbButton;
+
+fn reset_two_thumb_state(mut state: ResMut<TwoThumbState>) {
+    *state = TwoThumbState::default();
+}
+
+fn spawn_two_thumb_controls(mut commands: Commands, game_settings: Res<GameSettings>) {
+    let multiplayer = &game_settings.multiplayer;
+    let needed = multiplayer
+        .players
+        .iter()
+        .take(multiplayer.player_count)
+        .any(|player| player.input.uses_two_thumb());
+    if !needed {
+        return;
+    }
+
+    let knob_offset = (STICK_BASE_SIZE - STICK_KNOB_SIZE) / 2.0;
+    commands.spawn((
+        Name::new("Two-Thumb Joystick"),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(TOUCH_CONTROLS_MARGIN),
+            bottom: Val::Px(TOUCH_CONTROLS_MARGIN),
+            width: Val::Px(STICK_BASE_SIZE),
+            height: Val::Px(STICK_BASE_SIZE),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.12)),
+        BorderRadius::MAX,
+        Pickable::IGNORE,
+        TwoThumbStick,
+        StateScoped(Screen::Gameplay),
+        children![(
+            Name::new("Two-Thumb Joystick Knob"),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(knob_offset),
+                top: Val::Px(knob_offset),
+                width: Val::Px(STICK_KNOB_SIZE),
+                height: Val::Px(STICK_KNOB_SIZE),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.45)),
+            BorderRadius::MAX,
+            Pickable::IGNORE,
+            TwoThumbKnob,
+        )],
+    ));
+
+    commands.spawn((
+        Name::new("Two-Thumb Dash Button"),
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(TOUCH_CONTROLS_MARGIN),
+            bottom: Val::Px(TOUCH_CONTROLS_MARGIN),
+            width: Val::Px(DASH_BUTTON_SIZE),
+            height: Val::Px(DASH_BUTTON_SIZE),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(DASH_BUTTON_COLOR),
+        BorderRadius::MAX,
+        Pickable::IGNORE,
+        TwoThumbButton,
+        StateScoped(Screen::Gameplay),
+        children![(
+            Name::new("Two-Thumb Dash Label"),
+            Text::new("Dash"),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Pickable::IGNORE,
+        )],
+    ));
+}
+
+/// Center and radius of a UI node in logical pixels, the space touches are reported in
+fn node_circle(node: &ComputedNode, transform: &GlobalTransform) -> (Vec2, f32) {
+    let scale = node.inverse_scale_factor();
+    (
+        transform.translation().truncate() * scale,
+        node.size().x * scale / 2.0,
+    )
+}
+
+fn handle_two_thumb_touches(
+    touches: Res<Touches>,
+    game_settings: Res<GameSettings>,
+    mut state: ResMut<TwoThumbState>,
+    stick_query: Query<(&ComputedNode, &GlobalTransform), With<TwoThumbStick>>,
+    button_query: Query<(&ComputedNode, &GlobalTransform), With<TwoThumbButton>>,
+    mut player_query: Query<
+        (Entity, &PlayerIndex, &mut InputController),
+        (
+            With<Player>,
+            Without<PlayerInputMapping>,
+            Without<BotController>,
+        ),
+    >,
+    mut dash_events: EventWriter<PlayerDashEvent>,
+) {
+    let (Ok(stick), Ok(button)) = (stick_query.single(), button_query.single()) else {
+        return;
+    };
+    let (stick_center, stick_radius) = node_circle(stick.0, stick.1);
+    let (button_center, button_radius) = node_circle(button.0, button.1);
+
+    let mut dash = false;
+    for touch in touches.iter_just_pressed() {
+        let position = touch.position();
+        if state.stick_touch.is_none()
+            && position.distance(stick_center) <= stick_radius * STICK_CAPTURE_FACTOR
+        {
+            state.stick_touch = Some(touch.id());
+        } else if state.button_touch.is_none()
+            && position.distance(button_center) <= button_radius * DASH_BUTTON_CAPTURE_FACTOR
+        {
+            state.button_touch = Some(touch.id());
+            dash = true;
+        }
+    }
+
+    for touch in touches
+        .iter_just_released()
+        .chain(touches.iter_just_canceled())
+    {
+        if state.stick_touch == Some(touch.id()) {
+            state.stick_touch = None;
+            state.movement = Vec2::ZERO;
+        }
+        if state.button_touch == Some(touch.id()) {
+            state.button_touch = None;
+        }
+    }
+
+    if let Some(touch) = state.stick_touch.and_then(|id| touches.get_pressed(id)) {
+        // Screen y points down, movement y points up
+        let offset = touch.position() - stick_center;
+        let deflection = Vec2::new(offset.x, -offset.y) / stick_radius.max(1.0);
+        state.movement = if deflection.length() < STICK_DEAD_ZONE {
+            Vec2::ZERO
+        } else {
+            deflection.clamp_length_max(1.0)
+        };
+    }
+
+    for (entity, player_index, mut input_controller) in &mut player_query {
+        let uses_two_thumb = game_settings
+            .multiplayer
+            .players
+            .get(player_index.0)
+            .is_some_and(|player| player.input.uses_two_thumb());
+        if !uses_two_thumb {
+            continue;
+        }
+
+        if input_controller.movement != state.movement {
+            input_controller.movement = state.movement;
+        }
+        if dash {
+            dash_events.write(PlayerDashEvent {
+                player_entity: entity,
+            });
+        }
+    }
+}
+
+/// Keep both controls clear of notches and the home indicator
+fn update_two_thumb_layout(
+    hud_layout: Res<HudLayout>,
+    added_controls: Query<(), Or<(Added<TwoThumbStick>, Added<TwoThumbButton>)>>,
+    mut stick_query: Query<&mut Node, With<TwoThumbStick>>,
+    mut button_query: Query<&mut Node, (With<TwoThumbButton>, Without<TwoThumbStick>)>,
+) {
+    if !hud_layout.is_changed() && added_controls.is_empty() {
+        return;
+    }
+
+    let safe_area = hud_layout.safe_area;
+    for mut node in &mut stick_query {
+        node.left = Val::Px(TOUCH_CONTROLS_MARGIN + safe_area.left);
+        node.bottom = Val::Px(TOUCH_CONTROLS_MARGIN + safe_area.bottom);
+    }
+    for mut node in &mut button_query {
+        node.right = Val::Px(TOUCH_CONTROLS_MARGIN + safe_area.right);
+        node.bottom = Val::Px(TOUCH_CONTROLS_MARGIN + safe_area.bottom);
+    }
+}
+
+/// Move the knob with the stick and dim the button while the dash recharges
+fn update_two_thumb_visuals(
+    state: Res<TwoThumbState>,
+    game_settings: Res<GameSettings>,
+    player_query: Query<(&PlayerIndex, &Dash), With<Player>>,
+    mut knob_query: Query<&mut Node, With<TwoThumbKnob>>,
+    mut button_query: Query<&mut BackgroundColor, With<TwoThumbButton>>,
+) {
+    let travel = (STICK_BASE_SIZE - STICK_KNOB_SIZE) / 2.0;
+    for mut node in &mut knob_query {
+        node.left = Val::Px(travel + state.movement.x * travel);
+        node.top = Val::Px(travel - state.movement.y * travel);
+    }
+
+    let dash_ready = player_query.iter().any(|(player_index, dash)| {
+        dash.is_ready()
+            && game_settings
+                .multiplayer
+                .players
+                .get(player_index.0)
+                .is_some_and(|player| player.input.uses_two_thumb())
+    });
+    let color = if state.button_touch.is_some() {
+        DASH_BUTTON_PRESSED_COLOR
+    } else if dash_ready {
+        DASH_BUTTON_COLOR
+    } else {
+        DASH_BUTTON_COOLDOWN_COLOR
+    };
+    for mut background in &mut button_query {
+        background.set_if_neq(BackgroundColor(color));
+    }
+}
+
+// Two-thumb touch control constants
+pub const TOUCH_CONTROLS_MARGIN: f32 = 30.0;
+pub const STICK_BASE_SIZE: f32 = 140.0;
+pub const STICK_KNOB_SIZE: f32 = 60.0;
+pub const STICK_DEAD_ZONE: f32 = 0.15; // Deflection ignored around the center
+pub const STICK_CAPTURE_FACTOR: f32 = 1.5; // Touches this far out still grab the stick
+pub const DASH_BUTTON_SIZE: f32 = 100.0;
+pub const DASH_BUTTON_CAPTURE_FACTOR: f32 = 1.3;
+pub const DASH_BUTTON_COLOR: Color = Color::srgba(0.9, 0.5, 0.2, 0.6);
+pub const DASH_BUTTON_PRESSED_COLOR: Color = Color::srgba(1.0, 0.7, 0.3, 0.85);
+pub const DASH_BUTTON_COOLDOWN_COLOR: Color = Color::srgba(0.5, 0.5, 0.5, 0.35);
@@ -0,0 +1,254 @@
+//! Transitions between screens and menus. Instead of an instant cut, a cover
+//! spawns over the entering state, stays closed for a frame or two while the
+//! state sets itself up and then opens with a fade, slide or wipe before it
+//! despawns. Screens fade (the lobby wipes in), menus slide. The loading
+//! screen darkens as loading progresses and only hands over to gameplay once
+//! fully covered, so the board appears out of a fade rather than popping in.
+//!
+//! The cover is painted on egui's foreground layer so it sits above both the
+//! egui menus and the game's own UI.
+
+use bevy::prelude::*;
+use bevy_egui::{EguiContextPass, egui};
+
+use crate::{
+    asset_tracking::ResourceHandles,
+    game_state::GameState,
+    menus::Menu,
+    screens::Screen,
+    settings::{EffectsIntensity, GameSettings},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<TransitionCover>();
+
+    app.add_systems(OnEnter(Screen::Loading), start_loading_cover);
+    app.add_systems(OnExit(Screen::Loading), end_loading_cover);
+    app.add_systems(
+        Update,
+        (
+            cover_screen_changes,
+            cover_menu_changes,
+            advance_transition_covers,
+            follow_loading_progress.run_if(resource_exists::<LoadingCover>),
+        )
+            .chain(),
+    );
+    app.add_systems(EguiContextPass, draw_transition_covers);
+}
+
+/// How a cover uncovers the entering state
+#[derive(Reflect, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransitionKind {
+    Fade,
+    /// The cover moves out to the left
+    Slide,
+    /// The cover is cut away from the left edge
+    Wipe,
+}
+
+/// Cover over the state being entered, opened once the state is set up
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct TransitionCover {
+    pub kind: TransitionKind,
+    pub timer: Timer,
+    /// Frames the cover stays closed so the entering state can set itself up
+    pub hold_frames: u32,
+}
+
+impl TransitionCover {
+    pub fn new(kind: TransitionKind, duration: f32) -> Self {
+        Self {
+            kind,
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+            hold_frames: TRANSITION_HOLD_FRAMES,
+        }
+    }
+
+    /// How much of the screen is still covered, from 1.0 down to 0.0
+    pub fn coverage(&self) -> f32 {
+        let t = self.timer.fraction_remaining();
+        // Ease out so the opening starts quick and settles gently
+        1.0 - (1.0 - t) * (2.0 - (1.0 - t))
+    }
+}
+
+/// Resource present on the loading screen with how dark the screen has become
+#[derive(Resource, Debug, Default)]
+pub struct LoadingCover {
+    pub alpha: f32,
+}
+
+/// The loading screen is fully covered and gameplay can take over
+pub fn loading_cover_closed(cover: Option<Res<LoadingCover>>) -> bool {
+    cover.is_none_or(|cover| cover.alpha >= 1.0)
+}
+
+fn cover_screen_changes(
+    mut commands: Commands,
+    mut transitions: EventReader<StateTransitionEvent<Screen>>,
+    game_settings: Res<GameSettings>,
+    cover_query: Query<Entity, With<TransitionCover>>,
+) {
+    for transition in transitions.read() {
+        let (Some(exited), Some(entered)) = (transition.exited, transition.entered) else {
+            continue;
+        };
+        if exited == entered {
+            continue;
+        }
+
+        let (kind, duration) = match (exited, entered) {
+            (Screen::Loading, Screen::Gameplay) => {
+                (TransitionKind::Fade, LOADING_TRANSITION_DURATION)
+            }
+            (_, Screen::Lobby) => (TransitionKind::Wipe, SCREEN_TRANSITION_DURATION),
+            _ => (TransitionKind::Fade, SCREEN_TRANSITION_DURATION),
+        };
+        for entity in &cover_query {
+            commands.entity(entity).despawn();
+        }
+        commands.spawn((
+            Name::new("Screen Transition Cover"),
+            TransitionCover::new(calm_kind(kind, &game_settings), duration),
+        ));
+    }
+}
+
+fn cover_menu_changes(
+    mut commands: Commands,
+    mut transitions: EventReader<StateTransitionEvent<Menu>>,
+    game_settings: Res<GameSettings>,
+    cover_query: Query<(), With<TransitionCover>>,
+) {
+    for transition in transitions.read() {
+        let (Some(exited), Some(entered)) = (transition.exited, transition.entered) else {
+            continue;
+        };
+        // Closing a menu drops straight back into the game
+        if exited == entered || entered == Menu::None {
+            continue;
+        }
+        // A screen change is already covered
+        if !cover_query.is_empty() {
+            continue;
+        }
+
+        commands.spawn((
+            Name::new("Menu Transition Cover"),
+            TransitionCover::new(
+                calm_kind(TransitionKind::Slide, &game_settings),
+                MENU_TRANSITION_DURATION,
+            ),
+        ));
+    }
+}
+
+/// Slides and wipes become fades when motion is kept to a minimum
+fn calm_kind(kind: TransitionKind, game_settings: &GameSettings) -> TransitionKind {
+    if game_settings.accessibility.effects_intensity == EffectsIntensity::Minimal {
+        TransitionKind::Fade
+    } else {
+        kind
+    }
+}
+
+/// Runs on real time so covers still open while the game is paused
+fn advance_transition_covers(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut cover_query: Query<(Entity, &mut TransitionCover)>,
+) {
+    for (entity, mut cover) in &mut cover_query {
+        if cover.hold_frames > 0 {
+            cover.hold_frames -= 1;
+            continue;
+        }
+
+        cover.timer.tick(time.delta());
+        if cover.timer.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn start_loading_cover(mut commands: Commands) {
+    commands.init_resource::<LoadingCover>();
+}
+
+fn end_loading_cover(mut commands: Commands) {
+    commands.remove_resource::<LoadingCover>();
+}
+
+/// Darken the loading screen with the loading progress and close it completely
+/// once everything is ready
+fn follow_loading_progress(
+    time: Res<Time<Real>>,
+    game_state: Res<GameState>,
+    handles: Res<ResourceHandles>,
+    mut loading_cover: ResMut<LoadingCover>,
+) {
+    let target = if game_state.is_ready() && handles.is_all_done() {
+        1.0
+    } else {
+        game_state.loading_progress(&handles) * LOADING_COVER_PROGRESS_ALPHA
+    };
+    let step = time.delta_secs() / LOADING_TRANSITION_DURATION;
+    loading_cover.alpha = if loading_cover.alpha < target {
+        (loading_cover.alpha + step).min(target)
+    } else {
+        target
+    };
+}
+
+fn draw_transition_covers(
+    mut contexts: bevy_egui::EguiContexts,
+    cover_query: Query<&TransitionCover>,
+    loading_cover: Option<Res<LoadingCover>>,
+) {
+    if cover_query.is_empty() && loading_cover.is_none() {
+        return;
+    }
+
+    let ctx = contexts.ctx_mut();
+    let screen = ctx.screen_rect();
+    let painter = ctx.layer_painter(egui::LayerId::new(
+        egui::Order::Foreground,
+        egui::Id::new("transition_cover"),
+    ));
+    let color = |alpha: f32| {
+        let [red, green, blue, _] = TRANSITION_COVER_COLOR.to_srgba().to_u8_array();
+        egui::Color32::from_rgba_unmultiplied(red, green, blue, (alpha * 255.0) as u8)
+    };
+
+    if let Some(loading_cover) = &loading_cover {
+        painter.rect_filled(screen, 0.0, color(loading_cover.alpha));
+    }
+
+    for cover in &cover_query {
+        let coverage = cover.coverage();
+        match cover.kind {
+            TransitionKind::Fade => {
+                painter.rect_filled(screen, 0.0, color(coverage));
+            }
+            TransitionKind::Slide => {
+                let offset = screen.width() * (1.0 - coverage);
+                painter.rect_filled(screen.translate(egui::vec2(-offset, 0.0)), 0.0, color(1.0));
+            }
+            TransitionKind::Wipe => {
+                let mut rect = screen;
+                rect.min.x = screen.max.x - screen.width() * coverage;
+                painter.rect_filled(rect, 0.0, color(1.0));
+            }
+        }
+    }
+}
+
+// Transition constants
+pub const SCREEN_TRANSITION_DURATION: f32 = 0.4;
+pub const MENU_TRANSITION_DURATION: f32 = 0.25;
+pub const LOADING_TRANSITION_DURATION: f32 = 0.6;
+pub const TRANSITION_HOLD_FRAMES: u32 = 2; // Let the entering state spawn and lay out its UI
+pub const LOADING_COVER_PROGRESS_ALPHA: f32 = 0.5; // Darkest the loading screen gets before it's ready
+pub const TRANSITION_COVER_COLOR: Color = Color::srgb(0.02, 0.02, 0.05);
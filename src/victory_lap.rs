@@ -0,0 +1,253 @@
+//! Victory lap: when the round ends, the winner's chain lifts off the board and
+//! circles the camera behind the round review, trailing sparkles. The segments
+//! are handed over as copies scoped to the review menu, so the round's own
+//! chain still goes away with the gameplay screen while the lap lives on for
+//! as long as the results are shown.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::{
+    chain::PlayerChain,
+    effects::{CelebrationKind, SpawnCelebrationEvent},
+    gameplay::{GameTimerEvent, GameplayScore},
+    menus::Menu,
+    player::PlayerIndex,
+    rng::GameRng,
+    screens::Screen,
+    settings::GameSettings,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        start_victory_lap.run_if(in_state(Screen::Gameplay).and(on_event::<GameTimerEvent>)),
+    );
+    app.add_systems(
+        Update,
+        (
+            orbit_victory_lap,
+            spawn_victory_sparkles,
+            update_victory_sparkles,
+        )
+            .run_if(in_state(Menu::Review).and(resource_exists::<VictoryLap>)),
+    );
+    app.add_systems(OnExit(Menu::Review), end_victory_lap);
+}
+
+/// Resource present while the winner's chain circles the results
+#[derive(Resource, Debug)]
+pub struct VictoryLap {
+    pub winner_name: String,
+    angle: f32,
+    sparkle_timer: Timer,
+    sparkle_mesh: Handle<Mesh>,
+    sparkle_material: Handle<ColorMaterial>,
+}
+
+/// Copy of a winning chain segment, `slot` of `count` places around the ring
+#[derive(Component, Debug)]
+struct VictoryLapSegment {
+    slot: usize,
+    count: usize,
+}
+
+#[derive(Component, Debug)]
+struct VictorySparkle {
+    velocity: Vec2,
+    lifetime: Timer,
+}
+
+fn start_victory_lap(
+    mut commands: Commands,
+    mut timer_events: EventReader<GameTimerEvent>,
+    gameplay_score: Res<GameplayScore>,
+    game_settings: Res<GameSettings>,
+    chain_query: Query<&PlayerChain>,
+    player_query: Query<&PlayerIndex>,
+    segment_query: Query<(&Mesh2d, &MeshMaterial2d<ColorMaterial>, &Transform)>,
+    camera_query: Query<&Transform, With<Camera2d>>,
+    mut celebration_events: EventWriter<SpawnCelebrationEvent>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    if !timer_events
+        .read()
+        .any(|event| matches!(event, GameTimerEvent::GameEnded))
+    {
+        return;
+    }
+
+    let Some((&winner, winner_score)) = gameplay_score
+        .players
+        .iter()
+        .max_by_key(|(_, score)| score.total_score)
+    else {
+        return;
+    };
+
+    // A co-op team chain isn't held by the winner, so fall back to the longest one
+    let segments = chain_query
+        .get(winner)
+        .ok()
+        .filter(|chain| !chain.segments.is_empty())
+        .or_else(|| chain_query.iter().max_by_key(|chain| chain.segments.len()))
+        .map(|chain| chain.segments.clone())
+        .unwrap_or_default();
+    if segments.is_empty() {
+        return;
+    }
+
+    let color = player_query
+        .get(winner)
+        .ok()
+        .and_then(|index| game_settings.multiplayer.players.get(index.0))
+        .map_or(Color::srgb(1.0, 0.85, 0.2), |player| player.color);
+
+    let count = segments.len();
+    for (slot, &segment) in segments.iter().enumerate() {
+        let Ok((mesh, material, transform)) = segment_query.get(segment) else {
+            continue;
+        };
+        commands.spawn((
+            Name::new("Victory Lap Segment"),
+            Mesh2d(mesh.0.clone()),
+            MeshMaterial2d(material.0.clone()),
+            Transform::from_translation(transform.translation.with_z(VICTORY_LAP_Z)),
+            VictoryLapSegment { slot, count },
+            StateScoped(Menu::Review),
+        ));
+    }
+
+    // Announce the winner with confetti and fireworks around the camera
+    if let Ok(camera) = camera_query.single() {
+        let center = camera.translation.with_z(0.0);
+        celebration_events.write(SpawnCelebrationEvent::new(
+            center,
+            CelebrationKind::Confetti,
+            color,
+        ));
+        celebration_events.write(SpawnCelebrationEvent::new(
+            center,
+            CelebrationKind::FireworkRing,
+            color,
+        ));
+    }
+
+    info!(
+        "{} takes a victory lap with {} segments",
+        winner_score.player_name, count
+    );
+    commands.insert_resource(VictoryLap {
+        winner_name: winner_score.player_name.clone(),
+        angle: 0.0,
+        sparkle_timer: Timer::from_seconds(VICTORY_SPARKLE_INTERVAL, TimerMode::Repeating),
+        sparkle_mesh: meshes.add(Circle::new(VICTORY_SPARKLE_RADIUS)),
+        sparkle_material: materials.add(color.lighter(0.2)),
+    });
+}
+
+/// Fly the copies onto a ring around the camera and turn it. The round is
+/// paused behind the review, so this runs on real time.
+fn orbit_victory_lap(
+    time: Res<Time<Real>>,
+    mut victory_lap: ResMut<VictoryLap>,
+    camera_query: Query<&Transform, (With<Camera2d>, Without<VictoryLapSegment>)>,
+    mut segment_query: Query<(&VictoryLapSegment, &mut Transform)>,
+) {
+    let Ok(camera) = camera_query.single() else {
+        return;
+    };
+    let delta = time.delta_secs();
+    victory_lap.angle = (victory_lap.angle + VICTORY_LAP_SPEED * delta) % TAU;
+
+    let center = camera.translation.truncate();
+    let radius = VICTORY_LAP_RADIUS * camera.scale.x;
+    let follow = 1.0 - (-VICTORY_LAP_FOLLOW * delta).exp();
+    for (segment, mut transform) in &mut segment_query {
+        let angle = victory_lap.angle + segment.slot as f32 * TAU / segment.count as f32;
+        let target = center + Vec2::from_angle(angle) * radius;
+        let position = transform.translation.truncate().lerp(target, follow);
+        transform.translation = position.extend(VICTORY_LAP_Z);
+        transform.rotation = Quat::from_rotation_z(angle);
+    }
+}
+
+fn spawn_victory_sparkles(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut victory_lap: ResMut<VictoryLap>,
+    mut game_rng: ResMut<GameRng>,
+    game_settings: Res<GameSettings>,
+    segment_query: Query<&Transform, With<VictoryLapSegment>>,
+) {
+    if !victory_lap.sparkle_timer.tick(time.delta()).just_finished() {
+        return;
+    }
+    let count = game_settings
+        .accessibility
+        .effects_intensity
+        .particle_count(VICTORY_SPARKLES_PER_BURST);
+    if count == 0 {
+        return;
+    }
+
+    let rng = game_rng.effects();
+    let positions: Vec<Vec2> = segment_query
+        .iter()
+        .map(|transform| transform.translation.truncate())
+        .collect();
+    if positions.is_empty() {
+        return;
+    }
+
+    for _ in 0..count {
+        let origin = positions[rng.gen_range(0..positions.len())];
+        let direction = Vec2::from_angle(rng.gen_range(0.0..TAU));
+        let speed = rng.gen_range(VICTORY_SPARKLE_MIN_SPEED..VICTORY_SPARKLE_MAX_SPEED);
+        commands.spawn((
+            Name::new("Victory Sparkle"),
+            Mesh2d(victory_lap.sparkle_mesh.clone()),
+            MeshMaterial2d(victory_lap.sparkle_material.clone()),
+            Transform::from_translation(origin.extend(VICTORY_LAP_Z + 0.1)),
+            VictorySparkle {
+                velocity: direction * speed,
+                lifetime: Timer::from_seconds(VICTORY_SPARKLE_LIFETIME, TimerMode::Once),
+            },
+            StateScoped(Menu::Review),
+        ));
+    }
+}
+
+fn update_victory_sparkles(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut sparkle_query: Query<(Entity, &mut VictorySparkle, &mut Transform)>,
+) {
+    for (entity, mut sparkle, mut transform) in &mut sparkle_query {
+        if sparkle.lifetime.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        transform.translation += (sparkle.velocity * time.delta_secs()).extend(0.0);
+        transform.scale = Vec3::splat(1.0 - sparkle.lifetime.fraction());
+    }
+}
+
+fn end_victory_lap(mut commands: Commands) {
+    commands.remove_resource::<VictoryLap>();
+}
+
+// Victory lap constants
+pub const VICTORY_LAP_Z: f32 = 5.0; // Above the board and its effects
+pub const VICTORY_LAP_RADIUS: f32 = 220.0;
+pub const VICTORY_LAP_SPEED: f32 = 0.8; // Radians per second
+pub const VICTORY_LAP_FOLLOW: f32 = 3.0; // How quickly segments settle onto the ring
+pub const VICTORY_SPARKLE_INTERVAL: f32 = 0.15;
+pub const VICTORY_SPARKLES_PER_BURST: usize = 3;
+pub const VICTORY_SPARKLE_RADIUS: f32 = 3.0;
+pub const VICTORY_SPARKLE_LIFETIME: f32 = 0.9;
+pub const VICTORY_SPARKLE_MIN_SPEED: f32 = 30.0;
+pub const VICTORY_SPARKLE_MAX_SPEED: f32 = 90.0;
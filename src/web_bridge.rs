@@ -0,0 +1,335 @@
+//! Bridge to the page embedding the web build, e.g. on konnektoren.help. The
+//! host page steers the game through `postMessage` (start a challenge, switch
+//! the language, mute the audio) and hears back when the game is ready and
+//! when a round has finished.
+//!
+//! Messages are JSON objects tagged by `type`, e.g.
+//! `{"type": "start_challenge", "challenge_id": "articles"}` from the host and
+//! `{"source": "konnektoren-chain-game", "type": "game_finished", ...}` back.
+//! Only pages on an allowed origin are listened to and posted to.
+
+use std::sync::{Arc, Mutex};
+
+use bevy::{audio::Volume, prelude::*};
+use konnektoren_bevy::assets::KonnektorenAssetRegistry;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{JsCast, prelude::Closure};
+
+use crate::{
+    game_state::GameState,
+    gameplay::{GameTimerEvent, GameplayScore},
+    locale::Language,
+    player::PlayerIndex,
+    round::RoundInfo,
+    screens::Screen,
+    settings::GameSettings,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<WebBridge>();
+
+    app.add_systems(Startup, (listen_for_host_messages, post_ready).chain());
+    app.add_systems(
+        Update,
+        (
+            handle_host_commands,
+            post_game_finished.run_if(in_state(Screen::Gameplay)),
+        ),
+    );
+}
+
+/// Commands the host page can send
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HostCommand {
+    /// Start a round of a challenge right away
+    StartChallenge { challenge_id: String },
+    /// Switch the UI language by code, e.g. `de`
+    SetLanguage { language: String },
+    /// Silence or restore all game audio
+    SetMuted { muted: bool },
+}
+
+/// Messages the game posts to the host page
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GameMessage {
+    /// The game listens for commands now
+    Ready { version: String },
+    /// A round ended, player one's result first
+    GameFinished {
+        challenge_id: String,
+        score: i32,
+        correct_answers: u32,
+        wrong_answers: u32,
+        players: Vec<PlayerFinished>,
+    },
+    /// A `start_challenge` named a challenge the game doesn't know
+    UnknownChallenge { challenge_id: String },
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct PlayerFinished {
+    pub name: String,
+    pub score: i32,
+}
+
+/// Outgoing envelope so the host can tell the game's messages apart
+#[derive(Serialize)]
+struct OutgoingMessage<'a> {
+    source: &'static str,
+    #[serde(flatten)]
+    message: &'a GameMessage,
+}
+
+/// Resource shared with the browser's message listener
+#[derive(Resource, Default)]
+pub struct WebBridge {
+    commands: Arc<Mutex<Vec<HostCommand>>>,
+    /// Origin of the page that last sent a valid command
+    host_origin: Arc<Mutex<Option<String>>>,
+    /// Volume to restore once the host unmutes
+    muted_volume: Option<Volume>,
+}
+
+impl WebBridge {
+    fn take_commands(&self) -> Vec<HostCommand> {
+        self.commands
+            .lock()
+            .map(|mut commands| std::mem::take(&mut *commands))
+            .unwrap_or_default()
+    }
+
+    /// Hand a command back to be handled on a later frame
+    fn requeue(&self, command: HostCommand) {
+        if let Ok(mut commands) = self.commands.lock() {
+            commands.push(command);
+        }
+    }
+
+    /// Where messages go: the host that talked to us, or the embedding page
+    /// when it is allowed and hasn't said anything yet
+    fn target_origin(&self) -> Option<String> {
+        let known = self
+            .host_origin
+            .lock()
+            .ok()
+            .and_then(|origin| origin.clone());
+        known.or_else(|| {
+            let referrer = web_sys::window()?.document()?.referrer();
+            origin_of(&referrer).filter(|origin| is_allowed_origin(origin))
+        })
+    }
+
+    /// Post a message to the embedding page, if there is one
+    pub fn post(&self, message: &GameMessage) {
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let Some(parent) = window.parent().ok().flatten() else {
+            return;
+        };
+        // Not embedded, nobody to tell
+        if js_sys::Object::is(&parent, &window) {
+            return;
+        }
+        let Some(target_origin) = self.target_origin() else {
+            debug!("No allowed host origin to post {:?} to", message);
+            return;
+        };
+
+        let envelope = OutgoingMessage {
+            source: WEB_BRIDGE_SOURCE,
+            message,
+        };
+        let Some(data) = serde_json::to_string(&envelope)
+            .ok()
+            .and_then(|json| js_sys::JSON::parse(&json).ok())
+        else {
+            warn!("Failed to serialize host message {:?}", message);
+            return;
+        };
+        if let Err(err) = parent.post_message(&data, &target_origin) {
+            warn!("Failed to post to the host page: {:?}", err);
+        }
+    }
+}
+
+/// Origins allowed to steer the game: konnektoren.help, the game's own origin
+/// and any listed in `KONNEKTOREN_HOST_ORIGINS` at build time
+fn is_allowed_origin(origin: &str) -> bool {
+    let own_origin = web_sys::window().and_then(|window| window.location().origin().ok());
+    WEB_BRIDGE_ALLOWED_ORIGINS
+        .iter()
+        .copied()
+        .chain(
+            option_env!("KONNEKTOREN_HOST_ORIGINS")
+                .unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|origin| !origin.is_empty()),
+        )
+        .chain(own_origin.as_deref())
+        .any(|allowed| allowed.trim_end_matches('/') == origin)
+}
+
+/// `scheme://host[:port]` of a URL
+fn origin_of(url: &str) -> Option<String> {
+    let (scheme, rest) = url.split_once("://")?;
+    let host = rest.split(['/', '?', '#']).next()?;
+    (!host.is_empty()).then(|| format!("{}://{}", scheme, host))
+}
+
+/// Read a message event's data as a command, whether sent as an object or a
+/// JSON string
+fn parse_command(data: &wasm_bindgen::JsValue) -> Option<HostCommand> {
+    let json = match data.as_string() {
+        Some(json) => json,
+        None => js_sys::JSON::stringify(data).ok()?.as_string()?,
+    };
+    serde_json::from_str(&json)
+        .inspect_err(|err| debug!("Ignoring host message {}: {}", json, err))
+        .ok()
+}
+
+fn listen_for_host_messages(bridge: Res<WebBridge>) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+
+    let commands = bridge.commands.clone();
+    let host_origin = bridge.host_origin.clone();
+    let listener =
+        Closure::<dyn FnMut(web_sys::MessageEvent)>::new(move |event: web_sys::MessageEvent| {
+            let origin = event.origin();
+            if !is_allowed_origin(&origin) {
+                debug!("Ignoring message from origin {}", origin);
+                return;
+            }
+            let Some(command) = parse_command(&event.data()) else {
+                return;
+            };
+            if let Ok(mut host_origin) = host_origin.lock() {
+                *host_origin = Some(origin);
+            }
+            if let Ok(mut commands) = commands.lock() {
+                commands.push(command);
+            }
+        });
+
+    if let Err(err) =
+        window.add_event_listener_with_callback("message", listener.as_ref().unchecked_ref())
+    {
+        warn!("Failed to listen for host messages: {:?}", err);
+        return;
+    }
+    // The listener lives as long as the page
+    listener.forget();
+}
+
+fn post_ready(bridge: Res<WebBridge>) {
+    bridge.post(&GameMessage::Ready {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+    });
+}
+
+fn handle_host_commands(
+    mut bridge: ResMut<WebBridge>,
+    mut game_settings: ResMut<GameSettings>,
+    mut game_state: ResMut<GameState>,
+    asset_registry: Option<Res<KonnektorenAssetRegistry>>,
+    mut global_volume: ResMut<GlobalVolume>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    for command in bridge.take_commands() {
+        // Challenges aren't registered yet, try again on a later frame
+        if asset_registry.is_none() && matches!(command, HostCommand::StartChallenge { .. }) {
+            bridge.requeue(command);
+            continue;
+        }
+        info!("Host command: {:?}", command);
+        match command {
+            HostCommand::StartChallenge { challenge_id } => {
+                // An unknown id would leave the game stuck on the loading screen
+                let known = asset_registry
+                    .as_deref()
+                    .is_some_and(|registry| registry.get_challenge_handle(&challenge_id).is_some());
+                if !known {
+                    warn!("Host asked for unknown challenge '{}'", challenge_id);
+                    bridge.post(&GameMessage::UnknownChallenge { challenge_id });
+                    continue;
+                }
+                game_state.random_challenge = false;
+                game_state.select_challenge(challenge_id);
+                // Also restarts a round in progress through the loading screen
+                next_screen.set(Screen::Loading);
+            }
+            HostCommand::SetLanguage { language } => match Language::from_code(&language) {
+                Some(language) => game_settings.display.language = language,
+                None => warn!("Host asked for unknown language '{}'", language),
+            },
+            HostCommand::SetMuted { muted: true } => {
+                if bridge.muted_volume.is_none() {
+                    bridge.muted_volume = Some(global_volume.volume);
+                    global_volume.volume = Volume::Linear(0.0);
+                }
+            }
+            HostCommand::SetMuted { muted: false } => {
+                if let Some(volume) = bridge.muted_volume.take() {
+                    global_volume.volume = volume;
+                }
+            }
+        }
+    }
+}
+
+fn post_game_finished(
+    mut timer_events: EventReader<GameTimerEvent>,
+    bridge: Res<WebBridge>,
+    gameplay_score: Res<GameplayScore>,
+    round_info: Option<Res<RoundInfo>>,
+    player_query: Query<&PlayerIndex>,
+) {
+    let ended = timer_events
+        .read()
+        .any(|event| matches!(event, GameTimerEvent::GameEnded));
+    if !ended {
+        return;
+    }
+
+    let mut players: Vec<_> = gameplay_score
+        .players
+        .iter()
+        .map(|(entity, score)| {
+            let index = player_query
+                .get(*entity)
+                .map_or(usize::MAX, |index| index.0);
+            (index, score)
+        })
+        .collect();
+    players.sort_by_key(|(index, _)| *index);
+    let Some((_, player_one)) = players.first() else {
+        return;
+    };
+
+    bridge.post(&GameMessage::GameFinished {
+        challenge_id: round_info
+            .map(|round_info| round_info.challenge_id.clone())
+            .unwrap_or_default(),
+        score: player_one.total_score,
+        correct_answers: player_one.correct_answers,
+        wrong_answers: player_one.wrong_answers,
+        players: players
+            .iter()
+            .map(|(_, score)| PlayerFinished {
+                name: score.player_name.clone(),
+                score: score.total_score,
+            })
+            .collect(),
+    });
+}
+
+// Web bridge constants
+pub const WEB_BRIDGE_SOURCE: &str = "konnektoren-chain-game";
+pub const WEB_BRIDGE_ALLOWED_ORIGINS: [&str; 2] =
+    ["https://konnektoren.help", "https://www.konnektoren.help"];